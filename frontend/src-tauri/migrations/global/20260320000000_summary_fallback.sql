@@ -0,0 +1,3 @@
+-- Retry policy and ordered fallback models for summary generation, stored as JSON
+-- (see SummaryFallbackConfig)
+ALTER TABLE settings ADD COLUMN summaryFallbackConfig TEXT;
@@ -0,0 +1,3 @@
+-- Endpoint URL for remote transcription providers (OpenAI-compatible servers, Deepgram);
+-- NULL uses the provider's default
+ALTER TABLE transcript_settings ADD COLUMN endpoint TEXT;
@@ -0,0 +1,89 @@
+-- Full-text search index over transcripts and summaries (FTS5).
+-- Every statement is idempotent because workspace migrations run on each switch.
+
+-- Stable integer key of a transcript segment in the search index. The implicit rowid of
+-- transcripts (TEXT primary key) may be renumbered by VACUUM, so it can't be used.
+ALTER TABLE transcripts ADD COLUMN search_rowid INTEGER;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_transcripts_search_rowid ON transcripts(search_rowid);
+
+-- Number segments that predate the column, after any already numbered
+UPDATE transcripts
+SET search_rowid = rowid + (SELECT COALESCE(MAX(search_rowid), 0) FROM transcripts)
+WHERE search_rowid IS NULL;
+
+-- Transcript index: external content table backed by transcripts.search_rowid
+CREATE VIRTUAL TABLE IF NOT EXISTS transcripts_fts USING fts5(
+    transcript,
+    content='transcripts',
+    content_rowid='search_rowid',
+    tokenize='unicode61 remove_diacritics 2'
+);
+
+CREATE TRIGGER IF NOT EXISTS transcripts_fts_ai AFTER INSERT ON transcripts BEGIN
+    UPDATE transcripts
+    SET search_rowid = (SELECT COALESCE(MAX(search_rowid), 0) + 1 FROM transcripts)
+    WHERE id = new.id AND search_rowid IS NULL;
+    INSERT INTO transcripts_fts(rowid, transcript)
+    SELECT search_rowid, transcript FROM transcripts WHERE id = new.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS transcripts_fts_ad AFTER DELETE ON transcripts BEGIN
+    INSERT INTO transcripts_fts(transcripts_fts, rowid, transcript) VALUES ('delete', old.search_rowid, old.transcript);
+END;
+
+CREATE TRIGGER IF NOT EXISTS transcripts_fts_au AFTER UPDATE OF transcript ON transcripts BEGIN
+    INSERT INTO transcripts_fts(transcripts_fts, rowid, transcript) VALUES ('delete', old.search_rowid, old.transcript);
+    INSERT INTO transcripts_fts(rowid, transcript) VALUES (new.search_rowid, new.transcript);
+END;
+
+-- Backfill transcripts indexed before the table existed (no-op once populated)
+INSERT INTO transcripts_fts(transcripts_fts)
+SELECT 'rebuild'
+WHERE EXISTS (SELECT 1 FROM transcripts)
+  AND NOT EXISTS (SELECT 1 FROM transcripts_fts_docsize);
+
+-- Summary index: standalone table keyed by meeting_id (one summary per meeting).
+-- Only the markdown body of the result JSON is indexed.
+CREATE VIRTUAL TABLE IF NOT EXISTS summaries_fts USING fts5(
+    summary,
+    meeting_id UNINDEXED,
+    tokenize='unicode61 remove_diacritics 2'
+);
+
+CREATE TRIGGER IF NOT EXISTS summaries_fts_ai AFTER INSERT ON summary_processes
+WHEN new.result IS NOT NULL BEGIN
+    INSERT INTO summaries_fts(summary, meeting_id)
+    VALUES (
+        CASE WHEN json_valid(new.result)
+             THEN COALESCE(json_extract(new.result, '$.markdown'), '')
+             ELSE new.result END,
+        new.meeting_id
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS summaries_fts_ad AFTER DELETE ON summary_processes BEGIN
+    DELETE FROM summaries_fts WHERE meeting_id = old.meeting_id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS summaries_fts_au AFTER UPDATE OF result ON summary_processes BEGIN
+    DELETE FROM summaries_fts WHERE meeting_id = old.meeting_id;
+    INSERT INTO summaries_fts(summary, meeting_id)
+    SELECT
+        CASE WHEN json_valid(new.result)
+             THEN COALESCE(json_extract(new.result, '$.markdown'), '')
+             ELSE new.result END,
+        new.meeting_id
+    WHERE new.result IS NOT NULL;
+END;
+
+-- Backfill summaries that are not yet indexed
+INSERT INTO summaries_fts(summary, meeting_id)
+SELECT
+    CASE WHEN json_valid(sp.result)
+         THEN COALESCE(json_extract(sp.result, '$.markdown'), '')
+         ELSE sp.result END,
+    sp.meeting_id
+FROM summary_processes sp
+WHERE sp.result IS NOT NULL
+  AND sp.meeting_id NOT IN (SELECT meeting_id FROM summaries_fts);
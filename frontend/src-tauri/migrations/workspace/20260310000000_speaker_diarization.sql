@@ -0,0 +1,17 @@
+-- Speaker diarization: per-segment speaker labels and per-meeting speaker names.
+
+-- Diarized speaker label ("Speaker 1".."Speaker N"); `speaker` keeps the mic/system source
+ALTER TABLE transcripts ADD COLUMN speaker_label TEXT;
+
+-- Speakers detected in a meeting, with optional user-assigned names
+CREATE TABLE IF NOT EXISTS meeting_speakers (
+    meeting_id TEXT NOT NULL,
+    label TEXT NOT NULL,
+    display_name TEXT,
+    segment_count INTEGER NOT NULL DEFAULT 0,
+    total_duration REAL NOT NULL DEFAULT 0.0,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    PRIMARY KEY (meeting_id, label),
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
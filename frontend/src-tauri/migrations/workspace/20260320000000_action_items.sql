@@ -0,0 +1,33 @@
+-- Action items and decisions extracted from meeting summaries as structured JSON,
+-- so they can be tracked across meetings instead of living in the summary markdown.
+
+CREATE TABLE IF NOT EXISTS action_items (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    meeting_id TEXT NOT NULL,
+    text TEXT NOT NULL,
+    owner TEXT,
+    -- Due date as YYYY-MM-DD
+    due_date TEXT,
+    -- Seconds from recording start of the transcript segment the item came from
+    source_timestamp REAL,
+    -- "open" or "done"
+    status TEXT NOT NULL DEFAULT 'open',
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    completed_at TEXT,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_action_items_meeting ON action_items(meeting_id);
+CREATE INDEX IF NOT EXISTS idx_action_items_status ON action_items(status, due_date);
+
+CREATE TABLE IF NOT EXISTS decisions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    meeting_id TEXT NOT NULL,
+    text TEXT NOT NULL,
+    source_timestamp REAL,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_decisions_meeting ON decisions(meeting_id);
@@ -0,0 +1,15 @@
+-- Questions asked about a meeting and the grounded answers, in conversation order.
+
+CREATE TABLE IF NOT EXISTS meeting_chat_messages (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    meeting_id TEXT NOT NULL,
+    -- "user" or "assistant"
+    role TEXT NOT NULL,
+    content TEXT NOT NULL,
+    -- JSON array of transcript segments cited by an answer
+    citations_json TEXT,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_meeting_chat_messages_meeting ON meeting_chat_messages(meeting_id, id);
@@ -0,0 +1,25 @@
+-- Sentence embeddings of transcript segments for semantic search.
+-- A derived index: rows are rebuilt from transcripts and are not copied between workspaces.
+
+CREATE TABLE IF NOT EXISTS segment_embeddings (
+    transcript_id TEXT PRIMARY KEY,
+    meeting_id TEXT NOT NULL,
+    -- Embedding model the vector was computed with
+    model TEXT NOT NULL,
+    dimensions INTEGER NOT NULL,
+    -- Little-endian f32 values, L2-normalized
+    vector BLOB NOT NULL,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_segment_embeddings_model ON segment_embeddings(model);
+
+-- Drop vectors of deleted or edited segments so they are re-embedded
+CREATE TRIGGER IF NOT EXISTS segment_embeddings_ad AFTER DELETE ON transcripts BEGIN
+    DELETE FROM segment_embeddings WHERE transcript_id = old.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS segment_embeddings_au AFTER UPDATE OF transcript ON transcripts BEGIN
+    DELETE FROM segment_embeddings WHERE transcript_id = old.id;
+END;
@@ -0,0 +1,50 @@
+-- Every generated, edited or restored summary of a meeting.
+-- summary_processes.result holds the active summary; this table keeps its history.
+
+CREATE TABLE IF NOT EXISTS summary_versions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    meeting_id TEXT NOT NULL,
+    -- 1, 2, 3... within a meeting
+    version INTEGER NOT NULL,
+    -- 'generated', 'edited' or 'restored'
+    source TEXT NOT NULL,
+    -- Summary JSON in the same format as summary_processes.result
+    result TEXT NOT NULL,
+    provider TEXT,
+    model TEXT,
+    template_id TEXT,
+    -- User-provided context the summary was generated with
+    prompt TEXT,
+    -- Version a 'restored' row was copied from
+    restored_from INTEGER,
+    -- A pinned version stays the active summary when a new one is generated
+    pinned INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE,
+    UNIQUE (meeting_id, version)
+);
+
+CREATE INDEX IF NOT EXISTS idx_summary_versions_meeting ON summary_versions(meeting_id);
+
+-- Existing summaries seed the history of their meeting: the summary kept from before the
+-- last regeneration (result_backup) becomes version 1, the current one the latest version.
+-- SQLite reads the whole SELECT before inserting, so both rows pass the NOT EXISTS check.
+INSERT INTO summary_versions (meeting_id, version, source, result, created_at)
+SELECT s.meeting_id,
+       ROW_NUMBER() OVER (PARTITION BY s.meeting_id ORDER BY s.position),
+       'generated',
+       s.result,
+       s.created_at
+FROM (
+    SELECT p.meeting_id, 0 AS position, p.result_backup AS result,
+           COALESCE(p.result_backup_timestamp, p.updated_at) AS created_at
+    FROM summary_processes p
+    WHERE p.result_backup IS NOT NULL
+      AND p.result_backup IS NOT p.result
+    UNION ALL
+    SELECT p.meeting_id, 1 AS position, p.result AS result, p.updated_at AS created_at
+    FROM summary_processes p
+    WHERE p.result IS NOT NULL
+) s
+WHERE EXISTS (SELECT 1 FROM meetings m WHERE m.id = s.meeting_id)
+  AND NOT EXISTS (SELECT 1 FROM summary_versions v WHERE v.meeting_id = s.meeting_id);
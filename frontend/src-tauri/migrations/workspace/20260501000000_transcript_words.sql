@@ -0,0 +1,21 @@
+-- Word-level timing of transcript segments, for highlighting the spoken word during
+-- playback and for precise subtitles. Times are seconds from recording start, like the
+-- segment's audio_start_time.
+
+CREATE TABLE IF NOT EXISTS transcript_words (
+    transcript_id TEXT NOT NULL,
+    -- Position of the word within its segment
+    word_index INTEGER NOT NULL,
+    word TEXT NOT NULL,
+    start_time REAL NOT NULL,
+    end_time REAL NOT NULL,
+    -- Recognition probability (0.0-1.0); NULL if the engine doesn't report one
+    probability REAL,
+    PRIMARY KEY (transcript_id, word_index),
+    FOREIGN KEY (transcript_id) REFERENCES transcripts(id) ON DELETE CASCADE
+);
+
+-- Edited segments no longer match their words
+CREATE TRIGGER IF NOT EXISTS transcript_words_au AFTER UPDATE OF transcript ON transcripts BEGIN
+    DELETE FROM transcript_words WHERE transcript_id = old.id;
+END;
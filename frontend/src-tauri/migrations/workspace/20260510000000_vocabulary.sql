@@ -0,0 +1,8 @@
+-- Custom vocabulary of the workspace: product names, people and acronyms that
+-- transcription should spell correctly.
+
+CREATE TABLE IF NOT EXISTS vocabulary_terms (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    term TEXT NOT NULL UNIQUE COLLATE NOCASE,
+    created_at TEXT NOT NULL
+);
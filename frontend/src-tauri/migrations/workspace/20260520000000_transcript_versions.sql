@@ -0,0 +1,22 @@
+-- Transcripts replaced by a re-transcription or a restore, kept so they can be restored.
+-- The transcripts table holds the active transcript; this table keeps the earlier ones.
+
+CREATE TABLE IF NOT EXISTS transcript_versions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    meeting_id TEXT NOT NULL,
+    -- 1, 2, 3... within a meeting
+    version INTEGER NOT NULL,
+    -- What replaced this transcript: 'retranscribed' or 'restored'
+    reason TEXT NOT NULL,
+    -- Engine and model of the replacing transcript, for 'retranscribed'
+    replaced_by_provider TEXT,
+    replaced_by_model TEXT,
+    segment_count INTEGER NOT NULL,
+    -- JSON array of transcript segments (text, timing, source, word timings)
+    segments TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE,
+    UNIQUE (meeting_id, version)
+);
+
+CREATE INDEX IF NOT EXISTS idx_transcript_versions_meeting ON transcript_versions(meeting_id);
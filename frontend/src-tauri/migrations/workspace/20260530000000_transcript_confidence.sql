@@ -0,0 +1,7 @@
+-- Transcription confidence per segment, so uncertain stretches can be re-transcribed.
+
+-- Engine confidence (0.0-1.0); NULL when the engine doesn't report one
+ALTER TABLE transcripts ADD COLUMN confidence REAL;
+
+-- 1 when the segment fell below the live confidence threshold and is kept only for review
+ALTER TABLE transcripts ADD COLUMN low_confidence INTEGER NOT NULL DEFAULT 0;
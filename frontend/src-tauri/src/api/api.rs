@@ -5,11 +5,12 @@ use tauri::{AppHandle, Runtime};
 use tauri_plugin_store::StoreExt;
 
 use crate::{
+    audio::transcription::WordTiming,
     database::{
-        models::MeetingModel,
+        models::{MeetingModel, MeetingNoteEntry, MeetingNotes},
         repositories::{
-            meeting::MeetingsRepository, setting::SettingsRepository,
-            transcript::TranscriptsRepository,
+            meeting::MeetingsRepository, notes::MeetingNotesRepository,
+            setting::SettingsRepository, transcript::TranscriptsRepository,
         },
     },
     onboarding::load_onboarding_status,
@@ -45,6 +46,13 @@ pub struct TranscriptSearchResult {
     #[serde(rename = "matchContext")]
     pub match_context: String,
     pub timestamp: String,
+    /// Where the hit was found: "transcript" or "summary"
+    pub source: String,
+    #[serde(rename = "transcriptId", skip_serializing_if = "Option::is_none")]
+    pub transcript_id: Option<String>,
+    /// Recording-relative start of the matching segment, for seeking playback
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio_start_time: Option<f64>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -102,6 +110,9 @@ pub struct TranscriptConfig {
     pub model: String,
     #[serde(rename = "apiKey")]
     pub api_key: Option<String>,
+    /// Endpoint URL for remote providers; None uses the provider's default
+    #[serde(default)]
+    pub endpoint: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -110,6 +121,8 @@ pub struct SaveTranscriptConfigRequest {
     pub model: String,
     #[serde(rename = "apiKey")]
     pub api_key: Option<String>,
+    #[serde(default)]
+    pub endpoint: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -138,6 +151,21 @@ pub struct MeetingTranscript {
     pub audio_end_time: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<f64>,
+    // Source stream: "mic" or "system"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<String>,
+    // Diarized speaker label ("Speaker 1".."Speaker N")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker_label: Option<String>,
+    // Engine confidence (0.0-1.0), if the engine reported one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
+    // Below the live confidence threshold; candidate for re-transcription
+    #[serde(default)]
+    pub low_confidence: bool,
+    // Word timings in seconds from recording start; empty if the engine had none
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub words: Vec<WordTiming>,
 }
 
 /// Meeting metadata without transcripts (for pagination)
@@ -151,6 +179,35 @@ pub struct MeetingMetadata {
     pub folder_path: Option<String>,
 }
 
+/// User notes for a meeting, with timestamped entries linked to the recording
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MeetingNotesResponse {
+    pub meeting_id: String,
+    pub notes_markdown: Option<String>,
+    pub entries: Vec<MeetingNoteEntry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+impl MeetingNotesResponse {
+    fn from_notes(meeting_id: String, notes: Option<MeetingNotes>) -> Self {
+        match notes {
+            Some(notes) => Self {
+                entries: notes.entries(),
+                meeting_id,
+                notes_markdown: notes.notes_markdown,
+                updated_at: Some(notes.updated_at.to_rfc3339()),
+            },
+            None => Self {
+                meeting_id,
+                notes_markdown: None,
+                entries: Vec::new(),
+                updated_at: None,
+            },
+        }
+    }
+}
+
 /// Paginated transcripts response with total count
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PaginatedTranscriptsResponse {
@@ -189,6 +246,18 @@ pub struct TranscriptSegment {
     pub audio_end_time: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<f64>,
+    // Source stream: "mic" or "system"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<String>,
+    // Engine confidence (0.0-1.0), if the engine reported one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
+    // Below the live confidence threshold; candidate for re-transcription
+    #[serde(default)]
+    pub low_confidence: bool,
+    // Word timings in seconds from recording start
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub words: Vec<WordTiming>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -620,6 +689,7 @@ pub async fn api_get_transcript_config<R: Runtime>(
                         provider: config.provider,
                         model: config.model,
                         api_key,
+                        endpoint: config.endpoint,
                     }))
                 }
                 Err(e) => {
@@ -638,6 +708,7 @@ pub async fn api_get_transcript_config<R: Runtime>(
                 provider: "parakeet".to_string(),
                 model: "parakeet-tdt-0.6b-v3-int8".to_string(),
                 api_key: None,
+                endpoint: None,
             }))
         }
         Err(e) => {
@@ -654,6 +725,7 @@ pub async fn api_save_transcript_config<R: Runtime>(
     provider: String,
     model: String,
     api_key: Option<String>,
+    endpoint: Option<String>,
     _auth_token: Option<String>,
 ) -> Result<serde_json::Value, String> {
     log_info!(
@@ -662,7 +734,13 @@ pub async fn api_save_transcript_config<R: Runtime>(
     );
     let pool = workspace_mgr.global_pool();
 
-    if let Err(e) = SettingsRepository::save_transcript_config(pool, &provider, &model).await {
+    let endpoint = endpoint
+        .as_deref()
+        .map(str::trim)
+        .filter(|e| !e.is_empty());
+    if let Err(e) =
+        SettingsRepository::save_transcript_config(pool, &provider, &model, endpoint).await
+    {
         log_error!("Failed to save transcript config: {}", e);
         return Err(e.to_string());
     }
@@ -869,16 +947,29 @@ pub async fn api_get_meeting_transcripts<R: Runtime>(
                 total_count
             );
 
+            let transcript_ids: Vec<String> = transcripts.iter().map(|t| t.id.clone()).collect();
+            let mut words = TranscriptsRepository::get_words(&pool, &transcript_ids)
+                .await
+                .unwrap_or_else(|e| {
+                    log_warn!("Failed to load word timings for meeting {}: {}", meeting_id, e);
+                    Default::default()
+                });
+
             // Convert Transcript to MeetingTranscript
             let meeting_transcripts = transcripts
                 .into_iter()
                 .map(|t| MeetingTranscript {
+                    words: words.remove(&t.id).unwrap_or_default(),
                     id: t.id,
                     text: t.transcript,
                     timestamp: t.timestamp,
                     audio_start_time: t.audio_start_time,
                     audio_end_time: t.audio_end_time,
                     duration: t.duration,
+                    speaker: t.speaker,
+                    speaker_label: t.speaker_label,
+                    confidence: t.confidence,
+                    low_confidence: t.low_confidence,
                 })
                 .collect::<Vec<_>>();
 
@@ -928,9 +1019,61 @@ pub async fn api_save_meeting_title<R: Runtime>(
 }
 
 #[tauri::command]
-pub async fn api_save_transcript<R: Runtime>(
+pub async fn api_get_meeting_notes<R: Runtime>(
+    _app: AppHandle<R>,
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    meeting_id: String,
+) -> Result<MeetingNotesResponse, String> {
+    log_info!("api_get_meeting_notes called for meeting_id: {}", meeting_id);
+    let pool = workspace_mgr.active_pool().await?;
+    match MeetingNotesRepository::get_notes(&pool, &meeting_id).await {
+        Ok(notes) => Ok(MeetingNotesResponse::from_notes(meeting_id, notes)),
+        Err(e) => {
+            log_error!("Failed to get notes for meeting {}: {}", meeting_id, e);
+            Err(format!("Failed to get meeting notes: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn api_save_meeting_notes<R: Runtime>(
     _app: AppHandle<R>,
     workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    meeting_id: String,
+    notes_markdown: Option<String>,
+    entries: Vec<MeetingNoteEntry>,
+) -> Result<MeetingNotesResponse, String> {
+    log_info!(
+        "api_save_meeting_notes called for meeting_id: {}, entries: {}",
+        meeting_id,
+        entries.len()
+    );
+    let pool = workspace_mgr.active_pool().await?;
+
+    match MeetingsRepository::get_meeting_metadata(&pool, &meeting_id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            log_error!("No meeting found with id {}", meeting_id);
+            return Err(format!("No meeting found with id {}", meeting_id));
+        }
+        Err(e) => return Err(format!("Failed to load meeting: {}", e)),
+    }
+
+    match MeetingNotesRepository::save_notes(&pool, &meeting_id, notes_markdown.as_deref(), &entries)
+        .await
+    {
+        Ok(notes) => Ok(MeetingNotesResponse::from_notes(meeting_id, Some(notes))),
+        Err(e) => {
+            log_error!("Failed to save notes for meeting {}: {}", meeting_id, e);
+            Err(format!("Failed to save meeting notes: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn api_save_transcript<R: Runtime>(
+    app: AppHandle<R>,
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
     meeting_title: String,
     transcripts: Vec<serde_json::Value>,
     folder_path: Option<String>,
@@ -978,7 +1121,7 @@ pub async fn api_save_transcript<R: Runtime>(
         &pool,
         &meeting_title,
         &transcripts_to_save,
-        folder_path,
+        folder_path.clone(),
     )
     .await
     {
@@ -987,6 +1130,30 @@ pub async fn api_save_transcript<R: Runtime>(
                 "Successfully saved transcript and created meeting with id: {}",
                 meeting_id
             );
+
+            if let Some(folder) = folder_path.as_deref() {
+                let folder = std::path::Path::new(folder);
+
+                // Carry over notes autosaved during the recording
+                if let Some(notes) = crate::audio::recording_saver::read_recording_notes(folder) {
+                    if let Err(e) = MeetingNotesRepository::save_notes(
+                        &pool,
+                        &meeting_id,
+                        notes.notes_markdown.as_deref(),
+                        &notes.entries,
+                    )
+                    .await
+                    {
+                        log_warn!("Failed to import recording notes for meeting {}: {}", meeting_id, e);
+                    }
+                }
+
+                // Diarization may have finished before the meeting was saved
+                crate::diarization::apply_saved_speaker_turns(&pool, &meeting_id, folder).await;
+
+                // Start re-transcription scheduled when the recording stopped
+                crate::audio::retranscribe::on_meeting_saved(&app, &meeting_id, folder).await;
+            }
             Ok(serde_json::json!({
                 "status": "success",
                 "message": "Transcript saved successfully",
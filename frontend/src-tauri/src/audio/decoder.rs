@@ -0,0 +1,225 @@
+// audio/decoder.rs
+//
+// Decodes recorded or imported audio files (audio.mp4, wav, mp3, ...) to mono f32 samples.
+
+use anyhow::{anyhow, Result};
+use log::{info, warn};
+use std::path::Path;
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::{DecoderOptions, CODEC_TYPE_NULL};
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+
+use super::audio_processing::{audio_to_mono, resample};
+use super::ffmpeg::find_ffmpeg_path;
+
+/// Sample rate expected by VAD, Whisper, Parakeet and the diarization model
+pub const TRANSCRIPTION_SAMPLE_RATE: u32 = 16000;
+
+/// Mono audio decoded from a file
+#[derive(Debug, Clone)]
+pub struct DecodedAudio {
+    pub samples: Vec<f32>,
+    pub sample_rate: u32,
+}
+
+impl DecodedAudio {
+    /// Duration in seconds
+    pub fn duration_seconds(&self) -> f64 {
+        if self.sample_rate == 0 {
+            return 0.0;
+        }
+        self.samples.len() as f64 / self.sample_rate as f64
+    }
+}
+
+/// Decode the first audio track of a file to mono f32 samples at its native sample rate
+pub fn decode_audio_file(path: &Path) -> Result<DecodedAudio> {
+    let file = std::fs::File::open(path)
+        .map_err(|e| anyhow!("Failed to open audio file {}: {}", path.display(), e))?;
+    let mss = MediaSourceStream::new(Box::new(file), Default::default());
+
+    let mut hint = Hint::new();
+    if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+        hint.with_extension(ext);
+    }
+
+    let probed = symphonia::default::get_probe()
+        .format(&hint, mss, &FormatOptions::default(), &MetadataOptions::default())
+        .map_err(|e| anyhow!("Unsupported audio format for {}: {}", path.display(), e))?;
+    let mut format = probed.format;
+
+    let track = format
+        .tracks()
+        .iter()
+        .find(|t| t.codec_params.codec != CODEC_TYPE_NULL)
+        .ok_or_else(|| anyhow!("No audio track found in {}", path.display()))?;
+    let track_id = track.id;
+    let sample_rate = track
+        .codec_params
+        .sample_rate
+        .ok_or_else(|| anyhow!("Unknown sample rate in {}", path.display()))?;
+
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(|e| anyhow!("Unsupported audio codec in {}: {}", path.display(), e))?;
+
+    let mut samples = Vec::new();
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => break,
+            Err(SymphoniaError::ResetRequired) => break,
+            Err(e) => return Err(anyhow!("Failed to read audio packet: {}", e)),
+        };
+
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        match decoder.decode(&packet) {
+            Ok(decoded) => {
+                let spec = *decoded.spec();
+                let channels = spec.channels.count();
+                let mut buffer = SampleBuffer::<f32>::new(decoded.capacity() as u64, spec);
+                buffer.copy_interleaved_ref(decoded);
+                samples.extend(audio_to_mono(buffer.samples(), channels as u16));
+            }
+            Err(SymphoniaError::DecodeError(e)) => {
+                // Corrupt packets are skipped rather than failing the whole file
+                warn!("Skipping undecodable audio packet: {}", e);
+            }
+            Err(e) => return Err(anyhow!("Failed to decode audio: {}", e)),
+        }
+    }
+
+    info!(
+        "Decoded {} ({} samples at {}Hz, {:.1}s)",
+        path.display(),
+        samples.len(),
+        sample_rate,
+        samples.len() as f64 / sample_rate as f64
+    );
+
+    Ok(DecodedAudio {
+        samples,
+        sample_rate,
+    })
+}
+
+/// Decode a file with the ffmpeg sidecar straight to 16kHz mono f32.
+///
+/// Used for containers and codecs symphonia can't read (webm/opus, video files with
+/// unusual audio tracks, ...). Any video stream is ignored.
+pub fn decode_with_ffmpeg(path: &Path) -> Result<DecodedAudio> {
+    let ffmpeg_path = find_ffmpeg_path().ok_or_else(|| anyhow!("FFmpeg not found"))?;
+
+    let mut command = std::process::Command::new(ffmpeg_path);
+    command
+        .arg("-nostdin")
+        .arg("-i")
+        .arg(path)
+        .args(["-vn", "-ac", "1", "-ar"])
+        .arg(TRANSCRIPTION_SAMPLE_RATE.to_string())
+        .args(["-f", "f32le", "-"]);
+
+    // Hide console window on Windows
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let output = command
+        .output()
+        .map_err(|e| anyhow!("Failed to run FFmpeg: {}", e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let last_line = stderr.lines().last().unwrap_or_default();
+        return Err(anyhow!("FFmpeg failed to decode {}: {}", path.display(), last_line));
+    }
+
+    let samples: Vec<f32> = output
+        .stdout
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect();
+    if samples.is_empty() {
+        return Err(anyhow!("No audio track found in {}", path.display()));
+    }
+
+    info!(
+        "Decoded {} with FFmpeg ({} samples, {:.1}s)",
+        path.display(),
+        samples.len(),
+        samples.len() as f64 / TRANSCRIPTION_SAMPLE_RATE as f64
+    );
+
+    Ok(DecodedAudio {
+        samples,
+        sample_rate: TRANSCRIPTION_SAMPLE_RATE,
+    })
+}
+
+/// Decode a file and resample it to 16kHz mono for transcription and analysis.
+///
+/// Falls back to FFmpeg when symphonia can't handle the format.
+pub fn decode_to_16k_mono(path: &Path) -> Result<Vec<f32>> {
+    let decoded = match decode_audio_file(path) {
+        Ok(decoded) => decoded,
+        Err(e) => {
+            warn!("Native decoding failed ({}), trying FFmpeg", e);
+            decode_with_ffmpeg(path).map_err(|ffmpeg_err| anyhow!("{} (FFmpeg fallback: {})", e, ffmpeg_err))?
+        }
+    };
+    resample(&decoded.samples, decoded.sample_rate, TRANSCRIPTION_SAMPLE_RATE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_test_wav(path: &Path, samples: &[i16], sample_rate: u32, channels: u16) {
+        let data_len = (samples.len() * 2) as u32;
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"RIFF");
+        bytes.extend_from_slice(&(36 + data_len).to_le_bytes());
+        bytes.extend_from_slice(b"WAVEfmt ");
+        bytes.extend_from_slice(&16u32.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&channels.to_le_bytes());
+        bytes.extend_from_slice(&sample_rate.to_le_bytes());
+        bytes.extend_from_slice(&(sample_rate * channels as u32 * 2).to_le_bytes());
+        bytes.extend_from_slice(&(channels * 2).to_le_bytes());
+        bytes.extend_from_slice(&16u16.to_le_bytes());
+        bytes.extend_from_slice(b"data");
+        bytes.extend_from_slice(&data_len.to_le_bytes());
+        for s in samples {
+            bytes.extend_from_slice(&s.to_le_bytes());
+        }
+        std::fs::write(path, bytes).unwrap();
+    }
+
+    #[test]
+    fn test_decode_stereo_wav_to_mono() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.wav");
+        // 100 stereo frames: left = 16384, right = 0
+        let samples: Vec<i16> = (0..200).map(|i| if i % 2 == 0 { 16384 } else { 0 }).collect();
+        write_test_wav(&path, &samples, 8000, 2);
+
+        let decoded = decode_audio_file(&path).unwrap();
+        assert_eq!(decoded.sample_rate, 8000);
+        assert_eq!(decoded.samples.len(), 100);
+        assert!((decoded.samples[10] - 0.25).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_decode_missing_file_fails() {
+        assert!(decode_audio_file(Path::new("/nonexistent/audio.mp4")).is_err());
+    }
+}
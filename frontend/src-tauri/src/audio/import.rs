@@ -0,0 +1,362 @@
+// audio/import.rs
+//
+// Transcribe an existing audio/video file (Zoom cloud recording, phone voice memo, ...)
+// into a new meeting, using the same VAD and transcription engine as live recording.
+
+use log::{error, info, warn};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::{AppHandle, Emitter, Runtime};
+
+use super::audio_processing::create_meeting_folder;
+use super::decoder::{decode_to_16k_mono, TRANSCRIPTION_SAMPLE_RATE};
+use super::ffmpeg::find_ffmpeg_path;
+use super::transcription::{get_or_init_transcription_engine, validate_transcription_model_ready};
+use super::vad::{get_speech_chunks, SpeechSegment};
+use crate::api::TranscriptSegment;
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::workspace::manager::WorkspaceManager;
+
+/// Same redemption time the live pipeline uses
+pub(super) const VAD_REDEMPTION_MS: u32 = 400;
+/// Whisper works on windows of up to 30s; longer speech runs are split
+pub(super) const MAX_SEGMENT_SECONDS: f64 = 28.0;
+/// Segments shorter than this (100ms) are too short to transcribe
+const MIN_SEGMENT_SAMPLES: usize = 1600;
+
+static IMPORT_IN_PROGRESS: AtomicBool = AtomicBool::new(false);
+static IMPORT_CANCELLED: AtomicBool = AtomicBool::new(false);
+
+/// Payload of `import-progress` events
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportProgress {
+    /// decoding | detecting_speech | transcribing | saving | completed | failed | cancelled
+    pub stage: String,
+    /// Overall progress, 0-100
+    pub progress: u8,
+    pub message: String,
+    pub current_segment: Option<usize>,
+    pub total_segments: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportResult {
+    pub meeting_id: String,
+    pub title: String,
+    pub folder_path: String,
+    pub segment_count: usize,
+    pub duration_seconds: f64,
+}
+
+/// A speech segment ready for transcription, with recording-relative times in seconds
+#[derive(Debug, Clone)]
+pub(super) struct TimedChunk {
+    pub(super) start: f64,
+    pub(super) end: f64,
+    pub(super) samples: Vec<f32>,
+}
+
+/// Clears the in-progress flag however the import ends
+struct ImportGuard;
+
+impl Drop for ImportGuard {
+    fn drop(&mut self) {
+        IMPORT_IN_PROGRESS.store(false, Ordering::SeqCst);
+    }
+}
+
+fn emit_progress<R: Runtime>(
+    app: &AppHandle<R>,
+    stage: &str,
+    progress: u8,
+    message: impl Into<String>,
+    segment: Option<(usize, usize)>,
+) {
+    let payload = ImportProgress {
+        stage: stage.to_string(),
+        progress,
+        message: message.into(),
+        current_segment: segment.map(|(current, _)| current),
+        total_segments: segment.map(|(_, total)| total),
+    };
+    if let Err(e) = app.emit("import-progress", &payload) {
+        warn!("Failed to emit import-progress event: {}", e);
+    }
+}
+
+/// Turn VAD segments into transcription-sized chunks, splitting long speech runs and
+/// dropping fragments too short to transcribe
+pub(super) fn split_speech_segments(segments: Vec<SpeechSegment>, max_seconds: f64) -> Vec<TimedChunk> {
+    let max_samples = (max_seconds * TRANSCRIPTION_SAMPLE_RATE as f64) as usize;
+    let mut chunks = Vec::new();
+
+    for segment in segments {
+        let start = segment.start_timestamp_ms / 1000.0;
+        for (i, piece) in segment.samples.chunks(max_samples.max(1)).enumerate() {
+            if piece.len() < MIN_SEGMENT_SAMPLES {
+                continue;
+            }
+            let piece_start = start + (i * max_samples) as f64 / TRANSCRIPTION_SAMPLE_RATE as f64;
+            chunks.push(TimedChunk {
+                start: piece_start,
+                end: piece_start + piece.len() as f64 / TRANSCRIPTION_SAMPLE_RATE as f64,
+                samples: piece.to_vec(),
+            });
+        }
+    }
+    chunks
+}
+
+/// Place the original file in the meeting folder as `audio.mp4` so playback and
+/// diarization work like for live recordings
+fn store_source_audio(source: &Path, meeting_folder: &Path) -> Result<PathBuf, String> {
+    let extension = source
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .unwrap_or_default();
+    let target = meeting_folder.join("audio.mp4");
+
+    if matches!(extension.as_str(), "mp4" | "m4a") {
+        std::fs::copy(source, &target).map_err(|e| format!("Failed to copy audio file: {}", e))?;
+        return Ok(target);
+    }
+
+    if let Some(ffmpeg_path) = find_ffmpeg_path() {
+        let mut command = std::process::Command::new(ffmpeg_path);
+        command
+            .arg("-nostdin")
+            .arg("-i")
+            .arg(source)
+            .args(["-vn", "-c:a", "aac", "-b:a", "128k", "-y"])
+            .arg(&target);
+
+        #[cfg(target_os = "windows")]
+        {
+            use std::os::windows::process::CommandExt;
+            const CREATE_NO_WINDOW: u32 = 0x08000000;
+            command.creation_flags(CREATE_NO_WINDOW);
+        }
+
+        match command.output() {
+            Ok(output) if output.status.success() => return Ok(target),
+            Ok(output) => warn!(
+                "FFmpeg could not convert imported audio, keeping original: {}",
+                String::from_utf8_lossy(&output.stderr).lines().last().unwrap_or_default()
+            ),
+            Err(e) => warn!("Failed to run FFmpeg for imported audio: {}", e),
+        }
+    }
+
+    let target = meeting_folder.join(format!("audio.{}", extension));
+    std::fs::copy(source, &target).map_err(|e| format!("Failed to copy audio file: {}", e))?;
+    Ok(target)
+}
+
+/// Import an audio or video file as a new meeting.
+///
+/// Decodes the file (symphonia, FFmpeg fallback), resamples to 16kHz, splits it into
+/// speech segments with VAD and transcribes them with the configured engine. Progress is
+/// reported through `import-progress` events; the meeting is only created once the whole
+/// file has been transcribed.
+#[tauri::command]
+pub async fn import_audio_file<R: Runtime>(
+    app: AppHandle<R>,
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    file_path: String,
+    title: Option<String>,
+) -> Result<ImportResult, String> {
+    info!("import_audio_file called: {}", file_path);
+
+    let source = PathBuf::from(&file_path);
+    if !source.is_file() {
+        return Err(format!("File not found: {}", file_path));
+    }
+    if super::recording_commands::is_recording().await {
+        return Err("Cannot import audio while a recording is in progress".to_string());
+    }
+    if IMPORT_IN_PROGRESS.swap(true, Ordering::SeqCst) {
+        return Err("Another import is already in progress".to_string());
+    }
+    let _guard = ImportGuard;
+    IMPORT_CANCELLED.store(false, Ordering::SeqCst);
+
+    let result = run_import(&app, &workspace_mgr, &source, title).await;
+    match &result {
+        Ok(imported) => emit_progress(
+            &app,
+            "completed",
+            100,
+            format!("Imported '{}'", imported.title),
+            None,
+        ),
+        Err(e) if IMPORT_CANCELLED.load(Ordering::SeqCst) => {
+            info!("Audio import cancelled: {}", e);
+            emit_progress(&app, "cancelled", 0, "Import cancelled", None);
+        }
+        Err(e) => {
+            error!("Audio import failed: {}", e);
+            emit_progress(&app, "failed", 0, e.clone(), None);
+        }
+    }
+    result
+}
+
+/// Stop the running import after the current segment
+#[tauri::command]
+pub async fn cancel_audio_import() -> Result<(), String> {
+    if IMPORT_IN_PROGRESS.load(Ordering::SeqCst) {
+        IMPORT_CANCELLED.store(true, Ordering::SeqCst);
+    }
+    Ok(())
+}
+
+async fn run_import<R: Runtime>(
+    app: &AppHandle<R>,
+    workspace_mgr: &WorkspaceManager,
+    source: &Path,
+    title: Option<String>,
+) -> Result<ImportResult, String> {
+    let title = title
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+        .or_else(|| source.file_stem().map(|s| s.to_string_lossy().to_string()))
+        .unwrap_or_else(|| "Imported meeting".to_string());
+
+    validate_transcription_model_ready(app).await?;
+    let engine = get_or_init_transcription_engine(app).await?;
+
+    // Decode and resample on a blocking thread
+    emit_progress(app, "decoding", 0, "Decoding audio", None);
+    let decode_path = source.to_path_buf();
+    let audio = tokio::task::spawn_blocking(move || decode_to_16k_mono(&decode_path))
+        .await
+        .map_err(|e| format!("Decoding task failed: {}", e))?
+        .map_err(|e| format!("Failed to decode audio: {}", e))?;
+    let duration_seconds = audio.len() as f64 / TRANSCRIPTION_SAMPLE_RATE as f64;
+    info!("Imported audio is {:.1}s long", duration_seconds);
+
+    emit_progress(app, "detecting_speech", 5, "Detecting speech", None);
+    let segments = tokio::task::spawn_blocking(move || get_speech_chunks(&audio, VAD_REDEMPTION_MS))
+        .await
+        .map_err(|e| format!("Speech detection task failed: {}", e))?
+        .map_err(|e| format!("Speech detection failed: {}", e))?;
+    let chunks = split_speech_segments(segments, MAX_SEGMENT_SECONDS);
+    if chunks.is_empty() {
+        return Err("No speech detected in the file".to_string());
+    }
+
+    // Transcribe segment by segment (5% -> 90%)
+    let total = chunks.len();
+    let mut transcripts = Vec::with_capacity(total);
+    for (i, chunk) in chunks.into_iter().enumerate() {
+        if IMPORT_CANCELLED.load(Ordering::SeqCst) {
+            return Err("Import cancelled".to_string());
+        }
+        let progress = 5 + (i * 85 / total) as u8;
+        emit_progress(
+            app,
+            "transcribing",
+            progress,
+            format!("Transcribing segment {} of {}", i + 1, total),
+            Some((i + 1, total)),
+        );
+
+        let result = match engine.transcribe(chunk.samples).await {
+            Ok(result) => result,
+            Err(e) => {
+                warn!("Failed to transcribe segment {} ({:.1}s): {}", i + 1, chunk.start, e);
+                continue;
+            }
+        };
+        if result.text.is_empty() {
+            continue;
+        }
+
+        transcripts.push(TranscriptSegment {
+            id: format!("import-{}", i),
+            text: result.text,
+            timestamp: crate::utils::format_timestamp(chunk.start),
+            audio_start_time: Some(chunk.start),
+            audio_end_time: Some(chunk.end),
+            duration: Some(chunk.end - chunk.start),
+            speaker: None,
+            confidence: result.confidence,
+            low_confidence: result.is_low_confidence(),
+            words: result.words.iter().map(|w| w.offset_by(chunk.start)).collect(),
+        });
+    }
+    if transcripts.is_empty() {
+        return Err("Transcription produced no text".to_string());
+    }
+
+    emit_progress(app, "saving", 95, "Saving meeting", None);
+    let base_folder = super::recording_preferences::load_recording_preferences(app)
+        .await
+        .map(|prefs| prefs.save_folder)
+        .unwrap_or_else(|_| super::recording_preferences::get_default_recordings_folder());
+    let meeting_folder = create_meeting_folder(&base_folder, &title, false)
+        .map_err(|e| format!("Failed to create meeting folder: {}", e))?;
+    if let Err(e) = store_source_audio(source, &meeting_folder) {
+        let _ = std::fs::remove_dir_all(&meeting_folder);
+        return Err(e);
+    }
+    let folder_path = meeting_folder.to_string_lossy().to_string();
+
+    let pool = workspace_mgr.active_pool().await?;
+    let meeting_id = match TranscriptsRepository::save_transcript(&pool, &title, &transcripts, Some(folder_path.clone())).await {
+        Ok(id) => id,
+        Err(e) => {
+            let _ = std::fs::remove_dir_all(&meeting_folder);
+            return Err(format!("Failed to save imported meeting: {}", e));
+        }
+    };
+
+    info!(
+        "Imported {} as meeting {} ({} segments)",
+        source.display(),
+        meeting_id,
+        transcripts.len()
+    );
+
+    Ok(ImportResult {
+        meeting_id,
+        title,
+        folder_path,
+        segment_count: transcripts.len(),
+        duration_seconds,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(start_ms: f64, seconds: f64) -> SpeechSegment {
+        let len = (seconds * TRANSCRIPTION_SAMPLE_RATE as f64) as usize;
+        SpeechSegment {
+            samples: vec![0.1; len],
+            start_timestamp_ms: start_ms,
+            end_timestamp_ms: start_ms + seconds * 1000.0,
+            confidence: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_split_speech_segments() {
+        let chunks = split_speech_segments(
+            vec![segment(1000.0, 5.0), segment(10_000.0, 60.0), segment(80_000.0, 0.05)],
+            28.0,
+        );
+
+        // 5s stays whole, 60s becomes 28 + 28 + 4, the 50ms blip is dropped
+        assert_eq!(chunks.len(), 4);
+        assert!((chunks[0].start - 1.0).abs() < 1e-9);
+        assert!((chunks[0].end - 6.0).abs() < 1e-9);
+        assert!((chunks[1].start - 10.0).abs() < 1e-9);
+        assert!((chunks[2].start - 38.0).abs() < 1e-9);
+        assert!((chunks[3].start - 66.0).abs() < 1e-9);
+        assert!((chunks[3].end - 70.0).abs() < 1e-6);
+    }
+}
@@ -1,7 +1,10 @@
 // src/audio/mod.rs
 pub mod audio_processing;
+pub mod decoder;
 pub mod encode;
 pub mod ffmpeg;
+pub mod import;
+pub mod retranscribe;
 pub mod vad;
 
 // Modularized device management
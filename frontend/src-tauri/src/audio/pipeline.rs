@@ -189,6 +189,63 @@ impl ProfessionalAudioMixer {
     }
 }
 
+/// Per-window mic/system energy on the mixed timeline.
+/// Lets each VAD segment (which is cut from mixed audio) be attributed to the
+/// stream that carried most of its energy, so transcripts can be tagged by source.
+struct SourceEnergyTracker {
+    /// (window_start_ms, window_end_ms, mic_energy, system_energy)
+    windows: VecDeque<(f64, f64, f32, f32)>,
+    elapsed_ms: f64,
+    sample_rate: u32,
+}
+
+impl SourceEnergyTracker {
+    /// Keep ~10 minutes of history; VAD segments are far shorter than this
+    const MAX_HISTORY_MS: f64 = 600_000.0;
+
+    fn new(sample_rate: u32) -> Self {
+        Self {
+            windows: VecDeque::new(),
+            elapsed_ms: 0.0,
+            sample_rate,
+        }
+    }
+
+    fn record(&mut self, mic_window: &[f32], sys_window: &[f32]) {
+        let window_len = mic_window.len().max(sys_window.len());
+        let window_ms = window_len as f64 / self.sample_rate as f64 * 1000.0;
+        let mic_energy: f32 = mic_window.iter().map(|&x| x * x).sum();
+        let sys_energy: f32 = sys_window.iter().map(|&x| x * x).sum();
+
+        let start_ms = self.elapsed_ms;
+        self.elapsed_ms += window_ms;
+        self.windows.push_back((start_ms, self.elapsed_ms, mic_energy, sys_energy));
+
+        while let Some(&(_, end_ms, _, _)) = self.windows.front() {
+            if self.elapsed_ms - end_ms > Self::MAX_HISTORY_MS {
+                self.windows.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Stream with the most energy between `start_ms` and `end_ms` (ties go to the microphone)
+    fn dominant_source(&self, start_ms: f64, end_ms: f64) -> DeviceType {
+        let (mic, sys) = self
+            .windows
+            .iter()
+            .filter(|(w_start, w_end, _, _)| *w_end > start_ms && *w_start < end_ms)
+            .fold((0.0f32, 0.0f32), |(mic, sys), (_, _, m, s)| (mic + m, sys + s));
+
+        if sys > mic {
+            DeviceType::System
+        } else {
+            DeviceType::Microphone
+        }
+    }
+}
+
 /// Simplified audio capture without broadcast channels
 #[derive(Clone)]
 pub struct AudioCapture {
@@ -692,6 +749,8 @@ pub struct AudioPipeline {
     // PROFESSIONAL AUDIO MIXING: Ring buffer + RMS-based mixer
     ring_buffer: AudioMixerRingBuffer,
     mixer: ProfessionalAudioMixer,
+    // Attributes VAD segments to the mic or system stream
+    source_tracker: SourceEnergyTracker,
     // Recording sender for pre-mixed audio
     recording_sender_for_mixed: Option<mpsc::UnboundedSender<AudioChunk>>,
 }
@@ -740,6 +799,7 @@ impl AudioPipeline {
         // Initialize professional audio mixing components
         let ring_buffer = AudioMixerRingBuffer::new(sample_rate);
         let mixer = ProfessionalAudioMixer::new(sample_rate);
+        let source_tracker = SourceEnergyTracker::new(sample_rate);
 
         // Note: target_chunk_duration_ms is ignored - VAD controls segmentation now
         let _ = target_chunk_duration_ms;
@@ -759,6 +819,7 @@ impl AudioPipeline {
             // Initialize professional audio mixing
             ring_buffer,
             mixer,
+            source_tracker,
             recording_sender_for_mixed: None,  // Will be set by manager
         }
     }
@@ -824,6 +885,7 @@ impl AudioPipeline {
                         if let Some((mic_window, sys_window)) = self.ring_buffer.extract_window() {
                             // Simple mixing without aggressive ducking
                             let mixed_clean = self.mixer.mix_window(&mic_window, &sys_window);
+                            self.source_tracker.record(&mic_window, &sys_window);
 
                             // NO POST-GAIN NEEDED: Microphone already normalized by EBU R128 to -23 LUFS
                             // This is broadcast-standard loudness (Netflix/YouTube/Spotify level)
@@ -841,12 +903,17 @@ impl AudioPipeline {
                                             info!("📤 Sending VAD segment: {:.1}ms, {} samples",
                                                   duration_ms, segment.samples.len());
 
+                                            // Tag with the stream that dominated this segment
+                                            let source = self.source_tracker.dominant_source(
+                                                segment.start_timestamp_ms,
+                                                segment.end_timestamp_ms,
+                                            );
                                             let transcription_chunk = AudioChunk {
                                                 data: segment.samples,
                                                 sample_rate: 16000,
                                                 timestamp: segment.start_timestamp_ms / 1000.0,
                                                 chunk_id: self.chunk_id_counter,
-                                                device_type: DeviceType::Microphone,  // Mixed audio
+                                                device_type: source,
                                             };
 
                                             if let Err(e) = self.transcription_sender.send(transcription_chunk) {
@@ -911,12 +978,16 @@ impl AudioPipeline {
                         info!("📤 Sending final VAD segment to Whisper: {:.1}ms duration, {} samples",
                               duration_ms, segment.samples.len());
 
+                        let source = self.source_tracker.dominant_source(
+                            segment.start_timestamp_ms,
+                            segment.end_timestamp_ms,
+                        );
                         let transcription_chunk = AudioChunk {
                             data: segment.samples,
                             sample_rate: 16000,
                             timestamp: segment.start_timestamp_ms / 1000.0,
                             chunk_id: self.chunk_id_counter,
-                            device_type: DeviceType::Microphone,
+                            device_type: source,
                         };
 
                         if let Err(e) = self.transcription_sender.send(transcription_chunk) {
@@ -87,6 +87,9 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
         return Err("Recording already in progress".to_string());
     }
 
+    // Live transcription needs the engine; stop any re-transcription still running
+    super::retranscribe::cancel_for_recording();
+
     // Validate that transcription models are available before starting recording
     info!("🔍 Validating transcription model availability before starting recording...");
     if let Err(validation_error) = transcription::validate_transcription_model_ready(&app).await {
@@ -271,7 +274,9 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
                     duration: update.duration,
                     display_time: update.timestamp.clone(), // Use wall-clock timestamp for display
                     confidence: update.confidence,
+                    low_confidence: update.low_confidence,
                     sequence_id: update.sequence_id,
+                    speaker: update.speaker.clone(),
                 };
 
                 // Save to recording manager
@@ -330,6 +335,9 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
         return Err("Recording already in progress".to_string());
     }
 
+    // Live transcription needs the engine; stop any re-transcription still running
+    super::retranscribe::cancel_for_recording();
+
     // Validate that transcription models are available before starting recording
     info!("🔍 Validating transcription model availability before starting recording...");
     if let Err(validation_error) = transcription::validate_transcription_model_ready(&app).await {
@@ -439,7 +447,9 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
                     duration: update.duration,
                     display_time: update.timestamp.clone(), // Use wall-clock timestamp for display
                     confidence: update.confidence,
+                    low_confidence: update.low_confidence,
                     sequence_id: update.sequence_id,
+                    speaker: update.speaker.clone(),
                 };
 
                 // Save to recording manager
@@ -1050,6 +1060,50 @@ pub async fn get_recording_meeting_name() -> Result<Option<String>, String> {
     }
 }
 
+/// Autosave notes taken during the current recording to notes.json in the meeting folder.
+/// Entries without a timestamp are linked to the current recording position.
+/// Returns the stamped entries so the frontend can keep them.
+#[tauri::command]
+pub async fn save_recording_notes(
+    notes_markdown: Option<String>,
+    entries: Vec<crate::database::models::MeetingNoteEntry>,
+) -> Result<Vec<crate::database::models::MeetingNoteEntry>, String> {
+    let (folder, position) = {
+        let manager_guard = RECORDING_MANAGER.lock().unwrap();
+        let manager = manager_guard
+            .as_ref()
+            .ok_or_else(|| "No active recording".to_string())?;
+        (manager.get_meeting_folder(), manager.get_active_recording_duration())
+    };
+    let folder = folder.ok_or_else(|| "Recording has no meeting folder".to_string())?;
+
+    let mut entries = entries;
+    for entry in entries.iter_mut().filter(|e| e.audio_start_time.is_none()) {
+        entry.audio_start_time = position;
+    }
+
+    let notes = super::recording_saver::RecordingNotes {
+        notes_markdown,
+        entries,
+    };
+    super::recording_saver::write_recording_notes(&folder, &notes)
+        .map_err(|e| format!("Failed to save notes: {}", e))?;
+
+    Ok(notes.entries)
+}
+
+/// Get notes autosaved during the current recording
+/// Used for syncing frontend state after page reload during active recording
+#[tauri::command]
+pub async fn get_recording_notes() -> Result<Option<super::recording_saver::RecordingNotes>, String> {
+    let folder = {
+        let manager_guard = RECORDING_MANAGER.lock().unwrap();
+        manager_guard.as_ref().and_then(|manager| manager.get_meeting_folder())
+    };
+
+    Ok(folder.and_then(|folder| super::recording_saver::read_recording_notes(&folder)))
+}
+
 // ============================================================================
 // DEVICE MONITORING COMMANDS (AirPods/Bluetooth disconnect/reconnect support)
 // ============================================================================
@@ -20,6 +20,12 @@ pub struct RecordingPreferences {
     pub preferred_mic_device: Option<String>,
     #[serde(default)]
     pub preferred_system_device: Option<String>,
+    /// Re-transcribe each saved recording with a larger Whisper model
+    #[serde(default)]
+    pub auto_retranscribe: bool,
+    /// Whisper model for automatic re-transcription; None uses large-v3
+    #[serde(default)]
+    pub retranscribe_model: Option<String>,
     #[cfg(target_os = "macos")]
     #[serde(default)]
     pub system_audio_backend: Option<String>,
@@ -33,6 +39,8 @@ impl Default for RecordingPreferences {
             file_format: "mp4".to_string(),
             preferred_mic_device: None,
             preferred_system_device: None,
+            auto_retranscribe: false,
+            retranscribe_model: None,
             #[cfg(target_os = "macos")]
             system_audio_backend: Some("coreaudio".to_string()),
         }
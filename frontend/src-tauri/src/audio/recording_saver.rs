@@ -5,8 +5,9 @@ use log::{info, warn, error};
 use tauri::{AppHandle, Runtime, Emitter};
 use tokio::sync::mpsc;
 use serde::{Serialize, Deserialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+use crate::database::models::MeetingNoteEntry;
 use super::recording_state::AudioChunk;
 use super::audio_processing::create_meeting_folder;
 use super::incremental_saver::IncrementalAudioSaver;
@@ -20,8 +21,54 @@ pub struct TranscriptSegment {
     pub audio_end_time: f64,   // Seconds from recording start
     pub duration: f64,          // Segment duration in seconds
     pub display_time: String,   // Formatted time for display like "[02:15]"
-    pub confidence: f32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>, // None if the engine doesn't report one
+    #[serde(default)]
+    pub low_confidence: bool, // Below the confidence threshold, kept for re-transcription
     pub sequence_id: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<String>, // "mic" or "system"
+}
+
+/// Notes taken during a recording, autosaved to notes.json in the meeting folder
+/// until the meeting is saved to the database
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RecordingNotes {
+    pub notes_markdown: Option<String>,
+    pub entries: Vec<MeetingNoteEntry>,
+}
+
+/// Write notes.json to a meeting folder (atomic write with temp file)
+pub fn write_recording_notes(folder: &Path, notes: &RecordingNotes) -> Result<()> {
+    let notes_path = folder.join("notes.json");
+    let temp_path = folder.join(".notes.json.tmp");
+
+    let json = serde_json::json!({
+        "version": "1.0",
+        "notes_markdown": notes.notes_markdown,
+        "entries": notes.entries,
+        "last_updated": chrono::Utc::now().to_rfc3339(),
+    });
+    let json_string = serde_json::to_string_pretty(&json)
+        .map_err(|e| anyhow::anyhow!("JSON serialization failed: {}", e))?;
+
+    std::fs::write(&temp_path, &json_string)
+        .map_err(|e| anyhow::anyhow!("Failed to write notes temp file: {}", e))?;
+    std::fs::rename(&temp_path, &notes_path)
+        .map_err(|e| anyhow::anyhow!("Failed to rename notes file: {}", e))?;
+    Ok(())
+}
+
+/// Read notes.json from a meeting folder, if notes were taken during the recording
+pub fn read_recording_notes(folder: &Path) -> Option<RecordingNotes> {
+    let content = std::fs::read_to_string(folder.join("notes.json")).ok()?;
+    match serde_json::from_str(&content) {
+        Ok(notes) => Some(notes),
+        Err(e) => {
+            warn!("Failed to parse notes.json in {}: {}", folder.display(), e);
+            None
+        }
+    }
 }
 
 /// Meeting metadata structure
@@ -127,8 +174,10 @@ impl RecordingSaver {
             audio_end_time: 0.0,
             duration: 0.0,
             display_time: "[00:00]".to_string(),
-            confidence: 1.0,
+            confidence: None,
+            low_confidence: false,
             sequence_id: 0,
+            speaker: None,
         };
         self.add_transcript_segment(segment);
     }
@@ -450,6 +499,30 @@ impl RecordingSaver {
             warn!("Failed to emit recording-saved event: {}", e);
         }
 
+        // Identify speakers in the background (no-op when the diarization model is absent)
+        if let Some(folder) = &self.meeting_folder {
+            let segments = if let Ok(segments) = self.transcript_segments.lock() {
+                segments
+                    .iter()
+                    .map(|seg| crate::diarization::TimedSegment {
+                        start: seg.audio_start_time,
+                        end: seg.audio_end_time,
+                    })
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            crate::diarization::spawn_post_recording_diarization(
+                app.clone(),
+                final_audio_path.clone(),
+                folder.clone(),
+                segments,
+            );
+
+            // Re-transcribe with a larger model if the preferences ask for it
+            crate::audio::retranscribe::schedule_post_recording_retranscription(app.clone(), folder.clone());
+        }
+
         // Clean up transcript segments
         if let Ok(mut segments) = self.transcript_segments.lock() {
             segments.clear();
@@ -14,6 +14,16 @@ pub enum DeviceType {
     System,
 }
 
+impl DeviceType {
+    /// Value stored in `transcripts.speaker` for segments from this stream
+    pub fn speaker_label(&self) -> &'static str {
+        match self {
+            DeviceType::Microphone => "mic",
+            DeviceType::System => "system",
+        }
+    }
+}
+
 /// Audio chunk with metadata for processing
 #[derive(Debug, Clone)]
 pub struct AudioChunk {
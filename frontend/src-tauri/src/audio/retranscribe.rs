@@ -0,0 +1,920 @@
+// audio/retranscribe.rs
+//
+// Re-transcribe a saved meeting from its recorded audio. Live transcription uses a small
+// model to keep up in real time; afterwards the recording can go through a large one.
+
+use log::{error, info, warn};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+
+use super::decoder::{decode_to_16k_mono, TRANSCRIPTION_SAMPLE_RATE};
+use super::import::{split_speech_segments, TimedChunk, MAX_SEGMENT_SECONDS, VAD_REDEMPTION_MS};
+use super::transcription::engine::{load_transcription_engine, refresh_vocabulary};
+use super::transcription::{TranscriptResult, TranscriptionEngine, LOW_CONFIDENCE_THRESHOLD};
+use super::vad::get_speech_chunks;
+use crate::api::TranscriptSegment;
+use crate::database::models::{Transcript, TranscriptVersion};
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::database::repositories::transcript_version::{
+    TranscriptVersionOrigin, TranscriptVersionsRepository,
+};
+use crate::diarization::diarizer::assign_labels_by_overlap;
+use crate::diarization::SpeakerTurn;
+use crate::whisper_engine::{
+    AudioChunk, ParallelConfig, ParallelProcessor, ParallelProcessorState, ProcessingEvent,
+};
+use crate::workspace::manager::WorkspaceManager;
+
+/// Engine and model used when the caller doesn't choose one
+const DEFAULT_PROVIDER: &str = "localWhisper";
+const DEFAULT_MODEL: &str = "large-v3";
+
+/// Audio kept on both sides of a low-confidence segment, so words cut at its edges are heard whole
+const SEGMENT_PADDING_SECONDS: f64 = 0.2;
+
+static RETRANSCRIBE_IN_PROGRESS: AtomicBool = AtomicBool::new(false);
+static RETRANSCRIBE_CANCELLED: AtomicBool = AtomicBool::new(false);
+
+/// Recording folders to re-transcribe once their meeting has been saved
+static PENDING_RECORDINGS: Mutex<Vec<PathBuf>> = Mutex::new(Vec::new());
+
+/// Payload of `retranscribe-progress` events
+#[derive(Debug, Clone, Serialize)]
+pub struct RetranscribeProgress {
+    pub meeting_id: String,
+    /// decoding | detecting_speech | loading_model | transcribing | saving | completed | failed | cancelled
+    pub stage: String,
+    /// Overall progress, 0-100
+    pub progress: u8,
+    pub message: String,
+    pub current_segment: Option<usize>,
+    pub total_segments: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RetranscribeResult {
+    pub meeting_id: String,
+    pub provider: String,
+    pub model: String,
+    pub segment_count: usize,
+    /// Version the previous transcript was archived as, if it was kept
+    pub archived_version: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LowConfidenceRetranscribeResult {
+    pub meeting_id: String,
+    pub provider: String,
+    pub model: String,
+    /// Low-confidence segments that were re-transcribed
+    pub candidate_count: usize,
+    /// Segments whose text was replaced by the new transcription
+    pub replaced_count: usize,
+    /// Version the previous transcript was archived as, if it was kept
+    pub archived_version: Option<i64>,
+}
+
+/// Clears the in-progress flag however the re-transcription ends
+struct RetranscribeGuard;
+
+impl Drop for RetranscribeGuard {
+    fn drop(&mut self) {
+        RETRANSCRIBE_IN_PROGRESS.store(false, Ordering::SeqCst);
+    }
+}
+
+fn emit_progress<R: Runtime>(
+    app: &AppHandle<R>,
+    meeting_id: &str,
+    stage: &str,
+    progress: u8,
+    message: impl Into<String>,
+    segment: Option<(usize, usize)>,
+) {
+    let payload = RetranscribeProgress {
+        meeting_id: meeting_id.to_string(),
+        stage: stage.to_string(),
+        progress,
+        message: message.into(),
+        current_segment: segment.map(|(current, _)| current),
+        total_segments: segment.map(|(_, total)| total),
+    };
+    if let Err(e) = app.emit("retranscribe-progress", &payload) {
+        warn!("Failed to emit retranscribe-progress event: {}", e);
+    }
+}
+
+/// Progress while transcribing segment `done` of `total` (5% -> 90%)
+fn transcribing_progress(done: usize, total: usize) -> u8 {
+    5 + (done * 85 / total.max(1)) as u8
+}
+
+/// The recorded audio of a meeting folder: `audio.mp4`, or `audio.<ext>` for imported
+/// files FFmpeg could not convert
+fn find_meeting_audio(folder: &Path) -> Option<PathBuf> {
+    let mp4 = folder.join("audio.mp4");
+    if mp4.is_file() {
+        return Some(mp4);
+    }
+    std::fs::read_dir(folder)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .find(|path| path.is_file() && path.file_stem().map_or(false, |stem| stem == "audio"))
+}
+
+/// Give new segments the mic/system source of the old segment they overlap most.
+///
+/// The recording is a mix of both streams, so the source can't be recovered from the audio.
+fn carry_over_sources(previous: &[TranscriptSegment], segments: &mut [TranscriptSegment]) {
+    let turns: Vec<SpeakerTurn> = previous
+        .iter()
+        .filter_map(|segment| {
+            let start = segment.audio_start_time?;
+            Some(SpeakerTurn {
+                start,
+                end: segment.audio_end_time.unwrap_or(start),
+                label: segment.speaker.clone()?,
+            })
+        })
+        .collect();
+    if turns.is_empty() {
+        return;
+    }
+
+    let rows: Vec<(String, Option<f64>, Option<f64>)> = segments
+        .iter()
+        .enumerate()
+        .map(|(i, segment)| (i.to_string(), segment.audio_start_time, segment.audio_end_time))
+        .collect();
+    for (index, source) in assign_labels_by_overlap(&turns, &rows) {
+        if let Ok(index) = index.parse::<usize>() {
+            segments[index].speaker = Some(source);
+        }
+    }
+}
+
+/// The audio of each segment's time range, padded on both sides
+fn segment_chunks(audio: &[f32], segments: &[Transcript]) -> Vec<TimedChunk> {
+    let rate = TRANSCRIPTION_SAMPLE_RATE as f64;
+    let audio_duration = audio.len() as f64 / rate;
+    segments
+        .iter()
+        .map(|segment| {
+            let start = (segment.audio_start_time.unwrap_or(0.0) - SEGMENT_PADDING_SECONDS).max(0.0);
+            let end = (segment.audio_end_time.unwrap_or(0.0) + SEGMENT_PADDING_SECONDS).min(audio_duration);
+            let from = ((start * rate) as usize).min(audio.len());
+            let to = ((end * rate) as usize).clamp(from, audio.len());
+            TimedChunk {
+                start,
+                end: end.max(start),
+                samples: audio[from..to].to_vec(),
+            }
+        })
+        .collect()
+}
+
+/// The new version of a low-confidence segment, or None if the current text should stay.
+///
+/// A result replaces the segment when it scores higher; when the new engine reports no
+/// confidence the chosen engine is trusted. Timing, source and labels are kept.
+fn improved_segment(
+    segment: &Transcript,
+    chunk: &TimedChunk,
+    result: TranscriptResult,
+) -> Option<TranscriptSegment> {
+    let text = result.text.trim();
+    if text.is_empty() {
+        return None;
+    }
+    if let (Some(current), Some(new)) = (segment.confidence, result.confidence) {
+        if new <= current {
+            return None;
+        }
+    }
+    Some(TranscriptSegment {
+        id: segment.id.clone(),
+        text: text.to_string(),
+        timestamp: segment.timestamp.clone(),
+        audio_start_time: segment.audio_start_time,
+        audio_end_time: segment.audio_end_time,
+        duration: segment.duration,
+        speaker: segment.speaker.clone(),
+        confidence: result.confidence,
+        low_confidence: result.is_low_confidence(),
+        words: result.words.iter().map(|w| w.offset_by(chunk.start)).collect(),
+    })
+}
+
+/// Transcribe chunks with the Whisper worker pool, one engine per worker.
+///
+/// Returns one result per chunk, None where a chunk failed after its retries.
+async fn transcribe_parallel<R: Runtime>(
+    app: &AppHandle<R>,
+    meeting_id: &str,
+    model: &str,
+    chunks: &[TimedChunk],
+) -> Result<Vec<Option<TranscriptResult>>, String> {
+    let state = app.state::<ParallelProcessorState>();
+    let mut config = ParallelConfig::default();
+    config.max_workers = state
+        .system_monitor
+        .calculate_safe_worker_count()
+        .await
+        .map_err(|e| format!("Failed to calculate safe worker count: {}", e))?
+        .max(1);
+
+    let (mut processor, mut events) = ParallelProcessor::new(config, state.system_monitor.clone())
+        .map_err(|e| format!("Failed to create parallel processor: {}", e))?;
+
+    // Workers take chunks from the end of the queue; reverse so they run in playback order
+    let audio_chunks: Vec<AudioChunk> = chunks
+        .iter()
+        .enumerate()
+        .rev()
+        .map(|(i, chunk)| AudioChunk {
+            id: i as u32,
+            data: chunk.samples.clone(),
+            sample_rate: TRANSCRIPTION_SAMPLE_RATE,
+            start_time_ms: chunk.start * 1000.0,
+            duration_ms: (chunk.end - chunk.start) * 1000.0,
+        })
+        .collect();
+    processor
+        .start_processing(audio_chunks, model.to_string())
+        .await
+        .map_err(|e| format!("Failed to start parallel processing: {}", e))?;
+    let workers = processor.worker_count();
+    if workers == 0 {
+        processor.stop_processing().await;
+        return Err("No transcription workers could be started".to_string());
+    }
+    info!("Re-transcribing {} segments with {} workers", chunks.len(), workers);
+
+    let total = chunks.len();
+    let mut results: Vec<Option<TranscriptResult>> = vec![None; total];
+    let mut finished = 0;
+    let mut stopped_workers = 0;
+    let outcome = loop {
+        if finished == total {
+            break Ok(());
+        }
+        if RETRANSCRIBE_CANCELLED.load(Ordering::SeqCst) {
+            break Err("Re-transcription cancelled".to_string());
+        }
+        // Wake up regularly to notice cancellation
+        let event = match tokio::time::timeout(std::time::Duration::from_millis(500), events.recv()).await {
+            Ok(Some(event)) => event,
+            Ok(None) => break Err("Parallel processor stopped unexpectedly".to_string()),
+            Err(_) => continue,
+        };
+
+        match event {
+            ProcessingEvent::ChunkCompleted(result) => {
+                finished += 1;
+                if let Some(slot) = results.get_mut(result.chunk_id as usize) {
+                    *slot = Some(TranscriptResult {
+                        text: result.text,
+                        confidence: result.confidence_score,
+                        is_partial: false,
+                        words: result.words,
+                    });
+                }
+                emit_progress(
+                    app,
+                    meeting_id,
+                    "transcribing",
+                    transcribing_progress(finished, total),
+                    format!("Transcribed {} of {} segments", finished, total),
+                    Some((finished, total)),
+                );
+            }
+            ProcessingEvent::ChunkFailed(failure) if !failure.is_recoverable => {
+                warn!("Segment {} could not be re-transcribed: {}", failure.chunk_id, failure.error_message);
+                finished += 1;
+            }
+            ProcessingEvent::WorkerStopped(_) => {
+                stopped_workers += 1;
+                if stopped_workers >= workers && finished < total {
+                    break Err("All transcription workers stopped before finishing".to_string());
+                }
+            }
+            ProcessingEvent::ResourceConstraint(constraint) => emit_progress(
+                app,
+                meeting_id,
+                "transcribing",
+                transcribing_progress(finished, total),
+                format!("Paused: {}", constraint),
+                Some((finished, total)),
+            ),
+            _ => {}
+        }
+    };
+
+    processor.stop_processing().await;
+    outcome.map(|_| results)
+}
+
+/// Transcribe chunks one after another with a single engine
+async fn transcribe_sequential<R: Runtime>(
+    app: &AppHandle<R>,
+    meeting_id: &str,
+    engine: &TranscriptionEngine,
+    chunks: &[TimedChunk],
+) -> Result<Vec<Option<TranscriptResult>>, String> {
+    let total = chunks.len();
+    let mut results = Vec::with_capacity(total);
+    for (i, chunk) in chunks.iter().enumerate() {
+        if RETRANSCRIBE_CANCELLED.load(Ordering::SeqCst) {
+            return Err("Re-transcription cancelled".to_string());
+        }
+        emit_progress(
+            app,
+            meeting_id,
+            "transcribing",
+            transcribing_progress(i, total),
+            format!("Transcribing segment {} of {}", i + 1, total),
+            Some((i + 1, total)),
+        );
+
+        match engine.transcribe(chunk.samples.clone()).await {
+            Ok(result) => results.push(Some(result)),
+            Err(e) => {
+                warn!("Failed to re-transcribe segment {} ({:.1}s): {}", i + 1, chunk.start, e);
+                results.push(None);
+            }
+        }
+    }
+    Ok(results)
+}
+
+/// Transcribe chunks with the chosen engine and model; local Whisper runs on the worker
+/// pool and falls back to a single engine if the pool can't start
+async fn transcribe_chunks<R: Runtime>(
+    app: &AppHandle<R>,
+    meeting_id: &str,
+    provider: &str,
+    model: &str,
+    chunks: &[TimedChunk],
+) -> Result<Vec<Option<TranscriptResult>>, String> {
+    emit_progress(app, meeting_id, "loading_model", 5, format!("Loading {}", model), None);
+    if provider == "localWhisper" {
+        refresh_vocabulary(app).await;
+        match transcribe_parallel(app, meeting_id, model, chunks).await {
+            Ok(results) => Ok(results),
+            Err(e) if RETRANSCRIBE_CANCELLED.load(Ordering::SeqCst) => Err(e),
+            Err(e) => {
+                warn!("Parallel re-transcription unavailable ({}), transcribing sequentially", e);
+                let engine = load_transcription_engine(app, provider, model).await?;
+                transcribe_sequential(app, meeting_id, &engine, chunks).await
+            }
+        }
+    } else {
+        let engine = load_transcription_engine(app, provider, model).await?;
+        transcribe_sequential(app, meeting_id, &engine, chunks).await
+    }
+}
+
+/// The recording folder of a saved meeting and its audio, decoded to 16kHz mono
+async fn load_meeting_audio<R: Runtime>(
+    app: &AppHandle<R>,
+    pool: &SqlitePool,
+    meeting_id: &str,
+) -> Result<(PathBuf, Vec<f32>), String> {
+    let meeting = MeetingsRepository::get_meeting_metadata(pool, meeting_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Meeting not found: {}", meeting_id))?;
+    let folder = meeting
+        .folder_path
+        .map(PathBuf::from)
+        .ok_or_else(|| "Meeting has no recording folder".to_string())?;
+    let audio_path = find_meeting_audio(&folder)
+        .ok_or_else(|| format!("Recording not found in {}", folder.display()))?;
+
+    emit_progress(app, meeting_id, "decoding", 0, "Decoding audio", None);
+    let audio = tokio::task::spawn_blocking(move || decode_to_16k_mono(&audio_path))
+        .await
+        .map_err(|e| format!("Decoding task failed: {}", e))?
+        .map_err(|e| format!("Failed to decode audio: {}", e))?;
+    Ok((folder, audio))
+}
+
+async fn run_retranscription<R: Runtime>(
+    app: &AppHandle<R>,
+    pool: &SqlitePool,
+    meeting_id: &str,
+    provider: &str,
+    model: &str,
+    keep_previous: bool,
+) -> Result<RetranscribeResult, String> {
+    let (folder, audio) = load_meeting_audio(app, pool, meeting_id).await?;
+
+    emit_progress(app, meeting_id, "detecting_speech", 3, "Detecting speech", None);
+    let speech = tokio::task::spawn_blocking(move || get_speech_chunks(&audio, VAD_REDEMPTION_MS))
+        .await
+        .map_err(|e| format!("Speech detection task failed: {}", e))?
+        .map_err(|e| format!("Speech detection failed: {}", e))?;
+    let chunks = split_speech_segments(speech, MAX_SEGMENT_SECONDS);
+    if chunks.is_empty() {
+        return Err("No speech detected in the recording".to_string());
+    }
+
+    let results = transcribe_chunks(app, meeting_id, provider, model, &chunks).await?;
+
+    let mut segments: Vec<TranscriptSegment> = chunks
+        .iter()
+        .zip(results)
+        .filter_map(|(chunk, result)| {
+            let result = result?;
+            let text = result.text.trim();
+            if text.is_empty() {
+                return None;
+            }
+            Some(TranscriptSegment {
+                id: String::new(),
+                text: text.to_string(),
+                timestamp: crate::utils::format_timestamp(chunk.start),
+                audio_start_time: Some(chunk.start),
+                audio_end_time: Some(chunk.end),
+                duration: Some(chunk.end - chunk.start),
+                speaker: None,
+                confidence: result.confidence,
+                low_confidence: result.is_low_confidence(),
+                words: result.words.iter().map(|w| w.offset_by(chunk.start)).collect(),
+            })
+        })
+        .collect();
+    if segments.is_empty() {
+        return Err("Re-transcription produced no text".to_string());
+    }
+
+    emit_progress(app, meeting_id, "saving", 95, "Saving transcript", None);
+    let mut conn = pool.acquire().await.map_err(|e| e.to_string())?;
+    let previous = TranscriptsRepository::load_segments(&mut *conn, meeting_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    drop(conn);
+    carry_over_sources(&previous, &mut segments);
+
+    let origin = TranscriptVersionOrigin::retranscribed(provider, model);
+    let archived = TranscriptsRepository::replace_transcripts(
+        pool,
+        meeting_id,
+        &segments,
+        keep_previous.then_some(&origin),
+    )
+    .await
+    .map_err(|e| format!("Failed to save transcript: {}", e))?;
+
+    // Speaker labels belong to the replaced rows; re-apply diarization to the new ones
+    crate::diarization::apply_saved_speaker_turns(pool, meeting_id, &folder).await;
+
+    info!(
+        "Re-transcribed meeting {} with {} {} ({} segments)",
+        meeting_id,
+        provider,
+        model,
+        segments.len()
+    );
+    Ok(RetranscribeResult {
+        meeting_id: meeting_id.to_string(),
+        provider: provider.to_string(),
+        model: model.to_string(),
+        segment_count: segments.len(),
+        archived_version: archived.map(|version| version.version),
+    })
+}
+
+async fn run_low_confidence_retranscription<R: Runtime>(
+    app: &AppHandle<R>,
+    pool: &SqlitePool,
+    meeting_id: &str,
+    provider: &str,
+    model: &str,
+    threshold: f32,
+    keep_previous: bool,
+) -> Result<LowConfidenceRetranscribeResult, String> {
+    let candidates = TranscriptsRepository::get_low_confidence_segments(pool, meeting_id, threshold)
+        .await
+        .map_err(|e| e.to_string())?;
+    let mut outcome = LowConfidenceRetranscribeResult {
+        meeting_id: meeting_id.to_string(),
+        provider: provider.to_string(),
+        model: model.to_string(),
+        candidate_count: candidates.len(),
+        replaced_count: 0,
+        archived_version: None,
+    };
+    if candidates.is_empty() {
+        info!("Meeting {} has no low-confidence segments", meeting_id);
+        return Ok(outcome);
+    }
+
+    let (_, audio) = load_meeting_audio(app, pool, meeting_id).await?;
+    let chunks = segment_chunks(&audio, &candidates);
+    drop(audio);
+
+    let results = transcribe_chunks(app, meeting_id, provider, model, &chunks).await?;
+    let improved: Vec<TranscriptSegment> = candidates
+        .iter()
+        .zip(&chunks)
+        .zip(results)
+        .filter_map(|((segment, chunk), result)| improved_segment(segment, chunk, result?))
+        .collect();
+
+    if !improved.is_empty() {
+        emit_progress(app, meeting_id, "saving", 95, "Saving transcript", None);
+        let origin = TranscriptVersionOrigin::retranscribed(provider, model);
+        let archived = TranscriptsRepository::update_segments(
+            pool,
+            meeting_id,
+            &improved,
+            keep_previous.then_some(&origin),
+        )
+        .await
+        .map_err(|e| format!("Failed to save transcript: {}", e))?;
+        outcome.replaced_count = improved.len();
+        outcome.archived_version = archived.map(|version| version.version);
+    }
+
+    info!(
+        "Re-transcribed {} low-confidence segments of meeting {} with {} {} ({} replaced)",
+        outcome.candidate_count, meeting_id, provider, model, outcome.replaced_count
+    );
+    Ok(outcome)
+}
+
+/// Allow one re-transcription at a time; the returned guard releases the slot
+fn begin_retranscription() -> Result<RetranscribeGuard, String> {
+    if RETRANSCRIBE_IN_PROGRESS.swap(true, Ordering::SeqCst) {
+        return Err("Another re-transcription is already in progress".to_string());
+    }
+    RETRANSCRIBE_CANCELLED.store(false, Ordering::SeqCst);
+    Ok(RetranscribeGuard)
+}
+
+/// The chosen engine and model, or the defaults
+fn engine_choice(provider: Option<String>, model: Option<String>) -> (String, String) {
+    (
+        provider.filter(|p| !p.is_empty()).unwrap_or_else(|| DEFAULT_PROVIDER.to_string()),
+        model.filter(|m| !m.is_empty()).unwrap_or_else(|| DEFAULT_MODEL.to_string()),
+    )
+}
+
+/// Emit the final progress event of a re-transcription
+fn report_outcome<R: Runtime, T>(
+    app: &AppHandle<R>,
+    meeting_id: &str,
+    result: &Result<T, String>,
+    completed_message: impl FnOnce(&T) -> String,
+) {
+    match result {
+        Ok(done) => emit_progress(app, meeting_id, "completed", 100, completed_message(done), None),
+        Err(e) if RETRANSCRIBE_CANCELLED.load(Ordering::SeqCst) => {
+            info!("Re-transcription of {} cancelled: {}", meeting_id, e);
+            emit_progress(app, meeting_id, "cancelled", 0, "Re-transcription cancelled", None);
+        }
+        Err(e) => {
+            error!("Re-transcription of {} failed: {}", meeting_id, e);
+            emit_progress(app, meeting_id, "failed", 0, e.clone(), None);
+        }
+    }
+}
+
+/// Run one re-transcription at a time and report how it ended
+async fn retranscribe<R: Runtime>(
+    app: &AppHandle<R>,
+    pool: &SqlitePool,
+    meeting_id: &str,
+    provider: Option<String>,
+    model: Option<String>,
+    keep_previous: bool,
+) -> Result<RetranscribeResult, String> {
+    let _guard = begin_retranscription()?;
+    let (provider, model) = engine_choice(provider, model);
+
+    let result = run_retranscription(app, pool, meeting_id, &provider, &model, keep_previous).await;
+    report_outcome(app, meeting_id, &result, |done| {
+        format!("Re-transcribed {} segments with {}", done.segment_count, model)
+    });
+    result
+}
+
+/// Re-transcribe a saved meeting from its recorded audio.
+///
+/// The audio is decoded, split into speech segments with VAD and transcribed with the
+/// chosen engine and model (default: local Whisper `large-v3`, spread over several workers).
+/// The new segments replace the transcript; with `keep_previous` (the default) the old one
+/// is kept as a transcript version. Progress is reported through `retranscribe-progress` events.
+#[tauri::command]
+pub async fn retranscribe_meeting<R: Runtime>(
+    app: AppHandle<R>,
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    meeting_id: String,
+    provider: Option<String>,
+    model: Option<String>,
+    keep_previous: Option<bool>,
+) -> Result<RetranscribeResult, String> {
+    info!("retranscribe_meeting called for meeting_id: {}", meeting_id);
+    if super::recording_commands::is_recording().await {
+        return Err("Cannot re-transcribe while a recording is in progress".to_string());
+    }
+    let pool = workspace_mgr.active_pool().await?;
+    retranscribe(&app, &pool, &meeting_id, provider, model, keep_previous.unwrap_or(true)).await
+}
+
+/// Re-transcribe only the low-confidence segments of a saved meeting.
+///
+/// Segments flagged during live transcription, or scoring below `threshold` (default
+/// 0.3), are cut from the recording and transcribed again with the chosen engine and model
+/// (default: local Whisper `large-v3`). A new transcription replaces a segment when it scores
+/// higher. With `keep_previous` (the default) the transcript is first kept as a transcript
+/// version. Progress is reported through `retranscribe-progress` events.
+#[tauri::command]
+pub async fn retranscribe_low_confidence_segments<R: Runtime>(
+    app: AppHandle<R>,
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    meeting_id: String,
+    provider: Option<String>,
+    model: Option<String>,
+    threshold: Option<f32>,
+    keep_previous: Option<bool>,
+) -> Result<LowConfidenceRetranscribeResult, String> {
+    info!("retranscribe_low_confidence_segments called for meeting_id: {}", meeting_id);
+    if super::recording_commands::is_recording().await {
+        return Err("Cannot re-transcribe while a recording is in progress".to_string());
+    }
+    let pool = workspace_mgr.active_pool().await?;
+    let _guard = begin_retranscription()?;
+    let (provider, model) = engine_choice(provider, model);
+
+    let result = run_low_confidence_retranscription(
+        &app,
+        &pool,
+        &meeting_id,
+        &provider,
+        &model,
+        threshold.unwrap_or(LOW_CONFIDENCE_THRESHOLD),
+        keep_previous.unwrap_or(true),
+    )
+    .await;
+    report_outcome(&app, &meeting_id, &result, |done| {
+        format!(
+            "Improved {} of {} low-confidence segments with {}",
+            done.replaced_count, done.candidate_count, model
+        )
+    });
+    result
+}
+
+/// Stop the running re-transcription; the transcript is left unchanged
+#[tauri::command]
+pub async fn cancel_retranscription() -> Result<(), String> {
+    if RETRANSCRIBE_IN_PROGRESS.load(Ordering::SeqCst) {
+        RETRANSCRIBE_CANCELLED.store(true, Ordering::SeqCst);
+    }
+    Ok(())
+}
+
+/// Cancel the running re-transcription because a recording is starting
+pub fn cancel_for_recording() {
+    if RETRANSCRIBE_IN_PROGRESS.load(Ordering::SeqCst) {
+        info!("Recording started, cancelling the running re-transcription");
+        RETRANSCRIBE_CANCELLED.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Lists the earlier transcripts of a meeting, newest first
+#[tauri::command]
+pub async fn api_list_transcript_versions(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    meeting_id: String,
+) -> Result<Vec<TranscriptVersion>, String> {
+    info!("api_list_transcript_versions called for meeting_id: {}", meeting_id);
+    let pool = workspace_mgr.active_pool().await?;
+    TranscriptVersionsRepository::list_versions(&pool, &meeting_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Makes an earlier transcript active again, archiving the current one
+///
+/// # Returns
+/// The number of restored segments
+#[tauri::command]
+pub async fn api_restore_transcript_version(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    meeting_id: String,
+    version: i64,
+) -> Result<usize, String> {
+    info!(
+        "api_restore_transcript_version called for meeting_id: {}, version: {}",
+        meeting_id, version
+    );
+    if RETRANSCRIBE_IN_PROGRESS.load(Ordering::SeqCst) {
+        return Err("Cannot restore a transcript while a re-transcription is running".to_string());
+    }
+    let pool = workspace_mgr.active_pool().await?;
+    let restored = TranscriptVersionsRepository::restore_version(&pool, &meeting_id, version)
+        .await
+        .map_err(|e| {
+            error!("Failed to restore transcript version {} of {}: {}", version, meeting_id, e);
+            e.to_string()
+        })?
+        .ok_or_else(|| format!("Transcript version {} not found", version))?;
+
+    if let Ok(Some(meeting)) = MeetingsRepository::get_meeting_metadata(&pool, &meeting_id).await {
+        if let Some(folder) = meeting.folder_path {
+            crate::diarization::apply_saved_speaker_turns(&pool, &meeting_id, Path::new(&folder)).await;
+        }
+    }
+    Ok(restored)
+}
+
+fn spawn_retranscription<R: Runtime>(app: AppHandle<R>, meeting_id: String, model: Option<String>) {
+    tauri::async_runtime::spawn(async move {
+        let Some(workspace_mgr) = app.try_state::<WorkspaceManager>() else {
+            return;
+        };
+        let pool = match workspace_mgr.active_pool().await {
+            Ok(pool) => pool,
+            Err(e) => {
+                warn!("Skipping automatic re-transcription of {}: {}", meeting_id, e);
+                return;
+            }
+        };
+        // A new recording may have started while the previous one was being saved
+        if super::recording_commands::is_recording().await {
+            info!("Skipping automatic re-transcription of {}: a recording is in progress", meeting_id);
+            return;
+        }
+        if let Err(e) = retranscribe(&app, &pool, &meeting_id, None, model, true).await {
+            warn!("Automatic re-transcription of {} failed: {}", meeting_id, e);
+        }
+    });
+}
+
+/// Take a folder off the pending list; true if it was on it
+fn take_pending(folder: &Path) -> bool {
+    let mut pending = PENDING_RECORDINGS.lock().unwrap();
+    match pending.iter().position(|p| p == folder) {
+        Some(index) => {
+            pending.remove(index);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Re-transcribe a just-finished recording when the recording preferences ask for it.
+///
+/// Only saved meetings can be re-transcribed: if the meeting is already in the database
+/// this starts right away, otherwise `on_meeting_saved` starts it once it is saved.
+pub fn schedule_post_recording_retranscription<R: Runtime>(app: AppHandle<R>, meeting_folder: PathBuf) {
+    tauri::async_runtime::spawn(async move {
+        let prefs = match super::recording_preferences::load_recording_preferences(&app).await {
+            Ok(prefs) if prefs.auto_retranscribe => prefs,
+            _ => return,
+        };
+        PENDING_RECORDINGS.lock().unwrap().push(meeting_folder.clone());
+
+        let Some(workspace_mgr) = app.try_state::<WorkspaceManager>() else {
+            return;
+        };
+        let Ok(pool) = workspace_mgr.active_pool().await else {
+            return;
+        };
+        let folder = meeting_folder.to_string_lossy().to_string();
+        if let Ok(Some(meeting_id)) = MeetingsRepository::find_meeting_id_by_folder_path(&pool, &folder).await {
+            if take_pending(&meeting_folder) {
+                spawn_retranscription(app.clone(), meeting_id, prefs.retranscribe_model);
+            }
+        }
+    });
+}
+
+/// Start a scheduled re-transcription once its recording has been saved as a meeting
+pub async fn on_meeting_saved<R: Runtime>(app: &AppHandle<R>, meeting_id: &str, folder: &Path) {
+    if !take_pending(folder) {
+        return;
+    }
+    let model = super::recording_preferences::load_recording_preferences(app)
+        .await
+        .ok()
+        .and_then(|prefs| prefs.retranscribe_model);
+    spawn_retranscription(app.clone(), meeting_id.to_string(), model);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::transcription::WordTiming;
+
+    fn segment(start: f64, end: f64, speaker: Option<&str>) -> TranscriptSegment {
+        TranscriptSegment {
+            id: String::new(),
+            text: "text".to_string(),
+            timestamp: crate::utils::format_timestamp(start),
+            audio_start_time: Some(start),
+            audio_end_time: Some(end),
+            duration: Some(end - start),
+            speaker: speaker.map(str::to_string),
+            confidence: None,
+            low_confidence: false,
+            words: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_carry_over_sources_by_overlap() {
+        let previous = vec![
+            segment(0.0, 4.0, Some("mic")),
+            segment(4.0, 9.0, Some("system")),
+            segment(9.0, 10.0, None),
+        ];
+        let mut segments = vec![
+            segment(0.5, 3.0, None),
+            segment(3.5, 8.0, None),
+            segment(30.0, 32.0, None),
+        ];
+        carry_over_sources(&previous, &mut segments);
+
+        let sources: Vec<Option<&str>> = segments.iter().map(|s| s.speaker.as_deref()).collect();
+        assert_eq!(sources, vec![Some("mic"), Some("system"), None]);
+    }
+
+    fn stored(start: f64, end: f64, confidence: Option<f32>) -> Transcript {
+        Transcript {
+            id: format!("transcript-{}", start),
+            meeting_id: "m1".to_string(),
+            transcript: "ship the road".to_string(),
+            timestamp: crate::utils::format_timestamp(start),
+            summary: None,
+            action_items: None,
+            key_points: None,
+            audio_start_time: Some(start),
+            audio_end_time: Some(end),
+            duration: Some(end - start),
+            speaker: Some("mic".to_string()),
+            speaker_label: Some("Speaker 1".to_string()),
+            confidence,
+            low_confidence: true,
+        }
+    }
+
+    fn result(text: &str, confidence: Option<f32>) -> TranscriptResult {
+        TranscriptResult {
+            text: text.to_string(),
+            confidence,
+            is_partial: false,
+            words: vec![WordTiming {
+                word: "Ship".to_string(),
+                start: 0.2,
+                end: 0.5,
+                probability: confidence,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_segment_chunks_pad_and_clamp_to_audio() {
+        let audio = vec![0.0; TRANSCRIPTION_SAMPLE_RATE as usize * 10];
+        let chunks = segment_chunks(&audio, &[stored(0.1, 2.0, None), stored(9.0, 12.0, None)]);
+
+        assert_eq!(chunks[0].start, 0.0);
+        assert!((chunks[0].end - 2.2).abs() < 1e-9);
+        assert_eq!(chunks[0].samples.len(), (2.2 * TRANSCRIPTION_SAMPLE_RATE as f64) as usize);
+        assert!((chunks[1].start - 8.8).abs() < 1e-9);
+        assert_eq!(chunks[1].end, 10.0);
+    }
+
+    #[test]
+    fn test_improved_segment_only_replaces_with_better_result() {
+        let segment = stored(4.0, 6.0, Some(0.2));
+        let chunk = TimedChunk {
+            start: 3.8,
+            end: 6.2,
+            samples: Vec::new(),
+        };
+
+        let better = improved_segment(&segment, &chunk, result(" Ship the road map. ", Some(0.7))).unwrap();
+        assert_eq!(better.id, segment.id);
+        assert_eq!(better.text, "Ship the road map.");
+        assert_eq!(better.audio_start_time, Some(4.0));
+        assert_eq!(better.speaker.as_deref(), Some("mic"));
+        assert!(!better.low_confidence);
+        assert!((better.words[0].start - 4.0).abs() < 1e-9);
+
+        assert!(improved_segment(&segment, &chunk, result("Ship the rode", Some(0.1))).is_none());
+        assert!(improved_segment(&segment, &chunk, result("  ", Some(0.9))).is_none());
+        // An engine without confidence scores is trusted
+        let unscored = improved_segment(&segment, &chunk, result("Ship the road map", None)).unwrap();
+        assert_eq!(unscored.confidence, None);
+        assert!(!unscored.low_confidence);
+    }
+}
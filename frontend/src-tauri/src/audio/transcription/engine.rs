@@ -2,10 +2,11 @@
 //
 // TranscriptionEngine enum and model initialization/validation logic.
 
-use super::provider::TranscriptionProvider;
+use super::provider::{TranscriptResult, TranscriptionError, TranscriptionProvider};
 use log::{info, warn};
 use std::sync::Arc;
 use tauri::{AppHandle, Manager, Runtime};
+use crate::workspace::manager::WorkspaceManager;
 
 // ============================================================================
 // TRANSCRIPTION ENGINE ENUM
@@ -45,13 +46,42 @@ impl TranscriptionEngine {
             Self::Provider(provider) => provider.provider_name(),
         }
     }
+
+    /// Transcribe one 16kHz mono speech segment using the saved language preference.
+    ///
+    /// Used for offline transcription of whole files; live recording goes through the worker.
+    pub async fn transcribe(&self, audio: Vec<f32>) -> Result<TranscriptResult, TranscriptionError> {
+        let language = crate::get_language_preference_internal();
+        let result = match self {
+            Self::Whisper(engine) => engine
+                .transcribe_audio_with_confidence(audio, language)
+                .await
+                .map_err(|e| TranscriptionError::EngineFailed(e.to_string()))?,
+            Self::Parakeet(engine) => engine
+                .transcribe_audio_with_words(audio)
+                .await
+                .map(|(text, words)| TranscriptResult {
+                    text,
+                    confidence: None,
+                    is_partial: false,
+                    words,
+                })
+                .map_err(|e| TranscriptionError::EngineFailed(e.to_string()))?,
+            Self::Provider(provider) => provider.transcribe(audio, language).await?,
+        };
+
+        Ok(TranscriptResult {
+            text: result.text.trim().to_string(),
+            ..result
+        })
+    }
 }
 
 // ============================================================================
 // MODEL VALIDATION AND INITIALIZATION
 // ============================================================================
 
-/// Validate that transcription models (Whisper or Parakeet) are ready before starting recording
+/// Validate that the transcription engine (Whisper, Parakeet or a remote provider) is ready before starting recording
 pub async fn validate_transcription_model_ready<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
     // Check transcript configuration to determine which engine to validate
     let config = match crate::api::api::api_get_transcript_config(
@@ -74,6 +104,7 @@ pub async fn validate_transcription_model_ready<R: Runtime>(app: &AppHandle<R>)
                 provider: "parakeet".to_string(),
                 model: "parakeet-tdt-0.6b-v3-int8".to_string(),
                 api_key: None,
+                endpoint: None,
             }
         }
         Err(e) => {
@@ -82,6 +113,7 @@ pub async fn validate_transcription_model_ready<R: Runtime>(app: &AppHandle<R>)
                 provider: "parakeet".to_string(),
                 model: "parakeet-tdt-0.6b-v3-int8".to_string(),
                 api_key: None,
+                endpoint: None,
             }
         }
     };
@@ -136,11 +168,18 @@ pub async fn validate_transcription_model_ready<R: Runtime>(app: &AppHandle<R>)
             }
         }
         other => {
-            warn!("❌ Unsupported transcription provider for local recording: {}", other);
-            Err(format!(
-                "Provider '{}' is not supported for local transcription. Please select 'localWhisper' or 'parakeet'.",
-                other
-            ))
+            // Remote providers have nothing to load; check they are fully configured
+            match super::remote_provider::remote_provider_from_config(&config) {
+                Ok(Some(_)) => {
+                    info!("✅ Remote transcription provider {} is configured", other);
+                    Ok(())
+                }
+                Ok(None) => Ok(()),
+                Err(e) => {
+                    warn!("❌ Transcription provider {} is not usable: {}", other, e);
+                    Err(e)
+                }
+            }
         }
     }
 }
@@ -170,6 +209,7 @@ pub async fn get_or_init_transcription_engine<R: Runtime>(
                 provider: "parakeet".to_string(),
                 model: "parakeet-tdt-0.6b-v3-int8".to_string(),
                 api_key: None,
+                endpoint: None,
             }
         }
         Err(e) => {
@@ -178,10 +218,17 @@ pub async fn get_or_init_transcription_engine<R: Runtime>(
                 provider: "parakeet".to_string(),
                 model: "parakeet-tdt-0.6b-v3-int8".to_string(),
                 api_key: None,
+                endpoint: None,
             }
         }
     };
 
+    refresh_vocabulary(app).await;
+
+    if let Some(provider) = super::remote_provider::remote_provider_from_config(&config)? {
+        return Ok(TranscriptionEngine::Provider(provider));
+    }
+
     // Initialize the appropriate engine based on provider
     match config.provider.as_str() {
         "parakeet" => {
@@ -220,6 +267,91 @@ pub async fn get_or_init_transcription_engine<R: Runtime>(
     }
 }
 
+/// Engines read the vocabulary of the workspace being recorded into
+pub(crate) async fn refresh_vocabulary<R: Runtime>(app: &AppHandle<R>) {
+    match app.state::<WorkspaceManager>().active_pool().await {
+        Ok(pool) => {
+            if let Err(e) = super::vocabulary::refresh_active_vocabulary(&pool).await {
+                warn!("⚠️ Failed to load transcription vocabulary: {}", e);
+            }
+        }
+        Err(e) => warn!("⚠️ No active workspace for transcription vocabulary: {}", e),
+    }
+}
+
+/// Load a dedicated engine for `provider` and `model`, separate from the live recording engine.
+///
+/// Used for post-meeting re-transcription, which usually wants a larger model than the one
+/// kept loaded for live transcription. Local models must already be downloaded; remote
+/// providers use the API key and, for the configured provider, the endpoint saved in settings.
+pub async fn load_transcription_engine<R: Runtime>(
+    app: &AppHandle<R>,
+    provider: &str,
+    model: &str,
+) -> Result<TranscriptionEngine, String> {
+    refresh_vocabulary(app).await;
+
+    match provider {
+        "localWhisper" => {
+            info!("🎤 Loading dedicated Whisper engine with model '{}'", model);
+            let engine = crate::whisper_engine::WhisperEngine::new_with_models_dir(
+                crate::whisper_engine::commands::get_models_directory(),
+            )
+            .map_err(|e| format!("Failed to initialize Whisper engine: {}", e))?;
+            engine
+                .discover_models()
+                .await
+                .map_err(|e| format!("Failed to discover models: {}", e))?;
+            engine
+                .load_model(model)
+                .await
+                .map_err(|e| format!("Failed to load Whisper model '{}': {}", model, e))?;
+            Ok(TranscriptionEngine::Whisper(Arc::new(engine)))
+        }
+        "parakeet" => {
+            info!("🦜 Loading dedicated Parakeet engine with model '{}'", model);
+            let engine = crate::parakeet_engine::ParakeetEngine::new_with_models_dir(
+                crate::parakeet_engine::commands::get_models_directory(),
+            )
+            .map_err(|e| format!("Failed to initialize Parakeet engine: {}", e))?;
+            engine
+                .discover_models()
+                .await
+                .map_err(|e| format!("Failed to discover Parakeet models: {}", e))?;
+            engine
+                .load_model(model)
+                .await
+                .map_err(|e| format!("Failed to load Parakeet model '{}': {}", model, e))?;
+            Ok(TranscriptionEngine::Parakeet(Arc::new(engine)))
+        }
+        _ => {
+            let workspace_mgr = app.state::<WorkspaceManager>();
+            let api_key = crate::database::repositories::setting::SettingsRepository::get_transcript_api_key(
+                workspace_mgr.global_pool(),
+                provider,
+            )
+            .await
+            .map_err(|e| format!("Failed to read API key for {}: {}", provider, e))?;
+            let endpoint = crate::api::api::api_get_transcript_config(app.clone(), app.clone().state(), None)
+                .await
+                .ok()
+                .flatten()
+                .filter(|config| config.provider == provider)
+                .and_then(|config| config.endpoint);
+
+            let config = crate::api::api::TranscriptConfig {
+                provider: provider.to_string(),
+                model: model.to_string(),
+                api_key,
+                endpoint,
+            };
+            super::remote_provider::remote_provider_from_config(&config)?
+                .map(TranscriptionEngine::Provider)
+                .ok_or_else(|| format!("Transcription provider '{}' is not supported", provider))
+        }
+    }
+}
+
 /// Get or initialize transcription engine using API configuration
 /// Returns Whisper engine if provider is localWhisper, otherwise returns error for non-Whisper providers
 pub async fn get_or_init_whisper<R: Runtime>(
@@ -5,13 +5,19 @@
 pub mod provider;
 pub mod whisper_provider;
 pub mod parakeet_provider;
+pub mod remote_provider;
+pub mod vocabulary;
 pub mod engine;
 pub mod worker;
 
 // Re-export commonly used types
-pub use provider::{TranscriptionError, TranscriptionProvider, TranscriptResult};
+pub use provider::{
+    TranscriptionError, TranscriptionProvider, TranscriptResult, WordTiming,
+    LOW_CONFIDENCE_THRESHOLD,
+};
 pub use whisper_provider::WhisperProvider;
 pub use parakeet_provider::ParakeetProvider;
+pub use remote_provider::{DeepgramProvider, OpenAiCompatibleProvider};
 pub use engine::{
     TranscriptionEngine,
     validate_transcription_model_ready,
@@ -33,11 +33,12 @@ impl TranscriptionProvider for ParakeetProvider {
             );
         }
 
-        match self.engine.transcribe_audio(audio).await {
-            Ok(text) => Ok(TranscriptResult {
+        match self.engine.transcribe_audio_with_words(audio).await {
+            Ok((text, words)) => Ok(TranscriptResult {
                 text: text.trim().to_string(),
                 confidence: None, // Parakeet doesn't provide confidence scores
                 is_partial: false, // Parakeet doesn't provide partial results
+                words,
             }),
             Err(e) => Err(TranscriptionError::EngineFailed(e.to_string())),
         }
@@ -4,6 +4,7 @@
 // transcription engines (Whisper, Parakeet, future providers).
 
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 
 // ============================================================================
 // TRANSCRIPTION PROVIDER TRAIT & ERROR TYPES
@@ -37,12 +38,104 @@ impl std::fmt::Display for TranscriptionError {
 
 impl std::error::Error for TranscriptionError {}
 
+/// Timing of one transcribed word, in seconds from the start of the transcribed audio
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WordTiming {
+    pub word: String,
+    pub start: f64,
+    pub end: f64,
+    /// Recognition probability (0.0-1.0); None if the engine doesn't report one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub probability: Option<f32>,
+}
+
+impl WordTiming {
+    /// The same word shifted by `offset` seconds, e.g. from segment to recording time
+    pub fn offset_by(&self, offset: f64) -> Self {
+        Self {
+            start: self.start + offset,
+            end: self.end + offset,
+            ..self.clone()
+        }
+    }
+}
+
 /// Unified transcription result across all providers
 #[derive(Debug, Clone)]
 pub struct TranscriptResult {
     pub text: String,
     pub confidence: Option<f32>, // None if provider doesn't support confidence scores
     pub is_partial: bool,
+    pub words: Vec<WordTiming>, // Empty if provider doesn't report word timings
+}
+
+/// Confidence below which a segment is flagged for review and re-transcription
+pub const LOW_CONFIDENCE_THRESHOLD: f32 = 0.3;
+
+impl TranscriptResult {
+    /// Whether the engine reported a confidence below `LOW_CONFIDENCE_THRESHOLD`.
+    /// Results without a confidence score are never low-confidence.
+    pub fn is_low_confidence(&self) -> bool {
+        self.confidence.is_some_and(|c| c < LOW_CONFIDENCE_THRESHOLD)
+    }
+}
+
+/// Recognition confidence of a transcription: the mean probability of its words.
+///
+/// Returns None when no word carries a probability.
+pub fn confidence_from_words(words: &[WordTiming]) -> Option<f32> {
+    let probabilities: Vec<f32> = words.iter().filter_map(|w| w.probability).collect();
+    if probabilities.is_empty() {
+        return None;
+    }
+    Some(probabilities.iter().sum::<f32>() / probabilities.len() as f32)
+}
+
+/// Groups sub-word tokens into words
+///
+/// Engines emit tokens like " Hel", "lo", ","; a token starting with whitespace begins a
+/// new word and any other token is appended to the current one. A word spans its tokens
+/// and its probability is the mean of theirs.
+pub fn words_from_tokens(tokens: impl IntoIterator<Item = WordTiming>) -> Vec<WordTiming> {
+    let mut words: Vec<WordTiming> = Vec::new();
+    let mut probabilities: Vec<f32> = Vec::new();
+
+    let finish = |word: &mut WordTiming, probabilities: &mut Vec<f32>| {
+        if !probabilities.is_empty() {
+            word.probability = Some(probabilities.iter().sum::<f32>() / probabilities.len() as f32);
+        }
+        probabilities.clear();
+    };
+
+    for token in tokens {
+        let text = token.word.trim();
+        if text.is_empty() {
+            continue;
+        }
+        let starts_word = token.word.starts_with(char::is_whitespace);
+        match words.last_mut() {
+            Some(word) if !starts_word => {
+                word.word.push_str(text);
+                word.end = word.end.max(token.end);
+            }
+            _ => {
+                if let Some(word) = words.last_mut() {
+                    finish(word, &mut probabilities);
+                }
+                words.push(WordTiming {
+                    word: text.to_string(),
+                    start: token.start,
+                    end: token.end,
+                    probability: None,
+                });
+            }
+        }
+        probabilities.extend(token.probability);
+    }
+    if let Some(word) = words.last_mut() {
+        finish(word, &mut probabilities);
+    }
+    words
 }
 
 /// Trait for transcription providers (Whisper, Parakeet, future providers)
@@ -71,3 +164,64 @@ pub trait TranscriptionProvider: Send + Sync {
     /// Get the provider name (for logging/debugging)
     fn provider_name(&self) -> &'static str;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(text: &str, start: f64, end: f64, probability: Option<f32>) -> WordTiming {
+        WordTiming {
+            word: text.to_string(),
+            start,
+            end,
+            probability,
+        }
+    }
+
+    #[test]
+    fn test_words_from_tokens() {
+        let words = words_from_tokens(vec![
+            token("Hel", 0.0, 0.2, Some(1.0)),
+            token("lo", 0.2, 0.4, Some(0.5)),
+            token(",", 0.4, 0.45, Some(0.75)),
+            token(" ", 0.45, 0.5, None),
+            token(" world", 0.5, 0.9, Some(0.6)),
+        ]);
+        assert_eq!(
+            words,
+            vec![
+                token("Hello,", 0.0, 0.45, Some(0.75)),
+                token("world", 0.5, 0.9, Some(0.6)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_confidence_from_words() {
+        // Short but clearly recognised: not low-confidence because of its length
+        let yes = TranscriptResult {
+            text: "Yes.".to_string(),
+            confidence: confidence_from_words(&[token("Yes.", 0.0, 0.3, Some(0.94))]),
+            is_partial: false,
+            words: Vec::new(),
+        };
+        assert_eq!(yes.confidence, Some(0.94));
+        assert!(!yes.is_low_confidence());
+
+        let mumbled = confidence_from_words(&[
+            token("ship", 0.0, 0.3, Some(0.2)),
+            token("the", 0.3, 0.4, Some(0.1)),
+            token("rode", 0.4, 0.8, None),
+        ]);
+        assert!((mumbled.unwrap() - 0.15).abs() < 1e-6);
+        assert_eq!(confidence_from_words(&[token("it", 0.0, 0.2, None)]), None);
+    }
+
+    #[test]
+    fn test_words_without_probabilities() {
+        let words = words_from_tokens(vec![token(" Ship", 1.0, 1.3, None), token(" it", 1.5, 1.75, None)]);
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[1].probability, None);
+        assert_eq!(words[1].offset_by(10.0).start, 11.5);
+    }
+}
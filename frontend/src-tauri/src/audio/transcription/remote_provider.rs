@@ -0,0 +1,463 @@
+// audio/transcription/remote_provider.rs
+//
+// Remote speech-to-text providers: OpenAI-compatible `/audio/transcriptions` endpoints
+// (OpenAI, Groq, a self-hosted whisper.cpp server) and Deepgram's REST API. Speech
+// segments are uploaded as 16-bit WAV.
+
+use super::provider::{TranscriptionError, TranscriptionProvider, TranscriptResult, WordTiming};
+use async_trait::async_trait;
+use log::info;
+use reqwest::multipart::{Form, Part};
+use reqwest::Client;
+use serde::Deserialize;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub const OPENAI_TRANSCRIPTION_URL: &str = "https://api.openai.com/v1/audio/transcriptions";
+pub const GROQ_TRANSCRIPTION_URL: &str = "https://api.groq.com/openai/v1/audio/transcriptions";
+pub const DEEPGRAM_LISTEN_URL: &str = "https://api.deepgram.com/v1/listen";
+
+/// Sample rate of the audio handed to providers
+const SAMPLE_RATE: u32 = 16000;
+
+/// Speech segments are short, so a slow response means the service is struggling
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Encodes 16kHz mono f32 samples as a 16-bit PCM WAV file
+pub fn encode_wav(samples: &[f32], sample_rate: u32) -> Vec<u8> {
+    let data_len = (samples.len() * 2) as u32;
+    let mut bytes = Vec::with_capacity(44 + data_len as usize);
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data_len).to_le_bytes());
+    bytes.extend_from_slice(b"WAVEfmt ");
+    bytes.extend_from_slice(&16u32.to_le_bytes()); // fmt chunk size
+    bytes.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    bytes.extend_from_slice(&1u16.to_le_bytes()); // mono
+    bytes.extend_from_slice(&sample_rate.to_le_bytes());
+    bytes.extend_from_slice(&(sample_rate * 2).to_le_bytes()); // byte rate
+    bytes.extend_from_slice(&2u16.to_le_bytes()); // block align
+    bytes.extend_from_slice(&16u16.to_le_bytes()); // bits per sample
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&data_len.to_le_bytes());
+    for sample in samples {
+        let value = (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+        bytes.extend_from_slice(&value.to_le_bytes());
+    }
+    bytes
+}
+
+/// Language code to send, or None to let the service detect it
+///
+/// "auto" and "auto-translate" are the app's automatic detection settings.
+fn language_code(language: Option<String>) -> Option<String> {
+    language.filter(|l| !l.is_empty() && l != "auto" && l != "auto-translate")
+}
+
+fn request_failed(provider: &str, e: reqwest::Error) -> TranscriptionError {
+    if e.is_timeout() {
+        TranscriptionError::EngineFailed(format!(
+            "{} request timed out after {} seconds",
+            provider,
+            REQUEST_TIMEOUT.as_secs()
+        ))
+    } else {
+        TranscriptionError::EngineFailed(format!("{} request failed: {}", provider, e))
+    }
+}
+
+/// Reads a successful response body, or turns an error status into a TranscriptionError
+async fn read_body(provider: &str, response: reqwest::Response) -> Result<String, TranscriptionError> {
+    let status = response.status();
+    let body = response
+        .text()
+        .await
+        .map_err(|e| request_failed(provider, e))?;
+    if !status.is_success() {
+        return Err(TranscriptionError::EngineFailed(format!(
+            "{} returned {}: {}",
+            provider,
+            status,
+            body.trim()
+        )));
+    }
+    Ok(body)
+}
+
+/// Provider for endpoints that implement OpenAI's `/v1/audio/transcriptions`
+pub struct OpenAiCompatibleProvider {
+    client: Client,
+    name: &'static str,
+    url: String,
+    api_key: Option<String>,
+    model: String,
+}
+
+impl OpenAiCompatibleProvider {
+    /// # Arguments
+    /// * `name` - Provider name for logs and errors
+    /// * `url` - Full transcription endpoint URL
+    /// * `api_key` - Sent as a bearer token; self-hosted servers may not need one
+    pub fn new(name: &'static str, url: &str, api_key: Option<String>, model: &str) -> Self {
+        Self {
+            client: Client::new(),
+            name,
+            url: url.to_string(),
+            api_key: api_key.filter(|k| !k.is_empty()),
+            model: model.to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct OpenAiTranscription {
+    text: String,
+}
+
+#[async_trait]
+impl TranscriptionProvider for OpenAiCompatibleProvider {
+    async fn transcribe(
+        &self,
+        audio: Vec<f32>,
+        language: Option<String>,
+    ) -> std::result::Result<TranscriptResult, TranscriptionError> {
+        let file = Part::bytes(encode_wav(&audio, SAMPLE_RATE))
+            .file_name("audio.wav")
+            .mime_str("audio/wav")
+            .map_err(|e| TranscriptionError::EngineFailed(e.to_string()))?;
+        let mut form = Form::new()
+            .part("file", file)
+            .text("model", self.model.clone())
+            .text("response_format", "json");
+        if let Some(language) = language_code(language) {
+            form = form.text("language", language);
+        }
+        // The Whisper API takes the same glossary prompt as the local engine
+        if let Some(prompt) = super::vocabulary::initial_prompt(&super::vocabulary::active_vocabulary()) {
+            form = form.text("prompt", prompt);
+        }
+
+        let mut request = self
+            .client
+            .post(&self.url)
+            .multipart(form)
+            .timeout(REQUEST_TIMEOUT);
+        if let Some(api_key) = &self.api_key {
+            request = request.bearer_auth(api_key);
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| request_failed(self.name, e))?;
+        let body = read_body(self.name, response).await?;
+
+        let transcription: OpenAiTranscription = serde_json::from_str(&body).map_err(|e| {
+            TranscriptionError::EngineFailed(format!("Invalid {} response: {}", self.name, e))
+        })?;
+        Ok(TranscriptResult {
+            text: transcription.text.trim().to_string(),
+            confidence: None, // The JSON response format has no confidence
+            is_partial: false,
+            words: Vec::new(),
+        })
+    }
+
+    async fn is_model_loaded(&self) -> bool {
+        true
+    }
+
+    async fn get_current_model(&self) -> Option<String> {
+        Some(self.model.clone())
+    }
+
+    fn provider_name(&self) -> &'static str {
+        self.name
+    }
+}
+
+/// Provider for Deepgram's pre-recorded audio API
+pub struct DeepgramProvider {
+    client: Client,
+    url: String,
+    api_key: String,
+    model: String,
+}
+
+impl DeepgramProvider {
+    pub fn new(url: &str, api_key: &str, model: &str) -> Self {
+        Self {
+            client: Client::new(),
+            url: url.to_string(),
+            api_key: api_key.to_string(),
+            model: model.to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct DeepgramResponse {
+    results: DeepgramResults,
+}
+
+#[derive(Deserialize)]
+struct DeepgramResults {
+    channels: Vec<DeepgramChannel>,
+}
+
+#[derive(Deserialize)]
+struct DeepgramChannel {
+    alternatives: Vec<DeepgramAlternative>,
+}
+
+#[derive(Deserialize)]
+struct DeepgramAlternative {
+    transcript: String,
+    confidence: Option<f32>,
+    #[serde(default)]
+    words: Vec<DeepgramWord>,
+}
+
+#[derive(Deserialize)]
+struct DeepgramWord {
+    word: String,
+    /// The word with smart formatting (capitalization, punctuation) applied
+    punctuated_word: Option<String>,
+    start: f64,
+    end: f64,
+    confidence: Option<f32>,
+}
+
+#[async_trait]
+impl TranscriptionProvider for DeepgramProvider {
+    async fn transcribe(
+        &self,
+        audio: Vec<f32>,
+        language: Option<String>,
+    ) -> std::result::Result<TranscriptResult, TranscriptionError> {
+        let mut query = vec![
+            ("model", self.model.clone()),
+            ("smart_format", "true".to_string()),
+        ];
+        match language_code(language) {
+            Some(language) => query.push(("language", language)),
+            None => query.push(("detect_language", "true".to_string())),
+        }
+
+        let response = self
+            .client
+            .post(&self.url)
+            .query(&query)
+            .header("Authorization", format!("Token {}", self.api_key))
+            .header("Content-Type", "audio/wav")
+            .body(encode_wav(&audio, SAMPLE_RATE))
+            .timeout(REQUEST_TIMEOUT)
+            .send()
+            .await
+            .map_err(|e| request_failed("Deepgram", e))?;
+        let body = read_body("Deepgram", response).await?;
+
+        let response: DeepgramResponse = serde_json::from_str(&body).map_err(|e| {
+            TranscriptionError::EngineFailed(format!("Invalid Deepgram response: {}", e))
+        })?;
+        let best = response
+            .results
+            .channels
+            .into_iter()
+            .next()
+            .and_then(|channel| channel.alternatives.into_iter().next());
+        Ok(match best {
+            Some(alternative) => TranscriptResult {
+                text: alternative.transcript.trim().to_string(),
+                confidence: alternative.confidence,
+                is_partial: false,
+                words: alternative
+                    .words
+                    .into_iter()
+                    .map(|w| WordTiming {
+                        word: w.punctuated_word.unwrap_or(w.word),
+                        start: w.start,
+                        end: w.end,
+                        probability: w.confidence,
+                    })
+                    .collect(),
+            },
+            None => TranscriptResult {
+                text: String::new(),
+                confidence: None,
+                is_partial: false,
+                words: Vec::new(),
+            },
+        })
+    }
+
+    async fn is_model_loaded(&self) -> bool {
+        true
+    }
+
+    async fn get_current_model(&self) -> Option<String> {
+        Some(self.model.clone())
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "Deepgram"
+    }
+}
+
+/// Builds the remote provider selected in the transcript settings
+///
+/// # Returns
+/// None for local engines (Whisper, Parakeet); an error if the provider is missing its
+/// API key or endpoint, or is not supported.
+pub fn remote_provider_from_config(
+    config: &crate::api::api::TranscriptConfig,
+) -> Result<Option<Arc<dyn TranscriptionProvider>>, String> {
+    let api_key = config.api_key.clone().filter(|k| !k.is_empty());
+    let endpoint = config
+        .endpoint
+        .as_deref()
+        .map(str::trim)
+        .filter(|e| !e.is_empty());
+    let require_key = |name: &str| {
+        api_key
+            .clone()
+            .ok_or_else(|| format!("No API key configured for {} transcription", name))
+    };
+
+    let provider: Arc<dyn TranscriptionProvider> = match config.provider.as_str() {
+        "localWhisper" | "parakeet" => return Ok(None),
+        "openai" => Arc::new(OpenAiCompatibleProvider::new(
+            "OpenAI",
+            endpoint.unwrap_or(OPENAI_TRANSCRIPTION_URL),
+            Some(require_key("OpenAI")?),
+            &config.model,
+        )),
+        "groq" => Arc::new(OpenAiCompatibleProvider::new(
+            "Groq",
+            endpoint.unwrap_or(GROQ_TRANSCRIPTION_URL),
+            Some(require_key("Groq")?),
+            &config.model,
+        )),
+        "whisperServer" => Arc::new(OpenAiCompatibleProvider::new(
+            "Whisper server",
+            endpoint.ok_or("No endpoint configured for the Whisper server")?,
+            api_key,
+            &config.model,
+        )),
+        "deepgram" => Arc::new(DeepgramProvider::new(
+            endpoint.unwrap_or(DEEPGRAM_LISTEN_URL),
+            &require_key("Deepgram")?,
+            &config.model,
+        )),
+        other => {
+            return Err(format!(
+                "Transcription provider '{}' is not supported",
+                other
+            ))
+        }
+    };
+    info!(
+        "Using remote transcription provider {} (model: {})",
+        provider.provider_name(),
+        config.model
+    );
+    Ok(Some(provider))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::api::TranscriptConfig;
+    use crate::summary::providers::test_support::mock_server;
+
+    fn config(provider: &str, api_key: Option<&str>, endpoint: Option<&str>) -> TranscriptConfig {
+        TranscriptConfig {
+            provider: provider.to_string(),
+            model: "test-model".to_string(),
+            api_key: api_key.map(str::to_string),
+            endpoint: endpoint.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_encode_wav_header() {
+        let wav = encode_wav(&[0.0, 1.0, -1.0], 16000);
+        assert_eq!(&wav[..4], b"RIFF");
+        assert_eq!(wav.len(), 44 + 6);
+        assert_eq!(u32::from_le_bytes(wav[24..28].try_into().unwrap()), 16000);
+        assert_eq!(i16::from_le_bytes([wav[46], wav[47]]), i16::MAX);
+    }
+
+    #[tokio::test]
+    async fn test_openai_compatible_transcription() {
+        let (base_url, server) = mock_server(200, r#"{"text":" Hello there. "}"#).await;
+        let provider =
+            remote_provider_from_config(&config("openai", Some("sk-test"), Some(&base_url)))
+                .unwrap()
+                .unwrap();
+
+        let result = provider
+            .transcribe(vec![0.1; 1600], Some("de".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(result.text, "Hello there.");
+        assert_eq!(result.confidence, None);
+
+        let request = server.await.unwrap();
+        assert!(request.starts_with("POST / HTTP/1.1"));
+        assert!(request.to_lowercase().contains("authorization: bearer sk-test"));
+        assert!(request.contains("name=\"file\"; filename=\"audio.wav\""));
+        assert!(request.contains("name=\"model\"\r\n\r\ntest-model"));
+        assert!(request.contains("name=\"language\"\r\n\r\nde"));
+    }
+
+    #[tokio::test]
+    async fn test_deepgram_transcription() {
+        let (base_url, server) = mock_server(
+            200,
+            r#"{"results":{"channels":[{"alternatives":[{"transcript":"Ship it.","confidence":0.93,"words":[{"word":"ship","punctuated_word":"Ship","start":0.08,"end":0.4,"confidence":0.99},{"word":"it","punctuated_word":"it.","start":0.4,"end":0.64,"confidence":0.87}]}]}]}}"#,
+        )
+        .await;
+        let provider =
+            remote_provider_from_config(&config("deepgram", Some("dg-key"), Some(&base_url)))
+                .unwrap()
+                .unwrap();
+
+        let result = provider.transcribe(vec![0.1; 1600], None).await.unwrap();
+        assert_eq!(result.text, "Ship it.");
+        assert_eq!(result.confidence, Some(0.93));
+        let words: Vec<_> = result.words.iter().map(|w| (w.word.as_str(), w.start)).collect();
+        assert_eq!(words, vec![("Ship", 0.08), ("it.", 0.4)]);
+
+        let request = server.await.unwrap();
+        assert!(request.starts_with("POST /?model=test-model&smart_format=true&detect_language=true"));
+        let lower = request.to_lowercase();
+        assert!(lower.contains("authorization: token dg-key"));
+        assert!(lower.contains("content-type: audio/wav"));
+    }
+
+    #[tokio::test]
+    async fn test_error_status_is_reported() {
+        let (base_url, _server) = mock_server(401, r#"{"error":"invalid key"}"#).await;
+        let provider =
+            remote_provider_from_config(&config("groq", Some("bad"), Some(&base_url)))
+                .unwrap()
+                .unwrap();
+
+        let err = provider.transcribe(vec![0.1; 1600], None).await.unwrap_err();
+        assert!(err.to_string().contains("Groq returned 401"), "{}", err);
+    }
+
+    #[test]
+    fn test_provider_selection() {
+        assert!(remote_provider_from_config(&config("parakeet", None, None))
+            .unwrap()
+            .is_none());
+        assert!(remote_provider_from_config(&config("openai", None, None)).is_err());
+        assert!(remote_provider_from_config(&config("whisperServer", None, None)).is_err());
+        assert!(
+            remote_provider_from_config(&config("whisperServer", None, Some("http://localhost:8178/inference")))
+                .unwrap()
+                .is_some()
+        );
+        assert!(remote_provider_from_config(&config("elevenLabs", Some("key"), None)).is_err());
+    }
+}
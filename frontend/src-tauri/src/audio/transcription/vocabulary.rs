@@ -0,0 +1,415 @@
+// audio/transcription/vocabulary.rs
+//
+// Custom vocabulary of the active workspace: product names, people and acronyms
+// that transcription should spell correctly. Whisper is biased towards the terms
+// through its initial prompt; engines without prompting (Parakeet) get a fuzzy
+// replacement pass over their output instead.
+
+use super::provider::WordTiming;
+use crate::database::repositories::vocabulary::VocabularyRepository;
+use crate::workspace::manager::WorkspaceManager;
+use sqlx::SqlitePool;
+use std::sync::{LazyLock, RwLock};
+use tracing::{error, info};
+
+/// Most terms a workspace can have
+pub const MAX_TERMS: usize = 500;
+
+/// Longest term accepted, in characters
+const MAX_TERM_CHARS: usize = 100;
+
+/// Longest initial prompt given to Whisper, in characters. Whisper only uses the
+/// last 224 tokens of its prompt, so a longer glossary would be cut anyway.
+const MAX_PROMPT_CHARS: usize = 800;
+
+/// Minimum normalized Damerau-Levenshtein similarity for a fuzzy replacement
+const MIN_SIMILARITY: f64 = 0.8;
+
+/// Terms shorter than this (once reduced to letters and digits) are only matched
+/// exactly, since one edit turns most short words into other words
+const MIN_FUZZY_CHARS: usize = 4;
+
+/// Vocabulary of the active workspace, read on every transcription
+static ACTIVE_VOCABULARY: LazyLock<RwLock<Vec<String>>> = LazyLock::new(|| RwLock::new(Vec::new()));
+
+/// The vocabulary of the active workspace
+pub fn active_vocabulary() -> Vec<String> {
+    ACTIVE_VOCABULARY
+        .read()
+        .map(|terms| terms.clone())
+        .unwrap_or_default()
+}
+
+pub fn set_active_vocabulary(terms: Vec<String>) {
+    if let Ok(mut active) = ACTIVE_VOCABULARY.write() {
+        *active = terms;
+    }
+}
+
+/// Reload the active vocabulary from the workspace database
+pub async fn refresh_active_vocabulary(pool: &SqlitePool) -> Result<(), sqlx::Error> {
+    let terms = VocabularyRepository::get_terms(pool).await?;
+    info!("Loaded {} vocabulary terms", terms.len());
+    set_active_vocabulary(terms);
+    Ok(())
+}
+
+/// Trim, deduplicate (case-insensitively) and validate terms entered by the user
+pub fn normalize_terms(terms: Vec<String>) -> Result<Vec<String>, String> {
+    let mut normalized: Vec<String> = Vec::new();
+    for term in terms {
+        let term = term.split_whitespace().collect::<Vec<_>>().join(" ");
+        if term.is_empty() {
+            continue;
+        }
+        if term.chars().any(char::is_control) {
+            return Err(format!(
+                "Vocabulary term '{}' contains control characters",
+                term
+            ));
+        }
+        if term.chars().count() > MAX_TERM_CHARS {
+            return Err(format!(
+                "Vocabulary terms can have at most {} characters",
+                MAX_TERM_CHARS
+            ));
+        }
+        if !normalized
+            .iter()
+            .any(|existing| existing.to_lowercase() == term.to_lowercase())
+        {
+            normalized.push(term);
+        }
+    }
+    if normalized.len() > MAX_TERMS {
+        return Err(format!(
+            "The vocabulary can have at most {} terms",
+            MAX_TERMS
+        ));
+    }
+    Ok(normalized)
+}
+
+/// Initial prompt that biases Whisper towards the spelling of `terms`
+///
+/// Returns `None` for an empty vocabulary. Terms that do not fit within
+/// `MAX_PROMPT_CHARS` are left out.
+pub fn initial_prompt(terms: &[String]) -> Option<String> {
+    let mut prompt = String::from("Glossary:");
+    let mut added = 0;
+    for term in terms {
+        let term = term.replace('\0', "");
+        if term.is_empty() {
+            continue;
+        }
+        if prompt.len() + term.len() + 3 > MAX_PROMPT_CHARS {
+            break;
+        }
+        prompt.push_str(if added == 0 { " " } else { ", " });
+        prompt.push_str(&term);
+        added += 1;
+    }
+    if added == 0 {
+        return None;
+    }
+    prompt.push('.');
+    Some(prompt)
+}
+
+/// A vocabulary term prepared for matching
+struct Term<'a> {
+    text: &'a str,
+    key: String,
+    word_count: usize,
+}
+
+/// Lowercase letters and digits of `text`, the form words are compared in
+fn match_key(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Split a word into its leading punctuation, its core and its trailing punctuation
+fn split_punctuation(word: &str) -> (&str, &str, &str) {
+    let core_start = word
+        .find(|c: char| c.is_alphanumeric())
+        .unwrap_or(word.len());
+    let core_end = word
+        .rfind(|c: char| c.is_alphanumeric())
+        .map(|i| i + word[i..].chars().next().map_or(1, char::len_utf8))
+        .unwrap_or(core_start);
+    (
+        &word[..core_start],
+        &word[core_start..core_end],
+        &word[core_end..],
+    )
+}
+
+/// Replacements of runs of `words` by vocabulary terms, as `(first, count, replacement)`
+///
+/// A run matches a term when its letters and digits are close enough to the term's,
+/// so "cuber netes" is corrected to "Kubernetes". Punctuation before the run and
+/// after it is kept; runs with punctuation between their words are never merged.
+fn find_replacements(words: &[&str], terms: &[String]) -> Vec<(usize, usize, String)> {
+    let terms: Vec<Term> = terms
+        .iter()
+        .map(|text| Term {
+            text: text.as_str(),
+            key: match_key(text),
+            word_count: text.split_whitespace().count(),
+        })
+        .filter(|term| !term.key.is_empty())
+        .collect();
+
+    let mut replacements = Vec::new();
+    let mut i = 0;
+    while i < words.len() {
+        // Best match starting at this word: (similarity, run length, term)
+        let mut best: Option<(f64, usize, &Term)> = None;
+        for term in &terms {
+            let lengths = [
+                term.word_count.saturating_sub(1),
+                term.word_count,
+                term.word_count + 1,
+            ];
+            for count in lengths {
+                if count == 0 || i + count > words.len() {
+                    continue;
+                }
+                let run = &words[i..i + count];
+                let merges_across_punctuation = run.iter().enumerate().any(|(j, word)| {
+                    let (leading, _, trailing) = split_punctuation(word);
+                    (j > 0 && !leading.is_empty()) || (j + 1 < count && !trailing.is_empty())
+                });
+                if merges_across_punctuation {
+                    continue;
+                }
+                let key: String = run.iter().map(|word| match_key(word)).collect();
+                if key.is_empty() {
+                    continue;
+                }
+                let similarity = if key == term.key {
+                    1.0
+                } else if term.key.chars().count() < MIN_FUZZY_CHARS {
+                    continue;
+                } else {
+                    strsim::normalized_damerau_levenshtein(&key, &term.key)
+                };
+                if similarity < MIN_SIMILARITY {
+                    continue;
+                }
+                let better = match best {
+                    None => true,
+                    Some((best_similarity, best_count, _)) => {
+                        similarity > best_similarity
+                            || (similarity == best_similarity && count < best_count)
+                    }
+                };
+                if better {
+                    best = Some((similarity, count, term));
+                }
+            }
+        }
+
+        match best {
+            Some((_, count, term)) => {
+                let (leading, _, _) = split_punctuation(words[i]);
+                let (_, _, trailing) = split_punctuation(words[i + count - 1]);
+                let replacement = format!("{}{}{}", leading, term.text, trailing);
+                if count > 1 || replacement != words[i] {
+                    replacements.push((i, count, replacement));
+                }
+                i += count;
+            }
+            None => i += 1,
+        }
+    }
+    replacements
+}
+
+/// Correct the spelling of vocabulary terms in `text`
+///
+/// Whitespace between words is collapsed to single spaces when anything is replaced.
+pub fn correct_text(text: &str, terms: &[String]) -> String {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let replacements = find_replacements(&words, terms);
+    if replacements.is_empty() {
+        return text.to_string();
+    }
+
+    let mut corrected: Vec<String> = Vec::with_capacity(words.len());
+    let mut next = 0;
+    for (first, count, replacement) in replacements {
+        corrected.extend(words[next..first].iter().map(|word| word.to_string()));
+        corrected.push(replacement);
+        next = first + count;
+    }
+    corrected.extend(words[next..].iter().map(|word| word.to_string()));
+    corrected.join(" ")
+}
+
+/// Correct the spelling of vocabulary terms in word timings
+///
+/// Words merged into one term span from the first one's start to the last one's end,
+/// with the mean of their probabilities.
+pub fn correct_words(words: Vec<WordTiming>, terms: &[String]) -> Vec<WordTiming> {
+    let texts: Vec<&str> = words.iter().map(|word| word.word.as_str()).collect();
+    let replacements = find_replacements(&texts, terms);
+    if replacements.is_empty() {
+        return words;
+    }
+
+    let mut corrected = Vec::with_capacity(words.len());
+    let mut next = 0;
+    for (first, count, replacement) in replacements {
+        corrected.extend_from_slice(&words[next..first]);
+        let run = &words[first..first + count];
+        let probabilities: Vec<f32> = run.iter().filter_map(|word| word.probability).collect();
+        corrected.push(WordTiming {
+            word: replacement,
+            start: run[0].start,
+            end: run[count - 1].end,
+            probability: if probabilities.is_empty() {
+                None
+            } else {
+                Some(probabilities.iter().sum::<f32>() / probabilities.len() as f32)
+            },
+        });
+        next = first + count;
+    }
+    corrected.extend_from_slice(&words[next..]);
+    corrected
+}
+
+// ============================================================================
+// COMMANDS
+// ============================================================================
+
+/// Gets the custom vocabulary of the active workspace
+#[tauri::command]
+pub async fn api_get_transcription_vocabulary(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+) -> Result<Vec<String>, String> {
+    let pool = workspace_mgr.active_pool().await?;
+    VocabularyRepository::get_terms(&pool).await.map_err(|e| {
+        error!("Failed to load vocabulary: {}", e);
+        format!("Failed to load vocabulary: {}", e)
+    })
+}
+
+/// Saves the custom vocabulary of the active workspace
+///
+/// # Arguments
+/// * `terms` - Words and phrases transcription should spell as given; blank and
+///   duplicate entries are dropped
+///
+/// # Returns
+/// The saved vocabulary, which applies from the next transcribed chunk on
+#[tauri::command]
+pub async fn api_save_transcription_vocabulary(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    terms: Vec<String>,
+) -> Result<Vec<String>, String> {
+    info!(
+        "api_save_transcription_vocabulary called with {} terms",
+        terms.len()
+    );
+    let terms = normalize_terms(terms)?;
+    let pool = workspace_mgr.active_pool().await?;
+
+    VocabularyRepository::replace_terms(&pool, &terms)
+        .await
+        .map_err(|e| {
+            error!("Failed to save vocabulary: {}", e);
+            format!("Failed to save vocabulary: {}", e)
+        })?;
+    refresh_active_vocabulary(&pool)
+        .await
+        .map_err(|e| format!("Failed to load vocabulary: {}", e))?;
+    Ok(active_vocabulary())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn terms(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    fn word(text: &str, start: f64, end: f64, probability: Option<f32>) -> WordTiming {
+        WordTiming {
+            word: text.to_string(),
+            start,
+            end,
+            probability,
+        }
+    }
+
+    #[test]
+    fn test_normalize_terms() {
+        let normalized =
+            normalize_terms(terms(&["  Kubernetes ", "", "kubernetes", "Acme   Corp"]));
+        assert_eq!(normalized.unwrap(), terms(&["Kubernetes", "Acme Corp"]));
+        assert!(normalize_terms(terms(&["bell\u{7}"])).is_err());
+    }
+
+    #[test]
+    fn test_initial_prompt() {
+        assert_eq!(initial_prompt(&[]), None);
+        assert_eq!(
+            initial_prompt(&terms(&["Meetily", "Acme Corp", "gRPC"])).as_deref(),
+            Some("Glossary: Meetily, Acme Corp, gRPC.")
+        );
+
+        let many: Vec<String> = (0..500).map(|i| format!("Term{}", i)).collect();
+        let prompt = initial_prompt(&many).unwrap();
+        assert!(prompt.len() <= MAX_PROMPT_CHARS);
+        assert!(prompt.ends_with('.'));
+    }
+
+    #[test]
+    fn test_correct_text() {
+        let vocabulary = terms(&["Kubernetes", "Meetily", "Acme Corp", "SQL"]);
+
+        assert_eq!(
+            correct_text("We moved to cuber netes, finally.", &vocabulary),
+            "We moved to Kubernetes, finally."
+        );
+        assert_eq!(
+            correct_text("Did you try meetilly? The acme corp. team did", &vocabulary),
+            "Did you try Meetily? The Acme Corp. team did"
+        );
+        // Short terms are only matched exactly, which fixes their case
+        assert_eq!(
+            correct_text("the sql and sol queries", &vocabulary),
+            "the SQL and sol queries"
+        );
+        // Words are not merged across punctuation
+        assert_eq!(correct_text("meet. ily", &vocabulary), "meet. ily");
+        assert_eq!(
+            correct_text("nothing  to fix", &vocabulary),
+            "nothing  to fix"
+        );
+    }
+
+    #[test]
+    fn test_correct_words_merges_timings() {
+        let vocabulary = terms(&["Kubernetes"]);
+        let words = vec![
+            word("on", 0.0, 0.25, Some(0.5)),
+            word("cuber", 0.25, 0.5, Some(0.5)),
+            word("netes.", 0.5, 1.0, Some(1.0)),
+        ];
+
+        assert_eq!(
+            correct_words(words, &vocabulary),
+            vec![
+                word("on", 0.0, 0.25, Some(0.5)),
+                word("Kubernetes.", 0.25, 1.0, Some(0.75)),
+            ]
+        );
+    }
+}
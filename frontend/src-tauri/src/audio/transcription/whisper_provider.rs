@@ -29,10 +29,9 @@ impl TranscriptionProvider for WhisperProvider {
             .transcribe_audio_with_confidence(audio, language)
             .await
         {
-            Ok((text, confidence, is_partial)) => Ok(TranscriptResult {
-                text: text.trim().to_string(),
-                confidence: Some(confidence),
-                is_partial,
+            Ok(result) => Ok(TranscriptResult {
+                text: result.text.trim().to_string(),
+                ..result
             }),
             Err(e) => Err(TranscriptionError::EngineFailed(e.to_string())),
         }
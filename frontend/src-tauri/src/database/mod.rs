@@ -3,3 +3,32 @@ pub mod manager;
 pub mod models;
 pub mod repositories;
 pub mod setup;
+
+#[cfg(test)]
+pub(crate) mod test_support {
+    use crate::workspace::manager::WorkspaceManager;
+    use sqlx::sqlite::SqlitePoolOptions;
+    use sqlx::SqlitePool;
+
+    async fn memory_pool() -> SqlitePool {
+        SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap()
+    }
+
+    /// In-memory database with the workspace schema applied
+    pub async fn workspace_pool() -> SqlitePool {
+        let pool = memory_pool().await;
+        WorkspaceManager::run_workspace_migrations(&pool).await.unwrap();
+        pool
+    }
+
+    /// In-memory database with the global settings schema applied
+    pub async fn global_pool() -> SqlitePool {
+        let pool = memory_pool().await;
+        WorkspaceManager::run_global_migrations(&pool).await.unwrap();
+        pool
+    }
+}
@@ -35,6 +35,132 @@ pub struct Transcript {
     pub audio_start_time: Option<f64>,
     pub audio_end_time: Option<f64>,
     pub duration: Option<f64>,
+    // Source stream: 'mic' for microphone, 'system' for system audio
+    pub speaker: Option<String>,
+    // Diarized speaker label ("Speaker 1".."Speaker N")
+    pub speaker_label: Option<String>,
+    // Engine confidence (0.0-1.0), if the engine reported one
+    pub confidence: Option<f32>,
+    // Fell below the live confidence threshold; candidate for re-transcription
+    pub low_confidence: bool,
+}
+
+/// A speaker detected by diarization in a meeting, optionally renamed by the user
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct MeetingSpeaker {
+    pub meeting_id: String,
+    pub label: String,
+    pub display_name: Option<String>,
+    pub segment_count: i64,
+    pub total_duration: f64,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// User notes for a meeting; `notes_json` holds the timestamped note entries
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct MeetingNotes {
+    pub meeting_id: String,
+    pub notes_markdown: Option<String>,
+    pub notes_json: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl MeetingNotes {
+    /// Note entries stored in `notes_json` (empty if missing or unparseable)
+    pub fn entries(&self) -> Vec<MeetingNoteEntry> {
+        self.notes_json
+            .as_deref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default()
+    }
+}
+
+/// A single note, linked to the recording position it was taken at
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MeetingNoteEntry {
+    pub id: String,
+    pub text: String,
+    // Seconds from recording start; None for notes added after the meeting
+    #[serde(default)]
+    pub audio_start_time: Option<f64>,
+    pub created_at: String,
+}
+
+/// A transcript segment found by cross-meeting search, with its meeting's title and date
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct SearchSegment {
+    pub transcript_id: String,
+    pub meeting_id: String,
+    pub meeting_title: String,
+    pub meeting_created_at: DateTime<Utc>,
+    pub text: String,
+    pub audio_start_time: Option<f64>,
+    pub audio_end_time: Option<f64>,
+    pub speaker_label: Option<String>,
+}
+
+/// An action item extracted from a meeting summary, with the title of its meeting
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ActionItem {
+    pub id: i64,
+    pub meeting_id: String,
+    pub meeting_title: String,
+    pub text: String,
+    pub owner: Option<String>,
+    // YYYY-MM-DD
+    pub due_date: Option<String>,
+    // Seconds from recording start of the segment the item came from
+    pub source_timestamp: Option<f64>,
+    // "open" or "done"
+    pub status: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// A decision extracted from a meeting summary, with the title of its meeting
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct Decision {
+    pub id: i64,
+    pub meeting_id: String,
+    pub meeting_title: String,
+    pub text: String,
+    pub source_timestamp: Option<f64>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// One message of the Q&A chat about a meeting; `citations_json` holds the cited segments
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct MeetingChatMessage {
+    pub id: i64,
+    pub meeting_id: String,
+    // "user" or "assistant"
+    pub role: String,
+    pub content: String,
+    pub citations_json: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl MeetingChatMessage {
+    /// Citations stored in `citations_json` (empty if missing or unparseable)
+    pub fn citations(&self) -> Vec<ChatCitation> {
+        self.citations_json
+            .as_deref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default()
+    }
+}
+
+/// A transcript segment an answer refers to
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChatCitation {
+    pub transcript_id: String,
+    // Seconds from recording start
+    pub audio_start_time: f64,
+    pub audio_end_time: Option<f64>,
+    pub text: String,
 }
 
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
@@ -50,8 +176,38 @@ pub struct SummaryProcess {
     pub chunk_count: i64,
     pub processing_time: f64,
     pub metadata: Option<String>, // JSON
-    pub result_backup: Option<String>, // Backup of result before regeneration
-    pub result_backup_timestamp: Option<chrono::DateTime<chrono::Utc>>, // When backup was created
+}
+
+/// One entry in a meeting's summary history
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct SummaryVersion {
+    pub id: i64,
+    pub meeting_id: String,
+    pub version: i64,
+    // "generated", "edited" or "restored"
+    pub source: String,
+    pub result: String, // JSON
+    pub provider: Option<String>,
+    pub model: Option<String>,
+    pub template_id: Option<String>,
+    pub prompt: Option<String>,
+    pub restored_from: Option<i64>,
+    pub pinned: bool,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// An earlier transcript of a meeting, replaced by re-transcription or a restore
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct TranscriptVersion {
+    pub id: i64,
+    pub meeting_id: String,
+    pub version: i64,
+    // "retranscribed" or "restored"
+    pub reason: String,
+    pub replaced_by_provider: Option<String>,
+    pub replaced_by_model: Option<String>,
+    pub segment_count: i64,
+    pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
@@ -66,6 +222,7 @@ pub struct TranscriptChunk {
     pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
+// The *ApiKey columns are legacy and always NULL; keys live in crate::secrets
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
 pub struct Setting {
     pub id: String,
@@ -96,6 +253,9 @@ pub struct Setting {
     #[sqlx(rename = "customOpenAIConfig")]
     #[serde(rename = "customOpenAIConfig")]
     pub custom_openai_config: Option<String>,
+    #[sqlx(rename = "geminiApiKey")]
+    #[serde(rename = "geminiApiKey")]
+    pub gemini_api_key: Option<String>,
 }
 
 impl Setting {
@@ -107,6 +267,7 @@ impl Setting {
     }
 }
 
+// The *ApiKey columns are legacy and always NULL; keys live in crate::secrets
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
 pub struct TranscriptSetting {
     pub id: String,
@@ -127,4 +288,7 @@ pub struct TranscriptSetting {
     #[sqlx(rename = "openaiApiKey")]
     #[serde(rename = "openaiApiKey")]
     pub openai_api_key: Option<String>,
+    /// Transcription endpoint URL for remote providers; None uses the provider's default
+    #[sqlx(default)]
+    pub endpoint: Option<String>,
 }
@@ -0,0 +1,324 @@
+use crate::database::models::{ActionItem, Decision};
+use crate::summary::extraction::{normalize_key, StructuredExtraction};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use sqlx::{Error as SqlxError, SqlitePool};
+use std::collections::HashMap;
+use tracing::info;
+
+/// Values of `action_items.status`
+pub const ACTION_ITEM_STATUSES: &[&str] = &["open", "done"];
+
+/// Filters for listing action items across the workspace; unset fields match everything
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ActionItemFilter {
+    /// "open" or "done"
+    pub status: Option<String>,
+    /// Case-insensitive substring of the owner
+    pub owner: Option<String>,
+    pub meeting_id: Option<String>,
+    /// Only items due on or before this date (YYYY-MM-DD)
+    pub due_before: Option<String>,
+    /// Case-insensitive substring of the item text
+    pub query: Option<String>,
+}
+
+/// Filters for listing decisions across the workspace; unset fields match everything
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DecisionFilter {
+    pub meeting_id: Option<String>,
+    /// Case-insensitive substring of the decision text
+    pub query: Option<String>,
+}
+
+pub struct ActionItemsRepository;
+
+impl ActionItemsRepository {
+    /// Replace the extracted action items and decisions of a meeting.
+    ///
+    /// Action items whose text matches an existing item of the meeting keep its status,
+    /// so regenerating a summary does not reopen completed items.
+    pub async fn replace_for_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        extraction: &StructuredExtraction,
+    ) -> Result<(), SqlxError> {
+        if meeting_id.trim().is_empty() {
+            return Err(SqlxError::Protocol(
+                "meeting_id cannot be empty".to_string(),
+            ));
+        }
+
+        let mut transaction = pool.begin().await?;
+
+        let existing: Vec<(String, String, Option<DateTime<Utc>>)> = sqlx::query_as(
+            "SELECT text, status, completed_at FROM action_items WHERE meeting_id = ?",
+        )
+        .bind(meeting_id)
+        .fetch_all(&mut *transaction)
+        .await?;
+        let previous_status: HashMap<String, (String, Option<DateTime<Utc>>)> = existing
+            .into_iter()
+            .map(|(text, status, completed_at)| (normalize_key(&text), (status, completed_at)))
+            .collect();
+
+        for table in ["action_items", "decisions"] {
+            sqlx::query(&format!("DELETE FROM {table} WHERE meeting_id = ?"))
+                .bind(meeting_id)
+                .execute(&mut *transaction)
+                .await?;
+        }
+
+        let now = Utc::now();
+        for item in &extraction.action_items {
+            let (status, completed_at) = previous_status
+                .get(&normalize_key(&item.text))
+                .cloned()
+                .unwrap_or_else(|| ("open".to_string(), None));
+            sqlx::query(
+                "INSERT INTO action_items (meeting_id, text, owner, due_date, source_timestamp, status, created_at, updated_at, completed_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(meeting_id)
+            .bind(&item.text)
+            .bind(&item.owner)
+            .bind(&item.due_date)
+            .bind(item.source_timestamp)
+            .bind(status)
+            .bind(now)
+            .bind(now)
+            .bind(completed_at)
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        for decision in &extraction.decisions {
+            sqlx::query(
+                "INSERT INTO decisions (meeting_id, text, source_timestamp, created_at) VALUES (?, ?, ?, ?)",
+            )
+            .bind(meeting_id)
+            .bind(&decision.text)
+            .bind(decision.source_timestamp)
+            .bind(now)
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        transaction.commit().await?;
+        info!(
+            "Stored {} action items and {} decisions for meeting {}",
+            extraction.action_items.len(),
+            extraction.decisions.len(),
+            meeting_id
+        );
+        Ok(())
+    }
+
+    /// Action items across the workspace: open before done, then by due date (undated
+    /// last) and newest meeting first
+    pub async fn list_action_items(
+        pool: &SqlitePool,
+        filter: &ActionItemFilter,
+    ) -> Result<Vec<ActionItem>, SqlxError> {
+        sqlx::query_as::<_, ActionItem>(
+            "SELECT a.*, m.title AS meeting_title
+             FROM action_items a
+             JOIN meetings m ON m.id = a.meeting_id
+             WHERE (?1 IS NULL OR a.status = ?1)
+               AND (?2 IS NULL OR a.owner LIKE '%' || ?2 || '%')
+               AND (?3 IS NULL OR a.meeting_id = ?3)
+               AND (?4 IS NULL OR a.due_date <= ?4)
+               AND (?5 IS NULL OR a.text LIKE '%' || ?5 || '%')
+             ORDER BY a.status = 'done', a.due_date IS NULL, a.due_date, m.created_at DESC, a.id",
+        )
+        .bind(non_blank(&filter.status))
+        .bind(non_blank(&filter.owner))
+        .bind(non_blank(&filter.meeting_id))
+        .bind(non_blank(&filter.due_before))
+        .bind(non_blank(&filter.query))
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get_action_item(
+        pool: &SqlitePool,
+        id: i64,
+    ) -> Result<Option<ActionItem>, SqlxError> {
+        sqlx::query_as::<_, ActionItem>(
+            "SELECT a.*, m.title AS meeting_title
+             FROM action_items a
+             JOIN meetings m ON m.id = a.meeting_id
+             WHERE a.id = ?",
+        )
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Mark an action item done (recording when) or reopen it
+    pub async fn set_completed(
+        pool: &SqlitePool,
+        id: i64,
+        completed: bool,
+    ) -> Result<Option<ActionItem>, SqlxError> {
+        let now = Utc::now();
+        let result = sqlx::query(
+            "UPDATE action_items SET status = ?, completed_at = ?, updated_at = ? WHERE id = ?",
+        )
+        .bind(if completed { "done" } else { "open" })
+        .bind(completed.then_some(now))
+        .bind(now)
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+        Self::get_action_item(pool, id).await
+    }
+
+    /// Decisions across the workspace, newest meeting first and in meeting order within one
+    pub async fn list_decisions(
+        pool: &SqlitePool,
+        filter: &DecisionFilter,
+    ) -> Result<Vec<Decision>, SqlxError> {
+        sqlx::query_as::<_, Decision>(
+            "SELECT d.*, m.title AS meeting_title
+             FROM decisions d
+             JOIN meetings m ON m.id = d.meeting_id
+             WHERE (?1 IS NULL OR d.meeting_id = ?1)
+               AND (?2 IS NULL OR d.text LIKE '%' || ?2 || '%')
+             ORDER BY m.created_at DESC, d.source_timestamp IS NULL, d.source_timestamp, d.id",
+        )
+        .bind(non_blank(&filter.meeting_id))
+        .bind(non_blank(&filter.query))
+        .fetch_all(pool)
+        .await
+    }
+}
+
+fn non_blank(value: &Option<String>) -> Option<&str> {
+    value.as_deref().map(str::trim).filter(|v| !v.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::summary::extraction::{ExtractedActionItem, ExtractedDecision};
+    use crate::database::test_support::workspace_pool;
+
+    async fn test_pool() -> SqlitePool {
+        let pool = workspace_pool().await;
+        for (id, title, created_at) in [
+            ("m1", "Planning", "2026-03-01T10:00:00Z"),
+            ("m2", "Review", "2026-03-08T10:00:00Z"),
+        ] {
+            sqlx::query("INSERT INTO meetings (id, title, created_at, updated_at) VALUES (?, ?, ?, ?)")
+                .bind(id)
+                .bind(title)
+                .bind(created_at)
+                .bind(created_at)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+        pool
+    }
+
+    fn item(text: &str, owner: Option<&str>, due_date: Option<&str>) -> ExtractedActionItem {
+        ExtractedActionItem {
+            text: text.to_string(),
+            owner: owner.map(str::to_string),
+            due_date: due_date.map(str::to_string),
+            source_timestamp: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_and_filter_across_meetings() {
+        let pool = test_pool().await;
+        let planning = StructuredExtraction {
+            action_items: vec![
+                item("Draft the roadmap", Some("Alice"), Some("2026-03-20")),
+                item("Book the offsite", None, None),
+            ],
+            decisions: vec![ExtractedDecision {
+                text: "Quarterly planning moves to Mondays".to_string(),
+                source_timestamp: Some(90.0),
+            }],
+        };
+        let review = StructuredExtraction {
+            action_items: vec![item("Fix the login bug", Some("alice"), Some("2026-03-10"))],
+            decisions: vec![],
+        };
+        ActionItemsRepository::replace_for_meeting(&pool, "m1", &planning).await.unwrap();
+        ActionItemsRepository::replace_for_meeting(&pool, "m2", &review).await.unwrap();
+
+        let all = ActionItemsRepository::list_action_items(&pool, &ActionItemFilter::default())
+            .await
+            .unwrap();
+        let texts: Vec<&str> = all.iter().map(|a| a.text.as_str()).collect();
+        assert_eq!(texts, vec!["Fix the login bug", "Draft the roadmap", "Book the offsite"]);
+        assert_eq!(all[0].meeting_title, "Review");
+
+        let filter = ActionItemFilter {
+            owner: Some("ALICE".to_string()),
+            due_before: Some("2026-03-15".to_string()),
+            ..Default::default()
+        };
+        let due = ActionItemsRepository::list_action_items(&pool, &filter).await.unwrap();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].meeting_id, "m2");
+
+        let decisions = ActionItemsRepository::list_decisions(&pool, &DecisionFilter::default())
+            .await
+            .unwrap();
+        assert_eq!(decisions.len(), 1);
+        assert_eq!(decisions[0].meeting_title, "Planning");
+    }
+
+    #[tokio::test]
+    async fn test_completed_items_survive_regeneration() {
+        let pool = test_pool().await;
+        let extraction = StructuredExtraction {
+            action_items: vec![item("Send the deck", Some("Bob"), None)],
+            decisions: vec![],
+        };
+        ActionItemsRepository::replace_for_meeting(&pool, "m1", &extraction).await.unwrap();
+        let id = ActionItemsRepository::list_action_items(&pool, &ActionItemFilter::default())
+            .await
+            .unwrap()[0]
+            .id;
+
+        let done = ActionItemsRepository::set_completed(&pool, id, true)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(done.status, "done");
+        assert!(done.completed_at.is_some());
+        assert!(ActionItemsRepository::set_completed(&pool, id + 100, true)
+            .await
+            .unwrap()
+            .is_none());
+
+        let regenerated = StructuredExtraction {
+            action_items: vec![
+                item("send the  deck", Some("Bob"), None),
+                item("Update the pricing page", None, None),
+            ],
+            decisions: vec![],
+        };
+        ActionItemsRepository::replace_for_meeting(&pool, "m1", &regenerated).await.unwrap();
+
+        let open = ActionItemFilter {
+            status: Some("open".to_string()),
+            ..Default::default()
+        };
+        let open_items = ActionItemsRepository::list_action_items(&pool, &open).await.unwrap();
+        assert_eq!(open_items.len(), 1);
+        assert_eq!(open_items[0].text, "Update the pricing page");
+    }
+}
@@ -0,0 +1,145 @@
+use crate::database::models::{ChatCitation, MeetingChatMessage};
+use chrono::Utc;
+use sqlx::{Error as SqlxError, SqlitePool};
+
+pub struct MeetingChatRepository;
+
+impl MeetingChatRepository {
+    /// All messages of a meeting's chat, oldest first
+    pub async fn get_messages(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<MeetingChatMessage>, SqlxError> {
+        sqlx::query_as::<_, MeetingChatMessage>(
+            "SELECT * FROM meeting_chat_messages WHERE meeting_id = ? ORDER BY id",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// The last `limit` messages of a meeting's chat, oldest first
+    pub async fn get_recent_messages(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        limit: usize,
+    ) -> Result<Vec<MeetingChatMessage>, SqlxError> {
+        let mut messages = sqlx::query_as::<_, MeetingChatMessage>(
+            "SELECT * FROM meeting_chat_messages WHERE meeting_id = ? ORDER BY id DESC LIMIT ?",
+        )
+        .bind(meeting_id)
+        .bind(limit as i64)
+        .fetch_all(pool)
+        .await?;
+        messages.reverse();
+        Ok(messages)
+    }
+
+    /// Store a question and its answer together, so a failed request leaves no
+    /// unanswered question behind
+    ///
+    /// # Returns
+    /// The stored (question, answer) messages
+    pub async fn add_exchange(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        question: &str,
+        answer: &str,
+        citations: &[ChatCitation],
+    ) -> Result<(MeetingChatMessage, MeetingChatMessage), SqlxError> {
+        if meeting_id.trim().is_empty() {
+            return Err(SqlxError::Protocol(
+                "meeting_id cannot be empty".to_string(),
+            ));
+        }
+
+        let citations_json = serde_json::to_string(citations)
+            .map_err(|e| SqlxError::Protocol(format!("Failed to serialize citations: {}", e)))?;
+        let now = Utc::now();
+
+        let mut transaction = pool.begin().await?;
+        let mut stored = Vec::with_capacity(2);
+        for (role, content, citations_json) in [
+            ("user", question, None),
+            ("assistant", answer, Some(citations_json.as_str())),
+        ] {
+            let message = sqlx::query_as::<_, MeetingChatMessage>(
+                "INSERT INTO meeting_chat_messages (meeting_id, role, content, citations_json, created_at)
+                 VALUES (?, ?, ?, ?, ?)
+                 RETURNING *",
+            )
+            .bind(meeting_id)
+            .bind(role)
+            .bind(content)
+            .bind(citations_json)
+            .bind(now)
+            .fetch_one(&mut *transaction)
+            .await?;
+            stored.push(message);
+        }
+        transaction.commit().await?;
+
+        let answer = stored.pop().ok_or(SqlxError::RowNotFound)?;
+        let question = stored.pop().ok_or(SqlxError::RowNotFound)?;
+        Ok((question, answer))
+    }
+
+    /// Delete a meeting's chat history, returning the number of messages removed
+    pub async fn clear(pool: &SqlitePool, meeting_id: &str) -> Result<u64, SqlxError> {
+        let result = sqlx::query("DELETE FROM meeting_chat_messages WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::test_support::workspace_pool;
+
+    #[tokio::test]
+    async fn test_exchanges_are_stored_in_order() {
+        let pool = workspace_pool().await;
+        sqlx::query("INSERT INTO meetings (id, title, created_at, updated_at) VALUES ('m1', 'Sync', datetime('now'), datetime('now'))")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let citation = ChatCitation {
+            transcript_id: "t1".to_string(),
+            audio_start_time: 65.0,
+            audio_end_time: Some(70.0),
+            text: "Let's ship on Monday.".to_string(),
+        };
+        let (question, answer) = MeetingChatRepository::add_exchange(
+            &pool,
+            "m1",
+            "When do we ship?",
+            "On Monday [00:01:05].",
+            &[citation.clone()],
+        )
+        .await
+        .unwrap();
+        assert_eq!(question.role, "user");
+        assert!(question.citations().is_empty());
+        assert_eq!(answer.citations(), vec![citation]);
+
+        MeetingChatRepository::add_exchange(&pool, "m1", "Who owns it?", "Alice.", &[])
+            .await
+            .unwrap();
+
+        let recent = MeetingChatRepository::get_recent_messages(&pool, "m1", 3)
+            .await
+            .unwrap();
+        let contents: Vec<&str> = recent.iter().map(|m| m.content.as_str()).collect();
+        assert_eq!(contents, vec!["On Monday [00:01:05].", "Who owns it?", "Alice."]);
+
+        assert_eq!(MeetingChatRepository::clear(&pool, "m1").await.unwrap(), 4);
+        assert!(MeetingChatRepository::get_messages(&pool, "m1")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,190 @@
+use crate::database::models::SearchSegment;
+use chrono::Utc;
+use sqlx::{Error as SqlxError, SqlitePool};
+
+/// A transcript segment that still needs an embedding
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct PendingSegment {
+    pub transcript_id: String,
+    pub meeting_id: String,
+    pub text: String,
+}
+
+pub struct SegmentEmbeddingsRepository;
+
+impl SegmentEmbeddingsRepository {
+    /// Up to `limit` non-empty segments without an embedding from `model`, oldest meeting first
+    pub async fn missing_segments(
+        pool: &SqlitePool,
+        model: &str,
+        limit: i64,
+    ) -> Result<Vec<PendingSegment>, SqlxError> {
+        sqlx::query_as::<_, PendingSegment>(
+            "SELECT t.id AS transcript_id, t.meeting_id, t.transcript AS text
+             FROM transcripts t
+             LEFT JOIN segment_embeddings e ON e.transcript_id = t.id AND e.model = ?
+             WHERE e.transcript_id IS NULL AND trim(t.transcript) != ''
+             ORDER BY t.meeting_id, t.audio_start_time
+             LIMIT ?",
+        )
+        .bind(model)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Store embeddings, replacing any earlier vector of the same segment
+    pub async fn save_embeddings(
+        pool: &SqlitePool,
+        model: &str,
+        embeddings: &[(PendingSegment, Vec<f32>)],
+    ) -> Result<(), SqlxError> {
+        let now = Utc::now();
+        let mut transaction = pool.begin().await?;
+        for (segment, vector) in embeddings {
+            sqlx::query(
+                "INSERT OR REPLACE INTO segment_embeddings (transcript_id, meeting_id, model, dimensions, vector, created_at)
+                 VALUES (?, ?, ?, ?, ?, ?)",
+            )
+            .bind(&segment.transcript_id)
+            .bind(&segment.meeting_id)
+            .bind(model)
+            .bind(vector.len() as i64)
+            .bind(encode_vector(vector))
+            .bind(now)
+            .execute(&mut *transaction)
+            .await?;
+        }
+        transaction.commit().await
+    }
+
+    /// Every stored (transcript id, vector) pair computed with `model`
+    pub async fn load_all(
+        pool: &SqlitePool,
+        model: &str,
+    ) -> Result<Vec<(String, Vec<f32>)>, SqlxError> {
+        let rows = sqlx::query_as::<_, (String, Vec<u8>)>(
+            "SELECT transcript_id, vector FROM segment_embeddings WHERE model = ?",
+        )
+        .bind(model)
+        .fetch_all(pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|(id, bytes)| (id, decode_vector(&bytes)))
+            .collect())
+    }
+
+    /// (segments embedded with `model`, non-empty segments in the workspace)
+    pub async fn counts(pool: &SqlitePool, model: &str) -> Result<(i64, i64), SqlxError> {
+        sqlx::query_as::<_, (i64, i64)>(
+            "SELECT
+                (SELECT COUNT(*) FROM segment_embeddings e
+                 JOIN transcripts t ON t.id = e.transcript_id
+                 WHERE e.model = ?),
+                (SELECT COUNT(*) FROM transcripts WHERE trim(transcript) != '')",
+        )
+        .bind(model)
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Segment details for the given transcript ids, in no particular order
+    pub async fn get_segments(
+        pool: &SqlitePool,
+        transcript_ids: &[String],
+    ) -> Result<Vec<SearchSegment>, SqlxError> {
+        if transcript_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = vec!["?"; transcript_ids.len()].join(", ");
+        let sql = format!(
+            "SELECT t.id AS transcript_id, t.meeting_id, m.title AS meeting_title,
+                    m.created_at AS meeting_created_at, t.transcript AS text,
+                    t.audio_start_time, t.audio_end_time, t.speaker_label
+             FROM transcripts t
+             JOIN meetings m ON m.id = t.meeting_id
+             WHERE t.id IN ({placeholders})"
+        );
+        let mut query = sqlx::query_as::<_, SearchSegment>(&sql);
+        for id in transcript_ids {
+            query = query.bind(id);
+        }
+        query.fetch_all(pool).await
+    }
+}
+
+fn encode_vector(vector: &[f32]) -> Vec<u8> {
+    vector.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+fn decode_vector(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::test_support::workspace_pool;
+
+    #[tokio::test]
+    async fn test_embeddings_follow_transcript_edits() {
+        let pool = workspace_pool().await;
+        sqlx::query("INSERT INTO meetings (id, title, created_at, updated_at) VALUES ('m1', 'Sync', '2026-04-01T10:00:00Z', '2026-04-01T10:00:00Z')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        for (id, text, start) in [("t1", "Budget review", 0.0), ("t2", "Hiring plan", 5.0), ("t3", "  ", 9.0)] {
+            sqlx::query(
+                "INSERT INTO transcripts (id, meeting_id, transcript, timestamp, audio_start_time) VALUES (?, 'm1', ?, '', ?)",
+            )
+            .bind(id)
+            .bind(text)
+            .bind(start)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+
+        let pending = SegmentEmbeddingsRepository::missing_segments(&pool, "mini", 10)
+            .await
+            .unwrap();
+        let ids: Vec<&str> = pending.iter().map(|s| s.transcript_id.as_str()).collect();
+        assert_eq!(ids, vec!["t1", "t2"]);
+
+        let embeddings: Vec<(PendingSegment, Vec<f32>)> = pending
+            .into_iter()
+            .map(|segment| (segment, vec![0.6, -0.8]))
+            .collect();
+        SegmentEmbeddingsRepository::save_embeddings(&pool, "mini", &embeddings)
+            .await
+            .unwrap();
+        assert_eq!(
+            SegmentEmbeddingsRepository::counts(&pool, "mini").await.unwrap(),
+            (2, 2)
+        );
+        let stored = SegmentEmbeddingsRepository::load_all(&pool, "mini").await.unwrap();
+        assert_eq!(stored[0].1, vec![0.6, -0.8]);
+
+        // Editing a segment drops its vector so it is embedded again
+        sqlx::query("UPDATE transcripts SET transcript = 'Hiring plan for Q3' WHERE id = 't2'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let pending = SegmentEmbeddingsRepository::missing_segments(&pool, "mini", 10)
+            .await
+            .unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].text, "Hiring plan for Q3");
+
+        let segments = SegmentEmbeddingsRepository::get_segments(&pool, &["t1".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(segments[0].meeting_title, "Sync");
+        assert_eq!(segments[0].audio_start_time, Some(0.0));
+    }
+}
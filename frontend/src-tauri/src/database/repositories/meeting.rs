@@ -92,6 +92,12 @@ impl MeetingsRepository {
                     audio_start_time: t.audio_start_time,
                     audio_end_time: t.audio_end_time,
                     duration: t.duration,
+                    speaker: t.speaker,
+                    speaker_label: t.speaker_label,
+                    confidence: t.confidence,
+                    low_confidence: t.low_confidence,
+                    // Word timings are served by api_get_meeting_transcripts
+                    words: Vec::new(),
                 })
                 .collect::<Vec<_>>();
 
@@ -128,6 +134,20 @@ impl MeetingsRepository {
         Ok(meeting)
     }
 
+    /// Find the meeting whose recording lives in `folder_path`
+    pub async fn find_meeting_id_by_folder_path(
+        pool: &SqlitePool,
+        folder_path: &str,
+    ) -> Result<Option<String>, SqlxError> {
+        let row: Option<(String,)> =
+            sqlx::query_as("SELECT id FROM meetings WHERE folder_path = ? LIMIT 1")
+                .bind(folder_path)
+                .fetch_optional(pool)
+                .await?;
+
+        Ok(row.map(|(id,)| id))
+    }
+
     /// Get meeting transcripts with pagination support
     pub async fn get_meeting_transcripts_paginated(
         pool: &SqlitePool,
@@ -1,5 +1,13 @@
+pub mod action_item;
+pub mod chat;
+pub mod embedding;
 pub mod meeting;
+pub mod notes;
 pub mod setting;
+pub mod speaker;
 pub mod summary;
+pub mod summary_version;
 pub mod transcript;
 pub mod transcript_chunk;
+pub mod transcript_version;
+pub mod vocabulary;
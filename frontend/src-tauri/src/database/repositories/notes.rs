@@ -0,0 +1,91 @@
+use crate::database::models::{MeetingNoteEntry, MeetingNotes};
+use chrono::Utc;
+use sqlx::{Error as SqlxError, SqlitePool};
+
+pub struct MeetingNotesRepository;
+
+impl MeetingNotesRepository {
+    pub async fn get_notes(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Option<MeetingNotes>, SqlxError> {
+        sqlx::query_as::<_, MeetingNotes>("SELECT * FROM meeting_notes WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// Insert or replace the notes of a meeting, keeping the original `created_at`
+    pub async fn save_notes(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        notes_markdown: Option<&str>,
+        entries: &[MeetingNoteEntry],
+    ) -> Result<MeetingNotes, SqlxError> {
+        if meeting_id.trim().is_empty() {
+            return Err(SqlxError::Protocol(
+                "meeting_id cannot be empty".to_string(),
+            ));
+        }
+
+        let notes_json = serde_json::to_string(entries)
+            .map_err(|e| SqlxError::Protocol(format!("Failed to serialize notes: {}", e)))?;
+        let now = Utc::now();
+
+        sqlx::query(
+            "INSERT INTO meeting_notes (meeting_id, notes_markdown, notes_json, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?)
+             ON CONFLICT(meeting_id) DO UPDATE SET
+                notes_markdown = excluded.notes_markdown,
+                notes_json = excluded.notes_json,
+                updated_at = excluded.updated_at",
+        )
+        .bind(meeting_id)
+        .bind(notes_markdown)
+        .bind(&notes_json)
+        .bind(now)
+        .bind(now)
+        .execute(pool)
+        .await?;
+
+        Self::get_notes(pool, meeting_id)
+            .await?
+            .ok_or(SqlxError::RowNotFound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::test_support::workspace_pool;
+
+    #[tokio::test]
+    async fn test_save_and_update_notes() {
+        let pool = workspace_pool().await;
+        sqlx::query("INSERT INTO meetings (id, title, created_at, updated_at) VALUES ('m1', 'Sync', datetime('now'), datetime('now'))")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        assert!(MeetingNotesRepository::get_notes(&pool, "m1").await.unwrap().is_none());
+
+        let entries = vec![MeetingNoteEntry {
+            id: "n1".to_string(),
+            text: "Follow up on pricing".to_string(),
+            audio_start_time: Some(125.5),
+            created_at: "2026-01-01T10:02:05Z".to_string(),
+        }];
+        let saved = MeetingNotesRepository::save_notes(&pool, "m1", Some("# Notes"), &entries)
+            .await
+            .unwrap();
+        assert_eq!(saved.notes_markdown.as_deref(), Some("# Notes"));
+        assert_eq!(saved.entries(), entries);
+
+        let updated = MeetingNotesRepository::save_notes(&pool, "m1", None, &[])
+            .await
+            .unwrap();
+        assert_eq!(updated.created_at, saved.created_at);
+        assert!(updated.notes_markdown.is_none());
+        assert!(updated.entries().is_empty());
+    }
+}
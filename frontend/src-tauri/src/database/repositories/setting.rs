@@ -1,5 +1,8 @@
 use crate::database::models::{Setting, TranscriptSetting};
-use crate::summary::CustomOpenAIConfig;
+use crate::secrets::migration::custom_openai_secret_key;
+use crate::secrets::{secret_key, SecretStore};
+use crate::summary::providers::get_provider;
+use crate::summary::{CustomOpenAIConfig, SummaryFallbackConfig};
 use sqlx::SqlitePool;
 
 #[derive(serde::Deserialize, Debug)]
@@ -24,9 +27,23 @@ pub struct SaveTranscriptConfigRequest {
 
 pub struct SettingsRepository;
 
-// Transcript providers: localWhisper, deepgram, elevenLabs, groq, openai
-// Summary providers: openai, claude, ollama, groq, added openrouter
+// Transcript providers: localWhisper, parakeet, deepgram, elevenLabs, groq, openai, whisperServer
+// Summary providers: openai, claude, ollama, groq, added openrouter, gemini
 // NOTE: Handle data exclusion in the higher layer as this is database abstraction layer(using SELECT *)
+// API keys are kept in the secret store (crate::secrets), named after their legacy `table.column`;
+// the key columns stay in the schema but are always NULL.
+
+fn secrets() -> std::result::Result<&'static SecretStore, sqlx::Error> {
+    crate::secrets::secret_store().map_err(|e| sqlx::Error::Protocol(e.to_string()))
+}
+
+/// Column a summary provider's API key is stored under, as declared by the provider
+/// registry (`None` for providers without a key)
+fn summary_api_key_column(provider: &str) -> std::result::Result<Option<&'static str>, sqlx::Error> {
+    get_provider(provider)
+        .map(|p| p.api_key_column())
+        .map_err(|_| sqlx::Error::Protocol(format!("Invalid provider: {}", provider).into()))
+}
 
 impl SettingsRepository {
     pub async fn get_model_config(
@@ -79,30 +96,25 @@ impl SettingsRepository {
             ));
         }
 
-        let api_key_column = match provider {
-            "openai" => "openaiApiKey",
-            "claude" => "anthropicApiKey",
-            "ollama" => "ollamaApiKey",
-            "groq" => "groqApiKey",
-            "openrouter" => "openRouterApiKey",
-            "builtin-ai" => return Ok(()), // No API key needed
-            _ => {
-                return Err(sqlx::Error::Protocol(
-                    format!("Invalid provider: {}", provider).into(),
-                ))
-            }
+        let api_key_column = match summary_api_key_column(provider)? {
+            Some(column) => column,
+            None => return Ok(()), // No API key needed
         };
 
-        let query = format!(
+        secrets()?
+            .set(&secret_key("settings", api_key_column), api_key)
+            .map_err(|e| sqlx::Error::Protocol(e.to_string()))?;
+
+        // Make sure the settings row exists, as it did when keys were stored inline
+        sqlx::query(
             r#"
-            INSERT INTO settings (id, provider, model, whisperModel, "{}")
-            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
-            ON CONFLICT(id) DO UPDATE SET
-                "{}" = $1
+            INSERT INTO settings (id, provider, model, whisperModel)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3')
+            ON CONFLICT(id) DO NOTHING
             "#,
-            api_key_column, api_key_column
-        );
-        sqlx::query(&query).bind(api_key).execute(pool).await?;
+        )
+        .execute(pool)
+        .await?;
 
         Ok(())
     }
@@ -117,26 +129,14 @@ impl SettingsRepository {
             return Ok(config.and_then(|c| c.api_key));
         }
 
-        let api_key_column = match provider {
-            "openai" => "openaiApiKey",
-            "ollama" => "ollamaApiKey",
-            "groq" => "groqApiKey",
-            "claude" => "anthropicApiKey",
-            "openrouter" => "openRouterApiKey",
-            "builtin-ai" => return Ok(None), // No API key needed
-            _ => {
-                return Err(sqlx::Error::Protocol(
-                    format!("Invalid provider: {}", provider).into(),
-                ))
-            }
+        let api_key_column = match summary_api_key_column(provider)? {
+            Some(column) => column,
+            None => return Ok(None), // No API key needed
         };
 
-        let query = format!(
-            "SELECT {} FROM settings WHERE id = '1' LIMIT 1",
-            api_key_column
-        );
-        let api_key = sqlx::query_scalar(&query).fetch_optional(pool).await?;
-        Ok(api_key)
+        secrets()?
+            .get(&secret_key("settings", api_key_column))
+            .map_err(|e| sqlx::Error::Protocol(e.to_string()))
     }
 
     pub async fn get_transcript_config(
@@ -154,18 +154,21 @@ impl SettingsRepository {
         pool: &SqlitePool,
         provider: &str,
         model: &str,
+        endpoint: Option<&str>,
     ) -> std::result::Result<(), sqlx::Error> {
         sqlx::query(
             r#"
-            INSERT INTO transcript_settings (id, provider, model)
-            VALUES ('1', $1, $2)
+            INSERT INTO transcript_settings (id, provider, model, endpoint)
+            VALUES ('1', $1, $2, $3)
             ON CONFLICT(id) DO UPDATE SET
                 provider = excluded.provider,
-                model = excluded.model
+                model = excluded.model,
+                endpoint = excluded.endpoint
             "#,
         )
         .bind(provider)
         .bind(model)
+        .bind(endpoint)
         .execute(pool)
         .await?;
 
@@ -184,6 +187,7 @@ impl SettingsRepository {
             "elevenLabs" => "elevenLabsApiKey",
             "groq" => "groqApiKey",
             "openai" => "openaiApiKey",
+            "whisperServer" => "whisperServerApiKey", // Optional, for servers behind auth
             _ => {
                 return Err(sqlx::Error::Protocol(
                     format!("Invalid provider: {}", provider).into(),
@@ -191,22 +195,25 @@ impl SettingsRepository {
             }
         };
 
-        let query = format!(
+        secrets()?
+            .set(&secret_key("transcript_settings", api_key_column), api_key)
+            .map_err(|e| sqlx::Error::Protocol(e.to_string()))?;
+
+        sqlx::query(
             r#"
-            INSERT INTO transcript_settings (id, provider, model, "{}")
-            VALUES ('1', 'parakeet', 'parakeet-tdt-0.6b-v3-int8', $1)
-            ON CONFLICT(id) DO UPDATE SET
-                "{}" = $1
+            INSERT INTO transcript_settings (id, provider, model)
+            VALUES ('1', 'parakeet', 'parakeet-tdt-0.6b-v3-int8')
+            ON CONFLICT(id) DO NOTHING
             "#,
-            api_key_column, api_key_column
-        );
-        sqlx::query(&query).bind(api_key).execute(pool).await?;
+        )
+        .execute(pool)
+        .await?;
 
         Ok(())
     }
 
     pub async fn get_transcript_api_key(
-        pool: &SqlitePool,
+        _pool: &SqlitePool,
         provider: &str,
     ) -> std::result::Result<Option<String>, sqlx::Error> {
         let api_key_column = match provider {
@@ -216,6 +223,7 @@ impl SettingsRepository {
             "elevenLabs" => "elevenLabsApiKey",
             "groq" => "groqApiKey",
             "openai" => "openaiApiKey",
+            "whisperServer" => "whisperServerApiKey", // Optional, for servers behind auth
             _ => {
                 return Err(sqlx::Error::Protocol(
                     format!("Invalid provider: {}", provider).into(),
@@ -223,12 +231,9 @@ impl SettingsRepository {
             }
         };
 
-        let query = format!(
-            "SELECT {} FROM transcript_settings WHERE id = '1' LIMIT 1",
-            api_key_column
-        );
-        let api_key = sqlx::query_scalar(&query).fetch_optional(pool).await?;
-        Ok(api_key)
+        secrets()?
+            .get(&secret_key("transcript_settings", api_key_column))
+            .map_err(|e| sqlx::Error::Protocol(e.to_string()))
     }
 
     pub async fn delete_api_key(
@@ -237,26 +242,25 @@ impl SettingsRepository {
     ) -> std::result::Result<(), sqlx::Error> {
         // Custom OpenAI uses JSON config - clear the entire config
         if provider == "custom-openai" {
+            secrets()?
+                .delete(&custom_openai_secret_key())
+                .map_err(|e| sqlx::Error::Protocol(e.to_string()))?;
             sqlx::query("UPDATE settings SET customOpenAIConfig = NULL WHERE id = '1'")
                 .execute(pool)
                 .await?;
             return Ok(());
         }
 
-        let api_key_column = match provider {
-            "openai" => "openaiApiKey",
-            "ollama" => "ollamaApiKey",
-            "groq" => "groqApiKey",
-            "claude" => "anthropicApiKey",
-            "openrouter" => "openRouterApiKey",
-            "builtin-ai" => return Ok(()), // No API key needed
-            _ => {
-                return Err(sqlx::Error::Protocol(
-                    format!("Invalid provider: {}", provider).into(),
-                ))
-            }
+        let api_key_column = match summary_api_key_column(provider)? {
+            Some(column) => column,
+            None => return Ok(()), // No API key needed
         };
 
+        secrets()?
+            .delete(&secret_key("settings", api_key_column))
+            .map_err(|e| sqlx::Error::Protocol(e.to_string()))?;
+
+        // Also clear any plaintext value left over from before the secret store
         let query = format!(
             "UPDATE settings SET {} = NULL WHERE id = '1'",
             api_key_column
@@ -296,11 +300,17 @@ impl SettingsRepository {
 
                 if let Some(json) = config_json {
                     // Parse JSON into CustomOpenAIConfig
-                    let config: CustomOpenAIConfig = serde_json::from_str(&json)
+                    let mut config: CustomOpenAIConfig = serde_json::from_str(&json)
                         .map_err(|e| sqlx::Error::Protocol(
                             format!("Invalid JSON in customOpenAIConfig: {}", e).into()
                         ))?;
 
+                    // The API key is kept in the secret store only; a key still left in
+                    // the JSON is never used
+                    config.api_key = secrets()?
+                        .get(&custom_openai_secret_key())
+                        .map_err(|e| sqlx::Error::Protocol(e.to_string()))?;
+
                     Ok(Some(config))
                 } else {
                     Ok(None)
@@ -323,6 +333,16 @@ impl SettingsRepository {
         pool: &SqlitePool,
         config: &CustomOpenAIConfig,
     ) -> std::result::Result<(), sqlx::Error> {
+        // Keep the API key out of the JSON stored in the database
+        let api_key = config.api_key.as_deref().unwrap_or_default();
+        secrets()?
+            .set(&custom_openai_secret_key(), api_key)
+            .map_err(|e| sqlx::Error::Protocol(e.to_string()))?;
+        let config = &CustomOpenAIConfig {
+            api_key: None,
+            ..config.clone()
+        };
+
         // Serialize config to JSON
         let config_json = serde_json::to_string(config)
             .map_err(|e| sqlx::Error::Protocol(
@@ -345,4 +365,52 @@ impl SettingsRepository {
 
         Ok(())
     }
+
+    // ===== SUMMARY FALLBACK CONFIG METHODS =====
+
+    /// Gets the retry policy and fallback chain for summary generation
+    ///
+    /// # Returns
+    /// * `Ok(Some(SummaryFallbackConfig))` - Config exists and is valid JSON
+    /// * `Ok(None)` - No config stored
+    /// * `Err(sqlx::Error)` - Database or JSON error
+    pub async fn get_summary_fallback_config(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<SummaryFallbackConfig>, sqlx::Error> {
+        let config_json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT summaryFallbackConfig FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        match config_json.flatten() {
+            Some(json) => serde_json::from_str(&json).map(Some).map_err(|e| {
+                sqlx::Error::Protocol(format!("Invalid JSON in summaryFallbackConfig: {}", e))
+            }),
+            None => Ok(None),
+        }
+    }
+
+    /// Saves the retry policy and fallback chain for summary generation as JSON
+    pub async fn save_summary_fallback_config(
+        pool: &SqlitePool,
+        config: &SummaryFallbackConfig,
+    ) -> std::result::Result<(), sqlx::Error> {
+        let config_json = serde_json::to_string(config).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize config to JSON: {}", e))
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, summaryFallbackConfig)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                summaryFallbackConfig = excluded.summaryFallbackConfig
+            "#,
+        )
+        .bind(config_json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
 }
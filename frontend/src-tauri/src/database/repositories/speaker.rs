@@ -0,0 +1,187 @@
+use crate::database::models::MeetingSpeaker;
+use chrono::Utc;
+use sqlx::{Error as SqlxError, SqlitePool};
+use std::collections::HashMap;
+use tracing::info;
+
+pub struct SpeakersRepository;
+
+impl SpeakersRepository {
+    /// Replace the diarized speaker labels of a meeting.
+    ///
+    /// `labels` are `(transcript_id, speaker_label)` pairs. Existing labels and speaker
+    /// names for the meeting are discarded, since cluster numbering is not stable
+    /// between diarization runs.
+    pub async fn replace_speaker_labels(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        labels: &[(String, String)],
+    ) -> Result<usize, SqlxError> {
+        if meeting_id.trim().is_empty() {
+            return Err(SqlxError::Protocol(
+                "meeting_id cannot be empty".to_string(),
+            ));
+        }
+
+        let mut transaction = pool.begin().await?;
+
+        sqlx::query("UPDATE transcripts SET speaker_label = NULL WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        let mut updated = 0;
+        for (transcript_id, label) in labels {
+            let result = sqlx::query(
+                "UPDATE transcripts SET speaker_label = ? WHERE id = ? AND meeting_id = ?",
+            )
+            .bind(label)
+            .bind(transcript_id)
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+            updated += result.rows_affected() as usize;
+        }
+
+        sqlx::query("DELETE FROM meeting_speakers WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        let now = Utc::now();
+        sqlx::query(
+            "INSERT INTO meeting_speakers (meeting_id, label, display_name, segment_count, total_duration, created_at, updated_at)
+             SELECT meeting_id, speaker_label, NULL, COUNT(*), COALESCE(SUM(duration), 0.0), ?, ?
+             FROM transcripts
+             WHERE meeting_id = ? AND speaker_label IS NOT NULL
+             GROUP BY speaker_label",
+        )
+        .bind(now)
+        .bind(now)
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+        transaction.commit().await?;
+        info!(
+            "Applied {} speaker labels to meeting {}",
+            updated, meeting_id
+        );
+        Ok(updated)
+    }
+
+    /// Speakers of a meeting in label order ("Speaker 2" before "Speaker 10")
+    pub async fn get_meeting_speakers(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<MeetingSpeaker>, SqlxError> {
+        sqlx::query_as::<_, MeetingSpeaker>(
+            "SELECT * FROM meeting_speakers WHERE meeting_id = ? ORDER BY length(label), label",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Set or clear (with `None` or a blank name) the display name of a speaker
+    pub async fn rename_speaker(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        label: &str,
+        display_name: Option<&str>,
+    ) -> Result<bool, SqlxError> {
+        let display_name = display_name.map(str::trim).filter(|name| !name.is_empty());
+
+        let result = sqlx::query(
+            "UPDATE meeting_speakers SET display_name = ?, updated_at = ? WHERE meeting_id = ? AND label = ?",
+        )
+        .bind(display_name)
+        .bind(Utc::now())
+        .bind(meeting_id)
+        .bind(label)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Map of speaker label to user-assigned name, for renamed speakers only
+    pub async fn get_speaker_names(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<HashMap<String, String>, SqlxError> {
+        let rows: Vec<(String, String)> = sqlx::query_as(
+            "SELECT label, display_name FROM meeting_speakers WHERE meeting_id = ? AND display_name IS NOT NULL",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::test_support::workspace_pool;
+
+    async fn setup_pool() -> SqlitePool {
+        let pool = workspace_pool().await;
+
+        sqlx::query("INSERT INTO meetings (id, title, created_at, updated_at) VALUES ('m1', 'Sync', datetime('now'), datetime('now'))")
+            .execute(&pool)
+            .await
+            .unwrap();
+        for (id, start, duration) in [("t1", 0.0, 2.0), ("t2", 2.0, 3.0), ("t3", 5.0, 1.5)] {
+            sqlx::query("INSERT INTO transcripts (id, meeting_id, transcript, timestamp, audio_start_time, audio_end_time, duration) VALUES (?, 'm1', 'text', '00:00', ?, ?, ?)")
+                .bind(id)
+                .bind(start)
+                .bind(start + duration)
+                .bind(duration)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+        pool
+    }
+
+    #[tokio::test]
+    async fn test_replace_labels_and_rename() {
+        let pool = setup_pool().await;
+        let labels = vec![
+            ("t1".to_string(), "Speaker 1".to_string()),
+            ("t2".to_string(), "Speaker 2".to_string()),
+            ("t3".to_string(), "Speaker 1".to_string()),
+        ];
+        let updated = SpeakersRepository::replace_speaker_labels(&pool, "m1", &labels)
+            .await
+            .unwrap();
+        assert_eq!(updated, 3);
+
+        let speakers = SpeakersRepository::get_meeting_speakers(&pool, "m1").await.unwrap();
+        assert_eq!(speakers.len(), 2);
+        assert_eq!(speakers[0].label, "Speaker 1");
+        assert_eq!(speakers[0].segment_count, 2);
+        assert!((speakers[0].total_duration - 3.5).abs() < 1e-9);
+
+        assert!(SpeakersRepository::rename_speaker(&pool, "m1", "Speaker 2", Some("Alice"))
+            .await
+            .unwrap());
+        assert!(!SpeakersRepository::rename_speaker(&pool, "m1", "Speaker 9", Some("Bob"))
+            .await
+            .unwrap());
+
+        let names = SpeakersRepository::get_speaker_names(&pool, "m1").await.unwrap();
+        assert_eq!(names.len(), 1);
+        assert_eq!(names.get("Speaker 2").map(String::as_str), Some("Alice"));
+
+        // Re-diarizing resets labels and names
+        SpeakersRepository::replace_speaker_labels(&pool, "m1", &labels[..1])
+            .await
+            .unwrap();
+        let speakers = SpeakersRepository::get_meeting_speakers(&pool, "m1").await.unwrap();
+        assert_eq!(speakers.len(), 1);
+        assert!(SpeakersRepository::get_speaker_names(&pool, "m1").await.unwrap().is_empty());
+    }
+}
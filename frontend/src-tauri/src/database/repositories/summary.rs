@@ -1,4 +1,7 @@
-use crate::database::models::SummaryProcess;
+use crate::database::models::{SummaryProcess, SummaryVersion};
+use crate::database::repositories::summary_version::{
+    insert_version, latest_result, pinned_result, SummaryVersionOrigin,
+};
 use chrono::Utc;
 use serde_json::Value;
 use sqlx::SqlitePool;
@@ -46,15 +49,22 @@ impl SummaryProcessesRepository {
             transaction.rollback().await?;
             return Ok(false);
         }
+        let result_json = result_json.unwrap();
         let now = Utc::now();
 
         sqlx::query("UPDATE summary_processes SET result = ?, updated_at = ? WHERE meeting_id = ?")
-            .bind(&result_json.unwrap())
+            .bind(&result_json)
             .bind(now)
             .bind(meeting_id)
             .execute(&mut *transaction)
             .await?;
 
+        // Saving without changes does not add a version
+        if latest_result(&mut transaction, meeting_id).await?.as_deref() != Some(result_json.as_str()) {
+            insert_version(&mut transaction, meeting_id, &result_json, &SummaryVersionOrigin::edited())
+                .await?;
+        }
+
         sqlx::query("UPDATE meetings SET updated_at = ? WHERE id = ?")
             .bind(now)
             .bind(meeting_id)
@@ -99,9 +109,6 @@ impl SummaryProcessesRepository {
                 status = 'PENDING',
                 updated_at = excluded.updated_at,
                 start_time = excluded.start_time,
-                result_backup = result,
-                result_backup_timestamp = excluded.updated_at,
-                result = result,
                 error = NULL
             "#
         )
@@ -111,44 +118,58 @@ impl SummaryProcessesRepository {
         .bind(now)
         .execute(pool)
         .await?;
-        log_info!(
-            "Backed up existing summary before regeneration for meeting_id: {}",
-            meeting_id
-        );
         Ok(())
     }
 
+    /// Marks a summary process completed and records the new summary as a version.
+    ///
+    /// When a version of the meeting is pinned it stays the active summary; the new one is
+    /// only added to the history.
     pub async fn update_process_completed(
         pool: &SqlitePool,
         meeting_id: &str,
         result: Value, // Keep this as Value to handle both old and new formats if needed
         chunk_count: i64,
         processing_time: f64,
-    ) -> Result<(), sqlx::Error> {
+        metadata: Value, // Which provider/model produced the summary, and any failed attempts
+        origin: &SummaryVersionOrigin,
+    ) -> Result<SummaryVersion, sqlx::Error> {
         let now = Utc::now();
         let result_str = serde_json::to_string(&result)
             .map_err(|e| sqlx::Error::Protocol(format!("Failed to serialize result: {}", e)))?;
+        let metadata_str = serde_json::to_string(&metadata)
+            .map_err(|e| sqlx::Error::Protocol(format!("Failed to serialize metadata: {}", e)))?;
+
+        let mut transaction = pool.begin().await?;
+        let version = insert_version(&mut transaction, meeting_id, &result_str, origin).await?;
+        let active_result = pinned_result(&mut transaction, meeting_id)
+            .await?
+            .unwrap_or(result_str);
 
         sqlx::query(
             r#"
             UPDATE summary_processes
-            SET status = 'completed', result = ?, updated_at = ?, end_time = ?, chunk_count = ?, processing_time = ?, error = NULL, result_backup = NULL, result_backup_timestamp = NULL
+            SET status = 'completed', result = ?, updated_at = ?, end_time = ?, chunk_count = ?, processing_time = ?, metadata = ?, error = NULL
             WHERE meeting_id = ?
             "#
         )
-        .bind(result_str)
+        .bind(active_result)
         .bind(now)
         .bind(now)
         .bind(chunk_count)
         .bind(processing_time)
+        .bind(metadata_str)
         .bind(meeting_id)
-        .execute(pool)
+        .execute(&mut *transaction)
         .await?;
+        transaction.commit().await?;
+
         log_info!(
-            "Summary completed and backup cleared for meeting_id: {}",
+            "Summary completed as version {} for meeting_id: {}",
+            version.version,
             meeting_id
         );
-        Ok(())
+        Ok(version)
     }
 
     pub async fn update_process_failed(
@@ -158,7 +179,7 @@ impl SummaryProcessesRepository {
     ) -> Result<(), sqlx::Error> {
         let now = Utc::now();
 
-        // Restore from backup if it exists, otherwise keep current result
+        // The previous summary is still in `result`; generation only replaces it on success
         sqlx::query(
             r#"
             UPDATE summary_processes
@@ -166,10 +187,7 @@ impl SummaryProcessesRepository {
                 status = 'failed',
                 error = ?,
                 updated_at = ?,
-                end_time = ?,
-                result = COALESCE(result_backup, result),
-                result_backup = NULL,
-                result_backup_timestamp = NULL
+                end_time = ?
             WHERE meeting_id = ?
             "#,
         )
@@ -180,7 +198,7 @@ impl SummaryProcessesRepository {
         .execute(pool)
         .await?;
         log_info!(
-            "Summary generation failed and backup restored for meeting_id: {}",
+            "Summary generation failed for meeting_id: {}",
             meeting_id
         );
         Ok(())
@@ -192,7 +210,6 @@ impl SummaryProcessesRepository {
     ) -> Result<(), sqlx::Error> {
         let now = Utc::now();
 
-        // Restore from backup if it exists, otherwise keep current result
         sqlx::query(
             r#"
             UPDATE summary_processes
@@ -200,10 +217,7 @@ impl SummaryProcessesRepository {
                 status = 'cancelled',
                 updated_at = ?,
                 end_time = ?,
-                error = 'Generation was cancelled by user',
-                result = COALESCE(result_backup, result),
-                result_backup = NULL,
-                result_backup_timestamp = NULL
+                error = 'Generation was cancelled by user'
             WHERE meeting_id = ?
             "#,
         )
@@ -213,7 +227,7 @@ impl SummaryProcessesRepository {
         .execute(pool)
         .await?;
         log_info!(
-            "Marked summary process as cancelled and restored backup for meeting_id: {}",
+            "Marked summary process as cancelled for meeting_id: {}",
             meeting_id
         );
         Ok(())
@@ -0,0 +1,360 @@
+use crate::database::models::SummaryVersion;
+use chrono::Utc;
+use sqlx::{Error as SqlxError, SqliteConnection, SqlitePool};
+use tracing::info;
+
+/// How a new summary version came about
+#[derive(Debug, Clone, Default)]
+pub struct SummaryVersionOrigin {
+    /// "generated", "edited" or "restored"
+    pub source: &'static str,
+    pub provider: Option<String>,
+    pub model: Option<String>,
+    pub template_id: Option<String>,
+    pub prompt: Option<String>,
+    pub restored_from: Option<i64>,
+}
+
+impl SummaryVersionOrigin {
+    pub fn generated(provider: &str, model: &str, template_id: &str, prompt: &str) -> Self {
+        Self {
+            source: "generated",
+            provider: Some(provider.to_string()),
+            model: Some(model.to_string()),
+            template_id: Some(template_id.to_string()),
+            prompt: Some(prompt.to_string()).filter(|p| !p.trim().is_empty()),
+            restored_from: None,
+        }
+    }
+
+    pub fn edited() -> Self {
+        Self {
+            source: "edited",
+            ..Default::default()
+        }
+    }
+}
+
+pub struct SummaryVersionsRepository;
+
+impl SummaryVersionsRepository {
+    /// All versions of a meeting's summary, newest first
+    pub async fn list_versions(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<SummaryVersion>, SqlxError> {
+        sqlx::query_as::<_, SummaryVersion>(
+            "SELECT * FROM summary_versions WHERE meeting_id = ? ORDER BY version DESC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get_version(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        version: i64,
+    ) -> Result<Option<SummaryVersion>, SqlxError> {
+        sqlx::query_as::<_, SummaryVersion>(
+            "SELECT * FROM summary_versions WHERE meeting_id = ? AND version = ?",
+        )
+        .bind(meeting_id)
+        .bind(version)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Make an earlier version the active summary again.
+    ///
+    /// The restored content is recorded as a new version, so history stays linear; if
+    /// another version was pinned the pin moves to the restored one.
+    ///
+    /// # Returns
+    /// The new version, or None if `version` does not exist
+    pub async fn restore_version(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        version: i64,
+    ) -> Result<Option<SummaryVersion>, SqlxError> {
+        let mut transaction = pool.begin().await?;
+
+        let Some(original) = sqlx::query_as::<_, SummaryVersion>(
+            "SELECT * FROM summary_versions WHERE meeting_id = ? AND version = ?",
+        )
+        .bind(meeting_id)
+        .bind(version)
+        .fetch_optional(&mut *transaction)
+        .await?
+        else {
+            return Ok(None);
+        };
+
+        let now = Utc::now();
+        sqlx::query("UPDATE summary_processes SET result = ?, updated_at = ? WHERE meeting_id = ?")
+            .bind(&original.result)
+            .bind(now)
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+        sqlx::query("UPDATE meetings SET updated_at = ? WHERE id = ?")
+            .bind(now)
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        let origin = SummaryVersionOrigin {
+            source: "restored",
+            provider: original.provider,
+            model: original.model,
+            template_id: original.template_id,
+            prompt: original.prompt,
+            restored_from: Some(original.version),
+        };
+        let restored = insert_version(&mut transaction, meeting_id, &original.result, &origin).await?;
+        transaction.commit().await?;
+
+        info!(
+            "Restored summary version {} of meeting {} as version {}",
+            version, meeting_id, restored.version
+        );
+        Ok(Some(restored))
+    }
+
+    /// Pin a version, making it the active summary until it is unpinned, or unpin it.
+    ///
+    /// At most one version of a meeting is pinned. Unpinning leaves the active summary
+    /// as it is.
+    ///
+    /// # Returns
+    /// The updated version, or None if `version` does not exist
+    pub async fn set_pinned(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        version: i64,
+        pinned: bool,
+    ) -> Result<Option<SummaryVersion>, SqlxError> {
+        let mut transaction = pool.begin().await?;
+
+        let Some(target) = sqlx::query_as::<_, SummaryVersion>(
+            "SELECT * FROM summary_versions WHERE meeting_id = ? AND version = ?",
+        )
+        .bind(meeting_id)
+        .bind(version)
+        .fetch_optional(&mut *transaction)
+        .await?
+        else {
+            return Ok(None);
+        };
+
+        if pinned {
+            sqlx::query("UPDATE summary_versions SET pinned = 0 WHERE meeting_id = ?")
+                .bind(meeting_id)
+                .execute(&mut *transaction)
+                .await?;
+            sqlx::query("UPDATE summary_processes SET result = ?, updated_at = ? WHERE meeting_id = ?")
+                .bind(&target.result)
+                .bind(Utc::now())
+                .bind(meeting_id)
+                .execute(&mut *transaction)
+                .await?;
+        }
+        let updated = sqlx::query_as::<_, SummaryVersion>(
+            "UPDATE summary_versions SET pinned = ? WHERE id = ? RETURNING *",
+        )
+        .bind(pinned)
+        .bind(target.id)
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        transaction.commit().await?;
+        Ok(Some(updated))
+    }
+}
+
+/// Result JSON of the pinned version of a meeting, if any
+pub(crate) async fn pinned_result(
+    conn: &mut SqliteConnection,
+    meeting_id: &str,
+) -> Result<Option<String>, SqlxError> {
+    sqlx::query_scalar::<_, String>(
+        "SELECT result FROM summary_versions WHERE meeting_id = ? AND pinned = 1",
+    )
+    .bind(meeting_id)
+    .fetch_optional(conn)
+    .await
+}
+
+/// Result JSON of the newest version of a meeting, if any
+pub(crate) async fn latest_result(
+    conn: &mut SqliteConnection,
+    meeting_id: &str,
+) -> Result<Option<String>, SqlxError> {
+    sqlx::query_scalar::<_, String>(
+        "SELECT result FROM summary_versions WHERE meeting_id = ? ORDER BY version DESC LIMIT 1",
+    )
+    .bind(meeting_id)
+    .fetch_optional(conn)
+    .await
+}
+
+/// Append a version to a meeting's summary history, inside the caller's transaction.
+///
+/// Edited and restored versions are what the user chose to see, so a pin on an
+/// older version moves to them; generated versions leave the pin where it is.
+pub(crate) async fn insert_version(
+    conn: &mut SqliteConnection,
+    meeting_id: &str,
+    result: &str,
+    origin: &SummaryVersionOrigin,
+) -> Result<SummaryVersion, SqlxError> {
+    let takes_pin = origin.source != "generated" && pinned_result(conn, meeting_id).await?.is_some();
+    if takes_pin {
+        sqlx::query("UPDATE summary_versions SET pinned = 0 WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *conn)
+            .await?;
+    }
+
+    sqlx::query_as::<_, SummaryVersion>(
+        "INSERT INTO summary_versions (meeting_id, version, source, result, provider, model, template_id, prompt, restored_from, pinned, created_at)
+         SELECT ?1, COALESCE(MAX(version), 0) + 1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10
+         FROM summary_versions WHERE meeting_id = ?1
+         RETURNING *",
+    )
+    .bind(meeting_id)
+    .bind(origin.source)
+    .bind(result)
+    .bind(&origin.provider)
+    .bind(&origin.model)
+    .bind(&origin.template_id)
+    .bind(&origin.prompt)
+    .bind(origin.restored_from)
+    .bind(takes_pin)
+    .bind(Utc::now())
+    .fetch_one(conn)
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::summary::SummaryProcessesRepository;
+    use crate::database::test_support::workspace_pool;
+    use crate::workspace::manager::WorkspaceManager;
+    use serde_json::json;
+
+    async fn active_result(pool: &SqlitePool) -> String {
+        sqlx::query_scalar("SELECT result FROM summary_processes WHERE meeting_id = 'm1'")
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    async fn complete(pool: &SqlitePool, markdown: &str) -> SummaryVersion {
+        SummaryProcessesRepository::create_or_reset_process(pool, "m1").await.unwrap();
+        SummaryProcessesRepository::update_process_completed(
+            pool,
+            "m1",
+            json!({ "markdown": markdown }),
+            1,
+            0.5,
+            json!({}),
+            &SummaryVersionOrigin::generated("ollama", "llama3.2", "standard_meeting", "Focus on QA"),
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_history_survives_regeneration_and_respects_pin() {
+        let pool = workspace_pool().await;
+        sqlx::query("INSERT INTO meetings (id, title, created_at, updated_at) VALUES ('m1', 'Sync', '2026-04-10T10:00:00Z', '2026-04-10T10:00:00Z')")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let first = complete(&pool, "First").await;
+        let second = complete(&pool, "Second").await;
+        assert_eq!((first.version, second.version), (1, 2));
+        assert_eq!(second.model.as_deref(), Some("llama3.2"));
+        assert_eq!(second.prompt.as_deref(), Some("Focus on QA"));
+
+        // Saving the same content twice records one edit
+        let edited = json!({ "markdown": "Second, edited" });
+        for _ in 0..2 {
+            assert!(SummaryProcessesRepository::update_meeting_summary(&pool, "m1", &edited)
+                .await
+                .unwrap());
+        }
+        let versions = SummaryVersionsRepository::list_versions(&pool, "m1").await.unwrap();
+        assert_eq!(versions.len(), 3);
+        assert_eq!(versions[0].source, "edited");
+
+        // A pinned version stays active when a new summary is generated
+        let pinned = SummaryVersionsRepository::set_pinned(&pool, "m1", 1, true)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(pinned.pinned);
+        assert_eq!(active_result(&pool).await, pinned.result);
+        let third = complete(&pool, "Third").await;
+        assert_eq!(third.version, 4);
+        assert!(!third.pinned);
+        assert_eq!(active_result(&pool).await, pinned.result);
+
+        // Restoring makes a new version that takes over the pin
+        let restored = SummaryVersionsRepository::restore_version(&pool, "m1", 2)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(restored.version, 5);
+        assert_eq!(restored.restored_from, Some(2));
+        assert!(restored.pinned);
+        assert_eq!(active_result(&pool).await, second.result);
+        let pinned_versions: Vec<i64> = SummaryVersionsRepository::list_versions(&pool, "m1")
+            .await
+            .unwrap()
+            .iter()
+            .filter(|v| v.pinned)
+            .map(|v| v.version)
+            .collect();
+        assert_eq!(pinned_versions, vec![5]);
+
+        assert!(SummaryVersionsRepository::restore_version(&pool, "m1", 42)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_migration_keeps_the_pre_regeneration_backup() {
+        let pool = workspace_pool().await;
+        sqlx::query("INSERT INTO meetings (id, title, created_at, updated_at) VALUES ('m1', 'Sync', '2026-04-01T10:00:00Z', '2026-04-01T10:00:00Z')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            r##"INSERT INTO summary_processes (meeting_id, status, created_at, updated_at, result, result_backup, result_backup_timestamp)
+               VALUES ('m1', 'completed', '2026-04-01T10:00:00Z', '2026-04-03T10:00:00Z',
+                       '{"markdown":"# Regenerated"}', '{"markdown":"# Original"}', '2026-04-02T10:00:00Z')"##,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        // Migrations run again on every workspace switch
+        for _ in 0..2 {
+            WorkspaceManager::run_workspace_migrations(&pool).await.unwrap();
+        }
+
+        let versions = SummaryVersionsRepository::list_versions(&pool, "m1").await.unwrap();
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions[0].version, 2);
+        assert_eq!(versions[0].result, r##"{"markdown":"# Regenerated"}"##);
+        assert_eq!(versions[1].version, 1);
+        assert_eq!(versions[1].source, "generated");
+        assert_eq!(versions[1].result, r##"{"markdown":"# Original"}"##);
+        assert_eq!(versions[1].created_at.to_rfc3339(), "2026-04-02T10:00:00+00:00");
+    }
+}
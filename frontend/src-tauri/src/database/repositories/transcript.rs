@@ -1,6 +1,10 @@
 use crate::api::{TranscriptSearchResult, TranscriptSegment};
+use crate::audio::transcription::WordTiming;
+use crate::database::models::{Transcript, TranscriptVersion};
+use crate::database::repositories::transcript_version::{archive_transcript, TranscriptVersionOrigin};
 use chrono::Utc;
-use sqlx::{Connection, Error as SqlxError, SqlitePool};
+use sqlx::{Connection, Error as SqlxError, SqliteConnection, SqlitePool};
+use std::collections::HashMap;
 use tracing::{error, info};
 use uuid::Uuid;
 
@@ -45,22 +49,7 @@ impl TranscriptsRepository {
 
         // 2. Save each transcript segment with audio timing fields
         for segment in transcripts {
-            let transcript_id = format!("transcript-{}", Uuid::new_v4());
-            let result = sqlx::query(
-                "INSERT INTO transcripts (id, meeting_id, transcript, timestamp, audio_start_time, audio_end_time, duration)
-                 VALUES (?, ?, ?, ?, ?, ?, ?)"
-            )
-            .bind(&transcript_id)
-            .bind(&meeting_id)
-            .bind(&segment.text)
-            .bind(&segment.timestamp)
-            .bind(segment.audio_start_time)
-            .bind(segment.audio_end_time)
-            .bind(segment.duration)
-            .execute(&mut *transaction)
-            .await;
-
-            if let Err(e) = result {
+            if let Err(e) = Self::insert_segment(&mut *transaction, &meeting_id, segment).await {
                 error!(
                     "Failed to save transcript segment for meeting {}: {}",
                     meeting_id, e
@@ -82,65 +71,843 @@ impl TranscriptsRepository {
         Ok(meeting_id)
     }
 
-    /// Searches for a query string within the transcripts.
-    /// It returns a list of matching transcripts with context.
+    /// Stores one transcript segment of a meeting, with its word timings, under a new id.
+    pub async fn insert_segment(
+        conn: &mut SqliteConnection,
+        meeting_id: &str,
+        segment: &TranscriptSegment,
+    ) -> Result<String, SqlxError> {
+        let transcript_id = format!("transcript-{}", Uuid::new_v4());
+        sqlx::query(
+            "INSERT INTO transcripts (id, meeting_id, transcript, timestamp, audio_start_time, audio_end_time, duration, speaker, confidence, low_confidence)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(&transcript_id)
+        .bind(meeting_id)
+        .bind(&segment.text)
+        .bind(&segment.timestamp)
+        .bind(segment.audio_start_time)
+        .bind(segment.audio_end_time)
+        .bind(segment.duration)
+        .bind(&segment.speaker)
+        .bind(segment.confidence)
+        .bind(segment.low_confidence)
+        .execute(&mut *conn)
+        .await?;
+
+        Self::insert_words(conn, &transcript_id, &segment.words).await?;
+        Ok(transcript_id)
+    }
+
+    /// The current transcript of a meeting as segments with word timings, in playback order.
+    pub async fn load_segments(
+        conn: &mut SqliteConnection,
+        meeting_id: &str,
+    ) -> Result<Vec<TranscriptSegment>, SqlxError> {
+        let rows = sqlx::query_as::<_, Transcript>(
+            "SELECT * FROM transcripts WHERE meeting_id = ? ORDER BY audio_start_time ASC, timestamp ASC",
+        )
+        .bind(meeting_id)
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut segments = Vec::with_capacity(rows.len());
+        for row in rows {
+            let words = sqlx::query_as::<_, (String, f64, f64, Option<f32>)>(
+                "SELECT word, start_time, end_time, probability
+                 FROM transcript_words WHERE transcript_id = ? ORDER BY word_index",
+            )
+            .bind(&row.id)
+            .fetch_all(&mut *conn)
+            .await?
+            .into_iter()
+            .map(|(word, start, end, probability)| WordTiming {
+                word,
+                start,
+                end,
+                probability,
+            })
+            .collect();
+
+            segments.push(TranscriptSegment {
+                id: row.id,
+                text: row.transcript,
+                timestamp: row.timestamp,
+                audio_start_time: row.audio_start_time,
+                audio_end_time: row.audio_end_time,
+                duration: row.duration,
+                speaker: row.speaker,
+                confidence: row.confidence,
+                low_confidence: row.low_confidence,
+                words,
+            });
+        }
+        Ok(segments)
+    }
+
+    /// Replaces every transcript segment of a meeting, in one transaction.
+    ///
+    /// With an `archive` origin the current transcript is first kept as a transcript
+    /// version, so it can be restored later. Speaker labels, word timings and search
+    /// embeddings of the old rows go with them.
+    ///
+    /// # Returns
+    /// The archived version, if one was created
+    pub async fn replace_transcripts(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        segments: &[TranscriptSegment],
+        archive: Option<&TranscriptVersionOrigin>,
+    ) -> Result<Option<TranscriptVersion>, SqlxError> {
+        let mut transaction = pool.begin().await?;
+
+        let archived = match archive {
+            Some(origin) => archive_transcript(&mut *transaction, meeting_id, origin).await?,
+            None => None,
+        };
+
+        sqlx::query("DELETE FROM transcripts WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+        for segment in segments {
+            Self::insert_segment(&mut *transaction, meeting_id, segment).await?;
+        }
+        sqlx::query("UPDATE meetings SET updated_at = ? WHERE id = ?")
+            .bind(Utc::now())
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        transaction.commit().await?;
+        info!(
+            "Replaced transcript of meeting {} with {} segments",
+            meeting_id,
+            segments.len()
+        );
+        Ok(archived)
+    }
+
+    /// Segments of a meeting worth re-transcribing: flagged as low-confidence, or with a
+    /// confidence below `threshold`. Segments without audio timing are left out.
+    pub async fn get_low_confidence_segments(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        threshold: f32,
+    ) -> Result<Vec<Transcript>, SqlxError> {
+        sqlx::query_as::<_, Transcript>(
+            "SELECT * FROM transcripts
+             WHERE meeting_id = ?
+               AND (low_confidence = 1 OR confidence < ?)
+               AND audio_start_time IS NOT NULL AND audio_end_time > audio_start_time
+             ORDER BY audio_start_time ASC",
+        )
+        .bind(meeting_id)
+        .bind(threshold)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Replaces the text, confidence and word timings of existing segments, matched by `id`,
+    /// in one transaction. Timing, source and speaker labels are kept.
+    ///
+    /// With an `archive` origin the current transcript is first kept as a transcript version.
+    ///
+    /// # Returns
+    /// The archived version, if one was created
+    pub async fn update_segments(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        segments: &[TranscriptSegment],
+        archive: Option<&TranscriptVersionOrigin>,
+    ) -> Result<Option<TranscriptVersion>, SqlxError> {
+        let mut transaction = pool.begin().await?;
+
+        let archived = match archive {
+            Some(origin) => archive_transcript(&mut *transaction, meeting_id, origin).await?,
+            None => None,
+        };
+
+        for segment in segments {
+            // Updating the text drops the old word timings (transcript_words_au trigger)
+            sqlx::query(
+                "UPDATE transcripts SET transcript = ?, confidence = ?, low_confidence = ?
+                 WHERE id = ? AND meeting_id = ?",
+            )
+            .bind(&segment.text)
+            .bind(segment.confidence)
+            .bind(segment.low_confidence)
+            .bind(&segment.id)
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+            Self::insert_words(&mut *transaction, &segment.id, &segment.words).await?;
+        }
+        sqlx::query("UPDATE meetings SET updated_at = ? WHERE id = ?")
+            .bind(Utc::now())
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        transaction.commit().await?;
+        info!(
+            "Updated {} transcript segments of meeting {}",
+            segments.len(),
+            meeting_id
+        );
+        Ok(archived)
+    }
+
+    /// Stores the word timings of a transcript segment.
+    pub async fn insert_words(
+        conn: &mut SqliteConnection,
+        transcript_id: &str,
+        words: &[WordTiming],
+    ) -> Result<(), SqlxError> {
+        for (index, word) in words.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO transcript_words (transcript_id, word_index, word, start_time, end_time, probability)
+                 VALUES (?, ?, ?, ?, ?, ?)",
+            )
+            .bind(transcript_id)
+            .bind(index as i64)
+            .bind(&word.word)
+            .bind(word.start)
+            .bind(word.end)
+            .bind(word.probability)
+            .execute(&mut *conn)
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// Word timings of the given transcript segments, keyed by transcript id.
+    /// Segments without word timings are absent from the map.
+    pub async fn get_words(
+        pool: &SqlitePool,
+        transcript_ids: &[String],
+    ) -> Result<HashMap<String, Vec<WordTiming>>, SqlxError> {
+        let mut words: HashMap<String, Vec<WordTiming>> = HashMap::new();
+        // Stay well below SQLite's bound parameter limit
+        for ids in transcript_ids.chunks(500) {
+            let placeholders = vec!["?"; ids.len()].join(", ");
+            let sql = format!(
+                "SELECT transcript_id, word, start_time, end_time, probability
+                 FROM transcript_words
+                 WHERE transcript_id IN ({placeholders})
+                 ORDER BY transcript_id, word_index"
+            );
+            let mut query = sqlx::query_as::<_, (String, String, f64, f64, Option<f32>)>(&sql);
+            for id in ids {
+                query = query.bind(id);
+            }
+            for (transcript_id, word, start, end, probability) in query.fetch_all(pool).await? {
+                words.entry(transcript_id).or_default().push(WordTiming {
+                    word,
+                    start,
+                    end,
+                    probability,
+                });
+            }
+        }
+        Ok(words)
+    }
+
+    /// Returns all transcript segments of a meeting in playback order.
+    pub async fn get_transcripts_for_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<Transcript>, SqlxError> {
+        sqlx::query_as::<_, Transcript>(
+            "SELECT * FROM transcripts WHERE meeting_id = ? ORDER BY audio_start_time ASC, timestamp ASC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Full-text search over transcripts and summaries using the FTS5 index.
+    ///
+    /// Supports quoted phrases, `prefix*` terms and `AND` / `OR` / `NOT` operators.
+    /// Results are ranked by BM25 and every hit carries its own snippet, with matched terms
+    /// wrapped in `<mark>`/`</mark>`, so a meeting that matches in several segments yields
+    /// several results.
     pub async fn search_transcripts(
         pool: &SqlitePool,
         query: &str,
     ) -> Result<Vec<TranscriptSearchResult>, SqlxError> {
-        if query.trim().is_empty() {
+        let Some(match_query) = build_fts_query(query) else {
             return Ok(Vec::new());
-        }
+        };
 
-        let search_query = format!("%{}%", query.to_lowercase());
+        let transcript_rows = sqlx::query_as::<_, (String, String, String, String, Option<f64>, String, f64)>(
+            "SELECT m.id, m.title, t.id, t.timestamp, t.audio_start_time,
+                    snippet(transcripts_fts, 0, '<mark>', '</mark>', '...', 24),
+                    bm25(transcripts_fts) AS rank
+             FROM transcripts_fts
+             JOIN transcripts t ON t.search_rowid = transcripts_fts.rowid
+             JOIN meetings m ON m.id = t.meeting_id
+             WHERE transcripts_fts MATCH ?
+             ORDER BY rank
+             LIMIT ?",
+        )
+        .bind(&match_query)
+        .bind(SEARCH_RESULT_LIMIT)
+        .fetch_all(pool)
+        .await?;
 
-        let rows = sqlx::query_as::<_, (String, String, String, String)>(
-            "SELECT m.id, m.title, t.transcript, t.timestamp
-             FROM meetings m
-             JOIN transcripts t ON m.id = t.meeting_id
-             WHERE LOWER(t.transcript) LIKE ?",
+        let summary_rows = sqlx::query_as::<_, (String, String, String, String, f64)>(
+            "SELECT m.id, m.title, m.created_at,
+                    snippet(summaries_fts, 0, '<mark>', '</mark>', '...', 24),
+                    bm25(summaries_fts) AS rank
+             FROM summaries_fts
+             JOIN meetings m ON m.id = summaries_fts.meeting_id
+             WHERE summaries_fts MATCH ?
+             ORDER BY rank
+             LIMIT ?",
         )
-        .bind(&search_query)
+        .bind(&match_query)
+        .bind(SEARCH_RESULT_LIMIT)
         .fetch_all(pool)
         .await?;
 
-        let results = rows
+        let transcript_ranks = relative_ranks(transcript_rows.iter().map(|row| row.6));
+        let summary_ranks = relative_ranks(summary_rows.iter().map(|row| row.4));
+
+        let mut ranked: Vec<(f64, TranscriptSearchResult)> = transcript_rows
             .into_iter()
-            .map(|(id, title, transcript, timestamp)| {
-                let match_context = Self::get_match_context(&transcript, query);
-                TranscriptSearchResult {
-                    id,
-                    title,
-                    match_context,
-                    timestamp,
-                }
+            .zip(transcript_ranks)
+            .map(|((id, title, transcript_id, timestamp, audio_start_time, match_context, _), rank)| {
+                (
+                    rank,
+                    TranscriptSearchResult {
+                        id,
+                        title,
+                        match_context,
+                        timestamp,
+                        source: "transcript".to_string(),
+                        transcript_id: Some(transcript_id),
+                        audio_start_time,
+                    },
+                )
             })
             .collect();
 
-        Ok(results)
+        ranked.extend(summary_rows.into_iter().zip(summary_ranks).map(
+            |((id, title, timestamp, match_context, _), rank)| {
+                (
+                    rank,
+                    TranscriptSearchResult {
+                        id,
+                        title,
+                        match_context,
+                        timestamp,
+                        source: "summary".to_string(),
+                        transcript_id: None,
+                        audio_start_time: None,
+                    },
+                )
+            },
+        ));
+
+        // Best relative rank first
+        ranked.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+        ranked.truncate(SEARCH_RESULT_LIMIT as usize);
+
+        Ok(ranked.into_iter().map(|(_, result)| result).collect())
+    }
+
+    /// Transcript segment ids matching any word of a natural-language query, best BM25
+    /// match first. Used as the keyword half of hybrid search, where requiring every
+    /// word (as `search_transcripts` does) would miss most conversational queries.
+    pub async fn rank_segments_by_keywords(
+        pool: &SqlitePool,
+        query: &str,
+        limit: i64,
+    ) -> Result<Vec<String>, SqlxError> {
+        let terms: Vec<String> = query
+            .split_whitespace()
+            .filter(|word| !matches!(*word, "AND" | "OR" | "NOT"))
+            .filter_map(build_fts_query)
+            .collect();
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        sqlx::query_scalar::<_, String>(
+            "SELECT t.id
+             FROM transcripts_fts
+             JOIN transcripts t ON t.search_rowid = transcripts_fts.rowid
+             WHERE transcripts_fts MATCH ?
+             ORDER BY bm25(transcripts_fts)
+             LIMIT ?",
+        )
+        .bind(terms.join(" OR "))
+        .bind(limit)
+        .fetch_all(pool)
+        .await
     }
+}
 
-    /// Helper function to extract a snippet of text around the first match of a query.
-    fn get_match_context(transcript: &str, query: &str) -> String {
-        let transcript_lower = transcript.to_lowercase();
-        let query_lower = query.to_lowercase();
+/// Scale the bm25() scores of one FTS table to 0.0-1.0 relative to its best hit.
+///
+/// bm25() values depend on each table's own document statistics, so raw scores from the
+/// transcript and summary indexes can't be compared; relative ranks can.
+fn relative_ranks(scores: impl Iterator<Item = f64>) -> Vec<f64> {
+    let scores: Vec<f64> = scores.collect();
+    // bm25() is negative; lower means a better match
+    let best = scores.iter().copied().fold(0.0_f64, f64::min);
+    scores
+        .iter()
+        .map(|score| if best < 0.0 { score / best } else { 1.0 })
+        .collect()
+}
 
-        match transcript_lower.find(&query_lower) {
-            Some(match_index) => {
-                let start_index = match_index.saturating_sub(100);
-                let end_index = (match_index + query.len() + 100).min(transcript.len());
+/// Maximum number of hits returned by a single search.
+const SEARCH_RESULT_LIMIT: i64 = 100;
 
-                let mut context = String::new();
-                if start_index > 0 {
-                    context.push_str("...");
+/// Convert user input into a safe FTS5 MATCH expression.
+///
+/// Every term and phrase is quoted so punctuation in user input cannot produce
+/// FTS5 syntax errors. Upper-case `AND`, `OR` and `NOT` are kept as operators when
+/// they sit between two terms; a trailing `*` turns a term or phrase into a prefix query.
+/// Returns `None` when nothing searchable remains.
+pub(crate) fn build_fts_query(input: &str) -> Option<String> {
+    let mut parts: Vec<String> = Vec::new();
+    let mut pending_operator: Option<&'static str> = None;
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        let (text, prefix, is_phrase) = if c == '"' {
+            chars.next();
+            let mut phrase = String::new();
+            while let Some(ch) = chars.next() {
+                if ch == '"' {
+                    break;
                 }
-                context.push_str(&transcript[start_index..end_index]);
-                if end_index < transcript.len() {
-                    context.push_str("...");
+                phrase.push(ch);
+            }
+            let prefix = chars.peek() == Some(&'*');
+            if prefix {
+                chars.next();
+            }
+            (phrase, prefix, true)
+        } else {
+            let mut word = String::new();
+            while let Some(&ch) = chars.peek() {
+                if ch.is_whitespace() || ch == '"' {
+                    break;
                 }
-                context
+                word.push(ch);
+                chars.next();
             }
-            None => transcript.chars().take(200).collect(), // Fallback to the start of the transcript
+            let prefix = word.ends_with('*');
+            (word.trim_end_matches('*').to_string(), prefix, false)
+        };
+
+        if !is_phrase {
+            let operator = match text.as_str() {
+                "AND" => Some("AND"),
+                "OR" => Some("OR"),
+                "NOT" => Some("NOT"),
+                _ => None,
+            };
+            if let Some(op) = operator {
+                if !parts.is_empty() {
+                    pending_operator = Some(op);
+                }
+                continue;
+            }
+        }
+
+        if !text.chars().any(|ch| ch.is_alphanumeric()) {
+            continue;
+        }
+
+        if let Some(op) = pending_operator.take() {
+            parts.push(op.to_string());
+        }
+        let mut term = format!("\"{}\"", text.trim().replace('"', "\"\""));
+        if prefix {
+            term.push('*');
+        }
+        parts.push(term);
+    }
+
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::test_support::workspace_pool;
+
+    #[test]
+    fn test_build_fts_query_quotes_plain_terms() {
+        assert_eq!(
+            build_fts_query("budget review").as_deref(),
+            Some("\"budget\" \"review\"")
+        );
+        assert_eq!(build_fts_query("   ").as_deref(), None);
+        assert_eq!(build_fts_query("- ( )").as_deref(), None);
+    }
+
+    #[test]
+    fn test_build_fts_query_phrases_and_prefixes() {
+        assert_eq!(
+            build_fts_query("\"launch plan\" deploy*").as_deref(),
+            Some("\"launch plan\" \"deploy\"*")
+        );
+        assert_eq!(
+            build_fts_query("\"road map\"*").as_deref(),
+            Some("\"road map\"*")
+        );
+    }
+
+    #[test]
+    fn test_build_fts_query_boolean_operators() {
+        assert_eq!(
+            build_fts_query("budget OR forecast NOT draft").as_deref(),
+            Some("\"budget\" OR \"forecast\" NOT \"draft\"")
+        );
+        // Leading, trailing and lower-case operators are not treated as syntax
+        assert_eq!(
+            build_fts_query("NOT budget AND").as_deref(),
+            Some("\"budget\"")
+        );
+        assert_eq!(
+            build_fts_query("this or that").as_deref(),
+            Some("\"this\" \"or\" \"that\"")
+        );
+    }
+
+    fn segment(text: &str, start: f64) -> TranscriptSegment {
+        TranscriptSegment {
+            id: String::new(),
+            text: text.to_string(),
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+            audio_start_time: Some(start),
+            audio_end_time: Some(start + 5.0),
+            duration: Some(5.0),
+            speaker: Some("mic".to_string()),
+            confidence: None,
+            low_confidence: false,
+            words: Vec::new(),
         }
     }
+
+    #[tokio::test]
+    async fn test_search_returns_every_hit_with_audio_time() {
+        let pool = workspace_pool().await;
+        TranscriptsRepository::save_transcript(
+            &pool,
+            "Planning",
+            &[
+                segment("We need to finalize the budget", 0.0),
+                segment("Nothing relevant here", 5.0),
+                segment("The budget review is on Friday", 10.0),
+            ],
+            None,
+        )
+        .await
+        .unwrap();
+
+        let results = TranscriptsRepository::search_transcripts(&pool, "budget")
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 2);
+        let mut starts: Vec<f64> = results.iter().filter_map(|r| r.audio_start_time).collect();
+        starts.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(starts, vec![0.0, 10.0]);
+
+        let phrase = TranscriptsRepository::search_transcripts(&pool, "\"budget review\"")
+            .await
+            .unwrap();
+        assert_eq!(phrase.len(), 1);
+        assert_eq!(phrase[0].audio_start_time, Some(10.0));
+
+        let prefix = TranscriptsRepository::search_transcripts(&pool, "fina*")
+            .await
+            .unwrap();
+        assert_eq!(prefix.len(), 1);
+        assert_eq!(prefix[0].match_context, "We need to <mark>finalize</mark> the budget");
+    }
+
+    #[tokio::test]
+    async fn test_search_index_survives_vacuum() {
+        let pool = workspace_pool().await;
+        let first = TranscriptsRepository::save_transcript(
+            &pool,
+            "Old",
+            &[segment("obsolete agenda", 0.0), segment("more obsolete notes", 5.0)],
+            None,
+        )
+        .await
+        .unwrap();
+        let meeting_id = TranscriptsRepository::save_transcript(
+            &pool,
+            "Launch",
+            &[segment("launch checklist", 0.0), segment("press release draft", 5.0)],
+            None,
+        )
+        .await
+        .unwrap();
+        sqlx::query("DELETE FROM meetings WHERE id = ?")
+            .bind(&first)
+            .execute(&pool)
+            .await
+            .unwrap();
+        // VACUUM may renumber the implicit rowids of the remaining segments
+        sqlx::query("VACUUM").execute(&pool).await.unwrap();
+
+        sqlx::query("UPDATE transcripts SET transcript = 'final press release' WHERE meeting_id = ? AND audio_start_time = 5.0")
+            .bind(&meeting_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let hits = TranscriptsRepository::search_transcripts(&pool, "release")
+            .await
+            .unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].match_context, "final press <mark>release</mark>");
+        assert_eq!(hits[0].audio_start_time, Some(5.0));
+        let checklist = TranscriptsRepository::search_transcripts(&pool, "checklist")
+            .await
+            .unwrap();
+        assert_eq!(checklist[0].match_context, "launch <mark>checklist</mark>");
+    }
+
+    #[test]
+    fn test_relative_ranks_are_comparable_across_indexes() {
+        let ranks = relative_ranks(vec![-4.0, -2.0, -1.0].into_iter());
+        assert_eq!(ranks, vec![1.0, 0.5, 0.25]);
+        assert!(relative_ranks(std::iter::empty()).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_save_transcript_persists_speaker() {
+        let pool = workspace_pool().await;
+        let mut others = segment("Can everyone hear me", 4.0);
+        others.speaker = Some("system".to_string());
+        let meeting_id = TranscriptsRepository::save_transcript(
+            &pool,
+            "Standup",
+            &[others, segment("Yes, loud and clear", 8.0)],
+            None,
+        )
+        .await
+        .unwrap();
+
+        let stored = TranscriptsRepository::get_transcripts_for_meeting(&pool, &meeting_id)
+            .await
+            .unwrap();
+        let speakers: Vec<Option<&str>> = stored.iter().map(|t| t.speaker.as_deref()).collect();
+        assert_eq!(speakers, vec![Some("system"), Some("mic")]);
+    }
+
+    #[tokio::test]
+    async fn test_word_timings_round_trip() {
+        let pool = workspace_pool().await;
+        let timings = vec![
+            WordTiming {
+                word: "Ship".to_string(),
+                start: 2.0,
+                end: 2.25,
+                probability: Some(0.5),
+            },
+            WordTiming {
+                word: "it".to_string(),
+                start: 2.25,
+                end: 2.5,
+                probability: None,
+            },
+        ];
+        let mut with_words = segment("Ship it", 2.0);
+        with_words.words = timings.clone();
+        let meeting_id = TranscriptsRepository::save_transcript(
+            &pool,
+            "Release",
+            &[with_words, segment("No timings", 4.0)],
+            None,
+        )
+        .await
+        .unwrap();
+        let stored = TranscriptsRepository::get_transcripts_for_meeting(&pool, &meeting_id)
+            .await
+            .unwrap();
+        let ids: Vec<String> = stored.iter().map(|t| t.id.clone()).collect();
+
+        let words = TranscriptsRepository::get_words(&pool, &ids).await.unwrap();
+        assert_eq!(words.len(), 1);
+        assert_eq!(words[&stored[0].id], timings);
+
+        // Editing a segment drops its now-stale timings
+        sqlx::query("UPDATE transcripts SET transcript = 'Ship it now' WHERE id = ?")
+            .bind(&stored[0].id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(TranscriptsRepository::get_words(&pool, &ids)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_low_confidence_segments_are_kept_and_updated() {
+        let pool = workspace_pool().await;
+        let mut flagged = segment("ship the road", 0.0);
+        flagged.confidence = Some(0.2);
+        flagged.low_confidence = true;
+        let mut unsure = segment("by friday", 5.0);
+        unsure.confidence = Some(0.5);
+        let mut confident = segment("Sounds good.", 10.0);
+        confident.confidence = Some(0.9);
+        let meeting_id = TranscriptsRepository::save_transcript(
+            &pool,
+            "Planning",
+            &[flagged, unsure, confident, segment("No score", 15.0)],
+            None,
+        )
+        .await
+        .unwrap();
+
+        let candidates = TranscriptsRepository::get_low_confidence_segments(&pool, &meeting_id, 0.3)
+            .await
+            .unwrap();
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates[0].low_confidence);
+        assert_eq!(candidates[0].confidence, Some(0.2));
+        let candidates = TranscriptsRepository::get_low_confidence_segments(&pool, &meeting_id, 0.6)
+            .await
+            .unwrap();
+        assert_eq!(candidates.len(), 2);
+
+        let mut better = segment("Ship the road map", 0.0);
+        better.id = candidates[0].id.clone();
+        better.confidence = Some(0.8);
+        better.words = vec![WordTiming {
+            word: "Ship".to_string(),
+            start: 0.0,
+            end: 0.4,
+            probability: Some(0.8),
+        }];
+        let archived = TranscriptsRepository::update_segments(
+            &pool,
+            &meeting_id,
+            &[better],
+            Some(&TranscriptVersionOrigin::retranscribed("localWhisper", "large-v3")),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(archived.segment_count, 4);
+
+        let stored = TranscriptsRepository::get_transcripts_for_meeting(&pool, &meeting_id)
+            .await
+            .unwrap();
+        assert_eq!(stored.len(), 4);
+        assert_eq!(stored[0].transcript, "Ship the road map");
+        assert_eq!(stored[0].confidence, Some(0.8));
+        assert!(!stored[0].low_confidence);
+        assert_eq!(stored[0].audio_start_time, Some(0.0));
+        let words = TranscriptsRepository::get_words(&pool, &[stored[0].id.clone()])
+            .await
+            .unwrap();
+        assert_eq!(words[&stored[0].id].len(), 1);
+        assert!(TranscriptsRepository::get_low_confidence_segments(&pool, &meeting_id, 0.3)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_search_index_follows_updates_and_summaries() {
+        let pool = workspace_pool().await;
+        let meeting_id = TranscriptsRepository::save_transcript(
+            &pool,
+            "Sync",
+            &[segment("initial wording", 0.0)],
+            None,
+        )
+        .await
+        .unwrap();
+
+        sqlx::query("UPDATE transcripts SET transcript = 'revised wording' WHERE meeting_id = ?")
+            .bind(&meeting_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(TranscriptsRepository::search_transcripts(&pool, "initial")
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            TranscriptsRepository::search_transcripts(&pool, "revised")
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+
+        sqlx::query(
+            "INSERT INTO summary_processes (meeting_id, status, created_at, updated_at, result)
+             VALUES (?, 'completed', '', '', ?)",
+        )
+        .bind(&meeting_id)
+        .bind(r#"{"markdown":"Decided to ship the roadmap"}"#)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let results = TranscriptsRepository::search_transcripts(&pool, "roadmap")
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].source, "summary");
+        assert_eq!(results[0].id, meeting_id);
+    }
+
+    #[tokio::test]
+    async fn test_keyword_ranking_matches_any_word() {
+        let pool = workspace_pool().await;
+        let meeting_id = TranscriptsRepository::save_transcript(
+            &pool,
+            "Pricing",
+            &[
+                segment("We should raise the pricing next quarter", 0.0),
+                segment("Lunch is at noon", 5.0),
+                segment("Pricing pricing pricing", 10.0),
+            ],
+            None,
+        )
+        .await
+        .unwrap();
+        let stored = TranscriptsRepository::get_transcripts_for_meeting(&pool, &meeting_id)
+            .await
+            .unwrap();
+
+        let ranked = TranscriptsRepository::rank_segments_by_keywords(
+            &pool,
+            "when did we discuss pricing?",
+            10,
+        )
+        .await
+        .unwrap();
+        assert_eq!(ranked.len(), 2);
+        assert!(!ranked.contains(&stored[1].id));
+        assert!(TranscriptsRepository::rank_segments_by_keywords(&pool, "?? AND", 10)
+            .await
+            .unwrap()
+            .is_empty());
+    }
 }
@@ -0,0 +1,206 @@
+use crate::api::TranscriptSegment;
+use crate::database::models::TranscriptVersion;
+use crate::database::repositories::transcript::TranscriptsRepository;
+use chrono::Utc;
+use sqlx::{Error as SqlxError, SqliteConnection, SqlitePool};
+use tracing::info;
+
+/// What replaced an archived transcript
+#[derive(Debug, Clone, Default)]
+pub struct TranscriptVersionOrigin {
+    /// "retranscribed" or "restored"
+    pub reason: &'static str,
+    pub provider: Option<String>,
+    pub model: Option<String>,
+}
+
+impl TranscriptVersionOrigin {
+    pub fn retranscribed(provider: &str, model: &str) -> Self {
+        Self {
+            reason: "retranscribed",
+            provider: Some(provider.to_string()),
+            model: Some(model.to_string()),
+        }
+    }
+
+    pub fn restored() -> Self {
+        Self {
+            reason: "restored",
+            ..Default::default()
+        }
+    }
+}
+
+const VERSION_COLUMNS: &str =
+    "id, meeting_id, version, reason, replaced_by_provider, replaced_by_model, segment_count, created_at";
+
+pub struct TranscriptVersionsRepository;
+
+impl TranscriptVersionsRepository {
+    /// All archived transcripts of a meeting, newest first
+    pub async fn list_versions(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<TranscriptVersion>, SqlxError> {
+        sqlx::query_as::<_, TranscriptVersion>(&format!(
+            "SELECT {VERSION_COLUMNS} FROM transcript_versions WHERE meeting_id = ? ORDER BY version DESC"
+        ))
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Make an archived transcript the active one again.
+    ///
+    /// The transcript being replaced is archived first, so restoring never loses text.
+    ///
+    /// # Returns
+    /// The number of restored segments, or None if `version` does not exist
+    pub async fn restore_version(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        version: i64,
+    ) -> Result<Option<usize>, SqlxError> {
+        let Some(segments_json) = sqlx::query_scalar::<_, String>(
+            "SELECT segments FROM transcript_versions WHERE meeting_id = ? AND version = ?",
+        )
+        .bind(meeting_id)
+        .bind(version)
+        .fetch_optional(pool)
+        .await?
+        else {
+            return Ok(None);
+        };
+
+        let segments: Vec<TranscriptSegment> = serde_json::from_str(&segments_json)
+            .map_err(|e| SqlxError::Decode(Box::new(e)))?;
+        TranscriptsRepository::replace_transcripts(
+            pool,
+            meeting_id,
+            &segments,
+            Some(&TranscriptVersionOrigin::restored()),
+        )
+        .await?;
+
+        info!(
+            "Restored transcript version {} of meeting {} ({} segments)",
+            version,
+            meeting_id,
+            segments.len()
+        );
+        Ok(Some(segments.len()))
+    }
+}
+
+/// Keep the current transcript of a meeting as a new version, inside the caller's transaction.
+///
+/// # Returns
+/// The new version, or None if the meeting has no transcript to archive
+pub(crate) async fn archive_transcript(
+    conn: &mut SqliteConnection,
+    meeting_id: &str,
+    origin: &TranscriptVersionOrigin,
+) -> Result<Option<TranscriptVersion>, SqlxError> {
+    let segments = TranscriptsRepository::load_segments(conn, meeting_id).await?;
+    if segments.is_empty() {
+        return Ok(None);
+    }
+    let segments_json =
+        serde_json::to_string(&segments).map_err(|e| SqlxError::Decode(Box::new(e)))?;
+
+    sqlx::query_as::<_, TranscriptVersion>(&format!(
+        "INSERT INTO transcript_versions (meeting_id, version, reason, replaced_by_provider, replaced_by_model, segment_count, segments, created_at)
+         SELECT ?1, COALESCE(MAX(version), 0) + 1, ?2, ?3, ?4, ?5, ?6, ?7
+         FROM transcript_versions WHERE meeting_id = ?1
+         RETURNING {VERSION_COLUMNS}"
+    ))
+    .bind(meeting_id)
+    .bind(origin.reason)
+    .bind(&origin.provider)
+    .bind(&origin.model)
+    .bind(segments.len() as i64)
+    .bind(segments_json)
+    .bind(Utc::now())
+    .fetch_one(conn)
+    .await
+    .map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::test_support::workspace_pool;
+
+    fn segment(text: &str, start: f64) -> TranscriptSegment {
+        TranscriptSegment {
+            id: String::new(),
+            text: text.to_string(),
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+            audio_start_time: Some(start),
+            audio_end_time: Some(start + 2.0),
+            duration: Some(2.0),
+            speaker: Some("mic".to_string()),
+            confidence: None,
+            low_confidence: false,
+            words: Vec::new(),
+        }
+    }
+
+    async fn texts(pool: &SqlitePool, meeting_id: &str) -> Vec<String> {
+        TranscriptsRepository::get_transcripts_for_meeting(pool, meeting_id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|t| t.transcript)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_replace_archives_and_restores() {
+        let pool = workspace_pool().await;
+
+        let meeting_id = TranscriptsRepository::save_transcript(
+            &pool,
+            "Review",
+            &[segment("shipping the roadmap", 0.0), segment("next week", 2.0)],
+            None,
+        )
+        .await
+        .unwrap();
+
+        let archived = TranscriptsRepository::replace_transcripts(
+            &pool,
+            &meeting_id,
+            &[segment("Shipping the road map next week.", 0.0)],
+            Some(&TranscriptVersionOrigin::retranscribed("localWhisper", "large-v3")),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(archived.version, 1);
+        assert_eq!(archived.segment_count, 2);
+        assert_eq!(archived.replaced_by_model.as_deref(), Some("large-v3"));
+        assert_eq!(texts(&pool, &meeting_id).await, vec!["Shipping the road map next week."]);
+
+        let restored = TranscriptVersionsRepository::restore_version(&pool, &meeting_id, 1)
+            .await
+            .unwrap();
+        assert_eq!(restored, Some(2));
+        assert_eq!(
+            texts(&pool, &meeting_id).await,
+            vec!["shipping the roadmap", "next week"]
+        );
+
+        // The re-transcribed text was archived by the restore
+        let versions = TranscriptVersionsRepository::list_versions(&pool, &meeting_id)
+            .await
+            .unwrap();
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions[0].reason, "restored");
+        assert_eq!(versions[0].segment_count, 1);
+        assert!(TranscriptVersionsRepository::restore_version(&pool, &meeting_id, 9)
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,76 @@
+use chrono::Utc;
+use sqlx::{Error as SqlxError, SqlitePool};
+use tracing::info;
+
+pub struct VocabularyRepository;
+
+impl VocabularyRepository {
+    /// Get the vocabulary terms of the workspace, alphabetically
+    pub async fn get_terms(pool: &SqlitePool) -> Result<Vec<String>, SqlxError> {
+        sqlx::query_scalar("SELECT term FROM vocabulary_terms ORDER BY term COLLATE NOCASE, id")
+            .fetch_all(pool)
+            .await
+    }
+
+    /// Replace the vocabulary with `terms`; terms are unique case-insensitively
+    pub async fn replace_terms(pool: &SqlitePool, terms: &[String]) -> Result<(), SqlxError> {
+        let mut transaction = pool.begin().await?;
+
+        sqlx::query("DELETE FROM vocabulary_terms")
+            .execute(&mut *transaction)
+            .await?;
+
+        let now = Utc::now().to_rfc3339();
+        for term in terms {
+            sqlx::query("INSERT OR IGNORE INTO vocabulary_terms (term, created_at) VALUES (?, ?)")
+                .bind(term)
+                .bind(&now)
+                .execute(&mut *transaction)
+                .await?;
+        }
+
+        transaction.commit().await?;
+        info!("Saved {} vocabulary terms", terms.len());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::test_support::workspace_pool;
+
+    fn terms(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_replace_terms() {
+        let pool = workspace_pool().await;
+
+        VocabularyRepository::replace_terms(&pool, &terms(&["Kubernetes", "acme", "Zoe", "ACME"]))
+            .await
+            .unwrap();
+        assert_eq!(
+            VocabularyRepository::get_terms(&pool).await.unwrap(),
+            terms(&["acme", "Kubernetes", "Zoe"])
+        );
+
+        // Saving replaces the whole list, including the spelling of existing terms
+        VocabularyRepository::replace_terms(&pool, &terms(&["ACME", "Meetily"]))
+            .await
+            .unwrap();
+        assert_eq!(
+            VocabularyRepository::get_terms(&pool).await.unwrap(),
+            terms(&["ACME", "Meetily"])
+        );
+
+        VocabularyRepository::replace_terms(&pool, &[])
+            .await
+            .unwrap();
+        assert!(VocabularyRepository::get_terms(&pool)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
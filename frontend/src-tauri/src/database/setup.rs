@@ -66,5 +66,17 @@ pub async fn initialize_workspace_manager(app: &AppHandle) -> Result<WorkspaceMa
         log::info!("Fresh install: created Default workspace");
     }
 
+    // Step 4: Move plaintext API keys out of global.sqlite. The OS keyring is used when it
+    // is available; otherwise the frontend asks for the vault passphrase on startup
+    // (creating the vault on first run) and the keys are moved by `secrets_unlock_vault`.
+    let secret_store = crate::secrets::init_secret_store(&app_data_dir);
+    if secret_store.is_locked() {
+        log::info!("Secret vault is locked; API key migration deferred until unlock");
+    } else if let Err(e) =
+        crate::secrets::migration::migrate_plaintext_secrets(workspace_mgr.global_pool(), secret_store).await
+    {
+        log::error!("Failed to migrate plaintext API keys: {}", e);
+    }
+
     Ok(workspace_mgr)
 }
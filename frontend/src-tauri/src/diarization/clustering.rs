@@ -0,0 +1,134 @@
+/// Cosine similarity between two embeddings
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a <= f32::EPSILON || norm_b <= f32::EPSILON {
+        return 0.0;
+    }
+    dot / (norm_a * norm_b)
+}
+
+/// Average-linkage agglomerative clustering of speaker embeddings.
+///
+/// Clusters are merged while the most similar pair is above `threshold`. If
+/// `max_speakers` is set, merging continues past the threshold until at most
+/// that many clusters remain.
+///
+/// Returns one cluster index per embedding, numbered in order of first appearance.
+pub fn cluster_embeddings(
+    embeddings: &[Vec<f32>],
+    threshold: f32,
+    max_speakers: Option<usize>,
+) -> Vec<usize> {
+    let n = embeddings.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    // Pairwise similarity matrix, updated in place with the Lance-Williams formula
+    let mut similarity = vec![vec![0.0f32; n]; n];
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let s = cosine_similarity(&embeddings[i], &embeddings[j]);
+            similarity[i][j] = s;
+            similarity[j][i] = s;
+        }
+    }
+
+    let mut active = vec![true; n];
+    let mut sizes = vec![1usize; n];
+    let mut assignment: Vec<usize> = (0..n).collect();
+    let mut cluster_count = n;
+    let min_clusters = max_speakers.unwrap_or(1).max(1);
+
+    while cluster_count > 1 {
+        let mut best: Option<(usize, usize, f32)> = None;
+        for i in 0..n {
+            if !active[i] {
+                continue;
+            }
+            for j in (i + 1)..n {
+                if active[j] && best.map_or(true, |(_, _, s)| similarity[i][j] > s) {
+                    best = Some((i, j, similarity[i][j]));
+                }
+            }
+        }
+
+        let Some((a, b, score)) = best else { break };
+        let must_merge = max_speakers.map_or(false, |_| cluster_count > min_clusters);
+        if score < threshold && !must_merge {
+            break;
+        }
+
+        // Merge b into a
+        let (size_a, size_b) = (sizes[a] as f32, sizes[b] as f32);
+        for k in 0..n {
+            if active[k] && k != a && k != b {
+                let merged = (size_a * similarity[a][k] + size_b * similarity[b][k]) / (size_a + size_b);
+                similarity[a][k] = merged;
+                similarity[k][a] = merged;
+            }
+        }
+        sizes[a] += sizes[b];
+        active[b] = false;
+        for cluster in assignment.iter_mut() {
+            if *cluster == b {
+                *cluster = a;
+            }
+        }
+        cluster_count -= 1;
+    }
+
+    // Renumber clusters in order of first appearance
+    let mut mapping: Vec<Option<usize>> = vec![None; n];
+    let mut next = 0;
+    assignment
+        .into_iter()
+        .map(|cluster| {
+            *mapping[cluster].get_or_insert_with(|| {
+                next += 1;
+                next - 1
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cosine_similarity() {
+        assert!((cosine_similarity(&[1.0, 0.0], &[1.0, 0.0]) - 1.0).abs() < 1e-6);
+        assert!(cosine_similarity(&[1.0, 0.0], &[0.0, 1.0]).abs() < 1e-6);
+        assert_eq!(cosine_similarity(&[0.0, 0.0], &[1.0, 0.0]), 0.0);
+    }
+
+    #[test]
+    fn test_cluster_separates_distinct_speakers() {
+        let embeddings = vec![
+            vec![1.0, 0.05, 0.0],
+            vec![0.0, 1.0, 0.1],
+            vec![0.98, 0.1, 0.0],
+            vec![0.05, 0.97, 0.0],
+            vec![0.0, 0.0, 1.0],
+        ];
+        let labels = cluster_embeddings(&embeddings, 0.7, None);
+        assert_eq!(labels, vec![0, 1, 0, 1, 2]);
+    }
+
+    #[test]
+    fn test_cluster_respects_max_speakers() {
+        let embeddings = vec![vec![1.0, 0.0, 0.0], vec![0.0, 1.0, 0.0], vec![0.0, 0.0, 1.0]];
+        let labels = cluster_embeddings(&embeddings, 0.9, Some(2));
+        let distinct: std::collections::HashSet<_> = labels.iter().collect();
+        assert_eq!(distinct.len(), 2);
+        assert_eq!(labels[0], 0);
+    }
+
+    #[test]
+    fn test_cluster_empty_input() {
+        assert!(cluster_embeddings(&[], 0.5, None).is_empty());
+    }
+}
@@ -0,0 +1,316 @@
+use crate::audio::decoder::decode_to_16k_mono;
+use crate::database::models::MeetingSpeaker;
+use crate::database::repositories::{meeting::MeetingsRepository, speaker::SpeakersRepository};
+use crate::diarization::diarizer::{
+    assign_labels_by_overlap, diarize_segments, read_speaker_turns, write_speaker_turns,
+    DiarizationOptions, SpeakerTurn, TimedSegment,
+};
+use crate::diarization::SpeakerEmbedder;
+use crate::workspace::manager::WorkspaceManager;
+use sqlx::SqlitePool;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+
+/// WeSpeaker ResNet34 speaker-embedding model (ONNX, ~26 MB)
+pub const DIARIZATION_MODEL_FILE: &str = "voxceleb_resnet34_LM.onnx";
+const DIARIZATION_MODEL_URL: &str =
+    "https://huggingface.co/Wespeaker/wespeaker-voxceleb-resnet34-LM/resolve/main/voxceleb_resnet34_LM.onnx";
+
+// Global models directory path (set during app initialization)
+static MODELS_DIR: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/// Initialize the diarization models directory using app_data_dir
+/// This should be called during app setup
+pub fn set_models_directory<R: Runtime>(app: &AppHandle<R>) {
+    let app_data_dir = app.path().app_data_dir()
+        .expect("Failed to get app data dir");
+
+    let models_dir = app_data_dir.join("models").join("diarization");
+
+    if !models_dir.exists() {
+        if let Err(e) = std::fs::create_dir_all(&models_dir) {
+            log::error!("Failed to create diarization models directory: {}", e);
+            return;
+        }
+    }
+
+    log::info!("Diarization models directory set to: {}", models_dir.display());
+
+    let mut guard = MODELS_DIR.lock().unwrap();
+    *guard = Some(models_dir);
+}
+
+fn get_model_path() -> Option<PathBuf> {
+    MODELS_DIR
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|dir| dir.join(DIARIZATION_MODEL_FILE))
+}
+
+/// Run diarization over decoded meeting audio on a blocking thread
+async fn run_diarization(
+    audio_path: PathBuf,
+    segments: Vec<TimedSegment>,
+    options: DiarizationOptions,
+) -> Result<Vec<SpeakerTurn>, String> {
+    let model_path = get_model_path()
+        .filter(|path| path.exists())
+        .ok_or_else(|| "Speaker diarization model is not downloaded".to_string())?;
+
+    tokio::task::spawn_blocking(move || {
+        let audio = decode_to_16k_mono(&audio_path).map_err(|e| e.to_string())?;
+        let mut embedder = SpeakerEmbedder::new(&model_path).map_err(|e| e.to_string())?;
+        diarize_segments(&mut embedder, &audio, &segments, options).map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(|e| format!("Diarization task failed: {}", e))?
+}
+
+/// Apply speaker turns to the transcript rows of a meeting
+async fn apply_speaker_turns(
+    pool: &SqlitePool,
+    meeting_id: &str,
+    turns: &[SpeakerTurn],
+) -> Result<usize, String> {
+    let rows: Vec<(String, Option<f64>, Option<f64>)> = sqlx::query_as(
+        "SELECT id, audio_start_time, audio_end_time FROM transcripts WHERE meeting_id = ?",
+    )
+    .bind(meeting_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let labels = assign_labels_by_overlap(turns, &rows);
+    SpeakersRepository::replace_speaker_labels(pool, meeting_id, &labels)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Apply `speakers.json` from a meeting folder, if diarization already produced it
+pub async fn apply_saved_speaker_turns(pool: &SqlitePool, meeting_id: &str, folder: &Path) {
+    let Some(turns) = read_speaker_turns(folder) else {
+        return;
+    };
+    if let Err(e) = apply_speaker_turns(pool, meeting_id, &turns).await {
+        log::warn!("Failed to apply saved speaker turns to meeting {}: {}", meeting_id, e);
+    }
+}
+
+/// Diarize a just-finished recording in the background.
+///
+/// Turns are written to `speakers.json` in the meeting folder. If the meeting has
+/// already been saved to the database they are applied immediately, otherwise
+/// `api_save_transcript` picks them up.
+pub fn spawn_post_recording_diarization<R: Runtime>(
+    app: AppHandle<R>,
+    audio_path: PathBuf,
+    meeting_folder: PathBuf,
+    segments: Vec<TimedSegment>,
+) {
+    if segments.is_empty() || !get_model_path().map_or(false, |path| path.exists()) {
+        log::debug!("Skipping post-recording diarization (no segments or model not downloaded)");
+        return;
+    }
+
+    tauri::async_runtime::spawn(async move {
+        let turns = match run_diarization(audio_path, segments, DiarizationOptions::default()).await {
+            Ok(turns) => turns,
+            Err(e) => {
+                log::warn!("Post-recording diarization failed: {}", e);
+                return;
+            }
+        };
+
+        if let Err(e) = write_speaker_turns(&meeting_folder, &turns) {
+            log::error!("Failed to write speaker turns: {}", e);
+            return;
+        }
+
+        let Some(workspace_mgr) = app.try_state::<WorkspaceManager>() else {
+            return;
+        };
+        let Ok(pool) = workspace_mgr.active_pool().await else {
+            return;
+        };
+        let folder = meeting_folder.to_string_lossy().to_string();
+        if let Ok(Some(meeting_id)) = MeetingsRepository::find_meeting_id_by_folder_path(&pool, &folder).await {
+            match apply_speaker_turns(&pool, &meeting_id, &turns).await {
+                Ok(_) => {
+                    let _ = app.emit(
+                        "diarization-complete",
+                        serde_json::json!({ "meetingId": meeting_id }),
+                    );
+                }
+                Err(e) => log::warn!("Failed to apply speaker turns to meeting {}: {}", meeting_id, e),
+            }
+        }
+    });
+}
+
+#[command]
+pub async fn diarization_get_model_status() -> Result<serde_json::Value, String> {
+    let path = get_model_path().ok_or_else(|| "Diarization models directory not initialized".to_string())?;
+    Ok(serde_json::json!({
+        "modelName": DIARIZATION_MODEL_FILE,
+        "path": path.to_string_lossy(),
+        "downloaded": path.exists(),
+    }))
+}
+
+#[command]
+pub async fn diarization_download_model<R: Runtime>(app_handle: AppHandle<R>) -> Result<(), String> {
+    use futures_util::StreamExt;
+    use tokio::io::AsyncWriteExt;
+
+    let model_path = get_model_path().ok_or_else(|| "Diarization models directory not initialized".to_string())?;
+    if model_path.exists() {
+        return Ok(());
+    }
+
+    log::info!("Downloading speaker diarization model from {}", DIARIZATION_MODEL_URL);
+    let response = reqwest::get(DIARIZATION_MODEL_URL)
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| format!("Failed to download diarization model: {}", e))?;
+    let total_bytes = response.content_length().unwrap_or(0);
+
+    let temp_path = model_path.with_extension("onnx.tmp");
+    let mut file = tokio::fs::File::create(&temp_path)
+        .await
+        .map_err(|e| format!("Failed to create model file: {}", e))?;
+
+    let mut stream = response.bytes_stream();
+    let mut downloaded: u64 = 0;
+    let mut last_percent = 0;
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| format!("Download interrupted: {}", e))?;
+        file.write_all(&chunk)
+            .await
+            .map_err(|e| format!("Failed to write model file: {}", e))?;
+        downloaded += chunk.len() as u64;
+
+        let percent = if total_bytes > 0 { (downloaded * 100 / total_bytes) as u8 } else { 0 };
+        if percent != last_percent {
+            last_percent = percent;
+            let _ = app_handle.emit(
+                "diarization-model-download-progress",
+                serde_json::json!({
+                    "progress": percent,
+                    "downloaded_bytes": downloaded,
+                    "total_bytes": total_bytes,
+                    "status": "downloading"
+                }),
+            );
+        }
+    }
+    file.flush().await.map_err(|e| e.to_string())?;
+    drop(file);
+
+    tokio::fs::rename(&temp_path, &model_path)
+        .await
+        .map_err(|e| format!("Failed to finalize model file: {}", e))?;
+
+    let _ = app_handle.emit(
+        "diarization-model-download-progress",
+        serde_json::json!({
+            "progress": 100,
+            "downloaded_bytes": downloaded,
+            "total_bytes": total_bytes,
+            "status": "completed"
+        }),
+    );
+    log::info!("Speaker diarization model downloaded to {}", model_path.display());
+    Ok(())
+}
+
+/// Diarize a saved meeting from its recorded audio, replacing any previous speaker labels
+#[command]
+pub async fn api_diarize_meeting<R: Runtime>(
+    app: AppHandle<R>,
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    meeting_id: String,
+    max_speakers: Option<usize>,
+) -> Result<Vec<MeetingSpeaker>, String> {
+    log::info!("api_diarize_meeting called for meeting_id: {}", meeting_id);
+    let pool = workspace_mgr.active_pool().await?;
+
+    let meeting = MeetingsRepository::get_meeting_metadata(&pool, &meeting_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Meeting not found: {}", meeting_id))?;
+    let folder = meeting
+        .folder_path
+        .map(PathBuf::from)
+        .ok_or_else(|| "Meeting has no recording folder".to_string())?;
+    let audio_path = folder.join("audio.mp4");
+    if !audio_path.exists() {
+        return Err(format!("Recording not found: {}", audio_path.display()));
+    }
+
+    let segments: Vec<TimedSegment> = sqlx::query_as::<_, (f64, f64)>(
+        "SELECT audio_start_time, audio_end_time FROM transcripts
+         WHERE meeting_id = ? AND audio_start_time IS NOT NULL AND audio_end_time IS NOT NULL
+         ORDER BY audio_start_time",
+    )
+    .bind(&meeting_id)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| e.to_string())?
+    .into_iter()
+    .map(|(start, end)| TimedSegment { start, end })
+    .collect();
+    if segments.is_empty() {
+        return Err("Meeting has no timed transcript segments to diarize".to_string());
+    }
+
+    let options = DiarizationOptions {
+        max_speakers,
+        ..DiarizationOptions::default()
+    };
+    let turns = run_diarization(audio_path, segments, options).await?;
+
+    if let Err(e) = write_speaker_turns(&folder, &turns) {
+        log::warn!("Failed to write speaker turns for meeting {}: {}", meeting_id, e);
+    }
+    apply_speaker_turns(&pool, &meeting_id, &turns).await?;
+
+    let _ = app.emit(
+        "diarization-complete",
+        serde_json::json!({ "meetingId": meeting_id }),
+    );
+
+    SpeakersRepository::get_meeting_speakers(&pool, &meeting_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[command]
+pub async fn api_get_meeting_speakers(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    meeting_id: String,
+) -> Result<Vec<MeetingSpeaker>, String> {
+    let pool = workspace_mgr.active_pool().await?;
+    SpeakersRepository::get_meeting_speakers(&pool, &meeting_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Rename a diarized speaker; the name is used in transcripts and future summaries
+#[command]
+pub async fn api_rename_meeting_speaker(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    meeting_id: String,
+    label: String,
+    display_name: Option<String>,
+) -> Result<(), String> {
+    let pool = workspace_mgr.active_pool().await?;
+    let updated = SpeakersRepository::rename_speaker(&pool, &meeting_id, &label, display_name.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
+    if !updated {
+        return Err(format!("Speaker '{}' not found in meeting {}", label, meeting_id));
+    }
+    Ok(())
+}
@@ -0,0 +1,254 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use super::clustering::cluster_embeddings;
+use super::embedding::SpeakerEmbedder;
+use super::features::SAMPLE_RATE;
+use super::DiarizationError;
+
+/// Speaker turns are persisted next to audio.mp4 so they survive until the meeting is saved
+pub const SPEAKER_TURNS_FILE: &str = "speakers.json";
+
+/// Longest audio window embedded in one pass; longer segments are averaged over windows
+const MAX_WINDOW_SECONDS: f64 = 8.0;
+
+/// Recording-relative time span of a transcript segment
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimedSegment {
+    pub start: f64,
+    pub end: f64,
+}
+
+/// A span of audio attributed to one speaker
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpeakerTurn {
+    pub start: f64,
+    pub end: f64,
+    pub label: String,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DiarizationOptions {
+    /// Minimum cosine similarity for two clusters to be the same speaker
+    pub similarity_threshold: f32,
+    /// Upper bound on the number of speakers, if known
+    pub max_speakers: Option<usize>,
+}
+
+impl Default for DiarizationOptions {
+    fn default() -> Self {
+        Self {
+            similarity_threshold: 0.5,
+            max_speakers: None,
+        }
+    }
+}
+
+/// Label for the n-th (zero-based) speaker cluster
+pub fn speaker_label(index: usize) -> String {
+    format!("Speaker {}", index + 1)
+}
+
+/// Assign a speaker to every segment of a meeting.
+///
+/// Each segment is embedded from the 16kHz meeting audio, embeddings are clustered
+/// per meeting, and segments too short to embed inherit the label of the nearest
+/// embedded segment in time.
+pub fn diarize_segments(
+    embedder: &mut SpeakerEmbedder,
+    audio_16k: &[f32],
+    segments: &[TimedSegment],
+    options: DiarizationOptions,
+) -> Result<Vec<SpeakerTurn>, DiarizationError> {
+    let mut embedded_indices = Vec::new();
+    let mut embeddings = Vec::new();
+
+    for (index, segment) in segments.iter().enumerate() {
+        if let Some(embedding) = embed_segment(embedder, audio_16k, segment)? {
+            embedded_indices.push(index);
+            embeddings.push(embedding);
+        }
+    }
+
+    if embeddings.is_empty() {
+        return Err(DiarizationError::NoSpeech);
+    }
+
+    let clusters = cluster_embeddings(&embeddings, options.similarity_threshold, options.max_speakers);
+    log::info!(
+        "Diarization clustered {} of {} segments into {} speakers",
+        embeddings.len(),
+        segments.len(),
+        clusters.iter().max().map_or(0, |m| m + 1)
+    );
+
+    let turns = segments
+        .iter()
+        .enumerate()
+        .map(|(index, segment)| {
+            let nearest = embedded_indices
+                .iter()
+                .enumerate()
+                .min_by(|(_, &a), (_, &b)| {
+                    let da = (segments[a].start - segment.start).abs();
+                    let db = (segments[b].start - segment.start).abs();
+                    da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .map(|(position, _)| position)
+                .unwrap_or(0);
+            let cluster = match embedded_indices.binary_search(&index) {
+                Ok(position) => clusters[position],
+                Err(_) => clusters[nearest],
+            };
+            SpeakerTurn {
+                start: segment.start,
+                end: segment.end,
+                label: speaker_label(cluster),
+            }
+        })
+        .collect();
+
+    Ok(turns)
+}
+
+fn embed_segment(
+    embedder: &mut SpeakerEmbedder,
+    audio_16k: &[f32],
+    segment: &TimedSegment,
+) -> Result<Option<Vec<f32>>, DiarizationError> {
+    let start = ((segment.start.max(0.0)) * SAMPLE_RATE as f64) as usize;
+    let end = ((segment.end.max(0.0)) * SAMPLE_RATE as f64) as usize;
+    let end = end.min(audio_16k.len());
+    if start >= end {
+        return Ok(None);
+    }
+
+    let window = (MAX_WINDOW_SECONDS * SAMPLE_RATE as f64) as usize;
+    let mut sum: Option<Vec<f32>> = None;
+    for chunk in audio_16k[start..end].chunks(window) {
+        if let Some(embedding) = embedder.embed(chunk)? {
+            match sum.as_mut() {
+                Some(acc) => acc.iter_mut().zip(embedding.iter()).for_each(|(a, e)| *a += e),
+                None => sum = Some(embedding),
+            }
+        }
+    }
+
+    Ok(sum.map(|mut v| {
+        let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt().max(f32::EPSILON);
+        v.iter_mut().for_each(|x| *x /= norm);
+        v
+    }))
+}
+
+/// Pick the speaker label for each transcript row from the turn that overlaps it most.
+///
+/// `transcripts` are `(id, audio_start_time, audio_end_time)`. Rows without timing
+/// or without any turn within two seconds are left unlabelled.
+pub fn assign_labels_by_overlap(
+    turns: &[SpeakerTurn],
+    transcripts: &[(String, Option<f64>, Option<f64>)],
+) -> Vec<(String, String)> {
+    const MAX_GAP_SECONDS: f64 = 2.0;
+
+    transcripts
+        .iter()
+        .filter_map(|(id, start, end)| {
+            let start = (*start)?;
+            let end = end.unwrap_or(start).max(start);
+
+            let best_overlap = turns
+                .iter()
+                .map(|turn| (turn, end.min(turn.end) - start.max(turn.start)))
+                .filter(|(_, overlap)| *overlap > 0.0)
+                .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+                .map(|(turn, _)| turn);
+
+            let turn = best_overlap.or_else(|| {
+                turns
+                    .iter()
+                    .map(|turn| {
+                        let gap = if turn.end < start { start - turn.end } else { turn.start - end };
+                        (turn, gap.max(0.0))
+                    })
+                    .filter(|(_, gap)| *gap <= MAX_GAP_SECONDS)
+                    .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+                    .map(|(turn, _)| turn)
+            })?;
+
+            Some((id.clone(), turn.label.clone()))
+        })
+        .collect()
+}
+
+#[derive(Serialize, Deserialize)]
+struct SpeakerTurnsFile {
+    version: String,
+    turns: Vec<SpeakerTurn>,
+}
+
+/// Write speaker turns to `speakers.json` in the meeting folder (atomic write)
+pub fn write_speaker_turns(folder: &Path, turns: &[SpeakerTurn]) -> Result<(), DiarizationError> {
+    let file = SpeakerTurnsFile {
+        version: "1.0".to_string(),
+        turns: turns.to_vec(),
+    };
+    let json = serde_json::to_string_pretty(&file)
+        .map_err(|e| DiarizationError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, e)))?;
+    let temp_path = folder.join(format!(".{}.tmp", SPEAKER_TURNS_FILE));
+    std::fs::write(&temp_path, json)?;
+    std::fs::rename(&temp_path, folder.join(SPEAKER_TURNS_FILE))?;
+    Ok(())
+}
+
+/// Read speaker turns from a meeting folder, if diarization has produced them
+pub fn read_speaker_turns(folder: &Path) -> Option<Vec<SpeakerTurn>> {
+    let content = std::fs::read_to_string(folder.join(SPEAKER_TURNS_FILE)).ok()?;
+    serde_json::from_str::<SpeakerTurnsFile>(&content)
+        .ok()
+        .map(|file| file.turns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn turn(start: f64, end: f64, label: &str) -> SpeakerTurn {
+        SpeakerTurn {
+            start,
+            end,
+            label: label.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_assign_labels_prefers_largest_overlap() {
+        let turns = vec![turn(0.0, 4.0, "Speaker 1"), turn(4.0, 10.0, "Speaker 2")];
+        let transcripts = vec![
+            ("a".to_string(), Some(0.5), Some(3.0)),
+            ("b".to_string(), Some(3.0), Some(9.0)),
+            ("c".to_string(), Some(11.0), Some(12.0)),
+            ("d".to_string(), Some(30.0), Some(31.0)),
+            ("e".to_string(), None, None),
+        ];
+        let assigned = assign_labels_by_overlap(&turns, &transcripts);
+        assert_eq!(
+            assigned,
+            vec![
+                ("a".to_string(), "Speaker 1".to_string()),
+                ("b".to_string(), "Speaker 2".to_string()),
+                ("c".to_string(), "Speaker 2".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_speaker_turns_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(read_speaker_turns(dir.path()).is_none());
+
+        let turns = vec![turn(0.0, 1.5, "Speaker 1"), turn(1.5, 3.0, "Speaker 2")];
+        write_speaker_turns(dir.path(), &turns).unwrap();
+        assert_eq!(read_speaker_turns(dir.path()), Some(turns));
+    }
+}
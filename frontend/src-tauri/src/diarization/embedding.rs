@@ -0,0 +1,83 @@
+use ndarray::Axis;
+use ort::execution_providers::CPUExecutionProvider;
+use ort::inputs;
+use ort::session::builder::GraphOptimizationLevel;
+use ort::session::Session;
+use ort::value::TensorRef;
+use std::path::Path;
+
+use super::features::{FbankExtractor, SAMPLE_RATE};
+use super::DiarizationError;
+
+/// Shortest audio that yields a usable embedding (0.5s)
+pub const MIN_EMBEDDING_SAMPLES: usize = SAMPLE_RATE / 2;
+
+/// ONNX speaker-embedding model (WeSpeaker ResNet34, fbank input, 256-dim output)
+pub struct SpeakerEmbedder {
+    session: Session,
+    fbank: FbankExtractor,
+    input_name: String,
+    output_name: String,
+}
+
+impl SpeakerEmbedder {
+    pub fn new(model_path: &Path) -> Result<Self, DiarizationError> {
+        if !model_path.exists() {
+            return Err(DiarizationError::ModelNotFound(
+                model_path.display().to_string(),
+            ));
+        }
+
+        log::info!("Loading speaker embedding model from {}", model_path.display());
+        let session = Session::builder()?
+            .with_optimization_level(GraphOptimizationLevel::Level3)?
+            .with_execution_providers(vec![CPUExecutionProvider::default().build()])?
+            .commit_from_file(model_path)?;
+
+        let input_name = session
+            .inputs
+            .first()
+            .map(|i| i.name.clone())
+            .ok_or_else(|| DiarizationError::InvalidModel("model has no inputs".to_string()))?;
+        let output_name = session
+            .outputs
+            .first()
+            .map(|o| o.name.clone())
+            .ok_or_else(|| DiarizationError::InvalidModel("model has no outputs".to_string()))?;
+
+        Ok(Self {
+            session,
+            fbank: FbankExtractor::new(),
+            input_name,
+            output_name,
+        })
+    }
+
+    /// Compute an L2-normalized embedding for 16kHz mono samples.
+    ///
+    /// Returns `None` when the audio is too short to characterize a speaker.
+    pub fn embed(&mut self, samples: &[f32]) -> Result<Option<Vec<f32>>, DiarizationError> {
+        if samples.len() < MIN_EMBEDDING_SAMPLES {
+            return Ok(None);
+        }
+
+        let features = self.fbank.compute(samples).insert_axis(Axis(0));
+        let outputs = self.session.run(inputs![
+            self.input_name.as_str() => TensorRef::from_array_view(features.view())?,
+        ])?;
+
+        let embedding = outputs
+            .get(self.output_name.as_str())
+            .ok_or_else(|| DiarizationError::InvalidModel(format!("missing output {}", self.output_name)))?
+            .try_extract_array::<f32>()?;
+
+        let mut vector: Vec<f32> = embedding.iter().copied().collect();
+        let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+        if norm <= f32::EPSILON {
+            return Ok(None);
+        }
+        vector.iter_mut().for_each(|v| *v /= norm);
+
+        Ok(Some(vector))
+    }
+}
@@ -0,0 +1,175 @@
+use ndarray::Array2;
+use realfft::{RealFftPlanner, RealToComplex};
+use std::sync::Arc;
+
+/// Input sample rate expected by the feature extractor
+pub const SAMPLE_RATE: usize = 16000;
+/// Number of mel filterbank bins (WeSpeaker / Kaldi default)
+pub const NUM_MEL_BINS: usize = 80;
+
+const FRAME_LENGTH: usize = 400; // 25ms
+const FRAME_SHIFT: usize = 160; // 10ms
+const FFT_SIZE: usize = 512;
+const PREEMPHASIS: f32 = 0.97;
+const LOW_FREQ: f32 = 20.0;
+
+/// Kaldi-compatible log mel filterbank extractor
+///
+/// Matches `torchaudio.compliance.kaldi.fbank` defaults used to train the
+/// speaker-embedding model: povey window, pre-emphasis, no dither, snip edges.
+pub struct FbankExtractor {
+    fft: Arc<dyn RealToComplex<f32>>,
+    window: Vec<f32>,
+    mel_banks: Vec<Vec<f32>>,
+}
+
+impl FbankExtractor {
+    pub fn new() -> Self {
+        let mut planner = RealFftPlanner::<f32>::new();
+        let fft = planner.plan_fft_forward(FFT_SIZE);
+
+        let window = (0..FRAME_LENGTH)
+            .map(|i| {
+                let hann = 0.5
+                    - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / (FRAME_LENGTH - 1) as f32).cos();
+                hann.powf(0.85)
+            })
+            .collect();
+
+        Self {
+            fft,
+            window,
+            mel_banks: Self::build_mel_banks(),
+        }
+    }
+
+    fn mel_scale(freq: f32) -> f32 {
+        1127.0 * (1.0 + freq / 700.0).ln()
+    }
+
+    fn build_mel_banks() -> Vec<Vec<f32>> {
+        let num_fft_bins = FFT_SIZE / 2;
+        let fft_bin_width = SAMPLE_RATE as f32 / FFT_SIZE as f32;
+        let mel_low = Self::mel_scale(LOW_FREQ);
+        let mel_high = Self::mel_scale(SAMPLE_RATE as f32 / 2.0);
+        let mel_delta = (mel_high - mel_low) / (NUM_MEL_BINS + 1) as f32;
+
+        (0..NUM_MEL_BINS)
+            .map(|bin| {
+                let left = mel_low + bin as f32 * mel_delta;
+                let center = left + mel_delta;
+                let right = center + mel_delta;
+                (0..num_fft_bins)
+                    .map(|k| {
+                        let mel = Self::mel_scale(fft_bin_width * k as f32);
+                        if mel > left && mel < right {
+                            if mel <= center {
+                                (mel - left) / (center - left)
+                            } else {
+                                (right - mel) / (right - center)
+                            }
+                        } else {
+                            0.0
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Number of frames produced for `num_samples` input samples
+    pub fn num_frames(num_samples: usize) -> usize {
+        if num_samples < FRAME_LENGTH {
+            0
+        } else {
+            1 + (num_samples - FRAME_LENGTH) / FRAME_SHIFT
+        }
+    }
+
+    /// Compute mean-normalized log mel features, shape `[frames, NUM_MEL_BINS]`
+    pub fn compute(&self, samples: &[f32]) -> Array2<f32> {
+        let num_frames = Self::num_frames(samples.len());
+        let mut features = Array2::<f32>::zeros((num_frames, NUM_MEL_BINS));
+        if num_frames == 0 {
+            return features;
+        }
+
+        let mut frame = vec![0.0f32; FFT_SIZE];
+        let mut spectrum = self.fft.make_output_vec();
+
+        for f in 0..num_frames {
+            let start = f * FRAME_SHIFT;
+            // Model was trained on int16-scaled audio
+            for (i, value) in frame.iter_mut().take(FRAME_LENGTH).enumerate() {
+                *value = samples[start + i] * 32768.0;
+            }
+            frame[FRAME_LENGTH..].iter_mut().for_each(|v| *v = 0.0);
+
+            let mean = frame[..FRAME_LENGTH].iter().sum::<f32>() / FRAME_LENGTH as f32;
+            frame[..FRAME_LENGTH].iter_mut().for_each(|v| *v -= mean);
+
+            for i in (1..FRAME_LENGTH).rev() {
+                frame[i] -= PREEMPHASIS * frame[i - 1];
+            }
+            frame[0] -= PREEMPHASIS * frame[0];
+
+            for (value, w) in frame.iter_mut().zip(self.window.iter()) {
+                *value *= w;
+            }
+
+            if self.fft.process(&mut frame, &mut spectrum).is_err() {
+                continue;
+            }
+
+            for (bin, bank) in self.mel_banks.iter().enumerate() {
+                let energy: f32 = bank
+                    .iter()
+                    .zip(spectrum.iter())
+                    .map(|(w, c)| w * c.norm_sqr())
+                    .sum();
+                features[[f, bin]] = energy.max(f32::EPSILON).ln();
+            }
+        }
+
+        // Cepstral mean normalization over the utterance
+        let means = features.mean_axis(ndarray::Axis(0));
+        if let Some(means) = means {
+            features -= &means;
+        }
+
+        features
+    }
+}
+
+impl Default for FbankExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_count_matches_kaldi_snip_edges() {
+        assert_eq!(FbankExtractor::num_frames(0), 0);
+        assert_eq!(FbankExtractor::num_frames(399), 0);
+        assert_eq!(FbankExtractor::num_frames(400), 1);
+        assert_eq!(FbankExtractor::num_frames(16000), 98);
+    }
+
+    #[test]
+    fn test_features_are_mean_normalized() {
+        let extractor = FbankExtractor::new();
+        let samples: Vec<f32> = (0..16000)
+            .map(|i| (2.0 * std::f32::consts::PI * 440.0 * i as f32 / 16000.0).sin() * 0.3)
+            .collect();
+        let features = extractor.compute(&samples);
+        assert_eq!(features.shape(), &[98, NUM_MEL_BINS]);
+        for bin in 0..NUM_MEL_BINS {
+            let mean = features.column(bin).mean().unwrap();
+            assert!(mean.abs() < 1e-3, "bin {} mean {}", bin, mean);
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! Offline speaker diarization.
+//!
+//! Splits a recorded meeting into per-speaker turns ("Speaker 1", "Speaker 2", ...)
+//! without any cloud service. Transcript segments are embedded with a WeSpeaker
+//! ONNX model and grouped per meeting with agglomerative clustering.
+//!
+//! # Module Structure
+//!
+//! - `features`: Kaldi-compatible fbank feature extraction
+//! - `embedding`: ONNX speaker-embedding model wrapper
+//! - `clustering`: Cosine-similarity agglomerative clustering
+//! - `diarizer`: Segment embedding, label assignment and `speakers.json` persistence
+//! - `commands`: Tauri command interface and post-recording hook
+
+pub mod features;
+pub mod embedding;
+pub mod clustering;
+pub mod diarizer;
+pub mod commands;
+
+#[derive(thiserror::Error, Debug)]
+pub enum DiarizationError {
+    #[error("ORT error")]
+    Ort(#[from] ort::Error),
+    #[error("I/O error")]
+    Io(#[from] std::io::Error),
+    #[error("ndarray shape error")]
+    Shape(#[from] ndarray::ShapeError),
+    #[error("Speaker embedding model not found: {0}")]
+    ModelNotFound(String),
+    #[error("Invalid speaker embedding model: {0}")]
+    InvalidModel(String),
+    #[error("Audio error: {0}")]
+    Audio(String),
+    #[error("No speech long enough to identify speakers")]
+    NoSpeech,
+}
+
+pub use diarizer::{DiarizationOptions, SpeakerTurn, TimedSegment};
+pub use embedding::SpeakerEmbedder;
+pub use commands::*;
@@ -0,0 +1,328 @@
+//! Versioned JSON archive of a meeting that can be re-imported into any workspace.
+//!
+//! The archive has its own serde types rather than reusing the database models, so the
+//! file format stays stable when the schema changes. Bump [`ARCHIVE_VERSION`] for
+//! incompatible changes; new optional fields only need `#[serde(default)]`.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Connection, SqlitePool};
+use uuid::Uuid;
+
+use super::MeetingExportData;
+use crate::audio::transcription::WordTiming;
+use crate::database::models::MeetingNoteEntry;
+use crate::database::repositories::summary_version::{insert_version, SummaryVersionOrigin};
+use crate::database::repositories::transcript::TranscriptsRepository;
+
+pub const ARCHIVE_FORMAT: &str = "meetily-meeting-archive";
+pub const ARCHIVE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MeetingArchive {
+    pub format: String,
+    pub version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub app_version: String,
+    pub meeting: ArchivedMeeting,
+    #[serde(default)]
+    pub transcripts: Vec<ArchivedTranscript>,
+    #[serde(default)]
+    pub summary: Option<ArchivedSummary>,
+    #[serde(default)]
+    pub notes: Option<ArchivedNotes>,
+    #[serde(default)]
+    pub speakers: Vec<ArchivedSpeaker>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivedMeeting {
+    pub id: String,
+    pub title: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivedTranscript {
+    pub text: String,
+    pub timestamp: String,
+    #[serde(default)]
+    pub audio_start_time: Option<f64>,
+    #[serde(default)]
+    pub audio_end_time: Option<f64>,
+    #[serde(default)]
+    pub duration: Option<f64>,
+    #[serde(default)]
+    pub speaker: Option<String>,
+    #[serde(default)]
+    pub speaker_label: Option<String>,
+    #[serde(default)]
+    pub confidence: Option<f32>,
+    #[serde(default)]
+    pub low_confidence: bool,
+    /// Word timings in seconds from recording start
+    #[serde(default)]
+    pub words: Vec<WordTiming>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivedSummary {
+    pub status: String,
+    /// Summary result as stored (`{"markdown": ...}`), kept as JSON rather than a string
+    #[serde(default)]
+    pub result: Option<serde_json::Value>,
+    #[serde(default)]
+    pub metadata: Option<serde_json::Value>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivedNotes {
+    #[serde(default)]
+    pub markdown: Option<String>,
+    #[serde(default)]
+    pub entries: Vec<MeetingNoteEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivedSpeaker {
+    pub label: String,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub segment_count: i64,
+    #[serde(default)]
+    pub total_duration: f64,
+}
+
+/// Stored JSON text as a JSON value, falling back to a plain string for legacy rows
+fn json_value(text: &str) -> serde_json::Value {
+    serde_json::from_str(text).unwrap_or_else(|_| serde_json::Value::String(text.to_string()))
+}
+
+fn json_text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+impl MeetingArchive {
+    pub fn from_export(data: &MeetingExportData) -> Self {
+        Self {
+            format: ARCHIVE_FORMAT.to_string(),
+            version: ARCHIVE_VERSION,
+            exported_at: Utc::now(),
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            meeting: ArchivedMeeting {
+                id: data.meeting.id.clone(),
+                title: data.meeting.title.clone(),
+                created_at: data.meeting.created_at.0,
+                updated_at: data.meeting.updated_at.0,
+            },
+            transcripts: data
+                .transcripts
+                .iter()
+                .map(|t| ArchivedTranscript {
+                    text: t.transcript.clone(),
+                    timestamp: t.timestamp.clone(),
+                    audio_start_time: t.audio_start_time,
+                    audio_end_time: t.audio_end_time,
+                    duration: t.duration,
+                    speaker: t.speaker.clone(),
+                    speaker_label: t.speaker_label.clone(),
+                    confidence: t.confidence,
+                    low_confidence: t.low_confidence,
+                    words: data.words(t).to_vec(),
+                })
+                .collect(),
+            summary: data.summary.as_ref().map(|s| ArchivedSummary {
+                status: s.status.clone(),
+                result: s.result.as_deref().map(json_value),
+                metadata: s.metadata.as_deref().map(json_value),
+                created_at: s.created_at,
+                updated_at: s.updated_at,
+            }),
+            notes: data.notes.as_ref().map(|n| ArchivedNotes {
+                markdown: n.notes_markdown.clone(),
+                entries: n.entries(),
+            }),
+            speakers: data
+                .speakers
+                .iter()
+                .map(|s| ArchivedSpeaker {
+                    label: s.label.clone(),
+                    display_name: s.display_name.clone(),
+                    segment_count: s.segment_count,
+                    total_duration: s.total_duration,
+                })
+                .collect(),
+        }
+    }
+
+    /// Parse an archive file, rejecting other JSON files and archives from newer versions
+    pub fn parse(json: &str) -> Result<Self, String> {
+        let header: serde_json::Value =
+            serde_json::from_str(json).map_err(|e| format!("Invalid archive JSON: {}", e))?;
+        if header.get("format").and_then(|f| f.as_str()) != Some(ARCHIVE_FORMAT) {
+            return Err("Not a meeting archive".to_string());
+        }
+        let version = header.get("version").and_then(|v| v.as_u64()).unwrap_or(0);
+        if version == 0 || version > ARCHIVE_VERSION as u64 {
+            return Err(format!(
+                "Unsupported archive version {} (this app reads up to {})",
+                version, ARCHIVE_VERSION
+            ));
+        }
+        serde_json::from_value(header).map_err(|e| format!("Invalid meeting archive: {}", e))
+    }
+
+    /// Insert the archived meeting into a workspace under a new id and return that id.
+    ///
+    /// Archives don't carry audio, so the imported meeting has no recording folder.
+    pub async fn import(&self, pool: &SqlitePool) -> Result<String, sqlx::Error> {
+        let meeting_id = format!("meeting-{}", Uuid::new_v4());
+        let mut conn = pool.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        sqlx::query("INSERT INTO meetings (id, title, created_at, updated_at, folder_path) VALUES (?, ?, ?, ?, NULL)")
+            .bind(&meeting_id)
+            .bind(&self.meeting.title)
+            .bind(self.meeting.created_at)
+            .bind(self.meeting.updated_at)
+            .execute(&mut *tx)
+            .await?;
+
+        for t in &self.transcripts {
+            let transcript_id = format!("transcript-{}", Uuid::new_v4());
+            sqlx::query(
+                "INSERT INTO transcripts (id, meeting_id, transcript, timestamp, audio_start_time, audio_end_time, duration, speaker, speaker_label, confidence, low_confidence)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(&transcript_id)
+            .bind(&meeting_id)
+            .bind(&t.text)
+            .bind(&t.timestamp)
+            .bind(t.audio_start_time)
+            .bind(t.audio_end_time)
+            .bind(t.duration)
+            .bind(&t.speaker)
+            .bind(&t.speaker_label)
+            .bind(t.confidence)
+            .bind(t.low_confidence)
+            .execute(&mut *tx)
+            .await?;
+            TranscriptsRepository::insert_words(&mut *tx, &transcript_id, &t.words).await?;
+        }
+
+        if let Some(summary) = &self.summary {
+            sqlx::query(
+                "INSERT INTO summary_processes (meeting_id, status, created_at, updated_at, result, metadata)
+                 VALUES (?, ?, ?, ?, ?, ?)",
+            )
+            .bind(&meeting_id)
+            .bind(&summary.status)
+            .bind(summary.created_at)
+            .bind(summary.updated_at)
+            .bind(summary.result.as_ref().map(json_text))
+            .bind(summary.metadata.as_ref().map(json_text))
+            .execute(&mut *tx)
+            .await?;
+
+            // The imported summary starts the meeting's version history
+            if let Some(result) = &summary.result {
+                let origin = SummaryVersionOrigin {
+                    source: "generated",
+                    ..Default::default()
+                };
+                insert_version(&mut tx, &meeting_id, &json_text(result), &origin).await?;
+            }
+        }
+
+        if let Some(notes) = &self.notes {
+            let notes_json = (!notes.entries.is_empty())
+                .then(|| serde_json::to_string(&notes.entries).unwrap_or_default());
+            let now = Utc::now();
+            sqlx::query(
+                "INSERT INTO meeting_notes (meeting_id, notes_markdown, notes_json, created_at, updated_at) VALUES (?, ?, ?, ?, ?)",
+            )
+            .bind(&meeting_id)
+            .bind(&notes.markdown)
+            .bind(notes_json)
+            .bind(now)
+            .bind(now)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for speaker in &self.speakers {
+            let now = Utc::now();
+            sqlx::query(
+                "INSERT INTO meeting_speakers (meeting_id, label, display_name, segment_count, total_duration, created_at, updated_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(&meeting_id)
+            .bind(&speaker.label)
+            .bind(&speaker.display_name)
+            .bind(speaker.segment_count)
+            .bind(speaker.total_duration)
+            .bind(now)
+            .bind(now)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(meeting_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::test_support::workspace_pool;
+    use crate::export::test_support::{sample_data, word};
+
+    #[test]
+    fn test_parse_rejects_foreign_and_newer_files() {
+        assert!(MeetingArchive::parse(r#"{"title":"x"}"#).is_err());
+        let newer = format!(r#"{{"format":"{}","version":{}}}"#, ARCHIVE_FORMAT, ARCHIVE_VERSION + 1);
+        assert!(MeetingArchive::parse(&newer).unwrap_err().contains("Unsupported"));
+    }
+
+    #[tokio::test]
+    async fn test_archive_roundtrip() {
+        let pool = workspace_pool().await;
+
+        let mut data = sample_data();
+        let words = vec![word("Let's", 0.5, 0.8), word("start", 0.8, 1.2)];
+        data.words.insert("t1".to_string(), words.clone());
+        let archive = MeetingArchive::from_export(&data);
+        let json = serde_json::to_string_pretty(&archive).unwrap();
+        assert!(json.contains(r##""markdown": "# Summary"##));
+
+        let imported_id = MeetingArchive::parse(&json).unwrap().import(&pool).await.unwrap();
+        assert_ne!(imported_id, "m1");
+
+        let loaded = MeetingExportData::load(&pool, &imported_id).await.unwrap().unwrap();
+        assert_eq!(loaded.meeting.title, "Pricing <review> & plan");
+        assert_eq!(loaded.transcripts.len(), 3);
+        assert_eq!(loaded.transcripts[1].speaker_label.as_deref(), Some("Speaker 1"));
+        assert_eq!(loaded.summary_markdown().as_deref(), Some("# Summary\n- Raise prices by 5%"));
+        assert_eq!(loaded.speaker_name(&loaded.transcripts[1]).as_deref(), Some("Alice"));
+        assert_eq!(loaded.words(&loaded.transcripts[1]), words.as_slice());
+        assert!(loaded.words(&loaded.transcripts[2]).is_empty());
+
+        // A second import of the same archive creates another independent meeting
+        let again = MeetingArchive::parse(&json).unwrap().import(&pool).await.unwrap();
+        assert_ne!(again, imported_id);
+    }
+}
@@ -0,0 +1,66 @@
+use std::path::PathBuf;
+
+use super::archive::MeetingArchive;
+use super::{export_file_name, render, ExportFormat, MeetingExportData};
+use crate::workspace::manager::WorkspaceManager;
+
+/// Export a meeting of the active workspace to `output_path` and return the written path.
+///
+/// When `output_path` is a directory, the file is named after the meeting title.
+#[tauri::command]
+pub async fn api_export_meeting(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    meeting_id: String,
+    format: ExportFormat,
+    output_path: String,
+) -> Result<String, String> {
+    let pool = workspace_mgr.active_pool().await?;
+    let data = MeetingExportData::load(&pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting {}: {}", meeting_id, e))?
+        .ok_or_else(|| format!("Meeting not found: {}", meeting_id))?;
+
+    let mut path = PathBuf::from(&output_path);
+    if path.is_dir() {
+        path.push(export_file_name(&data.meeting.title, format));
+    }
+
+    let bytes = render(&data, format)?;
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    tokio::fs::write(&path, bytes)
+        .await
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+
+    log::info!("Exported meeting {} as {:?} to {}", meeting_id, format, path.display());
+    Ok(path.to_string_lossy().to_string())
+}
+
+/// Import a JSON meeting archive into the active workspace and return the new meeting id
+#[tauri::command]
+pub async fn api_import_meeting_archive(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    file_path: String,
+) -> Result<String, String> {
+    let json = tokio::fs::read_to_string(&file_path)
+        .await
+        .map_err(|e| format!("Failed to read {}: {}", file_path, e))?;
+    let archive = MeetingArchive::parse(&json)?;
+
+    let pool = workspace_mgr.active_pool().await?;
+    let meeting_id = archive
+        .import(&pool)
+        .await
+        .map_err(|e| format!("Failed to import meeting archive: {}", e))?;
+
+    log::info!(
+        "Imported meeting archive '{}' (v{}) as {}",
+        archive.meeting.title,
+        archive.version,
+        meeting_id
+    );
+    Ok(meeting_id)
+}
@@ -0,0 +1,209 @@
+//! Minimal WordprocessingML (.docx) writer.
+//!
+//! Produces the four parts Word, LibreOffice and Google Docs need (content types, package
+//! relationships, styles and the document body). Markdown from the summary is mapped to
+//! headings, bullets and bold runs; anything fancier is kept as plain text.
+
+use std::io::{Cursor, Write};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use super::markdown::{format_clock, render_notes};
+use super::MeetingExportData;
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+<Default Extension="xml" ContentType="application/xml"/>
+<Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/>
+<Override PartName="/word/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml"/>
+</Types>"#;
+
+const PACKAGE_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/>
+</Relationships>"#;
+
+const DOCUMENT_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/>
+</Relationships>"#;
+
+const STYLES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:style w:type="paragraph" w:default="1" w:styleId="Normal"><w:name w:val="Normal"/><w:pPr><w:spacing w:after="120"/></w:pPr><w:rPr><w:sz w:val="22"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="Title"><w:name w:val="Title"/><w:basedOn w:val="Normal"/><w:rPr><w:b/><w:sz w:val="40"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="Subtitle"><w:name w:val="Subtitle"/><w:basedOn w:val="Normal"/><w:rPr><w:i/><w:color w:val="666666"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="Heading1"><w:name w:val="heading 1"/><w:basedOn w:val="Normal"/><w:pPr><w:keepNext/><w:spacing w:before="240"/><w:outlineLvl w:val="0"/></w:pPr><w:rPr><w:b/><w:sz w:val="32"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="Heading2"><w:name w:val="heading 2"/><w:basedOn w:val="Normal"/><w:pPr><w:keepNext/><w:spacing w:before="200"/><w:outlineLvl w:val="1"/></w:pPr><w:rPr><w:b/><w:sz w:val="26"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="Heading3"><w:name w:val="heading 3"/><w:basedOn w:val="Normal"/><w:pPr><w:keepNext/><w:outlineLvl w:val="2"/></w:pPr><w:rPr><w:b/><w:sz w:val="24"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="ListBullet"><w:name w:val="List Bullet"/><w:basedOn w:val="Normal"/><w:pPr><w:ind w:left="360" w:hanging="360"/></w:pPr></w:style>
+</w:styles>"#;
+
+/// Render a meeting as a .docx file
+pub fn render_docx(data: &MeetingExportData) -> Result<Vec<u8>, String> {
+    let mut body = String::new();
+    body.push_str(&paragraph(Some("Title"), &[Run::plain(data.meeting.title.trim())]));
+    body.push_str(&paragraph(
+        Some("Subtitle"),
+        &[Run::plain(&data.meeting.created_at.0.format("%Y-%m-%d %H:%M UTC").to_string())],
+    ));
+
+    if let Some(summary) = data.summary_markdown() {
+        body.push_str(&paragraph(Some("Heading1"), &[Run::plain("Summary")]));
+        body.push_str(&markdown_paragraphs(&summary));
+    }
+
+    if let Some(notes) = render_notes(data) {
+        body.push_str(&paragraph(Some("Heading1"), &[Run::plain("Notes")]));
+        body.push_str(&markdown_paragraphs(&notes));
+    }
+
+    body.push_str(&paragraph(Some("Heading1"), &[Run::plain("Transcript")]));
+    for t in data.transcripts.iter().filter(|t| !t.transcript.trim().is_empty()) {
+        let time = t
+            .audio_start_time
+            .map(format_clock)
+            .unwrap_or_else(|| t.timestamp.clone());
+        let label = match data.speaker_name(t) {
+            Some(speaker) => format!("[{}] {}: ", time, speaker),
+            None => format!("[{}] ", time),
+        };
+        body.push_str(&paragraph(None, &[Run::bold(&label), Run::plain(t.transcript.trim())]));
+    }
+
+    let document = format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>{}<w:sectPr><w:pgSz w:w="11906" w:h="16838"/><w:pgMar w:top="1440" w:right="1440" w:bottom="1440" w:left="1440"/></w:sectPr></w:body></w:document>"#,
+        body
+    );
+
+    write_package(&[
+        ("[Content_Types].xml", CONTENT_TYPES),
+        ("_rels/.rels", PACKAGE_RELS),
+        ("word/_rels/document.xml.rels", DOCUMENT_RELS),
+        ("word/styles.xml", STYLES),
+        ("word/document.xml", &document),
+    ])
+    .map_err(|e| format!("Failed to build DOCX: {}", e))
+}
+
+fn write_package(parts: &[(&str, &str)]) -> zip::result::ZipResult<Vec<u8>> {
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    for (name, content) in parts {
+        zip.start_file(*name, options)?;
+        zip.write_all(content.as_bytes())?;
+    }
+    Ok(zip.finish()?.into_inner())
+}
+
+struct Run {
+    text: String,
+    bold: bool,
+}
+
+impl Run {
+    fn plain(text: &str) -> Self {
+        Self { text: text.to_string(), bold: false }
+    }
+
+    fn bold(text: &str) -> Self {
+        Self { text: text.to_string(), bold: true }
+    }
+}
+
+fn paragraph(style: Option<&str>, runs: &[Run]) -> String {
+    let mut xml = String::from("<w:p>");
+    if let Some(style) = style {
+        xml.push_str(&format!(r#"<w:pPr><w:pStyle w:val="{}"/></w:pPr>"#, style));
+    }
+    for run in runs.iter().filter(|r| !r.text.is_empty()) {
+        xml.push_str("<w:r>");
+        if run.bold {
+            xml.push_str("<w:rPr><w:b/></w:rPr>");
+        }
+        xml.push_str(&format!(
+            r#"<w:t xml:space="preserve">{}</w:t></w:r>"#,
+            escape_xml(&run.text)
+        ));
+    }
+    xml.push_str("</w:p>");
+    xml
+}
+
+/// Map Markdown headings, bullets and `**bold**` spans to styled paragraphs
+fn markdown_paragraphs(markdown: &str) -> String {
+    let mut xml = String::new();
+    for line in markdown.lines().map(str::trim_end).filter(|l| !l.trim().is_empty()) {
+        let trimmed = line.trim_start();
+        let hashes = trimmed.chars().take_while(|c| *c == '#').count();
+        if (1..=6).contains(&hashes) && trimmed[hashes..].starts_with(' ') {
+            let style = match hashes {
+                1 | 2 => "Heading2",
+                _ => "Heading3",
+            };
+            xml.push_str(&paragraph(Some(style), &inline_runs(trimmed[hashes..].trim())));
+        } else if let Some(item) = trimmed.strip_prefix("- ").or_else(|| trimmed.strip_prefix("* ")) {
+            let mut runs = vec![Run::plain("• ")];
+            runs.extend(inline_runs(item));
+            xml.push_str(&paragraph(Some("ListBullet"), &runs));
+        } else {
+            xml.push_str(&paragraph(None, &inline_runs(trimmed)));
+        }
+    }
+    xml
+}
+
+fn inline_runs(text: &str) -> Vec<Run> {
+    text.split("**")
+        .enumerate()
+        .map(|(i, part)| Run { text: part.to_string(), bold: i % 2 == 1 })
+        .collect()
+}
+
+fn escape_xml(text: &str) -> String {
+    text.chars()
+        .filter(|c| !c.is_control() || matches!(c, '\t' | '\n'))
+        .collect::<String>()
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::test_support::sample_data;
+    use std::io::Read;
+
+    #[test]
+    fn test_render_docx_package() {
+        let bytes = render_docx(&sample_data()).unwrap();
+        let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+
+        for part in ["[Content_Types].xml", "_rels/.rels", "word/styles.xml"] {
+            assert!(archive.by_name(part).is_ok(), "missing {}", part);
+        }
+
+        let mut document = String::new();
+        archive
+            .by_name("word/document.xml")
+            .unwrap()
+            .read_to_string(&mut document)
+            .unwrap();
+        assert!(document.contains("Pricing &lt;review&gt; &amp; plan"));
+        assert!(document.contains(r#"<w:pStyle w:val="ListBullet"/>"#));
+        assert!(document.contains("Raise prices by 5%"));
+        assert!(document.contains("[00:00:00] Alice: "));
+    }
+
+    #[test]
+    fn test_inline_bold_runs() {
+        let runs = inline_runs("Owner: **Alice** by Friday");
+        assert_eq!(runs.len(), 3);
+        assert!(!runs[0].bold && runs[1].bold && !runs[2].bold);
+        assert_eq!(runs[1].text, "Alice");
+    }
+}
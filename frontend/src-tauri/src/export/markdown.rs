@@ -0,0 +1,106 @@
+use super::MeetingExportData;
+
+/// Render a meeting as Markdown: title, date, summary, notes and the speaker-labelled transcript
+pub fn render_markdown(data: &MeetingExportData) -> String {
+    let mut out = String::new();
+    out.push_str(&format!("# {}\n\n", data.meeting.title.trim()));
+    out.push_str(&format!(
+        "_{}_\n\n",
+        data.meeting.created_at.0.format("%Y-%m-%d %H:%M UTC")
+    ));
+
+    if let Some(summary) = data.summary_markdown() {
+        out.push_str("## Summary\n\n");
+        out.push_str(&demote_headings(&summary));
+        out.push_str("\n\n");
+    }
+
+    if let Some(notes) = render_notes(data) {
+        out.push_str("## Notes\n\n");
+        out.push_str(&notes);
+        out.push_str("\n\n");
+    }
+
+    out.push_str("## Transcript\n\n");
+    for t in data.transcripts.iter().filter(|t| !t.transcript.trim().is_empty()) {
+        let time = t
+            .audio_start_time
+            .map(format_clock)
+            .unwrap_or_else(|| t.timestamp.clone());
+        match data.speaker_name(t) {
+            Some(speaker) => out.push_str(&format!("**[{}] {}:** {}\n\n", time, speaker, t.transcript.trim())),
+            None => out.push_str(&format!("**[{}]** {}\n\n", time, t.transcript.trim())),
+        }
+    }
+
+    out.trim_end().to_string() + "\n"
+}
+
+/// User notes as Markdown; free-form notes win over the timestamped entries
+pub(super) fn render_notes(data: &MeetingExportData) -> Option<String> {
+    let notes = data.notes.as_ref()?;
+    if let Some(markdown) = notes.notes_markdown.as_deref().filter(|m| !m.trim().is_empty()) {
+        return Some(markdown.trim().to_string());
+    }
+
+    let entries = notes.entries();
+    if entries.is_empty() {
+        return None;
+    }
+    Some(
+        entries
+            .iter()
+            .map(|entry| match entry.audio_start_time {
+                Some(at) => format!("- [{}] {}", format_clock(at), entry.text.trim()),
+                None => format!("- {}", entry.text.trim()),
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+}
+
+/// `HH:MM:SS` position in the recording
+pub fn format_clock(seconds: f64) -> String {
+    let total = seconds.max(0.0) as u64;
+    format!("{:02}:{:02}:{:02}", total / 3600, (total % 3600) / 60, total % 60)
+}
+
+/// Nest the summary's own headings under the "## Summary" section
+fn demote_headings(markdown: &str) -> String {
+    markdown
+        .lines()
+        .map(|line| {
+            if line.starts_with('#') && line.trim_start_matches('#').starts_with(' ') {
+                format!("##{}", line)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::test_support::sample_data;
+
+    #[test]
+    fn test_render_markdown() {
+        let markdown = render_markdown(&sample_data());
+
+        assert!(markdown.starts_with("# Pricing <review> & plan\n"));
+        assert!(markdown.contains("### Summary\n- Raise prices by 5%"));
+        assert!(markdown.contains("**[00:00:00] Alice:** Let's start with pricing."));
+        assert!(markdown.contains("**[00:00:03] Speaker 2:** Sounds good."));
+        assert!(markdown.contains("**[10:00:00] Me:** Untimed remark."));
+        assert!(!markdown.contains("## Notes"));
+    }
+
+    #[test]
+    fn test_format_clock() {
+        assert_eq!(format_clock(0.0), "00:00:00");
+        assert_eq!(format_clock(3725.9), "01:02:05");
+        assert_eq!(format_clock(-1.0), "00:00:00");
+    }
+}
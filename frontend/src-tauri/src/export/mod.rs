@@ -0,0 +1,232 @@
+//! Meeting export: Markdown, SRT/WebVTT subtitles, DOCX and a re-importable JSON archive.
+//!
+//! Every format renders from [`MeetingExportData`], which gathers the meeting, its timed
+//! transcript, summary, notes and speaker names from the workspace database.
+
+pub mod archive;
+pub mod commands;
+pub mod docx;
+pub mod markdown;
+pub mod subtitles;
+
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+
+use crate::audio::transcription::WordTiming;
+use crate::database::models::{MeetingModel, MeetingNotes, MeetingSpeaker, SummaryProcess, Transcript};
+use crate::database::repositories::{
+    notes::MeetingNotesRepository, speaker::SpeakersRepository, summary::SummaryProcessesRepository,
+    transcript::TranscriptsRepository,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Markdown,
+    Srt,
+    Vtt,
+    Docx,
+    Json,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::Srt => "srt",
+            Self::Vtt => "vtt",
+            Self::Docx => "docx",
+            Self::Json => "json",
+        }
+    }
+}
+
+/// Everything an exporter needs about one meeting
+#[derive(Debug, Clone)]
+pub struct MeetingExportData {
+    pub meeting: MeetingModel,
+    /// Ordered by `audio_start_time`
+    pub transcripts: Vec<Transcript>,
+    pub summary: Option<SummaryProcess>,
+    pub notes: Option<MeetingNotes>,
+    pub speakers: Vec<MeetingSpeaker>,
+    /// Word timings by transcript id, in seconds from recording start
+    pub words: HashMap<String, Vec<WordTiming>>,
+}
+
+impl MeetingExportData {
+    /// Load a meeting with all exportable data; `None` if the meeting doesn't exist
+    pub async fn load(pool: &SqlitePool, meeting_id: &str) -> Result<Option<Self>, sqlx::Error> {
+        let meeting = sqlx::query_as::<_, MeetingModel>("SELECT * FROM meetings WHERE id = ?")
+            .bind(meeting_id)
+            .fetch_optional(pool)
+            .await?;
+        let Some(meeting) = meeting else {
+            return Ok(None);
+        };
+
+        let transcripts = TranscriptsRepository::get_transcripts_for_meeting(pool, meeting_id).await?;
+        let transcript_ids: Vec<String> = transcripts.iter().map(|t| t.id.clone()).collect();
+        Ok(Some(Self {
+            words: TranscriptsRepository::get_words(pool, &transcript_ids).await?,
+            transcripts,
+            summary: SummaryProcessesRepository::get_summary_data(pool, meeting_id).await?,
+            notes: MeetingNotesRepository::get_notes(pool, meeting_id).await?,
+            speakers: SpeakersRepository::get_meeting_speakers(pool, meeting_id).await?,
+            meeting,
+        }))
+    }
+
+    /// Summary markdown from the `{"markdown": ...}` result JSON, if a summary exists
+    pub fn summary_markdown(&self) -> Option<String> {
+        let result = self.summary.as_ref()?.result.as_deref()?;
+        let markdown = match serde_json::from_str::<serde_json::Value>(result) {
+            Ok(value) => value.get("markdown")?.as_str()?.to_string(),
+            Err(_) => result.to_string(),
+        };
+        let markdown = markdown.trim();
+        (!markdown.is_empty()).then(|| markdown.to_string())
+    }
+
+    /// Display name for the speaker of a segment: renamed diarization label, diarization
+    /// label, or the capture source
+    pub fn speaker_name(&self, transcript: &Transcript) -> Option<String> {
+        if let Some(label) = transcript.speaker_label.as_deref() {
+            return Some(
+                self.speaker_names()
+                    .get(label)
+                    .cloned()
+                    .unwrap_or_else(|| label.to_string()),
+            );
+        }
+        transcript
+            .speaker
+            .as_deref()
+            .map(crate::summary::processor::speaker_display_label)
+            .map(str::to_string)
+    }
+
+    /// Stored word timings of a segment; empty if it was transcribed without them
+    pub fn words(&self, transcript: &Transcript) -> &[WordTiming] {
+        self.words.get(&transcript.id).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    fn speaker_names(&self) -> HashMap<String, String> {
+        self.speakers
+            .iter()
+            .filter_map(|s| s.display_name.clone().map(|name| (s.label.clone(), name)))
+            .collect()
+    }
+
+    /// Transcript segments with usable audio timing, for subtitle formats
+    pub fn timed_segments(&self) -> impl Iterator<Item = (f64, f64, &Transcript)> {
+        self.transcripts.iter().filter_map(|t| {
+            let start = t.audio_start_time?;
+            let end = t
+                .audio_end_time
+                .or_else(|| t.duration.map(|d| start + d))
+                .unwrap_or(start + 2.0);
+            (!t.transcript.trim().is_empty() && end > start).then_some((start, end, t))
+        })
+    }
+}
+
+/// Render a meeting in the given format
+pub fn render(data: &MeetingExportData, format: ExportFormat) -> Result<Vec<u8>, String> {
+    Ok(match format {
+        ExportFormat::Markdown => markdown::render_markdown(data).into_bytes(),
+        ExportFormat::Srt => subtitles::render_srt(data).into_bytes(),
+        ExportFormat::Vtt => subtitles::render_vtt(data).into_bytes(),
+        ExportFormat::Docx => docx::render_docx(data)?,
+        ExportFormat::Json => serde_json::to_vec_pretty(&archive::MeetingArchive::from_export(data))
+            .map_err(|e| format!("Failed to serialize meeting archive: {}", e))?,
+    })
+}
+
+/// Suggested file name for an export, e.g. `Weekly sync.md`
+pub fn export_file_name(title: &str, format: ExportFormat) -> String {
+    let name = crate::audio::audio_processing::sanitize_filename(title);
+    let name = if name.is_empty() { "meeting".to_string() } else { name };
+    format!("{}.{}", name, format.extension())
+}
+
+#[cfg(test)]
+pub(crate) mod test_support {
+    use super::*;
+    use crate::database::models::DateTimeUtc;
+    use chrono::{TimeZone, Utc};
+
+    pub fn transcript(id: &str, text: &str, start: Option<f64>, end: Option<f64>, label: Option<&str>) -> Transcript {
+        Transcript {
+            id: id.to_string(),
+            meeting_id: "m1".to_string(),
+            transcript: text.to_string(),
+            timestamp: "10:00:00".to_string(),
+            summary: None,
+            action_items: None,
+            key_points: None,
+            audio_start_time: start,
+            audio_end_time: end,
+            duration: match (start, end) {
+                (Some(s), Some(e)) => Some(e - s),
+                _ => None,
+            },
+            speaker: Some("mic".to_string()),
+            speaker_label: label.map(str::to_string),
+            confidence: None,
+            low_confidence: false,
+        }
+    }
+
+    pub fn word(text: &str, start: f64, end: f64) -> WordTiming {
+        WordTiming {
+            word: text.to_string(),
+            start,
+            end,
+            probability: None,
+        }
+    }
+
+    pub fn sample_data() -> MeetingExportData {
+        let created = Utc.with_ymd_and_hms(2026, 3, 2, 14, 30, 0).unwrap();
+        MeetingExportData {
+            meeting: MeetingModel {
+                id: "m1".to_string(),
+                title: "Pricing <review> & plan".to_string(),
+                created_at: DateTimeUtc(created),
+                updated_at: DateTimeUtc(created),
+                folder_path: None,
+            },
+            transcripts: vec![
+                transcript("t1", "Let's start with pricing.", Some(0.5), Some(3.25), Some("Speaker 1")),
+                transcript("t2", "Sounds good.", Some(3.5), Some(4.0), Some("Speaker 2")),
+                transcript("t3", "Untimed remark.", None, None, None),
+            ],
+            summary: Some(SummaryProcess {
+                meeting_id: "m1".to_string(),
+                status: "completed".to_string(),
+                created_at: created,
+                updated_at: created,
+                error: None,
+                result: Some(r##"{"markdown":"# Summary\n- Raise prices by 5%"}"##.to_string()),
+                start_time: None,
+                end_time: None,
+                chunk_count: 0,
+                processing_time: 0.0,
+                metadata: None,
+            }),
+            notes: None,
+            speakers: vec![MeetingSpeaker {
+                meeting_id: "m1".to_string(),
+                label: "Speaker 1".to_string(),
+                display_name: Some("Alice".to_string()),
+                segment_count: 1,
+                total_duration: 2.75,
+                created_at: created,
+                updated_at: created,
+            }],
+            words: HashMap::new(),
+        }
+    }
+}
@@ -0,0 +1,262 @@
+//! SRT and WebVTT subtitles timed against the saved recording.
+//!
+//! Cue times are offsets into the meeting's audio file, so the subtitles line up when
+//! played alongside it. Long segments are split into several cues, timed by the stored
+//! word timings; segments without them share out their `audio_start_time`/`audio_end_time`.
+
+use super::MeetingExportData;
+use crate::audio::transcription::WordTiming;
+
+/// Subtitle line length before wrapping, per common broadcast guidelines
+const MAX_LINE_CHARS: usize = 42;
+
+/// Text of one cue, which wraps to at most two lines
+const MAX_CUE_CHARS: usize = 2 * MAX_LINE_CHARS;
+
+/// Longest a cue built from word timings stays on screen, in seconds
+const MAX_CUE_SECONDS: f64 = 7.0;
+
+struct Cue {
+    start: f64,
+    end: f64,
+    speaker: Option<String>,
+    text: String,
+}
+
+fn cues(data: &MeetingExportData) -> Vec<Cue> {
+    let mut cues: Vec<Cue> = Vec::new();
+    for (start, end, t) in data.timed_segments() {
+        let words = data.words(t);
+        let parts = if words_match_text(words, &t.transcript) {
+            timed_word_cues(words)
+        } else {
+            evenly_timed_cues(&t.transcript, start, end)
+        };
+        let speaker = data.speaker_name(t);
+        cues.extend(parts.into_iter().map(|(start, end, text)| Cue {
+            start,
+            end,
+            speaker: speaker.clone(),
+            text: wrap_text(&text, MAX_LINE_CHARS),
+        }));
+    }
+
+    // Trim overlaps so players never show two cues at once
+    for i in 1..cues.len() {
+        let next_start = cues[i].start;
+        let cue = &mut cues[i - 1];
+        if next_start > cue.start && next_start < cue.end {
+            cue.end = next_start;
+        }
+    }
+    cues
+}
+
+/// Word timings are only usable while they spell out the segment; an edited segment
+/// keeps the timings of the words it was transcribed with
+fn words_match_text(words: &[WordTiming], text: &str) -> bool {
+    !words.is_empty() && words.iter().map(|w| w.word.trim()).eq(text.split_whitespace())
+}
+
+/// Groups timed words into cues, each running from its first word's start to its last
+/// word's end
+fn timed_word_cues(words: &[WordTiming]) -> Vec<(f64, f64, String)> {
+    let mut cues: Vec<(f64, f64, String)> = Vec::new();
+    for word in words {
+        let text = word.word.trim();
+        match cues.last_mut() {
+            Some((start, end, cue))
+                if cue.chars().count() + 1 + text.chars().count() <= MAX_CUE_CHARS
+                    && word.end - *start <= MAX_CUE_SECONDS =>
+            {
+                cue.push(' ');
+                cue.push_str(text);
+                *end = end.max(word.end);
+            }
+            _ => cues.push((word.start, word.end.max(word.start), text.to_string())),
+        }
+    }
+    cues
+}
+
+/// Groups the words of a segment without word timings into cues and splits the
+/// segment's time between them by word count
+fn evenly_timed_cues(text: &str, start: f64, end: f64) -> Vec<(f64, f64, String)> {
+    let mut groups: Vec<Vec<&str>> = Vec::new();
+    let mut chars = 0;
+    for word in text.split_whitespace() {
+        match groups.last_mut() {
+            Some(group) if chars + 1 + word.chars().count() <= MAX_CUE_CHARS => {
+                chars += 1 + word.chars().count();
+                group.push(word);
+            }
+            _ => {
+                chars = word.chars().count();
+                groups.push(vec![word]);
+            }
+        }
+    }
+
+    let word_count: usize = groups.iter().map(Vec::len).sum();
+    let seconds_per_word = (end - start) / word_count.max(1) as f64;
+    let mut words_before = 0;
+    groups
+        .into_iter()
+        .map(|group| {
+            let cue_start = start + words_before as f64 * seconds_per_word;
+            words_before += group.len();
+            let cue_end = start + words_before as f64 * seconds_per_word;
+            (cue_start, cue_end, group.join(" "))
+        })
+        .collect()
+}
+
+pub fn render_srt(data: &MeetingExportData) -> String {
+    let mut out = String::new();
+    for (i, cue) in cues(data).iter().enumerate() {
+        out.push_str(&format!(
+            "{}\n{} --> {}\n",
+            i + 1,
+            format_timestamp(cue.start, ','),
+            format_timestamp(cue.end, ',')
+        ));
+        match &cue.speaker {
+            Some(speaker) => out.push_str(&format!("{}: {}\n\n", speaker, cue.text)),
+            None => out.push_str(&format!("{}\n\n", cue.text)),
+        }
+    }
+    out
+}
+
+pub fn render_vtt(data: &MeetingExportData) -> String {
+    let mut out = String::from("WEBVTT\n\n");
+    for cue in cues(data) {
+        out.push_str(&format!(
+            "{} --> {}\n",
+            format_timestamp(cue.start, '.'),
+            format_timestamp(cue.end, '.')
+        ));
+        let text = escape_vtt(&cue.text);
+        match cue.speaker {
+            Some(speaker) => out.push_str(&format!("<v {}>{}\n\n", escape_vtt(&speaker), text)),
+            None => out.push_str(&format!("{}\n\n", text)),
+        }
+    }
+    out
+}
+
+/// `HH:MM:SS,mmm` (SRT) or `HH:MM:SS.mmm` (WebVTT)
+fn format_timestamp(seconds: f64, separator: char) -> String {
+    let total_ms = (seconds.max(0.0) * 1000.0).round() as u64;
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        total_ms / 3_600_000,
+        (total_ms % 3_600_000) / 60_000,
+        (total_ms % 60_000) / 1000,
+        separator,
+        total_ms % 1000
+    )
+}
+
+fn wrap_text(text: &str, max_chars: usize) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        if !current.is_empty() && current.chars().count() + 1 + word.chars().count() > max_chars {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines.join("\n")
+}
+
+fn escape_vtt(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::test_support::{sample_data, transcript, word};
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0.5, ','), "00:00:00,500");
+        assert_eq!(format_timestamp(3723.0456, '.'), "01:02:03.046");
+    }
+
+    #[test]
+    fn test_render_srt() {
+        let srt = render_srt(&sample_data());
+        assert_eq!(
+            srt,
+            "1\n00:00:00,500 --> 00:00:03,250\nAlice: Let's start with pricing.\n\n\
+             2\n00:00:03,500 --> 00:00:04,000\nSpeaker 2: Sounds good.\n\n"
+        );
+    }
+
+    #[test]
+    fn test_render_vtt_escapes_and_trims_overlaps() {
+        let mut data = sample_data();
+        data.transcripts = vec![
+            transcript("t1", "a < b & c", Some(1.0), Some(5.0), None),
+            transcript("t2", "next", Some(4.0), Some(6.0), None),
+        ];
+        let vtt = render_vtt(&data);
+        assert!(vtt.starts_with("WEBVTT\n\n"));
+        assert!(vtt.contains("00:00:01.000 --> 00:00:04.000\n<v Me>a &lt; b &amp; c\n"));
+        assert!(vtt.contains("00:00:04.000 --> 00:00:06.000\n<v Me>next\n"));
+    }
+
+    #[test]
+    fn test_long_segments_are_split_on_word_timings() {
+        let mut data = sample_data();
+        let text = "We should raise prices by five percent next quarter. Enterprise customers keep their current rate.";
+        data.transcripts = vec![transcript("t1", text, Some(10.0), Some(20.0), None)];
+        // The second sentence follows a pause and would keep the first cue up too long
+        let words: Vec<_> = text
+            .split_whitespace()
+            .enumerate()
+            .map(|(i, w)| {
+                let start = if i < 9 { 10.0 + i as f64 * 0.4 } else { 18.0 + (i - 9) as f64 * 0.4 };
+                word(w, start, start + 0.3)
+            })
+            .collect();
+        data.words.insert("t1".to_string(), words);
+
+        let srt = render_srt(&data);
+        assert!(srt.contains("1\n00:00:10,000 --> 00:00:13,500\n"), "{}", srt);
+        assert!(srt.contains("2\n00:00:18,000 --> 00:00:20,300\n"), "{}", srt);
+        let cue_text: Vec<&str> = srt
+            .split("\n\n")
+            .filter_map(|cue| cue.splitn(3, '\n').nth(2))
+            .collect();
+        assert_eq!(cue_text.join(" ").replace("Me: ", "").replace('\n', " "), text);
+    }
+
+    #[test]
+    fn test_segments_without_matching_word_timings_are_split_evenly() {
+        let mut data = sample_data();
+        let text = "one two three four five six seven eight nine ten eleven twelve thirteen fourteen fifteen sixteen";
+        data.transcripts = vec![transcript("t1", text, Some(0.0), Some(16.0), None)];
+        // Timings of the words before the segment was edited are ignored
+        data.words.insert("t1".to_string(), vec![word("uno", 3.0, 4.0)]);
+
+        let vtt = render_vtt(&data);
+        assert!(vtt.contains("00:00:00.000 --> 00:00:14.000\n"), "{}", vtt);
+        assert!(vtt.contains("00:00:14.000 --> 00:00:16.000\n<v Me>fifteen sixteen\n"), "{}", vtt);
+    }
+
+    #[test]
+    fn test_wrap_text() {
+        let wrapped = wrap_text("one two three four five six seven eight nine ten eleven", 20);
+        assert!(wrapped.lines().all(|l| l.chars().count() <= 20));
+        assert_eq!(wrapped.replace('\n', " "), "one two three four five six seven eight nine ten eleven");
+    }
+}
@@ -40,11 +40,15 @@ pub mod api;
 pub mod audio;
 pub mod console_utils;
 pub mod database;
+pub mod diarization;
+pub mod export;
 pub mod notifications;
 pub mod ollama;
 pub mod onboarding;
 pub mod openrouter;
 pub mod parakeet_engine;
+pub mod secrets;
+pub mod semantic_search;
 pub mod state;
 pub mod summary;
 pub mod tray;
@@ -456,6 +460,12 @@ pub fn run() {
             // Set Parakeet models directory
             parakeet_engine::commands::set_models_directory(&_app.handle());
 
+            // Set speaker diarization models directory
+            diarization::commands::set_models_directory(&_app.handle());
+
+            // Set text embedding models directory for semantic search
+            semantic_search::commands::set_models_directory(&_app.handle());
+
             // Initialize Parakeet engine on startup
             tauri::async_runtime::spawn(async {
                 if let Err(e) = parakeet_engine::commands::parakeet_init().await {
@@ -564,6 +574,33 @@ pub fn run() {
             parakeet_engine::commands::parakeet_cancel_download,
             parakeet_engine::commands::parakeet_delete_corrupted_model,
             parakeet_engine::commands::open_parakeet_models_folder,
+            // Speaker diarization commands
+            diarization::commands::diarization_get_model_status,
+            diarization::commands::diarization_download_model,
+            diarization::commands::api_diarize_meeting,
+            diarization::commands::api_get_meeting_speakers,
+            diarization::commands::api_rename_meeting_speaker,
+            // Semantic search commands
+            semantic_search::commands::semantic_search_get_model_status,
+            semantic_search::commands::semantic_search_download_model,
+            semantic_search::commands::api_build_semantic_index,
+            semantic_search::commands::api_semantic_search,
+            // API key storage commands
+            secrets::commands::secrets_get_status,
+            secrets::commands::secrets_unlock_vault,
+            secrets::commands::secrets_lock_vault,
+            // Workspace management commands
+            workspace::commands::workspace_list,
+            workspace::commands::workspace_create,
+            workspace::commands::workspace_rename,
+            workspace::commands::workspace_get_manifest,
+            workspace::commands::workspace_update_manifest,
+            workspace::commands::workspace_reorder,
+            workspace::commands::workspace_delete,
+            workspace::commands::workspace_switch,
+            workspace::commands::workspace_move_meetings,
+            workspace::commands::workspace_copy_meetings,
+            workspace::commands::workspace_merge,
             // Parallel processing commands
             whisper_engine::parallel_commands::initialize_parallel_processor,
             whisper_engine::parallel_commands::start_parallel_processing,
@@ -592,6 +629,20 @@ pub fn run() {
             // Reload sync commands (retrieve transcript history and meeting name)
             audio::recording_commands::get_transcript_history,
             audio::recording_commands::get_recording_meeting_name,
+            audio::recording_commands::save_recording_notes,
+            audio::recording_commands::get_recording_notes,
+            // Audio file import
+            audio::import::import_audio_file,
+            audio::import::cancel_audio_import,
+            // Post-meeting re-transcription and transcript history
+            audio::retranscribe::retranscribe_meeting,
+            audio::retranscribe::cancel_retranscription,
+            audio::retranscribe::api_list_transcript_versions,
+            audio::retranscribe::api_restore_transcript_version,
+            audio::retranscribe::retranscribe_low_confidence_segments,
+            // Meeting export / archive import
+            export::commands::api_export_meeting,
+            export::commands::api_import_meeting_archive,
             // Device monitoring commands (AirPods/Bluetooth disconnect/reconnect)
             audio::recording_commands::poll_audio_device_events,
             audio::recording_commands::get_reconnection_status,
@@ -622,11 +673,15 @@ pub fn run() {
             api::api_get_transcript_config,
             api::api_save_transcript_config,
             api::api_get_transcript_api_key,
+            audio::transcription::vocabulary::api_get_transcription_vocabulary,
+            audio::transcription::vocabulary::api_save_transcription_vocabulary,
             api::api_delete_meeting,
             api::api_get_meeting,
             api::api_get_meeting_metadata,
             api::api_get_meeting_transcripts,
             api::api_save_meeting_title,
+            api::api_get_meeting_notes,
+            api::api_save_meeting_notes,
             api::api_save_transcript,
             api::open_meeting_folder,
             api::test_backend_connection,
@@ -641,6 +696,23 @@ pub fn run() {
             summary::api_get_summary,
             summary::api_save_meeting_summary,
             summary::api_cancel_summary,
+            summary::api_list_llm_models,
+            summary::api_test_llm_connection,
+            summary::api_get_summary_fallback_config,
+            summary::api_save_summary_fallback_config,
+            // Action item and decision commands
+            summary::api_list_action_items,
+            summary::api_complete_action_item,
+            summary::api_list_decisions,
+            // Meeting chat commands
+            summary::api_ask_meeting,
+            summary::api_get_meeting_chat,
+            summary::api_clear_meeting_chat,
+            // Summary version history commands
+            summary::api_list_summary_versions,
+            summary::api_diff_summary_versions,
+            summary::api_restore_summary_version,
+            summary::api_pin_summary_version,
             // Template commands
             summary::api_list_templates,
             summary::api_get_template_details,
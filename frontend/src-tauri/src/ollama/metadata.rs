@@ -12,10 +12,26 @@ use once_cell::sync::Lazy;
 pub struct ModelMetadata {
     pub name: String,
     pub context_size: usize,
+    /// `num_ctx` set in the modelfile, i.e. the context the model is loaded with
+    #[serde(default)]
+    pub num_ctx: Option<usize>,
     pub parameter_count: String,
     pub family: String,
 }
 
+impl ModelMetadata {
+    /// Context window of requests through Ollama's OpenAI-compatible API
+    ///
+    /// That API cannot set `num_ctx` per request, so the model runs with the modelfile's
+    /// `num_ctx` or the server default, however large its trained context is. Prompts longer
+    /// than this are silently truncated by Ollama.
+    pub fn request_context_size(&self) -> usize {
+        self.num_ctx
+            .unwrap_or(OLLAMA_DEFAULT_NUM_CTX)
+            .min(self.context_size)
+    }
+}
+
 /// Response structure from Ollama /api/show endpoint
 #[derive(Debug, Deserialize)]
 struct OllamaShowResponse {
@@ -126,6 +142,9 @@ const DEFAULT_CONTEXT_SIZES: &[(&str, usize)] = &[
     ("neural-chat", 4096),
 ];
 
+/// Context Ollama loads a model with when its modelfile sets no `num_ctx`
+const OLLAMA_DEFAULT_NUM_CTX: usize = 4096;
+
 /// Ultimate fallback context size when model family is unknown
 const ULTIMATE_FALLBACK: usize = 4000;
 
@@ -209,6 +228,7 @@ async fn fetch_model_info(
     Ok(ModelMetadata {
         name: model_name.to_string(),
         context_size,
+        num_ctx: find_num_ctx(&show_response.modelfile),
         parameter_count: show_response.details.parameter_size,
         family: show_response.details.family,
     })
@@ -255,7 +275,17 @@ fn extract_context_from_model_info(
 /// # Returns
 /// Context size in tokens, defaults to 4000 if not found
 fn parse_num_ctx_from_modelfile(modelfile: &str) -> usize {
-    // Regex to match: PARAMETER num_ctx <number>
+    find_num_ctx(modelfile).unwrap_or_else(|| {
+        tracing::debug!(
+            "num_ctx not found in modelfile, using default {}",
+            ULTIMATE_FALLBACK
+        );
+        ULTIMATE_FALLBACK
+    })
+}
+
+/// The `PARAMETER num_ctx <number>` value of a modelfile, if set
+fn find_num_ctx(modelfile: &str) -> Option<usize> {
     static RE: Lazy<Regex> = Lazy::new(|| {
         Regex::new(r"PARAMETER\s+num_ctx\s+(\d+)").expect("Invalid regex pattern")
     });
@@ -263,13 +293,6 @@ fn parse_num_ctx_from_modelfile(modelfile: &str) -> usize {
     RE.captures(modelfile)
         .and_then(|caps| caps.get(1))
         .and_then(|m| m.as_str().parse::<usize>().ok())
-        .unwrap_or_else(|| {
-            tracing::debug!(
-                "num_ctx not found in modelfile, using default {}",
-                ULTIMATE_FALLBACK
-            );
-            ULTIMATE_FALLBACK
-        })
 }
 
 /// Get fallback metadata based on model name pattern matching
@@ -306,6 +329,7 @@ fn get_fallback_metadata(model_name: &str) -> ModelMetadata {
     ModelMetadata {
         name: model_name.to_string(),
         context_size,
+        num_ctx: None,
         parameter_count: "unknown".to_string(),
         family,
     }
@@ -339,6 +363,17 @@ mod tests {
         assert_eq!(parse_num_ctx_from_modelfile(modelfile), 32768);
     }
 
+    #[test]
+    fn test_request_context_size_is_limited_to_num_ctx() {
+        let mut metadata = get_fallback_metadata("mistral:7b");
+        metadata.context_size = 131072;
+        assert_eq!(metadata.request_context_size(), OLLAMA_DEFAULT_NUM_CTX);
+        metadata.num_ctx = Some(32768);
+        assert_eq!(metadata.request_context_size(), 32768);
+        metadata.context_size = 8192;
+        assert_eq!(metadata.request_context_size(), 8192);
+    }
+
     #[test]
     fn test_fallback_metadata_llama() {
         let metadata = get_fallback_metadata("llama3.2:1b");
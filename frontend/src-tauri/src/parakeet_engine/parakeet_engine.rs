@@ -1,4 +1,6 @@
 use crate::parakeet_engine::model::ParakeetModel;
+use crate::audio::transcription::provider::{words_from_tokens, WordTiming};
+use crate::audio::transcription::vocabulary;
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
@@ -452,6 +454,19 @@ impl ParakeetEngine {
 
     /// Transcribe audio samples using the loaded Parakeet model
     pub async fn transcribe_audio(&self, audio_data: Vec<f32>) -> Result<String> {
+        self.transcribe_audio_with_words(audio_data)
+            .await
+            .map(|(text, _)| text)
+    }
+
+    /// Transcribe audio samples and return the text with word timings
+    ///
+    /// Parakeet reports when each token starts; a token ends where the next one starts,
+    /// and the last one at the end of the audio.
+    pub async fn transcribe_audio_with_words(
+        &self,
+        audio_data: Vec<f32>,
+    ) -> Result<(String, Vec<WordTiming>)> {
         let mut model_guard = self.current_model.write().await;
         let model = model_guard
             .as_mut()
@@ -471,7 +486,32 @@ impl ParakeetEngine {
 
         log::debug!("Parakeet transcription result: '{}'", result.text);
 
-        Ok(result.text)
+        let tokens = result.tokens.iter().enumerate().map(|(i, token)| {
+            let start = result.timestamps.get(i).copied().unwrap_or_default() as f64;
+            let end = result
+                .timestamps
+                .get(i + 1)
+                .map(|&t| t as f64)
+                .unwrap_or(duration_seconds)
+                .max(start);
+            WordTiming {
+                word: token.clone(),
+                start,
+                end,
+                probability: None,
+            }
+        });
+        let words = words_from_tokens(tokens.collect::<Vec<_>>());
+
+        // Parakeet has no prompt, so vocabulary terms are corrected afterwards
+        let terms = vocabulary::active_vocabulary();
+        if terms.is_empty() {
+            return Ok((result.text, words));
+        }
+        Ok((
+            vocabulary::correct_text(&result.text, &terms),
+            vocabulary::correct_words(words, &terms),
+        ))
     }
 
     /// Get the models directory path
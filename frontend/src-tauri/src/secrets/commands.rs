@@ -0,0 +1,48 @@
+use serde::Serialize;
+
+use super::migration::migrate_plaintext_secrets;
+use super::{secret_store, SecretBackend};
+use crate::workspace::manager::WorkspaceManager;
+
+#[derive(Debug, Serialize)]
+pub struct SecretStoreStatus {
+    pub backend: SecretBackend,
+    pub locked: bool,
+    /// False until the first passphrase is set (vault backend only)
+    pub vault_exists: bool,
+}
+
+#[tauri::command]
+pub async fn secrets_get_status() -> Result<SecretStoreStatus, String> {
+    let store = secret_store().map_err(|e| e.to_string())?;
+    Ok(SecretStoreStatus {
+        backend: store.backend(),
+        locked: store.is_locked(),
+        vault_exists: store.vault_exists(),
+    })
+}
+
+/// Unlock the encrypted vault (or create it on first use) and move any plaintext keys
+/// that were waiting for it
+#[tauri::command]
+pub async fn secrets_unlock_vault(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    passphrase: String,
+) -> Result<SecretStoreStatus, String> {
+    let store = secret_store().map_err(|e| e.to_string())?;
+    let unlock = tokio::task::spawn_blocking(move || store.unlock(&passphrase))
+        .await
+        .map_err(|e| format!("Vault unlock task failed: {}", e))?;
+    unlock.map_err(|e| e.to_string())?;
+
+    migrate_plaintext_secrets(workspace_mgr.global_pool(), store).await?;
+
+    secrets_get_status().await
+}
+
+#[tauri::command]
+pub async fn secrets_lock_vault() -> Result<(), String> {
+    let store = secret_store().map_err(|e| e.to_string())?;
+    store.lock();
+    Ok(())
+}
@@ -0,0 +1,177 @@
+use sqlx::SqlitePool;
+
+use super::{secret_key, SecretStore};
+
+/// `settings` columns whose values now live in the secret store
+pub const SETTINGS_KEY_COLUMNS: &[&str] = &[
+    "openaiApiKey",
+    "anthropicApiKey",
+    "ollamaApiKey",
+    "groqApiKey",
+    "openRouterApiKey",
+    "geminiApiKey",
+];
+
+/// `transcript_settings` columns whose values now live in the secret store
+pub const TRANSCRIPT_KEY_COLUMNS: &[&str] = &[
+    "whisperApiKey",
+    "deepgramApiKey",
+    "elevenLabsApiKey",
+    "groqApiKey",
+    "openaiApiKey",
+];
+
+/// Secret name of the API key embedded in `settings.customOpenAIConfig`
+pub fn custom_openai_secret_key() -> String {
+    secret_key("settings", "customOpenAIConfig.apiKey")
+}
+
+/// Move plaintext API keys from the global settings tables into the secret store.
+///
+/// Each value is written to the store before its column is cleared, so a locked vault or
+/// keyring failure leaves the key where it was. Freed pages are vacuumed afterwards so
+/// the old values don't linger in the database file. Returns the number of keys moved.
+pub async fn migrate_plaintext_secrets(pool: &SqlitePool, store: &SecretStore) -> Result<usize, String> {
+    let mut migrated = 0;
+
+    for (table, columns) in [
+        ("settings", SETTINGS_KEY_COLUMNS),
+        ("transcript_settings", TRANSCRIPT_KEY_COLUMNS),
+    ] {
+        for column in columns {
+            let query = format!(r#"SELECT "{}" FROM {} WHERE "{}" IS NOT NULL"#, column, table, column);
+            let values: Vec<Option<String>> = sqlx::query_scalar(&query)
+                .fetch_all(pool)
+                .await
+                .map_err(|e| format!("Failed to read {}.{}: {}", table, column, e))?;
+
+            for value in values.into_iter().flatten().filter(|v| !v.trim().is_empty()) {
+                store
+                    .set(&secret_key(table, column), &value)
+                    .map_err(|e| format!("Failed to store {}.{}: {}", table, column, e))?;
+                migrated += 1;
+            }
+
+            let clear = format!(r#"UPDATE {} SET "{}" = NULL WHERE "{}" IS NOT NULL"#, table, column, column);
+            sqlx::query(&clear)
+                .execute(pool)
+                .await
+                .map_err(|e| format!("Failed to clear {}.{}: {}", table, column, e))?;
+        }
+    }
+
+    migrated += migrate_custom_openai_key(pool, store).await?;
+
+    if migrated > 0 {
+        log::info!("Moved {} plaintext API keys into the secret store", migrated);
+        for statement in ["VACUUM", "PRAGMA wal_checkpoint(TRUNCATE)"] {
+            if let Err(e) = sqlx::query(statement).execute(pool).await {
+                log::warn!("Failed to run {} after key migration: {}", statement, e);
+            }
+        }
+    }
+
+    Ok(migrated)
+}
+
+/// Strip `apiKey` from the custom OpenAI JSON config
+async fn migrate_custom_openai_key(pool: &SqlitePool, store: &SecretStore) -> Result<usize, String> {
+    let config_json: Option<Option<String>> =
+        sqlx::query_scalar("SELECT customOpenAIConfig FROM settings WHERE id = '1'")
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| format!("Failed to read customOpenAIConfig: {}", e))?;
+    let Some(config_json) = config_json.flatten() else {
+        return Ok(0);
+    };
+
+    let mut config: serde_json::Value = match serde_json::from_str(&config_json) {
+        Ok(config) => config,
+        Err(e) => {
+            log::warn!("Skipping key migration for invalid customOpenAIConfig: {}", e);
+            return Ok(0);
+        }
+    };
+    let Some(api_key) = config.get("apiKey").and_then(|k| k.as_str()).map(str::to_string) else {
+        return Ok(0);
+    };
+
+    if !api_key.trim().is_empty() {
+        store
+            .set(&custom_openai_secret_key(), &api_key)
+            .map_err(|e| format!("Failed to store custom OpenAI key: {}", e))?;
+    }
+    config["apiKey"] = serde_json::Value::Null;
+
+    sqlx::query("UPDATE settings SET customOpenAIConfig = ? WHERE id = '1'")
+        .bind(config.to_string())
+        .execute(pool)
+        .await
+        .map_err(|e| format!("Failed to rewrite customOpenAIConfig: {}", e))?;
+
+    Ok(if api_key.trim().is_empty() { 0 } else { 1 })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::test_support::global_pool;
+
+    #[tokio::test]
+    async fn test_migrate_plaintext_secrets() {
+        let dir = tempfile::tempdir().unwrap();
+        let pool = global_pool().await;
+
+        sqlx::query(
+            r#"INSERT OR REPLACE INTO settings (id, provider, model, whisperModel, openaiApiKey, geminiApiKey, customOpenAIConfig)
+               VALUES ('1', 'openai', 'gpt-4o', 'large-v3', 'sk-plain', '', '{"endpoint":"http://localhost:8000/v1","apiKey":"custom-key","model":"llama"}')"#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT OR REPLACE INTO transcript_settings (id, provider, model, deepgramApiKey) VALUES ('1', 'deepgram', 'nova-2', 'dg-plain')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        // A locked vault must leave the plaintext untouched
+        let store = SecretStore::vault(dir.path().join("secrets.vault"));
+        assert!(migrate_plaintext_secrets(&pool, &store).await.is_err());
+        let still_there: Option<String> = sqlx::query_scalar("SELECT openaiApiKey FROM settings")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(still_there.as_deref(), Some("sk-plain"));
+
+        store.unlock("migration passphrase").unwrap();
+        assert_eq!(migrate_plaintext_secrets(&pool, &store).await.unwrap(), 3);
+
+        assert_eq!(
+            store.get("settings.openaiApiKey").unwrap().as_deref(),
+            Some("sk-plain")
+        );
+        assert_eq!(
+            store.get("transcript_settings.deepgramApiKey").unwrap().as_deref(),
+            Some("dg-plain")
+        );
+        assert_eq!(
+            store.get(&custom_openai_secret_key()).unwrap().as_deref(),
+            Some("custom-key")
+        );
+
+        let (openai, gemini, custom): (Option<String>, Option<String>, String) =
+            sqlx::query_as("SELECT openaiApiKey, geminiApiKey, customOpenAIConfig FROM settings")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert!(openai.is_none());
+        assert!(gemini.is_none());
+        assert!(!custom.contains("custom-key"));
+        assert!(custom.contains("localhost:8000"));
+
+        // Nothing left to move on the next start
+        assert_eq!(migrate_plaintext_secrets(&pool, &store).await.unwrap(), 0);
+    }
+}
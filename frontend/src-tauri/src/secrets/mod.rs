@@ -0,0 +1,188 @@
+//! Encrypted storage for provider API keys.
+//!
+//! Keys live in the OS keyring (macOS Keychain, Windows Credential Manager, Secret Service
+//! on Linux) when one is available. Otherwise they are kept in `secrets.vault` in the app
+//! data directory, encrypted with AES-256-GCM under a key derived from a user passphrase.
+//! The `settings` tables only keep non-secret configuration.
+
+pub mod commands;
+pub mod migration;
+pub mod vault;
+
+use once_cell::sync::OnceCell;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+use vault::Vault;
+
+/// Keyring service name, matches the app identifier
+const KEYRING_SERVICE: &str = "com.meetily.ai";
+
+/// Fallback vault file name inside the app data directory
+pub const VAULT_FILE: &str = "secrets.vault";
+
+#[derive(Debug, Error)]
+pub enum SecretError {
+    #[error("Secret store is not initialized")]
+    NotInitialized,
+
+    #[error("Secret vault is locked. Unlock it with your passphrase to use saved API keys.")]
+    Locked,
+
+    #[error("Incorrect vault passphrase")]
+    WrongPassphrase,
+
+    #[error("Keyring error: {0}")]
+    Keyring(#[from] keyring::Error),
+
+    #[error("Vault error: {0}")]
+    Vault(String),
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Where secrets are persisted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SecretBackend {
+    Keyring,
+    Vault,
+}
+
+enum Backend {
+    Keyring,
+    Vault(Vault),
+}
+
+pub struct SecretStore {
+    backend: Backend,
+}
+
+impl SecretStore {
+    /// Pick the backend for this machine.
+    ///
+    /// An existing vault file keeps being used, so keys never silently split across two
+    /// stores. Otherwise the OS keyring is preferred when it responds.
+    pub fn open(app_data_dir: &Path) -> Self {
+        let vault_path = app_data_dir.join(VAULT_FILE);
+        if !vault_path.exists() && keyring_available() {
+            return Self::keyring();
+        }
+        Self::vault(vault_path)
+    }
+
+    pub fn keyring() -> Self {
+        Self {
+            backend: Backend::Keyring,
+        }
+    }
+
+    pub fn vault(path: PathBuf) -> Self {
+        Self {
+            backend: Backend::Vault(Vault::new(path)),
+        }
+    }
+
+    pub fn backend(&self) -> SecretBackend {
+        match self.backend {
+            Backend::Keyring => SecretBackend::Keyring,
+            Backend::Vault(_) => SecretBackend::Vault,
+        }
+    }
+
+    /// The keyring is unlocked by the OS; the vault needs `unlock` first
+    pub fn is_locked(&self) -> bool {
+        match &self.backend {
+            Backend::Keyring => false,
+            Backend::Vault(vault) => vault.is_locked(),
+        }
+    }
+
+    /// Whether a vault file has been created (always false for the keyring backend)
+    pub fn vault_exists(&self) -> bool {
+        match &self.backend {
+            Backend::Keyring => false,
+            Backend::Vault(vault) => vault.exists(),
+        }
+    }
+
+    /// Unlock the vault, creating it with this passphrase if it doesn't exist yet
+    pub fn unlock(&self, passphrase: &str) -> Result<(), SecretError> {
+        match &self.backend {
+            Backend::Keyring => Ok(()),
+            Backend::Vault(vault) => vault.unlock(passphrase),
+        }
+    }
+
+    pub fn lock(&self) {
+        if let Backend::Vault(vault) = &self.backend {
+            vault.lock();
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Result<Option<String>, SecretError> {
+        match &self.backend {
+            Backend::Keyring => match keyring::Entry::new(KEYRING_SERVICE, key)?.get_password() {
+                Ok(value) => Ok(Some(value)),
+                Err(keyring::Error::NoEntry) => Ok(None),
+                Err(e) => Err(e.into()),
+            },
+            Backend::Vault(vault) => vault.get(key),
+        }
+    }
+
+    /// Store a secret; an empty value deletes it
+    pub fn set(&self, key: &str, value: &str) -> Result<(), SecretError> {
+        if value.is_empty() {
+            return self.delete(key);
+        }
+        match &self.backend {
+            Backend::Keyring => Ok(keyring::Entry::new(KEYRING_SERVICE, key)?.set_password(value)?),
+            Backend::Vault(vault) => vault.set(key, value),
+        }
+    }
+
+    pub fn delete(&self, key: &str) -> Result<(), SecretError> {
+        match &self.backend {
+            Backend::Keyring => match keyring::Entry::new(KEYRING_SERVICE, key)?.delete_credential() {
+                Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+                Err(e) => Err(e.into()),
+            },
+            Backend::Vault(vault) => vault.delete(key),
+        }
+    }
+}
+
+/// Probe the OS keyring; a missing entry still means the keyring works
+fn keyring_available() -> bool {
+    let probe = keyring::Entry::new(KEYRING_SERVICE, "availability-probe").and_then(|e| e.get_password());
+    match probe {
+        Ok(_) | Err(keyring::Error::NoEntry) => true,
+        Err(e) => {
+            log::warn!("OS keyring unavailable, falling back to encrypted vault: {}", e);
+            false
+        }
+    }
+}
+
+static SECRET_STORE: OnceCell<SecretStore> = OnceCell::new();
+
+/// Initialize the process-wide secret store. Called once during app setup.
+pub fn init_secret_store(app_data_dir: &Path) -> &'static SecretStore {
+    SECRET_STORE.get_or_init(|| {
+        let store = SecretStore::open(app_data_dir);
+        log::info!("Secret store initialized with {:?} backend", store.backend());
+        store
+    })
+}
+
+pub fn secret_store() -> Result<&'static SecretStore, SecretError> {
+    SECRET_STORE.get().ok_or(SecretError::NotInitialized)
+}
+
+/// Secret name for an API key that used to live in `{table}.{column}`
+pub fn secret_key(table: &str, column: &str) -> String {
+    format!("{}.{}", table, column)
+}
@@ -0,0 +1,219 @@
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use super::SecretError;
+
+const VAULT_VERSION: u32 = 1;
+const SALT_LEN: usize = 16;
+const MIN_PASSPHRASE_LEN: usize = 8;
+
+/// On-disk vault format. Only the salt and nonce are stored in the clear.
+#[derive(Serialize, Deserialize)]
+struct VaultFile {
+    version: u32,
+    kdf: String,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+struct Unlocked {
+    key: Key<Aes256Gcm>,
+    salt: Vec<u8>,
+    entries: BTreeMap<String, String>,
+}
+
+/// Passphrase-protected secret file (Argon2id key derivation, AES-256-GCM).
+///
+/// The whole key/value map is encrypted as one blob and re-encrypted with a fresh
+/// nonce on every write. The derived key is held in memory while unlocked.
+pub struct Vault {
+    path: PathBuf,
+    state: Mutex<Option<Unlocked>>,
+}
+
+impl Vault {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            state: Mutex::new(None),
+        }
+    }
+
+    pub fn exists(&self) -> bool {
+        self.path.exists()
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.state.lock().unwrap().is_none()
+    }
+
+    /// Decrypt the vault with `passphrase`, or create an empty vault if there is no file yet
+    pub fn unlock(&self, passphrase: &str) -> Result<(), SecretError> {
+        let unlocked = if self.exists() {
+            self.open(passphrase)?
+        } else {
+            if passphrase.chars().count() < MIN_PASSPHRASE_LEN {
+                return Err(SecretError::Vault(format!(
+                    "Passphrase must be at least {} characters",
+                    MIN_PASSPHRASE_LEN
+                )));
+            }
+            let mut salt = vec![0u8; SALT_LEN];
+            rand::rngs::OsRng.fill_bytes(&mut salt);
+            let unlocked = Unlocked {
+                key: derive_key(passphrase, &salt)?,
+                salt,
+                entries: BTreeMap::new(),
+            };
+            self.persist(&unlocked)?;
+            log::info!("Created encrypted secret vault at {:?}", self.path);
+            unlocked
+        };
+
+        *self.state.lock().unwrap() = Some(unlocked);
+        Ok(())
+    }
+
+    pub fn lock(&self) {
+        *self.state.lock().unwrap() = None;
+    }
+
+    pub fn get(&self, name: &str) -> Result<Option<String>, SecretError> {
+        let state = self.state.lock().unwrap();
+        let unlocked = state.as_ref().ok_or(SecretError::Locked)?;
+        Ok(unlocked.entries.get(name).cloned())
+    }
+
+    pub fn set(&self, name: &str, value: &str) -> Result<(), SecretError> {
+        let mut state = self.state.lock().unwrap();
+        let unlocked = state.as_mut().ok_or(SecretError::Locked)?;
+        unlocked.entries.insert(name.to_string(), value.to_string());
+        self.persist(unlocked)
+    }
+
+    pub fn delete(&self, name: &str) -> Result<(), SecretError> {
+        let mut state = self.state.lock().unwrap();
+        let unlocked = state.as_mut().ok_or(SecretError::Locked)?;
+        if unlocked.entries.remove(name).is_some() {
+            self.persist(unlocked)?;
+        }
+        Ok(())
+    }
+
+    fn open(&self, passphrase: &str) -> Result<Unlocked, SecretError> {
+        let content = std::fs::read_to_string(&self.path)?;
+        let file: VaultFile = serde_json::from_str(&content)
+            .map_err(|e| SecretError::Vault(format!("Corrupt vault file: {}", e)))?;
+        if file.version != VAULT_VERSION {
+            return Err(SecretError::Vault(format!("Unsupported vault version {}", file.version)));
+        }
+
+        let salt = decode(&file.salt)?;
+        let nonce = decode(&file.nonce)?;
+        let ciphertext = decode(&file.ciphertext)?;
+        if nonce.len() != 12 {
+            return Err(SecretError::Vault("Corrupt vault nonce".to_string()));
+        }
+
+        let key = derive_key(passphrase, &salt)?;
+        let plaintext = Aes256Gcm::new(&key)
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_ref())
+            .map_err(|_| SecretError::WrongPassphrase)?;
+        let entries = serde_json::from_slice(&plaintext)
+            .map_err(|e| SecretError::Vault(format!("Corrupt vault contents: {}", e)))?;
+
+        Ok(Unlocked { key, salt, entries })
+    }
+
+    /// Encrypt and atomically write the vault, readable by the current user only
+    fn persist(&self, unlocked: &Unlocked) -> Result<(), SecretError> {
+        let plaintext = serde_json::to_vec(&unlocked.entries)
+            .map_err(|e| SecretError::Vault(e.to_string()))?;
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = Aes256Gcm::new(&unlocked.key)
+            .encrypt(&nonce, plaintext.as_ref())
+            .map_err(|_| SecretError::Vault("Encryption failed".to_string()))?;
+
+        let file = VaultFile {
+            version: VAULT_VERSION,
+            kdf: "argon2id".to_string(),
+            salt: BASE64.encode(&unlocked.salt),
+            nonce: BASE64.encode(nonce),
+            ciphertext: BASE64.encode(ciphertext),
+        };
+        let json = serde_json::to_string_pretty(&file).map_err(|e| SecretError::Vault(e.to_string()))?;
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let tmp_path = self.path.with_extension("vault.tmp");
+        std::fs::write(&tmp_path, json)?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&tmp_path, std::fs::Permissions::from_mode(0o600))?;
+        }
+        std::fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<Key<Aes256Gcm>, SecretError> {
+    let mut key = Key::<Aes256Gcm>::default();
+    argon2::Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| SecretError::Vault(format!("Key derivation failed: {}", e)))?;
+    Ok(key)
+}
+
+fn decode(value: &str) -> Result<Vec<u8>, SecretError> {
+    BASE64
+        .decode(value)
+        .map_err(|e| SecretError::Vault(format!("Corrupt vault encoding: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vault_roundtrip_and_wrong_passphrase() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("secrets.vault");
+
+        let vault = Vault::new(path.clone());
+        assert!(matches!(vault.get("settings.openaiApiKey"), Err(SecretError::Locked)));
+        assert!(vault.unlock("short").is_err());
+
+        vault.unlock("correct horse battery").unwrap();
+        vault.set("settings.openaiApiKey", "sk-test-123").unwrap();
+        vault.set("settings.groqApiKey", "gsk-456").unwrap();
+        vault.delete("settings.groqApiKey").unwrap();
+
+        let on_disk = std::fs::read_to_string(&path).unwrap();
+        assert!(!on_disk.contains("sk-test-123"));
+        assert!(!on_disk.contains("openaiApiKey"));
+
+        let reopened = Vault::new(path);
+        assert!(matches!(
+            reopened.unlock("wrong passphrase"),
+            Err(SecretError::WrongPassphrase)
+        ));
+        reopened.unlock("correct horse battery").unwrap();
+        assert_eq!(
+            reopened.get("settings.openaiApiKey").unwrap().as_deref(),
+            Some("sk-test-123")
+        );
+        assert!(reopened.get("settings.groqApiKey").unwrap().is_none());
+
+        reopened.lock();
+        assert!(reopened.is_locked());
+    }
+}
@@ -0,0 +1,403 @@
+use crate::database::models::SearchSegment;
+use crate::database::repositories::{
+    embedding::SegmentEmbeddingsRepository, transcript::TranscriptsRepository,
+};
+use crate::semantic_search::hybrid::{
+    cosine_top_k, rank_meetings, reciprocal_rank_fusion, KEYWORD_CANDIDATES, MIN_SEMANTIC_SCORE,
+    SEMANTIC_CANDIDATES,
+};
+use crate::semantic_search::{SemanticSearchError, TextEmbedder};
+use crate::workspace::manager::WorkspaceManager;
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+
+/// Identifier stored with every vector; changing the model re-embeds all segments
+pub const EMBEDDING_MODEL_ID: &str = "all-MiniLM-L6-v2";
+/// all-MiniLM-L6-v2 sentence-embedding model (ONNX, ~90 MB) and its tokenizer
+const EMBEDDING_MODEL_FILE: &str = "all-MiniLM-L6-v2.onnx";
+const EMBEDDING_TOKENIZER_FILE: &str = "all-MiniLM-L6-v2.tokenizer.json";
+const EMBEDDING_MODEL_URL: &str =
+    "https://huggingface.co/sentence-transformers/all-MiniLM-L6-v2/resolve/main/onnx/model.onnx";
+const EMBEDDING_TOKENIZER_URL: &str =
+    "https://huggingface.co/sentence-transformers/all-MiniLM-L6-v2/resolve/main/tokenizer.json";
+
+/// Segments embedded per model call while indexing
+const INDEX_BATCH_SIZE: i64 = 32;
+/// Meetings returned by a search when the caller gives no limit
+const DEFAULT_MEETING_LIMIT: usize = 20;
+
+// Global models directory path (set during app initialization)
+static MODELS_DIR: Mutex<Option<PathBuf>> = Mutex::new(None);
+// Loaded lazily on first use and kept for the lifetime of the app
+static EMBEDDER: Mutex<Option<TextEmbedder>> = Mutex::new(None);
+// Only one indexing run at a time
+static INDEXING: AtomicBool = AtomicBool::new(false);
+
+/// Initialize the embedding models directory using app_data_dir
+/// This should be called during app setup
+pub fn set_models_directory<R: Runtime>(app: &AppHandle<R>) {
+    let app_data_dir = app.path().app_data_dir()
+        .expect("Failed to get app data dir");
+
+    let models_dir = app_data_dir.join("models").join("embeddings");
+
+    if !models_dir.exists() {
+        if let Err(e) = std::fs::create_dir_all(&models_dir) {
+            log::error!("Failed to create embedding models directory: {}", e);
+            return;
+        }
+    }
+
+    log::info!("Embedding models directory set to: {}", models_dir.display());
+
+    let mut guard = MODELS_DIR.lock().unwrap();
+    *guard = Some(models_dir);
+}
+
+/// (model path, tokenizer path)
+fn get_model_paths() -> Option<(PathBuf, PathBuf)> {
+    MODELS_DIR.lock().unwrap().as_ref().map(|dir| {
+        (
+            dir.join(EMBEDDING_MODEL_FILE),
+            dir.join(EMBEDDING_TOKENIZER_FILE),
+        )
+    })
+}
+
+fn is_model_downloaded() -> bool {
+    get_model_paths().is_some_and(|(model, tokenizer)| model.exists() && tokenizer.exists())
+}
+
+/// Embed texts on a blocking thread with the shared embedder, loading it on first use
+async fn embed_texts(texts: Vec<String>) -> Result<Vec<Vec<f32>>, String> {
+    tokio::task::spawn_blocking(move || {
+        let mut guard = EMBEDDER.lock().unwrap();
+        if guard.is_none() {
+            let (model_path, tokenizer_path) = get_model_paths()
+                .ok_or_else(|| "Embedding models directory not initialized".to_string())?;
+            let embedder = TextEmbedder::new(&model_path, &tokenizer_path)
+                .map_err(|e: SemanticSearchError| format!("Failed to load embedding model: {}", e))?;
+            *guard = Some(embedder);
+        }
+        let embedder = guard.as_mut().expect("embedder loaded above");
+        let texts: Vec<&str> = texts.iter().map(String::as_str).collect();
+        embedder
+            .embed_batch(&texts)
+            .map_err(|e| format!("Failed to embed text: {}", e))
+    })
+    .await
+    .map_err(|e| format!("Embedding task panicked: {}", e))?
+}
+
+/// Embed every segment of the workspace that has no vector yet
+///
+/// # Returns
+/// The number of segments embedded
+async fn index_missing_segments<R: Runtime>(app: &AppHandle<R>, pool: &SqlitePool) -> Result<i64, String> {
+    let (already_indexed, total) = SegmentEmbeddingsRepository::counts(pool, EMBEDDING_MODEL_ID)
+        .await
+        .map_err(|e| format!("Failed to count segments: {}", e))?;
+    let mut indexed = already_indexed;
+    let mut embedded = 0;
+
+    loop {
+        let pending = SegmentEmbeddingsRepository::missing_segments(pool, EMBEDDING_MODEL_ID, INDEX_BATCH_SIZE)
+            .await
+            .map_err(|e| format!("Failed to load segments: {}", e))?;
+        if pending.is_empty() {
+            break;
+        }
+
+        let vectors = embed_texts(pending.iter().map(|s| s.text.clone()).collect()).await?;
+        let batch: Vec<_> = pending.into_iter().zip(vectors).collect();
+        SegmentEmbeddingsRepository::save_embeddings(pool, EMBEDDING_MODEL_ID, &batch)
+            .await
+            .map_err(|e| format!("Failed to save embeddings: {}", e))?;
+
+        embedded += batch.len() as i64;
+        indexed += batch.len() as i64;
+        let _ = app.emit(
+            "semantic-index-progress",
+            serde_json::json!({
+                "indexed": indexed.min(total),
+                "total": total,
+                "status": "indexing"
+            }),
+        );
+    }
+
+    Ok(embedded)
+}
+
+#[command]
+pub async fn semantic_search_get_model_status() -> Result<serde_json::Value, String> {
+    let (model_path, _) = get_model_paths().ok_or_else(|| "Embedding models directory not initialized".to_string())?;
+    Ok(serde_json::json!({
+        "modelName": EMBEDDING_MODEL_ID,
+        "path": model_path.to_string_lossy(),
+        "downloaded": is_model_downloaded(),
+    }))
+}
+
+#[command]
+pub async fn semantic_search_download_model<R: Runtime>(app_handle: AppHandle<R>) -> Result<(), String> {
+    use futures_util::StreamExt;
+    use tokio::io::AsyncWriteExt;
+
+    let (model_path, tokenizer_path) =
+        get_model_paths().ok_or_else(|| "Embedding models directory not initialized".to_string())?;
+
+    // The tokenizer is small; the model drives the progress events
+    for (url, path, report_progress) in [
+        (EMBEDDING_TOKENIZER_URL, tokenizer_path, false),
+        (EMBEDDING_MODEL_URL, model_path, true),
+    ] {
+        if path.exists() {
+            continue;
+        }
+
+        log::info!("Downloading text embedding model file from {}", url);
+        let response = reqwest::get(url)
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| format!("Failed to download embedding model: {}", e))?;
+        let total_bytes = response.content_length().unwrap_or(0);
+
+        let temp_path = path.with_extension("tmp");
+        let mut file = tokio::fs::File::create(&temp_path)
+            .await
+            .map_err(|e| format!("Failed to create model file: {}", e))?;
+
+        let mut stream = response.bytes_stream();
+        let mut downloaded: u64 = 0;
+        let mut last_percent = 0;
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(|e| format!("Download interrupted: {}", e))?;
+            file.write_all(&chunk)
+                .await
+                .map_err(|e| format!("Failed to write model file: {}", e))?;
+            downloaded += chunk.len() as u64;
+
+            let percent = if total_bytes > 0 { (downloaded * 100 / total_bytes) as u8 } else { 0 };
+            if report_progress && percent != last_percent {
+                last_percent = percent;
+                let _ = app_handle.emit(
+                    "embedding-model-download-progress",
+                    serde_json::json!({
+                        "progress": percent,
+                        "downloaded_bytes": downloaded,
+                        "total_bytes": total_bytes,
+                        "status": "downloading"
+                    }),
+                );
+            }
+        }
+        file.flush().await.map_err(|e| e.to_string())?;
+        drop(file);
+
+        tokio::fs::rename(&temp_path, &path)
+            .await
+            .map_err(|e| format!("Failed to finalize model file: {}", e))?;
+    }
+
+    let _ = app_handle.emit(
+        "embedding-model-download-progress",
+        serde_json::json!({
+            "progress": 100,
+            "status": "completed"
+        }),
+    );
+    log::info!("Text embedding model downloaded");
+    Ok(())
+}
+
+/// Start embedding the active workspace's segments that have no vector yet
+///
+/// Runs in the background and reports `semantic-index-progress` events
+/// ({indexed, total, status}); status ends as "completed" or "error".
+#[command]
+pub async fn api_build_semantic_index<R: Runtime>(
+    app: AppHandle<R>,
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+) -> Result<(), String> {
+    if !is_model_downloaded() {
+        return Err("Text embedding model is not downloaded".to_string());
+    }
+    let pool = workspace_mgr.active_pool().await?;
+    if INDEXING.swap(true, Ordering::SeqCst) {
+        return Err("Semantic indexing is already running".to_string());
+    }
+
+    tauri::async_runtime::spawn(async move {
+        let result = index_missing_segments(&app, &pool).await;
+        INDEXING.store(false, Ordering::SeqCst);
+
+        let (indexed, total) = SegmentEmbeddingsRepository::counts(&pool, EMBEDDING_MODEL_ID)
+            .await
+            .unwrap_or((0, 0));
+        let (status, error) = match result {
+            Ok(embedded) => {
+                log::info!("Semantic index updated: embedded {} segments", embedded);
+                ("completed", None)
+            }
+            Err(e) => {
+                log::error!("Semantic indexing failed: {}", e);
+                ("error", Some(e))
+            }
+        };
+        let _ = app.emit(
+            "semantic-index-progress",
+            serde_json::json!({
+                "indexed": indexed,
+                "total": total,
+                "status": status,
+                "error": error
+            }),
+        );
+    });
+    Ok(())
+}
+
+/// A matching segment of a search result
+#[derive(Debug, Serialize)]
+pub struct SegmentSearchResult {
+    pub transcript_id: String,
+    pub text: String,
+    pub audio_start_time: Option<f64>,
+    pub audio_end_time: Option<f64>,
+    pub speaker_label: Option<String>,
+    pub score: f32,
+}
+
+/// A meeting matching a search, with its best segments
+#[derive(Debug, Serialize)]
+pub struct MeetingSearchResult {
+    pub meeting_id: String,
+    pub title: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub score: f32,
+    pub segments: Vec<SegmentSearchResult>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SemanticSearchResponse {
+    pub meetings: Vec<MeetingSearchResult>,
+    /// False when only keyword ranking was used (model missing or query not embeddable)
+    pub semantic: bool,
+    pub indexed_segments: i64,
+    pub total_segments: i64,
+}
+
+/// Searches all meetings of the active workspace by meaning and keywords
+///
+/// # Arguments
+/// * `query` - Natural-language query
+/// * `limit` - Maximum number of meetings to return (default 20)
+///
+/// # Returns
+/// Meetings ranked by their best matching segments. Segments that are not indexed
+/// yet can still be found by keyword.
+#[command]
+pub async fn api_semantic_search(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    query: String,
+    limit: Option<usize>,
+) -> Result<SemanticSearchResponse, String> {
+    log::info!("api_semantic_search called");
+    let query = query.trim();
+    let pool = workspace_mgr.active_pool().await?;
+    let (indexed_segments, total_segments) =
+        SegmentEmbeddingsRepository::counts(&pool, EMBEDDING_MODEL_ID)
+            .await
+            .map_err(|e| format!("Failed to count indexed segments: {}", e))?;
+    if query.is_empty() {
+        return Ok(SemanticSearchResponse {
+            meetings: Vec::new(),
+            semantic: false,
+            indexed_segments,
+            total_segments,
+        });
+    }
+
+    let keyword_ranking = TranscriptsRepository::rank_segments_by_keywords(&pool, query, KEYWORD_CANDIDATES)
+        .await
+        .map_err(|e| format!("Keyword search failed: {}", e))?;
+
+    let mut semantic_ranking = None;
+    if is_model_downloaded() && indexed_segments > 0 {
+        match embed_texts(vec![query.to_string()]).await {
+            Ok(mut vectors) => {
+                let query_vector = vectors.pop().unwrap_or_default();
+                let stored = SegmentEmbeddingsRepository::load_all(&pool, EMBEDDING_MODEL_ID)
+                    .await
+                    .map_err(|e| format!("Failed to load embeddings: {}", e))?;
+                let top = cosine_top_k(&query_vector, &stored, SEMANTIC_CANDIDATES, MIN_SEMANTIC_SCORE);
+                semantic_ranking = Some(top.into_iter().map(|(id, _)| id).collect::<Vec<_>>());
+            }
+            Err(e) => log::warn!("Falling back to keyword search: {}", e),
+        }
+    }
+    let semantic = semantic_ranking.is_some();
+
+    let mut rankings = Vec::with_capacity(2);
+    rankings.extend(semantic_ranking);
+    rankings.push(keyword_ranking);
+    let fused = reciprocal_rank_fusion(&rankings);
+
+    let ids: Vec<String> = fused.iter().map(|(id, _)| id.clone()).collect();
+    let segments: HashMap<String, SearchSegment> = SegmentEmbeddingsRepository::get_segments(&pool, &ids)
+        .await
+        .map_err(|e| format!("Failed to load matching segments: {}", e))?
+        .into_iter()
+        .map(|segment| (segment.transcript_id.clone(), segment))
+        .collect();
+    let meeting_of: HashMap<String, String> = segments
+        .values()
+        .map(|s| (s.transcript_id.clone(), s.meeting_id.clone()))
+        .collect();
+
+    let meetings = rank_meetings(&fused, &meeting_of, limit.unwrap_or(DEFAULT_MEETING_LIMIT))
+        .into_iter()
+        .filter_map(|ranked| {
+            let segment_results: Vec<SegmentSearchResult> = ranked
+                .segments
+                .iter()
+                .filter_map(|(id, score)| {
+                    segments.get(id).map(|s| SegmentSearchResult {
+                        transcript_id: s.transcript_id.clone(),
+                        text: s.text.clone(),
+                        audio_start_time: s.audio_start_time,
+                        audio_end_time: s.audio_end_time,
+                        speaker_label: s.speaker_label.clone(),
+                        score: *score,
+                    })
+                })
+                .collect();
+            let first = segments.get(&segment_results.first()?.transcript_id)?;
+            Some(MeetingSearchResult {
+                meeting_id: ranked.meeting_id,
+                title: first.meeting_title.clone(),
+                created_at: first.meeting_created_at,
+                score: ranked.score,
+                segments: segment_results,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    log::info!(
+        "Semantic search returned {} meetings (semantic: {})",
+        meetings.len(),
+        semantic
+    );
+    Ok(SemanticSearchResponse {
+        meetings,
+        semantic,
+        indexed_segments,
+        total_segments,
+    })
+}
@@ -0,0 +1,148 @@
+use ndarray::{Array2, Ix3};
+use ort::execution_providers::CPUExecutionProvider;
+use ort::inputs;
+use ort::session::builder::GraphOptimizationLevel;
+use ort::session::Session;
+use ort::value::TensorRef;
+use std::path::Path;
+use tokenizers::{PaddingParams, PaddingStrategy, Tokenizer, TruncationParams};
+
+use super::SemanticSearchError;
+
+/// Longest input in word pieces; MiniLM was trained on 256-token sequences
+pub const MAX_SEQUENCE_TOKENS: usize = 256;
+
+/// ONNX sentence-embedding model (all-MiniLM-L6-v2, mean pooled, 384-dim output)
+pub struct TextEmbedder {
+    session: Session,
+    tokenizer: Tokenizer,
+    output_name: String,
+    // BERT exports take token_type_ids, some other exports do not
+    uses_token_type_ids: bool,
+}
+
+impl TextEmbedder {
+    pub fn new(model_path: &Path, tokenizer_path: &Path) -> Result<Self, SemanticSearchError> {
+        for path in [model_path, tokenizer_path] {
+            if !path.exists() {
+                return Err(SemanticSearchError::ModelNotFound(
+                    path.display().to_string(),
+                ));
+            }
+        }
+
+        let mut tokenizer = Tokenizer::from_file(tokenizer_path)
+            .map_err(|e| SemanticSearchError::Tokenizer(e.to_string()))?;
+        tokenizer
+            .with_truncation(Some(TruncationParams {
+                max_length: MAX_SEQUENCE_TOKENS,
+                ..Default::default()
+            }))
+            .map_err(|e| SemanticSearchError::Tokenizer(e.to_string()))?;
+        tokenizer.with_padding(Some(PaddingParams {
+            strategy: PaddingStrategy::BatchLongest,
+            ..Default::default()
+        }));
+
+        log::info!("Loading text embedding model from {}", model_path.display());
+        let session = Session::builder()?
+            .with_optimization_level(GraphOptimizationLevel::Level3)?
+            .with_execution_providers(vec![CPUExecutionProvider::default().build()])?
+            .commit_from_file(model_path)?;
+
+        let input_names: Vec<&str> = session.inputs.iter().map(|i| i.name.as_str()).collect();
+        for required in ["input_ids", "attention_mask"] {
+            if !input_names.contains(&required) {
+                return Err(SemanticSearchError::InvalidModel(format!(
+                    "missing input {}",
+                    required
+                )));
+            }
+        }
+        let uses_token_type_ids = input_names.contains(&"token_type_ids");
+        let output_name = session
+            .outputs
+            .first()
+            .map(|o| o.name.clone())
+            .ok_or_else(|| SemanticSearchError::InvalidModel("model has no outputs".to_string()))?;
+
+        Ok(Self {
+            session,
+            tokenizer,
+            output_name,
+            uses_token_type_ids,
+        })
+    }
+
+    /// Compute L2-normalized sentence embeddings, one per input text
+    pub fn embed_batch(&mut self, texts: &[&str]) -> Result<Vec<Vec<f32>>, SemanticSearchError> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let encodings = self
+            .tokenizer
+            .encode_batch(texts.to_vec(), true)
+            .map_err(|e| SemanticSearchError::Tokenizer(e.to_string()))?;
+        let sequence_len = encodings.iter().map(|e| e.len()).max().unwrap_or(0);
+
+        let mut input_ids = Array2::<i64>::zeros((texts.len(), sequence_len));
+        let mut attention_mask = Array2::<i64>::zeros((texts.len(), sequence_len));
+        let mut token_type_ids = Array2::<i64>::zeros((texts.len(), sequence_len));
+        for (row, encoding) in encodings.iter().enumerate() {
+            for (col, ((&id, &mask), &type_id)) in encoding
+                .get_ids()
+                .iter()
+                .zip(encoding.get_attention_mask())
+                .zip(encoding.get_type_ids())
+                .enumerate()
+            {
+                input_ids[[row, col]] = id as i64;
+                attention_mask[[row, col]] = mask as i64;
+                token_type_ids[[row, col]] = type_id as i64;
+            }
+        }
+
+        let outputs = if self.uses_token_type_ids {
+            self.session.run(inputs![
+                "input_ids" => TensorRef::from_array_view(input_ids.view())?,
+                "attention_mask" => TensorRef::from_array_view(attention_mask.view())?,
+                "token_type_ids" => TensorRef::from_array_view(token_type_ids.view())?,
+            ])?
+        } else {
+            self.session.run(inputs![
+                "input_ids" => TensorRef::from_array_view(input_ids.view())?,
+                "attention_mask" => TensorRef::from_array_view(attention_mask.view())?,
+            ])?
+        };
+
+        let hidden = outputs
+            .get(self.output_name.as_str())
+            .ok_or_else(|| SemanticSearchError::InvalidModel(format!("missing output {}", self.output_name)))?
+            .try_extract_array::<f32>()?
+            .into_dimensionality::<Ix3>()?;
+
+        // Mean over the real (unpadded) tokens of each text; normalizing the sum yields
+        // the same unit vector as normalizing the mean
+        let mut embeddings = Vec::with_capacity(texts.len());
+        for (row, token_states) in hidden.outer_iter().enumerate() {
+            let mut pooled = vec![0.0f32; token_states.shape()[1]];
+            let mut count = 0.0f32;
+            for (col, state) in token_states.outer_iter().enumerate() {
+                if attention_mask[[row, col]] == 0 {
+                    continue;
+                }
+                pooled.iter_mut().zip(state.iter()).for_each(|(p, v)| *p += v);
+                count += 1.0;
+            }
+
+            let norm = pooled.iter().map(|v| v * v).sum::<f32>().sqrt();
+            if count > 0.0 && norm > f32::EPSILON {
+                pooled.iter_mut().for_each(|v| *v /= norm);
+            }
+            embeddings.push(pooled);
+        }
+
+        Ok(embeddings)
+    }
+}
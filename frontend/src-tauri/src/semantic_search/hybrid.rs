@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+
+/// Semantic candidates considered per query
+pub const SEMANTIC_CANDIDATES: usize = 50;
+/// Keyword candidates considered per query
+pub const KEYWORD_CANDIDATES: i64 = 50;
+/// Cosine similarity below which a segment is not considered related at all
+pub const MIN_SEMANTIC_SCORE: f32 = 0.25;
+/// Reciprocal rank fusion constant; dampens the advantage of the very first ranks
+const RRF_K: f32 = 60.0;
+/// A meeting is scored by its best few segments so one long meeting with many
+/// weak matches does not outrank a short one with a strong match
+const SEGMENTS_SCORED_PER_MEETING: usize = 3;
+/// Segments returned with each meeting
+pub const SEGMENTS_PER_MEETING: usize = 5;
+
+/// A meeting ranked by its matching segments, best segment first
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankedMeeting {
+    pub meeting_id: String,
+    pub score: f32,
+    pub segments: Vec<(String, f32)>,
+}
+
+/// The `k` stored vectors most similar to `query`, best first.
+///
+/// Vectors are L2-normalized, so the dot product is their cosine similarity.
+/// Vectors of another dimension (a different model) are ignored.
+pub fn cosine_top_k(
+    query: &[f32],
+    vectors: &[(String, Vec<f32>)],
+    k: usize,
+    min_score: f32,
+) -> Vec<(String, f32)> {
+    let mut scored: Vec<(String, f32)> = vectors
+        .iter()
+        .filter(|(_, vector)| vector.len() == query.len())
+        .map(|(id, vector)| {
+            let score = vector.iter().zip(query).map(|(a, b)| a * b).sum::<f32>();
+            (id.clone(), score)
+        })
+        .filter(|(_, score)| *score >= min_score)
+        .collect();
+    scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    scored.truncate(k);
+    scored
+}
+
+/// Merge several rankings of the same items with reciprocal rank fusion, best first.
+///
+/// Each ranking contributes `1 / (RRF_K + rank)` per item, so items found by both
+/// semantic and keyword search rise above items found by only one of them.
+pub fn reciprocal_rank_fusion(rankings: &[Vec<String>]) -> Vec<(String, f32)> {
+    let mut scores: HashMap<&str, f32> = HashMap::new();
+    let mut order: Vec<&str> = Vec::new();
+    for ranking in rankings {
+        for (rank, id) in ranking.iter().enumerate() {
+            let score = scores.entry(id.as_str()).or_insert_with(|| {
+                order.push(id.as_str());
+                0.0
+            });
+            *score += 1.0 / (RRF_K + rank as f32 + 1.0);
+        }
+    }
+
+    let mut fused: Vec<(String, f32)> = order
+        .into_iter()
+        .map(|id| (id.to_string(), scores[id]))
+        .collect();
+    // Stable sort keeps first-seen order for ties
+    fused.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    fused
+}
+
+/// Group fused segment scores by meeting and rank the meetings, best first
+pub fn rank_meetings(
+    fused: &[(String, f32)],
+    meeting_of: &HashMap<String, String>,
+    limit: usize,
+) -> Vec<RankedMeeting> {
+    let mut meetings: Vec<RankedMeeting> = Vec::new();
+    let mut index: HashMap<&str, usize> = HashMap::new();
+    // `fused` is sorted, so each meeting's segments arrive best first
+    for (segment_id, score) in fused {
+        let Some(meeting_id) = meeting_of.get(segment_id) else {
+            continue;
+        };
+        let position = *index.entry(meeting_id.as_str()).or_insert_with(|| {
+            meetings.push(RankedMeeting {
+                meeting_id: meeting_id.clone(),
+                score: 0.0,
+                segments: Vec::new(),
+            });
+            meetings.len() - 1
+        });
+        let meeting = &mut meetings[position];
+        if meeting.segments.len() < SEGMENTS_SCORED_PER_MEETING {
+            meeting.score += score;
+        }
+        if meeting.segments.len() < SEGMENTS_PER_MEETING {
+            meeting.segments.push((segment_id.clone(), *score));
+        }
+    }
+
+    meetings.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+    meetings.truncate(limit);
+    meetings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(items: &[(String, f32)]) -> Vec<&str> {
+        items.iter().map(|(id, _)| id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_cosine_top_k_ranks_and_filters() {
+        let vectors = vec![
+            ("orthogonal".to_string(), vec![0.0, 1.0]),
+            ("close".to_string(), vec![0.8, 0.6]),
+            ("exact".to_string(), vec![1.0, 0.0]),
+            ("other-model".to_string(), vec![1.0, 0.0, 0.0]),
+        ];
+        let top = cosine_top_k(&[1.0, 0.0], &vectors, 5, MIN_SEMANTIC_SCORE);
+        assert_eq!(ids(&top), vec!["exact", "close"]);
+        assert!((top[1].1 - 0.8).abs() < 1e-6);
+
+        assert_eq!(ids(&cosine_top_k(&[1.0, 0.0], &vectors, 1, 0.0)), vec!["exact"]);
+    }
+
+    #[test]
+    fn test_fusion_favours_items_found_by_both() {
+        let semantic = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let keyword = vec!["c".to_string(), "d".to_string()];
+        let fused = reciprocal_rank_fusion(&[semantic, keyword]);
+        assert_eq!(ids(&fused), vec!["c", "a", "b", "d"]);
+    }
+
+    #[test]
+    fn test_meetings_are_scored_by_their_best_segments() {
+        let mut fused = vec![("f1".to_string(), 0.5), ("f2".to_string(), 0.2)];
+        let mut meeting_of: HashMap<String, String> = HashMap::new();
+        meeting_of.insert("f1".to_string(), "focused".to_string());
+        meeting_of.insert("f2".to_string(), "focused".to_string());
+        // Many weak matches in one long meeting
+        for i in 0..8 {
+            let id = format!("l{}", i);
+            fused.push((id.clone(), 0.15));
+            meeting_of.insert(id, "long".to_string());
+        }
+        fused.push(("orphan".to_string(), 0.9));
+
+        let ranked = rank_meetings(&fused, &meeting_of, 10);
+        let meeting_ids: Vec<&str> = ranked.iter().map(|m| m.meeting_id.as_str()).collect();
+        assert_eq!(meeting_ids, vec!["focused", "long"]);
+        assert!((ranked[0].score - 0.7).abs() < 1e-6);
+        assert!((ranked[1].score - 0.45).abs() < 1e-6);
+        assert_eq!(ranked[1].segments.len(), SEGMENTS_PER_MEETING);
+
+        assert_eq!(rank_meetings(&fused, &meeting_of, 1).len(), 1);
+    }
+}
@@ -0,0 +1,35 @@
+//! Cross-meeting semantic search.
+//!
+//! Transcript segments are embedded locally with a sentence-embedding ONNX model
+//! (all-MiniLM-L6-v2) and the vectors are stored in the workspace database. Searches
+//! fuse cosine similarity over those vectors with FTS5 keyword ranking, so a query
+//! finds meetings that talk about a topic without using its exact words.
+//!
+//! # Module Structure
+//!
+//! - `embedder`: ONNX sentence-embedding model and tokenizer wrapper
+//! - `hybrid`: Cosine ranking, reciprocal rank fusion and per-meeting grouping
+//! - `commands`: Tauri command interface, model download and background indexing
+
+pub mod embedder;
+pub mod hybrid;
+pub mod commands;
+
+#[derive(thiserror::Error, Debug)]
+pub enum SemanticSearchError {
+    #[error("ORT error")]
+    Ort(#[from] ort::Error),
+    #[error("I/O error")]
+    Io(#[from] std::io::Error),
+    #[error("ndarray shape error")]
+    Shape(#[from] ndarray::ShapeError),
+    #[error("Text embedding model not found: {0}")]
+    ModelNotFound(String),
+    #[error("Invalid text embedding model: {0}")]
+    InvalidModel(String),
+    #[error("Tokenizer error: {0}")]
+    Tokenizer(String),
+}
+
+pub use embedder::TextEmbedder;
+pub use commands::*;
@@ -0,0 +1,76 @@
+use crate::database::models::{ActionItem, Decision};
+use crate::database::repositories::action_item::{
+    ActionItemFilter, ActionItemsRepository, DecisionFilter, ACTION_ITEM_STATUSES,
+};
+use crate::workspace::manager::WorkspaceManager;
+use tracing::{error, info};
+
+/// Lists action items across all meetings of the active workspace
+///
+/// # Arguments
+/// * `filter` - Optional status / owner / meeting / due date / text filters
+#[tauri::command]
+pub async fn api_list_action_items(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    filter: Option<ActionItemFilter>,
+) -> Result<Vec<ActionItem>, String> {
+    let filter = filter.unwrap_or_default();
+    info!("api_list_action_items called with filter: {:?}", filter);
+
+    if let Some(status) = filter.status.as_deref().filter(|s| !s.trim().is_empty()) {
+        if !ACTION_ITEM_STATUSES.contains(&status) {
+            return Err(format!(
+                "Invalid action item status '{}'. Must be 'open' or 'done'",
+                status
+            ));
+        }
+    }
+
+    let pool = workspace_mgr.active_pool().await?;
+    ActionItemsRepository::list_action_items(&pool, &filter)
+        .await
+        .map_err(|e| {
+            error!("Failed to list action items: {}", e);
+            format!("Failed to list action items: {}", e)
+        })
+}
+
+/// Marks an action item done, or reopens it with `completed: false`
+#[tauri::command]
+pub async fn api_complete_action_item(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    id: i64,
+    completed: bool,
+) -> Result<ActionItem, String> {
+    info!(
+        "api_complete_action_item called for id: {}, completed: {}",
+        id, completed
+    );
+
+    let pool = workspace_mgr.active_pool().await?;
+    ActionItemsRepository::set_completed(&pool, id, completed)
+        .await
+        .map_err(|e| {
+            error!("Failed to update action item {}: {}", id, e);
+            format!("Failed to update action item: {}", e)
+        })?
+        .ok_or_else(|| format!("Action item not found: {}", id))
+}
+
+/// Lists decisions across all meetings of the active workspace
+#[tauri::command]
+pub async fn api_list_decisions(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    filter: Option<DecisionFilter>,
+) -> Result<Vec<Decision>, String> {
+    let filter = filter.unwrap_or_default();
+    info!("api_list_decisions called with filter: {:?}", filter);
+
+    let pool = workspace_mgr.active_pool().await?;
+    ActionItemsRepository::list_decisions(&pool, &filter)
+        .await
+        .map_err(|e| {
+            error!("Failed to list decisions: {}", e);
+            format!("Failed to list decisions: {}", e)
+        })
+}
@@ -0,0 +1,200 @@
+use crate::database::models::{ChatCitation, MeetingChatMessage};
+use crate::database::repositories::{
+    chat::MeetingChatRepository, meeting::MeetingsRepository, setting::SettingsRepository,
+    speaker::SpeakersRepository, transcript::TranscriptsRepository,
+};
+use crate::summary::chat::{
+    answer_question, CLOUD_EXCERPT_TOKEN_BUDGET, PROMPT_OVERHEAD_TOKENS,
+    MAX_HISTORY_MESSAGES,
+};
+use crate::summary::providers::get_provider;
+use crate::workspace::manager::WorkspaceManager;
+use serde::Serialize;
+use std::collections::HashMap;
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+use tracing::{error, info, warn};
+
+/// A chat message as returned to the frontend, with its citations parsed
+#[derive(Debug, Serialize)]
+pub struct MeetingChatEntry {
+    pub id: i64,
+    pub role: String,
+    pub content: String,
+    pub citations: Vec<ChatCitation>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<MeetingChatMessage> for MeetingChatEntry {
+    fn from(message: MeetingChatMessage) -> Self {
+        Self {
+            citations: message.citations(),
+            id: message.id,
+            role: message.role,
+            content: message.content,
+            created_at: message.created_at,
+        }
+    }
+}
+
+/// Payload of the `meeting-chat-progress` event, emitted as the answer streams in
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MeetingChatProgressEvent<'a> {
+    meeting_id: &'a str,
+    delta: &'a str,
+}
+
+/// Answers a question about a meeting from its transcript and stores the exchange
+///
+/// # Arguments
+/// * `meeting_id` - Meeting to ask about
+/// * `question` - The user's question
+/// * `provider` / `model` - LLM to use; defaults to the saved summary model
+///
+/// # Returns
+/// The stored answer with the transcript segments it cites. The answer is also streamed
+/// as `meeting-chat-progress` events.
+#[tauri::command]
+pub async fn api_ask_meeting<R: Runtime>(
+    app: AppHandle<R>,
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    meeting_id: String,
+    question: String,
+    provider: Option<String>,
+    model: Option<String>,
+) -> Result<MeetingChatEntry, String> {
+    info!("api_ask_meeting called for meeting_id: {}", meeting_id);
+    let question = question.trim();
+    if question.is_empty() {
+        return Err("Question cannot be empty".to_string());
+    }
+
+    let pool = workspace_mgr.active_pool().await?;
+    let settings_pool = workspace_mgr.global_pool();
+
+    match MeetingsRepository::get_meeting_metadata(&pool, &meeting_id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => return Err(format!("No meeting found with id {}", meeting_id)),
+        Err(e) => return Err(format!("Failed to load meeting: {}", e)),
+    }
+
+    // Fall back to the saved summary model for whatever was not given
+    let saved = SettingsRepository::get_model_config(settings_pool)
+        .await
+        .map_err(|e| format!("Failed to load model config: {}", e))?;
+    let provider_id = provider
+        .or_else(|| saved.as_ref().map(|s| s.provider.clone()))
+        .ok_or("No LLM provider configured")?;
+    let mut model_name = model
+        .or_else(|| saved.as_ref().map(|s| s.model.clone()))
+        .unwrap_or_default();
+    if provider_id == "custom-openai" && model_name.is_empty() {
+        if let Ok(Some(custom)) = SettingsRepository::get_custom_openai_config(settings_pool).await {
+            model_name = custom.model;
+        }
+    }
+    if model_name.is_empty() {
+        return Err(format!("No model configured for {}", provider_id));
+    }
+
+    let llm_provider = get_provider(&provider_id)?;
+    let mut config = llm_provider.load_config(settings_pool).await?;
+    config.app_data_dir = app.path().app_data_dir().ok();
+
+    // Local models get as much transcript as their context window allows; cloud models
+    // are capped to keep requests fast and cheap
+    let context_size = llm_provider.context_size(&config, &model_name).await;
+    let mut token_budget = context_size
+        .saturating_sub(PROMPT_OVERHEAD_TOKENS)
+        .max(256);
+    if !llm_provider.is_local() {
+        token_budget = token_budget.min(CLOUD_EXCERPT_TOKEN_BUDGET);
+    }
+
+    let transcripts = TranscriptsRepository::get_transcripts_for_meeting(&pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load transcripts: {}", e))?;
+    let speaker_names = SpeakersRepository::get_speaker_names(&pool, &meeting_id)
+        .await
+        .unwrap_or_else(|e| {
+            warn!("Failed to load speaker names: {}", e);
+            HashMap::new()
+        });
+    let history = MeetingChatRepository::get_recent_messages(&pool, &meeting_id, MAX_HISTORY_MESSAGES)
+        .await
+        .map_err(|e| format!("Failed to load chat history: {}", e))?;
+
+    let on_token = |delta: &str| {
+        let event = MeetingChatProgressEvent {
+            meeting_id: &meeting_id,
+            delta,
+        };
+        if let Err(e) = app.emit("meeting-chat-progress", event) {
+            warn!("Failed to emit meeting-chat-progress event: {}", e);
+        }
+    };
+
+    let (answer, citations) = answer_question(
+        &reqwest::Client::new(),
+        llm_provider.as_ref(),
+        &config,
+        &model_name,
+        &transcripts,
+        &speaker_names,
+        &history,
+        question,
+        token_budget,
+        Some(&on_token),
+    )
+    .await
+    .map_err(|e| {
+        error!("Failed to answer question for meeting {}: {}", meeting_id, e);
+        e
+    })?;
+
+    let (_, answer) =
+        MeetingChatRepository::add_exchange(&pool, &meeting_id, question, &answer, &citations)
+            .await
+            .map_err(|e| format!("Failed to save chat message: {}", e))?;
+
+    info!(
+        "Answered question for meeting {} with {} citations",
+        meeting_id,
+        citations.len()
+    );
+    Ok(answer.into())
+}
+
+/// Gets the chat history of a meeting, oldest first
+#[tauri::command]
+pub async fn api_get_meeting_chat(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    meeting_id: String,
+) -> Result<Vec<MeetingChatEntry>, String> {
+    info!("api_get_meeting_chat called for meeting_id: {}", meeting_id);
+    let pool = workspace_mgr.active_pool().await?;
+    MeetingChatRepository::get_messages(&pool, &meeting_id)
+        .await
+        .map(|messages| messages.into_iter().map(MeetingChatEntry::from).collect())
+        .map_err(|e| {
+            error!("Failed to load chat for meeting {}: {}", meeting_id, e);
+            format!("Failed to load meeting chat: {}", e)
+        })
+}
+
+/// Deletes the chat history of a meeting
+#[tauri::command]
+pub async fn api_clear_meeting_chat(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    meeting_id: String,
+) -> Result<(), String> {
+    info!("api_clear_meeting_chat called for meeting_id: {}", meeting_id);
+    let pool = workspace_mgr.active_pool().await?;
+    MeetingChatRepository::clear(&pool, &meeting_id)
+        .await
+        .map(|_| ())
+        .map_err(|e| {
+            error!("Failed to clear chat for meeting {}: {}", meeting_id, e);
+            format!("Failed to clear meeting chat: {}", e)
+        })
+}
@@ -1,12 +1,14 @@
 use crate::database::repositories::{
-    meeting::MeetingsRepository, summary::SummaryProcessesRepository,
+    meeting::MeetingsRepository, setting::SettingsRepository, summary::SummaryProcessesRepository,
     transcript_chunk::TranscriptChunksRepository,
 };
 use crate::workspace::manager::WorkspaceManager;
+use crate::summary::providers::{get_provider, LlmProvider, ProviderConfig};
 use crate::summary::service::SummaryService;
+use crate::summary::SummaryFallbackConfig;
 use log::{error as log_error, info as log_info, warn as log_warn};
 use serde::{Deserialize, Serialize};
-use tauri::{AppHandle, Runtime};
+use tauri::{AppHandle, Manager, Runtime};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SummaryResponse {
@@ -187,6 +189,7 @@ pub async fn api_process_transcript<R: Runtime>(
     );
 
     let pool = workspace_mgr.active_pool().await?;
+    let settings_pool = workspace_mgr.global_pool().clone();
     let final_prompt = custom_prompt.unwrap_or_else(|| "".to_string());
     let final_template_id = template_id.unwrap_or_else(|| "daily_standup".to_string());
 
@@ -221,6 +224,7 @@ pub async fn api_process_transcript<R: Runtime>(
         SummaryService::process_transcript_background(
             app,
             pool,
+            settings_pool,
             meeting_id_clone.clone(),
             text,
             model,
@@ -275,3 +279,102 @@ pub async fn api_cancel_summary<R: Runtime>(
         }))
     }
 }
+
+/// Loads a provider and its saved settings for the model listing / connection test commands
+async fn provider_with_config<R: Runtime>(
+    app: &AppHandle<R>,
+    workspace_mgr: &WorkspaceManager,
+    provider: &str,
+) -> Result<(std::sync::Arc<dyn LlmProvider>, ProviderConfig), String> {
+    let llm_provider = get_provider(provider)?;
+    let mut config = llm_provider.load_config(workspace_mgr.global_pool()).await?;
+    config.app_data_dir = app.path().app_data_dir().ok();
+    Ok((llm_provider, config))
+}
+
+/// Lists the models a summary provider offers, using its saved settings
+#[tauri::command]
+pub async fn api_list_llm_models<R: Runtime>(
+    app: AppHandle<R>,
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    provider: String,
+) -> Result<Vec<String>, String> {
+    log_info!("api_list_llm_models called for provider: {}", provider);
+    let (llm_provider, config) = provider_with_config(&app, &workspace_mgr, &provider).await?;
+    llm_provider
+        .list_models(&reqwest::Client::new(), &config)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to list models for {}: {}", provider, e);
+            e
+        })
+}
+
+/// Checks that a summary provider is reachable with its saved settings
+#[tauri::command]
+pub async fn api_test_llm_connection<R: Runtime>(
+    app: AppHandle<R>,
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    provider: String,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_test_llm_connection called for provider: {}", provider);
+    let (llm_provider, config) = provider_with_config(&app, &workspace_mgr, &provider).await?;
+    match llm_provider
+        .test_connection(&reqwest::Client::new(), &config)
+        .await
+    {
+        Ok(()) => Ok(serde_json::json!({
+            "status": "success",
+            "message": format!("Connected to {}", llm_provider.display_name()),
+        })),
+        Err(e) => {
+            log_warn!("Connection test failed for {}: {}", provider, e);
+            Err(e)
+        }
+    }
+}
+
+/// Gets the retry policy and fallback models used when summary generation fails
+#[tauri::command]
+pub async fn api_get_summary_fallback_config(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+) -> Result<SummaryFallbackConfig, String> {
+    log_info!("api_get_summary_fallback_config called");
+    SettingsRepository::get_summary_fallback_config(workspace_mgr.global_pool())
+        .await
+        .map(Option::unwrap_or_default)
+        .map_err(|e| {
+            log_error!("Failed to get summary fallback config: {}", e);
+            format!("Failed to get summary fallback config: {}", e)
+        })
+}
+
+/// Saves the retry policy and the ordered list of fallback models
+#[tauri::command]
+pub async fn api_save_summary_fallback_config(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    config: SummaryFallbackConfig,
+) -> Result<serde_json::Value, String> {
+    log_info!(
+        "api_save_summary_fallback_config called with {} fallback model(s)",
+        config.fallbacks.len()
+    );
+    for fallback in &config.fallbacks {
+        get_provider(&fallback.provider)?;
+        if fallback.model.trim().is_empty() {
+            return Err(format!("No model set for fallback provider {}", fallback.provider));
+        }
+    }
+
+    SettingsRepository::save_summary_fallback_config(workspace_mgr.global_pool(), &config)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to save summary fallback config: {}", e);
+            format!("Failed to save summary fallback config: {}", e)
+        })?;
+
+    Ok(serde_json::json!({
+        "status": "success",
+        "message": "Summary fallback config saved successfully",
+    }))
+}
@@ -1,346 +1,665 @@
-use reqwest::{header, Client};
+use chrono::Utc;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Client, StatusCode};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
 use std::time::Duration;
 use tokio_util::sync::CancellationToken;
-use tracing::info;
+use tracing::{info, warn};
 
-const REQUEST_TIMEOUT_DURATION: Duration = Duration::from_secs(300);
+use crate::summary::providers::{CompletionRequest, LlmProvider, ProviderConfig};
 
-// Generic structure for OpenAI-compatible API chat messages
-#[derive(Debug, Serialize)]
-pub struct ChatMessage {
-    pub role: String,
-    pub content: String,
-}
+pub(crate) const REQUEST_TIMEOUT_DURATION: Duration = Duration::from_secs(300);
 
-// Generic structure for OpenAI-compatible API chat requests
-#[derive(Debug, Serialize)]
-pub struct ChatRequest {
-    pub model: String,
-    pub messages: Vec<ChatMessage>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_tokens: Option<u32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub temperature: Option<f32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub top_p: Option<f32>,
-}
+/// Longest silence tolerated between streamed chunks. Streaming requests have no overall
+/// timeout, so long summaries can take as long as they need while tokens keep arriving.
+const STREAM_IDLE_TIMEOUT: Duration = Duration::from_secs(120);
 
-// Generic structure for OpenAI-compatible API chat responses
-#[derive(Deserialize, Debug)]
-pub struct ChatResponse {
-    pub choices: Vec<Choice>,
-}
+/// Receives each text delta as the model generates it
+pub type TokenCallback<'a> = &'a (dyn Fn(&str) + Send + Sync);
 
-#[derive(Deserialize, Debug)]
-pub struct Choice {
-    pub message: MessageContent,
-}
+/// Extracts the text delta (if any) from one parsed stream event
+pub type StreamEventParser<'a> =
+    &'a (dyn Fn(&serde_json::Value) -> Result<Option<String>, String> + Send + Sync);
 
-#[derive(Deserialize, Debug)]
-pub struct MessageContent {
-    pub content: String,
+/// Why an LLM request produced no response
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum LlmError {
+    /// The cancellation token fired before the response was complete
+    #[error("Summary generation was cancelled")]
+    Cancelled,
+    #[error("{0}")]
+    Failed(String),
 }
 
-// Claude-specific request structure
-#[derive(Debug, Serialize)]
-pub struct ClaudeRequest {
-    pub model: String,
-    pub max_tokens: u32,
-    pub system: String,
-    pub messages: Vec<ChatMessage>,
+impl From<String> for LlmError {
+    fn from(message: String) -> Self {
+        LlmError::Failed(message)
+    }
 }
 
-// Claude-specific response structure
-#[derive(Deserialize, Debug)]
-pub struct ClaudeChatResponse {
-    pub content: Vec<ClaudeChatContent>,
+impl From<LlmError> for String {
+    fn from(error: LlmError) -> Self {
+        error.to_string()
+    }
 }
 
-#[derive(Deserialize, Debug)]
-pub struct ClaudeChatContent {
-    pub text: String,
+/// Retry behaviour for rate-limited (429) and temporarily unavailable (5xx) responses
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt (0 disables retrying)
+    pub max_retries: u32,
+    /// Delay before the first retry; doubled for every further retry
+    pub initial_backoff_ms: u64,
+    /// Upper bound for the backoff delay. A `Retry-After` longer than this is not waited
+    /// out; the error is returned so the next fallback provider can take over.
+    pub max_backoff_ms: u64,
 }
 
-/// LLM Provider enumeration for multi-provider support
-#[derive(Debug, Clone, PartialEq)]
-pub enum LLMProvider {
-    OpenAI,
-    Claude,
-    Groq,
-    Ollama,
-    OpenRouter,
-    BuiltInAI,
-    CustomOpenAI,
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff_ms: 1000,
+            max_backoff_ms: 30_000,
+        }
+    }
 }
 
-impl LLMProvider {
-    /// Parse provider from string (case-insensitive)
-    pub fn from_str(s: &str) -> Result<Self, String> {
-        match s.to_lowercase().as_str() {
-            "openai" => Ok(Self::OpenAI),
-            "claude" => Ok(Self::Claude),
-            "groq" => Ok(Self::Groq),
-            "ollama" => Ok(Self::Ollama),
-            "openrouter" => Ok(Self::OpenRouter),
-            "builtin-ai" | "local-llama" | "localllama" => Ok(Self::BuiltInAI),
-            "custom-openai" => Ok(Self::CustomOpenAI),
-            _ => Err(format!("Unsupported LLM provider: {}", s)),
+impl RetryPolicy {
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
         }
     }
+
+    fn max_backoff(&self) -> Duration {
+        Duration::from_millis(self.max_backoff_ms)
+    }
+
+    /// Exponential backoff delay before retry number `retry` (0-based)
+    fn backoff(&self, retry: u32) -> Duration {
+        let factor = 2u64.saturating_pow(retry);
+        Duration::from_millis(self.initial_backoff_ms.saturating_mul(factor)).min(self.max_backoff())
+    }
+}
+
+// Generic structure for chat messages (OpenAI-compatible and Claude)
+#[derive(Debug, Serialize)]
+pub struct ChatMessage {
+    pub role: String,
+    pub content: String,
 }
 
-/// Generates a summary using the specified LLM provider
+/// Generates a summary with the given LLM provider
 ///
 /// # Arguments
 /// * `client` - Reqwest HTTP client (reused for performance)
-/// * `provider` - The LLM provider to use
+/// * `provider` - The LLM provider to use (see [`crate::summary::providers::get_provider`])
+/// * `config` - API key, endpoint and sampling settings for the provider
 /// * `model_name` - The specific model to use (e.g., "gpt-4", "claude-3-opus")
-/// * `api_key` - API key for the provider (not needed for Ollama)
 /// * `system_prompt` - System instructions for the LLM
 /// * `user_prompt` - User query/content to process
-/// * `ollama_endpoint` - Optional custom Ollama endpoint (defaults to localhost:11434)
-/// * `custom_openai_endpoint` - Optional custom OpenAI-compatible endpoint
-/// * `max_tokens` - Optional max tokens (for CustomOpenAI provider)
-/// * `temperature` - Optional temperature (for CustomOpenAI provider)
-/// * `top_p` - Optional top_p (for CustomOpenAI provider)
-/// * `app_data_dir` - Optional app data directory (for BuiltInAI provider)
 /// * `cancellation_token` - Optional token to cancel the request
+/// * `on_token` - Optional callback; when given, the response is streamed and every text
+///   delta is passed to it as it arrives
 ///
 /// # Returns
-/// The generated summary text or an error message
+/// The generated summary text, or [`LlmError::Cancelled`] if the token fired
 pub async fn generate_summary(
     client: &Client,
-    provider: &LLMProvider,
+    provider: &dyn LlmProvider,
+    config: &ProviderConfig,
+    model_name: &str,
+    system_prompt: &str,
+    user_prompt: &str,
+    cancellation_token: Option<&CancellationToken>,
+    on_token: Option<TokenCallback<'_>>,
+) -> Result<String, LlmError> {
+    generate(
+        client,
+        provider,
+        config,
+        model_name,
+        system_prompt,
+        user_prompt,
+        None,
+        cancellation_token,
+        on_token,
+    )
+    .await
+}
+
+/// Generates a JSON response constrained to `json_schema`
+///
+/// Only providers that [support schemas](LlmProvider::supports_json_schema) enforce it;
+/// the response should be validated either way. See [`generate_summary`] for the other
+/// arguments.
+#[allow(clippy::too_many_arguments)]
+pub async fn generate_json(
+    client: &Client,
+    provider: &dyn LlmProvider,
+    config: &ProviderConfig,
+    model_name: &str,
+    system_prompt: &str,
+    user_prompt: &str,
+    json_schema: &serde_json::Value,
+    cancellation_token: Option<&CancellationToken>,
+    on_token: Option<TokenCallback<'_>>,
+) -> Result<String, LlmError> {
+    generate(
+        client,
+        provider,
+        config,
+        model_name,
+        system_prompt,
+        user_prompt,
+        Some(json_schema),
+        cancellation_token,
+        on_token,
+    )
+    .await
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn generate(
+    client: &Client,
+    provider: &dyn LlmProvider,
+    config: &ProviderConfig,
     model_name: &str,
-    api_key: &str,
     system_prompt: &str,
     user_prompt: &str,
-    ollama_endpoint: Option<&str>,
-    custom_openai_endpoint: Option<&str>,
-    max_tokens: Option<u32>,
-    temperature: Option<f32>,
-    top_p: Option<f32>,
-    app_data_dir: Option<&PathBuf>,
+    json_schema: Option<&serde_json::Value>,
     cancellation_token: Option<&CancellationToken>,
-) -> Result<String, String> {
+    on_token: Option<TokenCallback<'_>>,
+) -> Result<String, LlmError> {
     // Check if cancelled before starting
     if let Some(token) = cancellation_token {
         if token.is_cancelled() {
-            return Err("Summary generation was cancelled".to_string());
+            return Err(LlmError::Cancelled);
         }
     }
 
-    // Handle BuiltInAI provider separately (uses local sidecar, no HTTP API)
-    if provider == &LLMProvider::BuiltInAI {
-        let app_data_dir = app_data_dir
-            .ok_or_else(|| "app_data_dir is required for BuiltInAI provider".to_string())?;
-
-        return crate::summary::summary_engine::generate_with_builtin(
-            app_data_dir,
-            model_name,
-            system_prompt,
-            user_prompt,
-            cancellation_token,
-        )
-        .await
-        .map_err(|e| e.to_string());
-    }
+    info!("🐞 LLM Request to {}: model={}", provider.display_name(), model_name);
 
-    let (api_url, mut headers) = match provider {
-        LLMProvider::OpenAI => (
-            "https://api.openai.com/v1/chat/completions".to_string(),
-            header::HeaderMap::new(),
-        ),
-        LLMProvider::Groq => (
-            "https://api.groq.com/openai/v1/chat/completions".to_string(),
-            header::HeaderMap::new(),
-        ),
-        LLMProvider::OpenRouter => (
-            "https://openrouter.ai/api/v1/chat/completions".to_string(),
-            header::HeaderMap::new(),
-        ),
-        LLMProvider::Ollama => {
-            let host = ollama_endpoint
-                .map(|s| s.to_string())
-                .unwrap_or_else(|| "http://localhost:11434".to_string());
-            (
-                format!("{}/v1/chat/completions", host),
-                header::HeaderMap::new(),
-            )
-        }
-        LLMProvider::CustomOpenAI => {
-            let endpoint = custom_openai_endpoint
-                .ok_or_else(|| "Custom OpenAI endpoint not configured".to_string())?;
-            (
-                format!("{}/chat/completions", endpoint.trim_end_matches('/')),
-                header::HeaderMap::new(),
+    let request = CompletionRequest {
+        model: model_name,
+        system_prompt,
+        user_prompt,
+        json_schema,
+    };
+    let content = provider
+        .complete(client, config, &request, cancellation_token, on_token)
+        .await?;
+
+    info!("🐞 LLM Response received from {}", provider.display_name());
+    Ok(content)
+}
+
+/// Sends a request, racing it against the cancellation token if one is given
+async fn send_request(
+    request: reqwest::RequestBuilder,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<reqwest::Response, LlmError> {
+    let map_send_error = |e: reqwest::Error| {
+        LlmError::Failed(if e.is_timeout() {
+            format!(
+                "LLM request timed out after {} seconds",
+                REQUEST_TIMEOUT_DURATION.as_secs()
             )
+        } else {
+            format!("Failed to send request to LLM: {}", e)
+        })
+    };
+
+    if let Some(token) = cancellation_token {
+        tokio::select! {
+            result = request.send() => result.map_err(map_send_error),
+            _ = token.cancelled() => Err(LlmError::Cancelled),
         }
-        LLMProvider::Claude => {
-            let mut header_map = header::HeaderMap::new();
-            header_map.insert(
-                "x-api-key",
-                api_key
-                    .parse()
-                    .map_err(|_| "Invalid API key format".to_string())?,
-            );
-            header_map.insert(
-                "anthropic-version",
-                "2023-06-01"
-                    .parse()
-                    .map_err(|_| "Invalid anthropic version".to_string())?,
-            );
-            ("https://api.anthropic.com/v1/messages".to_string(), header_map)
+    } else {
+        request.send().await.map_err(map_send_error)
+    }
+}
+
+/// Rate limits (429), overload (Anthropic's 529) and transient server errors are retried
+fn is_retryable_status(status: StatusCode) -> bool {
+    matches!(status.as_u16(), 429 | 500 | 502 | 503 | 504 | 529)
+}
+
+/// Delay requested by a `Retry-After` header, given either in whole seconds (RFC 9110
+/// delay-seconds) or as an HTTP date. Second counts too large for a `u64` saturate.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) {
+        return Some(Duration::from_secs(value.parse::<u64>().unwrap_or(u64::MAX)));
+    }
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    Some((date.with_timezone(&Utc) - Utc::now()).to_std().unwrap_or(Duration::ZERO))
+}
+
+/// Sleeps for `delay`, returning early with an error if the token is cancelled
+async fn sleep_unless_cancelled(
+    delay: Duration,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<(), LlmError> {
+    match cancellation_token {
+        Some(token) => tokio::select! {
+            _ = tokio::time::sleep(delay) => Ok(()),
+            _ = token.cancelled() => Err(LlmError::Cancelled),
+        },
+        None => {
+            tokio::time::sleep(delay).await;
+            Ok(())
         }
-        LLMProvider::BuiltInAI => {
-            // This case is handled earlier with early returns
-            unreachable!("BuiltInAI is handled before this match statement")
+    }
+}
+
+/// Sends a request, retrying retryable statuses according to `policy`. Waits for the
+/// server's `Retry-After` when given, otherwise backs off exponentially. The last
+/// response is returned as-is, whatever its status.
+///
+/// `attempt_timeout` bounds the wait for response headers of each attempt.
+async fn send_with_retry(
+    request: reqwest::RequestBuilder,
+    policy: &RetryPolicy,
+    cancellation_token: Option<&CancellationToken>,
+    attempt_timeout: Option<Duration>,
+) -> Result<reqwest::Response, LlmError> {
+    let send_attempt = move |request: reqwest::RequestBuilder| async move {
+        match attempt_timeout {
+            Some(limit) => tokio::time::timeout(limit, send_request(request, cancellation_token))
+                .await
+                .map_err(|_| {
+                    LlmError::Failed(format!(
+                        "LLM request timed out after {} seconds",
+                        limit.as_secs()
+                    ))
+                })?,
+            None => send_request(request, cancellation_token).await,
         }
     };
 
-    // Add authorization header for non-Claude providers
-    if provider != &LLMProvider::Claude {
-        headers.insert(
-            header::AUTHORIZATION,
-            format!("Bearer {}", api_key)
-                .parse()
-                .map_err(|_| "Invalid authorization header".to_string())?,
+    let mut retry = 0;
+    loop {
+        // Bodies that can't be cloned (streams) can only be sent once
+        let Some(attempt) = request.try_clone() else {
+            return send_attempt(request).await;
+        };
+        let response = send_attempt(attempt).await?;
+        let status = response.status();
+        if !is_retryable_status(status) || retry >= policy.max_retries {
+            return Ok(response);
+        }
+
+        let delay = match retry_after(response.headers()) {
+            Some(wait) if wait > policy.max_backoff() => {
+                warn!(
+                    "LLM API returned {} with Retry-After of {}s, not retrying",
+                    status,
+                    wait.as_secs()
+                );
+                return Ok(response);
+            }
+            Some(wait) => wait,
+            None => policy.backoff(retry),
+        };
+        retry += 1;
+        warn!(
+            "LLM API returned {}, retrying in {}ms (retry {}/{})",
+            status,
+            delay.as_millis(),
+            retry,
+            policy.max_retries
         );
+        sleep_unless_cancelled(delay, cancellation_token).await?;
     }
-    headers.insert(
-        header::CONTENT_TYPE,
-        "application/json"
-            .parse()
-            .map_err(|_| "Invalid content type".to_string())?,
-    );
-
-    // Build request body based on provider
-    let request_body = if provider != &LLMProvider::Claude {
-        // For CustomOpenAI, apply optional parameters if provided
-        let (max_tokens_val, temperature_val, top_p_val) = if provider == &LLMProvider::CustomOpenAI {
-            (max_tokens, temperature, top_p)
-        } else {
-            (None, None, None)
-        };
+}
 
-        serde_json::json!(ChatRequest {
-            model: model_name.to_string(),
-            messages: vec![
-                ChatMessage {
-                    role: "system".to_string(),
-                    content: system_prompt.to_string(),
-                },
-                ChatMessage {
-                    role: "user".to_string(),
-                    content: user_prompt.to_string(),
-                }
-            ],
-            max_tokens: max_tokens_val,
-            temperature: temperature_val,
-            top_p: top_p_val,
-        })
-    } else {
-        serde_json::json!(ClaudeRequest {
-            system: system_prompt.to_string(),
-            model: model_name.to_string(),
-            max_tokens: 2048,
-            messages: vec![ChatMessage {
-                role: "user".to_string(),
-                content: user_prompt.to_string(),
-            }]
-        })
-    };
+/// Error for an unsuccessful response, including the status so rate limits are recognisable
+async fn error_from_response(response: reqwest::Response) -> String {
+    let status = response.status();
+    let body = response
+        .text()
+        .await
+        .unwrap_or_else(|_| "Unknown error".to_string());
+    format!("LLM API request failed ({}): {}", status, body)
+}
+
+/// Sends a request (with retries) and returns the body of a successful response
+pub(crate) async fn send_and_read(
+    request: reqwest::RequestBuilder,
+    policy: &RetryPolicy,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<String, LlmError> {
+    let response = send_with_retry(request, policy, cancellation_token, None).await?;
+    if !response.status().is_success() {
+        return Err(error_from_response(response).await.into());
+    }
+    response
+        .text()
+        .await
+        .map_err(|e| LlmError::Failed(format!("Failed to read LLM response: {}", e)))
+}
 
-    info!("🐞 LLM Request to {}: model={}", provider_name(provider), model_name);
+/// Sends a streaming request (with retries). Only the wait for response headers is
+/// bounded here; the body is bounded per chunk by `read_sse_stream`.
+pub(crate) async fn send_stream_request(
+    request: reqwest::RequestBuilder,
+    policy: &RetryPolicy,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<reqwest::Response, LlmError> {
+    let response =
+        send_with_retry(request, policy, cancellation_token, Some(STREAM_IDLE_TIMEOUT)).await?;
+    if !response.status().is_success() {
+        return Err(error_from_response(response).await.into());
+    }
+    Ok(response)
+}
 
-    // Send request with timeout and cancellation support
-    let request_future = client
-        .post(api_url)
-        .headers(headers)
-        .json(&request_body)
-        .timeout(REQUEST_TIMEOUT_DURATION)
-        .send();
+/// Next body chunk of a streaming response, or `None` at the end of the stream
+async fn next_chunk(
+    response: &mut reqwest::Response,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<Option<Vec<u8>>, LlmError> {
+    let read = async {
+        match tokio::time::timeout(STREAM_IDLE_TIMEOUT, response.chunk()).await {
+            Ok(Ok(chunk)) => Ok(chunk.map(|bytes| bytes.to_vec())),
+            Ok(Err(e)) => Err(LlmError::Failed(format!("LLM stream failed: {}", e))),
+            Err(_) => Err(LlmError::Failed(format!(
+                "LLM stream stalled for {} seconds",
+                STREAM_IDLE_TIMEOUT.as_secs()
+            ))),
+        }
+    };
 
-    // Use tokio::select to race between cancellation and request completion
-    let response = if let Some(token) = cancellation_token {
+    if let Some(token) = cancellation_token {
         tokio::select! {
-            result = request_future => {
-                result.map_err(|e| {
-                    if e.is_timeout() {
-                        format!("LLM request timed out after 60 seconds")
-                    } else {
-                        format!("Failed to send request to LLM: {}", e)
-                    }
-                })?
+            result = read => result,
+            _ = token.cancelled() => Err(LlmError::Cancelled),
+        }
+    } else {
+        read.await
+    }
+}
+
+/// Reads a server-sent event stream, passing each text delta to `on_token`, and returns
+/// the complete trimmed text
+pub(crate) async fn read_sse_stream(
+    mut response: reqwest::Response,
+    cancellation_token: Option<&CancellationToken>,
+    on_token: TokenCallback<'_>,
+    extract_delta: StreamEventParser<'_>,
+) -> Result<String, LlmError> {
+    let mut parser = SseParser::default();
+    let mut text = String::new();
+
+    'stream: loop {
+        let (events, done) = match next_chunk(&mut response, cancellation_token).await? {
+            Some(bytes) => (parser.push(&bytes), false),
+            None => (parser.finish(), true),
+        };
+
+        for data in events {
+            if data.trim() == "[DONE]" {
+                break 'stream;
             }
-            _ = token.cancelled() => {
-                return Err("Summary generation was cancelled".to_string());
+            let event: serde_json::Value = match serde_json::from_str(&data) {
+                Ok(event) => event,
+                Err(e) => {
+                    warn!("Skipping unparseable stream event: {}", e);
+                    continue;
+                }
+            };
+            if let Some(delta) = extract_delta(&event)?.filter(|d| !d.is_empty()) {
+                on_token(&delta);
+                text.push_str(&delta);
             }
         }
-    } else {
-        request_future.await.map_err(|e| {
-            if e.is_timeout() {
-                format!("LLM request timed out after 60 seconds")
-            } else {
-                format!("Failed to send request to LLM: {}", e)
+
+        if done {
+            break;
+        }
+    }
+
+    if text.trim().is_empty() {
+        return Err(LlmError::Failed("No content in LLM response".to_string()));
+    }
+    Ok(text.trim().to_string())
+}
+
+/// Incremental parser for `text/event-stream` bodies that yields the `data` payload of
+/// each complete event. Bytes are buffered until a full line arrives, so multi-byte
+/// characters split across network chunks decode correctly.
+#[derive(Default)]
+struct SseParser {
+    buffer: Vec<u8>,
+    data: Vec<String>,
+}
+
+impl SseParser {
+    fn push(&mut self, bytes: &[u8]) -> Vec<String> {
+        self.buffer.extend_from_slice(bytes);
+        let mut events = Vec::new();
+        while let Some(pos) = self.buffer.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=pos).collect();
+            let line = String::from_utf8_lossy(&line);
+            self.process_line(line.trim_end_matches(['\r', '\n']), &mut events);
+        }
+        events
+    }
+
+    /// Flush a final event that wasn't terminated by a blank line
+    fn finish(&mut self) -> Vec<String> {
+        let mut events = Vec::new();
+        let rest = std::mem::take(&mut self.buffer);
+        if !rest.is_empty() {
+            let line = String::from_utf8_lossy(&rest);
+            self.process_line(line.trim_end_matches('\r'), &mut events);
+        }
+        self.process_line("", &mut events);
+        events
+    }
+
+    fn process_line(&mut self, line: &str, events: &mut Vec<String>) {
+        if line.is_empty() {
+            if !self.data.is_empty() {
+                events.push(std::mem::take(&mut self.data).join("\n"));
             }
-        })?
-    };
+        } else if let Some(value) = line.strip_prefix("data:") {
+            self.data
+                .push(value.strip_prefix(' ').unwrap_or(value).to_string());
+        }
+        // `event:`, `id:`, `retry:` and `:` comment lines carry nothing we need
+    }
+}
 
-    if !response.status().is_success() {
-        let error_body = response
-            .text()
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::summary::providers::test_support::{mock_server_sequence, MockResponse};
+
+    fn fast_retries(max_retries: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            initial_backoff_ms: 1,
+            max_backoff_ms: 50,
+        }
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_max() {
+        let policy = RetryPolicy {
+            max_retries: 5,
+            initial_backoff_ms: 500,
+            max_backoff_ms: 3000,
+        };
+        assert_eq!(policy.backoff(0), Duration::from_millis(500));
+        assert_eq!(policy.backoff(1), Duration::from_millis(1000));
+        assert_eq!(policy.backoff(2), Duration::from_millis(2000));
+        assert_eq!(policy.backoff(3), Duration::from_millis(3000));
+        assert_eq!(policy.backoff(80), Duration::from_millis(3000));
+    }
+
+    #[test]
+    fn test_retry_after_parsing() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, "2".parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(2)));
+
+        let in_30s = (Utc::now() + chrono::Duration::seconds(30)).to_rfc2822();
+        headers.insert(RETRY_AFTER, in_30s.parse().unwrap());
+        let wait = retry_after(&headers).unwrap();
+        assert!(wait > Duration::from_secs(25) && wait <= Duration::from_secs(30), "{:?}", wait);
+
+        headers.insert(RETRY_AFTER, "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+        headers.insert(RETRY_AFTER, "soon".parse().unwrap());
+        assert_eq!(retry_after(&headers), None);
+
+        // Server-controlled values must not overflow
+        headers.insert(RETRY_AFTER, "99999999999999999999999".parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(u64::MAX)));
+        for invalid in ["inf", "1e400", "-1", "1.5"] {
+            headers.insert(RETRY_AFTER, invalid.parse().unwrap());
+            assert_eq!(retry_after(&headers), None, "{}", invalid);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_request_is_retried() {
+        let (base_url, server) = mock_server_sequence(vec![
+            MockResponse {
+                status: 429,
+                headers: &[("Retry-After", "0")],
+                body: r#"{"error":"rate_limited"}"#,
+            },
+            MockResponse {
+                status: 503,
+                headers: &[],
+                body: "{}",
+            },
+            MockResponse {
+                status: 200,
+                headers: &[],
+                body: r#"{"ok":true}"#,
+            },
+        ])
+        .await;
+
+        let request = Client::new()
+            .post(&base_url)
+            .json(&serde_json::json!({"model": "m"}));
+        let body = send_and_read(request, &fast_retries(3), None).await.unwrap();
+        assert_eq!(body, r#"{"ok":true}"#);
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests.len(), 3);
+        assert!(requests.iter().all(|r| r.ends_with(r#"{"model":"m"}"#)));
+    }
+
+    #[tokio::test]
+    async fn test_retries_are_limited() {
+        let (base_url, _server) = mock_server_sequence(vec![
+            MockResponse {
+                status: 500,
+                headers: &[],
+                body: "first",
+            },
+            MockResponse {
+                status: 500,
+                headers: &[],
+                body: "second",
+            },
+        ])
+        .await;
+        let err = send_and_read(Client::new().get(&base_url), &fast_retries(1), None)
             .await
-            .unwrap_or_else(|_| "Unknown error".to_string());
-        return Err(format!("LLM API request failed: {}", error_body));
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("500") && err.contains("second"), "{}", err);
     }
 
-    // Parse response based on provider
-    if provider == &LLMProvider::Claude {
-        let chat_response = response
-            .json::<ClaudeChatResponse>()
+    #[tokio::test]
+    async fn test_long_retry_after_is_not_waited_out() {
+        let (base_url, _server) = mock_server_sequence(vec![MockResponse {
+            status: 429,
+            headers: &[("Retry-After", "3600")],
+            body: r#"{"error":"quota exceeded"}"#,
+        }])
+        .await;
+        let err = send_and_read(Client::new().get(&base_url), &RetryPolicy::default(), None)
             .await
-            .map_err(|e| format!("Failed to parse LLM response: {}", e))?;
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("429") && err.contains("quota exceeded"), "{}", err);
+    }
 
-        info!("🐞 LLM Response received from Claude");
+    #[tokio::test]
+    async fn test_overflowing_retry_after_is_not_waited_out() {
+        let (base_url, _server) = mock_server_sequence(vec![MockResponse {
+            status: 429,
+            headers: &[("Retry-After", "1000000000000000000000000")],
+            body: r#"{"error":"rate_limited"}"#,
+        }])
+        .await;
+        let err = send_and_read(Client::new().get(&base_url), &RetryPolicy::default(), None)
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("429"), "{}", err);
+    }
 
-        let content = chat_response
-            .content
-            .get(0)
-            .ok_or("No content in LLM response")?
-            .text
-            .trim();
-        Ok(content.to_string())
-    } else {
-        let chat_response = response
-            .json::<ChatResponse>()
+    #[tokio::test]
+    async fn test_client_errors_are_not_retried() {
+        let (base_url, server) = mock_server_sequence(vec![MockResponse {
+            status: 400,
+            headers: &[],
+            body: "bad request",
+        }])
+        .await;
+        let err = send_and_read(Client::new().get(&base_url), &fast_retries(3), None)
             .await
-            .map_err(|e| format!("Failed to parse LLM response: {}", e))?;
-
-        info!("🐞 LLM Response received from {}", provider_name(provider));
-
-        let content = chat_response
-            .choices
-            .get(0)
-            .ok_or("No content in LLM response")?
-            .message
-            .content
-            .trim();
-        Ok(content.to_string())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("bad request"), "{}", err);
+        assert_eq!(server.await.unwrap().len(), 1);
     }
-}
 
-/// Helper function to get provider name for logging
-fn provider_name(provider: &LLMProvider) -> &str {
-    match provider {
-        LLMProvider::OpenAI => "OpenAI",
-        LLMProvider::Claude => "Claude",
-        LLMProvider::Groq => "Groq",
-        LLMProvider::Ollama => "Ollama",
-        LLMProvider::BuiltInAI => "Built-in AI",
-        LLMProvider::OpenRouter => "OpenRouter",
-        LLMProvider::CustomOpenAI => "Custom OpenAI",
+    #[tokio::test]
+    async fn test_only_cancellation_is_reported_as_cancelled() {
+        let token = CancellationToken::new();
+        token.cancel();
+        let err = sleep_unless_cancelled(Duration::from_secs(60), Some(&token))
+            .await
+            .unwrap_err();
+        assert_eq!(err, LlmError::Cancelled);
+
+        // A provider error that mentions cancellation is still an ordinary failure
+        let (base_url, _server) = mock_server_sequence(vec![MockResponse {
+            status: 400,
+            headers: &[],
+            body: r#"{"error":"request cancelled by upstream"}"#,
+        }])
+        .await;
+        let err = send_and_read(Client::new().get(&base_url), &RetryPolicy::none(), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LlmError::Failed(_)), "{:?}", err);
+    }
+
+    #[test]
+    fn test_sse_parser_handles_split_chunks() {
+        let mut parser = SseParser::default();
+        let body = "event: message\ndata: {\"t\":\"caf\u{e9}\"}\n\n: keep-alive\ndata: [DONE]\n\n".as_bytes();
+        // Split inside the multi-byte "é" and between the two events
+        let split = body.iter().position(|b| *b == 0xC3).unwrap() + 1;
+
+        let mut events = parser.push(&body[..split]);
+        assert!(events.is_empty());
+        events.extend(parser.push(&body[split..]));
+        events.extend(parser.finish());
+        assert_eq!(events, vec!["{\"t\":\"caf\u{e9}\"}".to_string(), "[DONE]".to_string()]);
     }
 }
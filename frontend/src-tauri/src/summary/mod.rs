@@ -1,10 +1,14 @@
 /// Summary module - handles all meeting summary generation functionality
 ///
 /// This module contains:
-/// - LLM client for communicating with various AI providers (OpenAI, Claude, Groq, Ollama, OpenRouter, CustomOpenAI)
+/// - LLM client and pluggable providers (OpenAI, Claude, Gemini, Groq, Ollama, OpenRouter, CustomOpenAI, BuiltInAI)
 /// - Processor for chunking transcripts and generating summaries
+/// - Tokenizers and context budgets used to size prompts per model
 /// - Service layer for orchestrating summary generation
 /// - Templates for structured meeting summary generation
+/// - Structured extraction of action items and decisions into their own tables
+/// - Grounded Q&A chat over a meeting's transcript
+/// - Version history of generated and edited summaries
 /// - Tauri commands for frontend integration
 
 use serde::{Deserialize, Serialize};
@@ -30,19 +34,68 @@ pub struct CustomOpenAIConfig {
     pub top_p: Option<f32>,
 }
 
+/// One entry of the summary fallback chain
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FallbackModel {
+    /// Provider id (e.g. "openrouter", "ollama", "builtin-ai")
+    pub provider: String,
+    pub model: String,
+}
+
+/// Retry and fallback settings for summary generation
+/// Stored as JSON in the database; an absent config means default retries and no fallbacks
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SummaryFallbackConfig {
+    /// Retries for rate-limited (429) and temporarily failing (5xx) requests, per model
+    pub retry: llm_client::RetryPolicy,
+    /// Models tried in order when the selected model still fails after its retries
+    pub fallbacks: Vec<FallbackModel>,
+}
+
+pub mod action_item_commands;
+pub mod chat;
+pub mod chat_commands;
 pub mod commands;
+pub mod extraction;
 pub mod llm_client;
 pub mod processor;
+pub mod providers;
 pub mod service;
 pub mod summary_engine;
 pub mod template_commands;
 pub mod templates;
+pub mod tokenizer;
+pub mod version_commands;
+pub mod versions;
 
 // Re-export Tauri commands (with their generated __cmd__ variants)
 pub use commands::{
-    __cmd__api_cancel_summary, __cmd__api_get_summary, __cmd__api_process_transcript,
-    __cmd__api_save_meeting_summary, api_cancel_summary, api_get_summary,
-    api_process_transcript, api_save_meeting_summary,
+    __cmd__api_cancel_summary, __cmd__api_get_summary, __cmd__api_get_summary_fallback_config,
+    __cmd__api_list_llm_models, __cmd__api_process_transcript, __cmd__api_save_meeting_summary,
+    __cmd__api_save_summary_fallback_config, __cmd__api_test_llm_connection, api_cancel_summary,
+    api_get_summary, api_get_summary_fallback_config, api_list_llm_models, api_process_transcript,
+    api_save_meeting_summary, api_save_summary_fallback_config, api_test_llm_connection,
+};
+
+// Re-export action item commands
+pub use action_item_commands::{
+    __cmd__api_complete_action_item, __cmd__api_list_action_items, __cmd__api_list_decisions,
+    api_complete_action_item, api_list_action_items, api_list_decisions,
+};
+
+// Re-export meeting chat commands
+pub use chat_commands::{
+    __cmd__api_ask_meeting, __cmd__api_clear_meeting_chat, __cmd__api_get_meeting_chat,
+    api_ask_meeting, api_clear_meeting_chat, api_get_meeting_chat,
+};
+
+// Re-export summary version commands
+pub use version_commands::{
+    __cmd__api_diff_summary_versions, __cmd__api_list_summary_versions,
+    __cmd__api_pin_summary_version, __cmd__api_restore_summary_version,
+    api_diff_summary_versions, api_list_summary_versions, api_pin_summary_version,
+    api_restore_summary_version,
 };
 
 // Re-export template commands
@@ -52,9 +105,9 @@ pub use template_commands::{
 };
 
 // Re-export commonly used items
-pub use llm_client::LLMProvider;
+pub use providers::{get_provider, LlmProvider, ProviderConfig};
 pub use processor::{
-    chunk_text, clean_llm_markdown_output, extract_meeting_name_from_markdown,
+    chunk_transcript, clean_llm_markdown_output, extract_meeting_name_from_markdown,
     generate_meeting_summary, rough_token_count,
 };
 pub use service::SummaryService;
@@ -0,0 +1,204 @@
+//! Built-in AI: GGUF models run locally by the llama-helper sidecar, no HTTP involved.
+
+use async_trait::async_trait;
+use reqwest::{Client, RequestBuilder};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+use super::{CompletionRequest, LlmProvider, ProviderConfig};
+use crate::summary::llm_client::{LlmError, TokenCallback};
+use crate::summary::summary_engine::{self, models};
+use crate::summary::tokenizer::{BuiltinTokenCounter, TokenCounter};
+
+/// Context size assumed for models missing from the registry
+const FALLBACK_CONTEXT_SIZE: usize = 2048;
+
+pub struct BuiltInProvider;
+
+impl BuiltInProvider {
+    fn app_data_dir(config: &ProviderConfig) -> Result<&PathBuf, String> {
+        config
+            .app_data_dir
+            .as_ref()
+            .ok_or_else(|| "app_data_dir is required for BuiltInAI provider".to_string())
+    }
+}
+
+#[async_trait]
+impl LlmProvider for BuiltInProvider {
+    fn id(&self) -> &'static str {
+        "builtin-ai"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Built-in AI"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["local-llama", "localllama"]
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    /// The sidecar turns the schema into a sampling grammar
+    fn supports_json_schema(&self) -> bool {
+        true
+    }
+
+    fn build_request(
+        &self,
+        _client: &Client,
+        _config: &ProviderConfig,
+        _request: &CompletionRequest<'_>,
+        _stream: bool,
+    ) -> Result<RequestBuilder, String> {
+        Err("Built-in AI runs through the local sidecar, not HTTP".to_string())
+    }
+
+    fn parse_response(&self, body: &str) -> Result<String, String> {
+        Ok(body.trim().to_string())
+    }
+
+    fn parse_stream_event(&self, event: &serde_json::Value) -> Result<Option<String>, String> {
+        Ok(event
+            .get("text")
+            .and_then(|t| t.as_str())
+            .map(str::to_string))
+    }
+
+    async fn complete(
+        &self,
+        _client: &Client,
+        config: &ProviderConfig,
+        request: &CompletionRequest<'_>,
+        cancellation_token: Option<&CancellationToken>,
+        on_token: Option<TokenCallback<'_>>,
+    ) -> Result<String, LlmError> {
+        summary_engine::generate_with_builtin(
+            Self::app_data_dir(config)?,
+            request.model,
+            request.system_prompt,
+            request.user_prompt,
+            request.json_schema,
+            cancellation_token,
+            on_token,
+        )
+        .await
+        .map_err(|e| match cancellation_token {
+            Some(token) if token.is_cancelled() => LlmError::Cancelled,
+            _ => LlmError::Failed(e.to_string()),
+        })
+    }
+
+    /// Registry models whose GGUF file has been downloaded
+    async fn list_models(
+        &self,
+        _client: &Client,
+        config: &ProviderConfig,
+    ) -> Result<Vec<String>, String> {
+        let models_dir = models::get_models_directory(Self::app_data_dir(config)?);
+        // Custom models are registered by the last scan of the models directory
+        Ok(models::get_available_models()
+            .into_iter()
+            .chain(models::get_custom_models())
+            .filter(|model| models_dir.join(&model.gguf_file).is_file())
+            .map(|model| model.name)
+            .collect())
+    }
+
+    async fn test_connection(
+        &self,
+        client: &Client,
+        config: &ProviderConfig,
+    ) -> Result<(), String> {
+        if self.list_models(client, config).await?.is_empty() {
+            return Err("No built-in AI model has been downloaded".to_string());
+        }
+        Ok(())
+    }
+
+    async fn context_size(&self, _config: &ProviderConfig, model: &str) -> usize {
+        match models::get_model_by_name(model) {
+            Some(model_def) => model_def.context_size as usize,
+            None => {
+                warn!(
+                    "Unknown model: {}, using default context size {}",
+                    model, FALLBACK_CONTEXT_SIZE
+                );
+                FALLBACK_CONTEXT_SIZE
+            }
+        }
+    }
+
+    /// The model's own GGUF vocabulary, through the sidecar
+    fn token_counter(&self, config: &ProviderConfig, model: &str) -> Arc<dyn TokenCounter> {
+        Arc::new(BuiltinTokenCounter::new(config.app_data_dir.clone(), model))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_list_models_only_returns_downloaded_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = ProviderConfig {
+            app_data_dir: Some(dir.path().to_path_buf()),
+            ..Default::default()
+        };
+        assert!(BuiltInProvider
+            .test_connection(&Client::new(), &config)
+            .await
+            .is_err());
+
+        let model = models::get_default_model();
+        let models_dir = models::get_models_directory(&dir.path().to_path_buf());
+        std::fs::create_dir_all(&models_dir).unwrap();
+        std::fs::write(models_dir.join(&model.gguf_file), b"GGUF").unwrap();
+
+        let listed = BuiltInProvider
+            .list_models(&Client::new(), &config)
+            .await
+            .unwrap();
+        assert_eq!(listed, vec![model.name]);
+        assert!(BuiltInProvider
+            .test_connection(&Client::new(), &config)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_context_size_from_registry() {
+        let config = ProviderConfig::default();
+        let model = models::get_default_model();
+        assert_eq!(
+            BuiltInProvider.context_size(&config, &model.name).await,
+            model.context_size as usize
+        );
+        assert_eq!(
+            BuiltInProvider.context_size(&config, "unknown:1b").await,
+            FALLBACK_CONTEXT_SIZE
+        );
+    }
+
+    #[tokio::test]
+    async fn test_complete_requires_app_data_dir() {
+        let request = CompletionRequest {
+            model: "gemma3:1b",
+            system_prompt: "",
+            user_prompt: "hi",
+            json_schema: None,
+        };
+        let err = BuiltInProvider
+            .complete(&Client::new(), &ProviderConfig::default(), &request, None, None)
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("app_data_dir"), "{}", err);
+    }
+}
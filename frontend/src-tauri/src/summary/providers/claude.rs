@@ -0,0 +1,204 @@
+//! Anthropic Messages API.
+
+use async_trait::async_trait;
+use reqwest::{Client, RequestBuilder};
+use serde::{Deserialize, Serialize};
+
+use super::{collect_names, fetch_json, CompletionRequest, LlmProvider, ProviderConfig};
+use crate::summary::llm_client::ChatMessage;
+
+const CLAUDE_API_BASE: &str = "https://api.anthropic.com";
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+const DEFAULT_MAX_TOKENS: u32 = 2048;
+
+// Claude-specific request structure
+#[derive(Debug, Serialize)]
+pub struct ClaudeRequest {
+    pub model: String,
+    pub max_tokens: u32,
+    pub system: String,
+    pub messages: Vec<ChatMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<bool>,
+}
+
+// Claude-specific response structure
+#[derive(Deserialize, Debug)]
+pub struct ClaudeChatResponse {
+    pub content: Vec<ClaudeChatContent>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ClaudeChatContent {
+    #[serde(default)]
+    pub text: String,
+}
+
+pub struct ClaudeProvider;
+
+impl ClaudeProvider {
+    fn with_headers(builder: RequestBuilder, config: &ProviderConfig) -> RequestBuilder {
+        builder
+            .header("x-api-key", config.api_key().unwrap_or_default())
+            .header("anthropic-version", ANTHROPIC_VERSION)
+    }
+}
+
+#[async_trait]
+impl LlmProvider for ClaudeProvider {
+    fn id(&self) -> &'static str {
+        "claude"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Claude"
+    }
+
+    fn api_key_column(&self) -> Option<&'static str> {
+        Some("anthropicApiKey")
+    }
+
+    fn build_request(
+        &self,
+        client: &Client,
+        config: &ProviderConfig,
+        request: &CompletionRequest<'_>,
+        stream: bool,
+    ) -> Result<RequestBuilder, String> {
+        let body = ClaudeRequest {
+            model: request.model.to_string(),
+            max_tokens: config.max_tokens.unwrap_or(DEFAULT_MAX_TOKENS),
+            system: request.system_prompt.to_string(),
+            messages: vec![ChatMessage {
+                role: "user".to_string(),
+                content: request.user_prompt.to_string(),
+            }],
+            temperature: config.temperature,
+            top_p: config.top_p,
+            stream: stream.then_some(true),
+        };
+        let url = format!("{}/v1/messages", config.base_url(CLAUDE_API_BASE));
+        Ok(Self::with_headers(client.post(url), config).json(&body))
+    }
+
+    fn parse_response(&self, body: &str) -> Result<String, String> {
+        let chat_response: ClaudeChatResponse = serde_json::from_str(body)
+            .map_err(|e| format!("Failed to parse LLM response: {}", e))?;
+        let content = chat_response
+            .content
+            .first()
+            .ok_or("No content in LLM response")?
+            .text
+            .trim();
+        Ok(content.to_string())
+    }
+
+    /// Text arrives in `content_block_delta` events; other event types are bookkeeping
+    fn parse_stream_event(&self, event: &serde_json::Value) -> Result<Option<String>, String> {
+        match event.get("type").and_then(|t| t.as_str()) {
+            Some("content_block_delta") => Ok(event
+                .pointer("/delta/text")
+                .and_then(|t| t.as_str())
+                .map(str::to_string)),
+            Some("error") => Err(format!(
+                "LLM API request failed: {}",
+                event.get("error").unwrap_or(event)
+            )),
+            _ => Ok(None),
+        }
+    }
+
+    async fn list_models(
+        &self,
+        client: &Client,
+        config: &ProviderConfig,
+    ) -> Result<Vec<String>, String> {
+        let url = format!("{}/v1/models", config.base_url(CLAUDE_API_BASE));
+        let models = fetch_json(Self::with_headers(client.get(url), config)).await?;
+        Ok(collect_names(&models, "/data", "id"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::summary::providers::test_support::{
+        complete_streaming, config_for, mock_server, request_json, REQUEST,
+    };
+
+    #[tokio::test]
+    async fn test_completion_request_shape() {
+        let (base_url, server) = mock_server(
+            200,
+            r#"{"id":"msg_1","type":"message","role":"assistant","content":[{"type":"text","text":" # Summary "}]}"#,
+        )
+        .await;
+
+        let result = ClaudeProvider
+            .complete(&Client::new(), &config_for(&base_url, Some("sk-ant")), &REQUEST, None, None)
+            .await;
+        assert_eq!(result.unwrap(), "# Summary");
+
+        let request = server.await.unwrap();
+        assert!(request.starts_with("POST /v1/messages "));
+        let lower = request.to_lowercase();
+        assert!(lower.contains("x-api-key: sk-ant"));
+        assert!(lower.contains("anthropic-version: 2023-06-01"));
+        assert!(!lower.contains("authorization:"));
+        let body = request_json(&request);
+        assert_eq!(body["system"], "You are a summarizer.");
+        assert_eq!(body["max_tokens"], 2048);
+        assert_eq!(body["messages"].as_array().unwrap().len(), 1);
+        assert_eq!(body["messages"][0]["role"], "user");
+    }
+
+    #[test]
+    fn test_stream_event_parsing() {
+        let delta = serde_json::json!({"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"Hi"}});
+        assert_eq!(ClaudeProvider.parse_stream_event(&delta).unwrap().as_deref(), Some("Hi"));
+        let ping = serde_json::json!({"type":"ping"});
+        assert!(ClaudeProvider.parse_stream_event(&ping).unwrap().is_none());
+        let error = serde_json::json!({"type":"error","error":{"type":"overloaded_error"}});
+        assert!(ClaudeProvider
+            .parse_stream_event(&error)
+            .unwrap_err()
+            .contains("overloaded_error"));
+    }
+
+    #[tokio::test]
+    async fn test_streaming() {
+        let (base_url, _server) = mock_server(
+            200,
+            "event: message_start\ndata: {\"type\":\"message_start\",\"message\":{\"id\":\"msg_1\"}}\n\n\
+             event: content_block_delta\ndata: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"text_delta\",\"text\":\"Hello\"}}\n\n\
+             event: content_block_delta\ndata: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"text_delta\",\"text\":\" there\"}}\n\n\
+             event: message_stop\ndata: {\"type\":\"message_stop\"}\n\n",
+        )
+        .await;
+        let (result, tokens) =
+            complete_streaming(&ClaudeProvider, &config_for(&base_url, Some("sk-ant"))).await;
+        assert_eq!(result.unwrap(), "Hello there");
+        assert_eq!(tokens, vec!["Hello", " there"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_models() {
+        let (base_url, server) = mock_server(
+            200,
+            r#"{"data":[{"id":"claude-sonnet-4-5","type":"model"}],"has_more":false}"#,
+        )
+        .await;
+        let models = ClaudeProvider
+            .list_models(&Client::new(), &config_for(&base_url, Some("sk-ant")))
+            .await
+            .unwrap();
+        assert_eq!(models, vec!["claude-sonnet-4-5"]);
+        let request = server.await.unwrap();
+        assert!(request.starts_with("GET /v1/models "));
+        assert!(request.to_lowercase().contains("x-api-key: sk-ant"));
+    }
+}
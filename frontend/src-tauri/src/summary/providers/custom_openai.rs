@@ -0,0 +1,149 @@
+//! Any server implementing the OpenAI chat completions API (vLLM, LM Studio, llama.cpp
+//! server, ...), configured through `CustomOpenAIConfig`.
+
+use async_trait::async_trait;
+use reqwest::{Client, RequestBuilder};
+use sqlx::SqlitePool;
+use tracing::info;
+
+use super::{openai_compat, CompletionRequest, LlmProvider, ProviderConfig};
+use crate::database::repositories::setting::SettingsRepository;
+
+pub struct CustomOpenAiProvider;
+
+impl CustomOpenAiProvider {
+    fn endpoint(config: &ProviderConfig) -> Result<&str, String> {
+        config
+            .endpoint
+            .as_deref()
+            .filter(|e| !e.trim().is_empty())
+            .map(|e| e.trim_end_matches('/'))
+            .ok_or_else(|| "Custom OpenAI endpoint not configured".to_string())
+    }
+}
+
+#[async_trait]
+impl LlmProvider for CustomOpenAiProvider {
+    fn id(&self) -> &'static str {
+        "custom-openai"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Custom OpenAI"
+    }
+
+    fn build_request(
+        &self,
+        client: &Client,
+        config: &ProviderConfig,
+        request: &CompletionRequest<'_>,
+        stream: bool,
+    ) -> Result<RequestBuilder, String> {
+        let base_url = Self::endpoint(config)?;
+        Ok(openai_compat::chat_request(client, base_url, config, request, stream))
+    }
+
+    fn parse_response(&self, body: &str) -> Result<String, String> {
+        openai_compat::parse_chat_response(body)
+    }
+
+    fn parse_stream_event(&self, event: &serde_json::Value) -> Result<Option<String>, String> {
+        openai_compat::stream_delta(event)
+    }
+
+    async fn list_models(
+        &self,
+        client: &Client,
+        config: &ProviderConfig,
+    ) -> Result<Vec<String>, String> {
+        openai_compat::list_models(client, Self::endpoint(config)?, config).await
+    }
+
+    /// Endpoint, key and sampling settings all come from the stored JSON config
+    async fn load_config(&self, settings_pool: &SqlitePool) -> Result<ProviderConfig, String> {
+        match SettingsRepository::get_custom_openai_config(settings_pool).await {
+            Ok(Some(config)) => {
+                info!("✓ Using custom OpenAI endpoint: {}", config.endpoint);
+                Ok(ProviderConfig {
+                    api_key: config.api_key,
+                    endpoint: Some(config.endpoint),
+                    max_tokens: config.max_tokens.map(|t| t as u32),
+                    temperature: config.temperature,
+                    top_p: config.top_p,
+                    ..Default::default()
+                })
+            }
+            Ok(None) => {
+                Err("Custom OpenAI provider selected but no configuration found".to_string())
+            }
+            Err(e) => Err(format!("Failed to retrieve custom OpenAI config: {}", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::summary::llm_client::LlmError;
+    use crate::summary::providers::test_support::{
+        complete_streaming, config_for, mock_server, request_json, REQUEST,
+    };
+
+    #[tokio::test]
+    async fn test_missing_endpoint_is_an_error() {
+        let err = CustomOpenAiProvider
+            .complete(&Client::new(), &ProviderConfig::default(), &REQUEST, None, None)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err,
+            LlmError::Failed("Custom OpenAI endpoint not configured".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sampling_settings_are_sent() {
+        let (base_url, server) = mock_server(
+            200,
+            r#"{"choices":[{"message":{"role":"assistant","content":"ok"}}]}"#,
+        )
+        .await;
+        let config = ProviderConfig {
+            max_tokens: Some(1024),
+            temperature: Some(0.3),
+            top_p: Some(0.9),
+            ..config_for(&format!("{}/v1/", base_url), None)
+        };
+        let result = CustomOpenAiProvider
+            .complete(&Client::new(), &config, &REQUEST, None, None)
+            .await;
+        assert_eq!(result.unwrap(), "ok");
+
+        let request = server.await.unwrap();
+        assert!(request.starts_with("POST /v1/chat/completions "));
+        let body = request_json(&request);
+        assert_eq!(body["max_tokens"], 1024);
+        assert!((body["temperature"].as_f64().unwrap() - 0.3).abs() < 1e-6);
+        assert!((body["top_p"].as_f64().unwrap() - 0.9).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn test_streaming() {
+        let (base_url, server) = mock_server(
+            200,
+            "data: {\"choices\":[{\"delta\":{\"content\":\"# Sum\"}}]}\n\n\
+             data: {\"choices\":[{\"delta\":{\"content\":\"mary \"}}]}\n\n\
+             data: [DONE]\n\n",
+        )
+        .await;
+
+        let config = config_for(&format!("{}/v1", base_url), Some("local-key"));
+        let (result, tokens) = complete_streaming(&CustomOpenAiProvider, &config).await;
+        assert_eq!(result.unwrap(), "# Summary");
+        assert_eq!(tokens, vec!["# Sum", "mary "]);
+
+        let request = server.await.unwrap();
+        assert!(request.starts_with("POST /v1/chat/completions "));
+        assert!(request.contains("\"stream\":true"));
+    }
+}
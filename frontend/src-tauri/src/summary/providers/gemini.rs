@@ -0,0 +1,352 @@
+//! Google Gemini `generateContent` API.
+//!
+//! The system prompt is sent as `systemInstruction`. Prompts or responses blocked by
+//! Gemini's safety filters are reported as errors instead of returning empty text.
+
+use async_trait::async_trait;
+use reqwest::{Client, RequestBuilder};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use super::{collect_names, fetch_json, CompletionRequest, LlmProvider, ProviderConfig};
+
+const GEMINI_API_BASE: &str = "https://generativelanguage.googleapis.com";
+
+// Gemini-specific request structure (generateContent)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeminiRequest {
+    pub contents: Vec<GeminiContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_instruction: Option<GeminiContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generation_config: Option<GeminiGenerationConfig>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GeminiContent {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+    #[serde(default)]
+    pub parts: Vec<GeminiPart>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GeminiPart {
+    #[serde(default)]
+    pub text: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeminiGenerationConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_output_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+}
+
+// Gemini-specific response structure
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GeminiResponse {
+    #[serde(default)]
+    pub candidates: Vec<GeminiCandidate>,
+    pub prompt_feedback: Option<GeminiPromptFeedback>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GeminiCandidate {
+    pub content: Option<GeminiContent>,
+    pub finish_reason: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GeminiPromptFeedback {
+    pub block_reason: Option<String>,
+}
+
+/// Finish reasons that mean the output was withheld by a safety filter
+fn is_block_reason(reason: &str) -> bool {
+    matches!(
+        reason,
+        "SAFETY" | "RECITATION" | "BLOCKLIST" | "PROHIBITED_CONTENT" | "SPII"
+    )
+}
+
+/// Extracts the generated text from a Gemini response, surfacing safety blocks
+fn extract_gemini_text(response: GeminiResponse) -> Result<String, String> {
+    if let Some(reason) = response
+        .prompt_feedback
+        .and_then(|feedback| feedback.block_reason)
+    {
+        return Err(format!("Gemini blocked the prompt (reason: {})", reason));
+    }
+
+    let candidate = response
+        .candidates
+        .into_iter()
+        .next()
+        .ok_or("No content in LLM response")?;
+
+    let text: String = candidate
+        .content
+        .map(|content| {
+            content
+                .parts
+                .into_iter()
+                .map(|part| part.text)
+                .collect::<Vec<_>>()
+                .join("")
+        })
+        .unwrap_or_default();
+
+    match candidate.finish_reason.as_deref() {
+        Some(reason) if is_block_reason(reason) && text.trim().is_empty() => {
+            Err(format!("Gemini blocked the response (reason: {})", reason))
+        }
+        _ if text.trim().is_empty() => Err("No content in LLM response".to_string()),
+        Some("MAX_TOKENS") => {
+            warn!("Gemini response was truncated at the max output token limit");
+            Ok(text.trim().to_string())
+        }
+        _ => Ok(text.trim().to_string()),
+    }
+}
+
+pub struct GeminiProvider;
+
+#[async_trait]
+impl LlmProvider for GeminiProvider {
+    fn id(&self) -> &'static str {
+        "gemini"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Gemini"
+    }
+
+    fn api_key_column(&self) -> Option<&'static str> {
+        Some("geminiApiKey")
+    }
+
+    /// With `stream`, `streamGenerateContent` is used and events arrive as SSE
+    fn build_request(
+        &self,
+        client: &Client,
+        config: &ProviderConfig,
+        request: &CompletionRequest<'_>,
+        stream: bool,
+    ) -> Result<RequestBuilder, String> {
+        let model = request.model.trim_start_matches("models/");
+        let method = if stream {
+            "streamGenerateContent?alt=sse"
+        } else {
+            "generateContent"
+        };
+        let api_url = format!(
+            "{}/v1beta/models/{}:{}",
+            config.base_url(GEMINI_API_BASE),
+            model,
+            method
+        );
+
+        let body = GeminiRequest {
+            contents: vec![GeminiContent {
+                role: Some("user".to_string()),
+                parts: vec![GeminiPart {
+                    text: request.user_prompt.to_string(),
+                }],
+            }],
+            system_instruction: if request.system_prompt.is_empty() {
+                None
+            } else {
+                Some(GeminiContent {
+                    role: None,
+                    parts: vec![GeminiPart {
+                        text: request.system_prompt.to_string(),
+                    }],
+                })
+            },
+            generation_config: Some(GeminiGenerationConfig {
+                max_output_tokens: config.max_tokens,
+                temperature: config.temperature,
+                top_p: config.top_p,
+            }),
+        };
+
+        Ok(client
+            .post(api_url)
+            .header("x-goog-api-key", config.api_key().unwrap_or_default())
+            .json(&body))
+    }
+
+    fn parse_response(&self, body: &str) -> Result<String, String> {
+        let response: GeminiResponse = serde_json::from_str(body)
+            .map_err(|e| format!("Failed to parse LLM response: {}", e))?;
+        extract_gemini_text(response)
+    }
+
+    /// Each SSE event is a partial `GenerateContentResponse`. Deltas are not trimmed,
+    /// since whitespace between chunks is part of the text.
+    fn parse_stream_event(&self, event: &serde_json::Value) -> Result<Option<String>, String> {
+        if let Some(error) = event.get("error") {
+            return Err(format!("LLM API request failed: {}", error));
+        }
+        if let Some(reason) = event
+            .pointer("/promptFeedback/blockReason")
+            .and_then(|r| r.as_str())
+        {
+            return Err(format!("Gemini blocked the prompt (reason: {})", reason));
+        }
+
+        let candidate = event.pointer("/candidates/0");
+        let text: String = candidate
+            .and_then(|c| c.pointer("/content/parts"))
+            .and_then(|parts| parts.as_array())
+            .map(|parts| {
+                parts
+                    .iter()
+                    .filter_map(|part| part.get("text").and_then(|t| t.as_str()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        match candidate
+            .and_then(|c| c.get("finishReason"))
+            .and_then(|r| r.as_str())
+        {
+            Some(reason) if is_block_reason(reason) && text.is_empty() => {
+                Err(format!("Gemini blocked the response (reason: {})", reason))
+            }
+            _ => Ok(Some(text)),
+        }
+    }
+
+    async fn list_models(
+        &self,
+        client: &Client,
+        config: &ProviderConfig,
+    ) -> Result<Vec<String>, String> {
+        let url = format!("{}/v1beta/models", config.base_url(GEMINI_API_BASE));
+        let request = client
+            .get(url)
+            .header("x-goog-api-key", config.api_key().unwrap_or_default());
+        let models = fetch_json(request).await?;
+        Ok(collect_names(&models, "/models", "name")
+            .into_iter()
+            .map(|name| name.trim_start_matches("models/").to_string())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::summary::llm_client::LlmError;
+    use crate::summary::providers::test_support::{
+        complete_streaming, config_for, mock_server, request_json,
+    };
+
+    async fn call_gemini(base_url: &str) -> Result<String, LlmError> {
+        let config = ProviderConfig {
+            max_tokens: Some(512),
+            temperature: Some(0.2),
+            ..config_for(base_url, Some("test-key"))
+        };
+        let request = CompletionRequest {
+            model: "models/gemini-2.5-flash",
+            system_prompt: "You are a summarizer.",
+            user_prompt: "Summarize this.",
+            json_schema: None,
+        };
+        GeminiProvider
+            .complete(&Client::new(), &config, &request, None, None)
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_gemini_request_shape_and_response() {
+        let (base_url, server) = mock_server(
+            200,
+            r##"{"candidates":[{"content":{"role":"model","parts":[{"text":"# Summary"},{"text":"\nDone "}]},"finishReason":"STOP"}]}"##,
+        )
+        .await;
+
+        let result = call_gemini(&base_url).await;
+        assert_eq!(result.unwrap(), "# Summary\nDone");
+
+        let request = server.await.unwrap();
+        assert!(request.starts_with("POST /v1beta/models/gemini-2.5-flash:generateContent "));
+        assert!(request.to_lowercase().contains("x-goog-api-key: test-key"));
+
+        let body = request_json(&request);
+        assert_eq!(body["systemInstruction"]["parts"][0]["text"], "You are a summarizer.");
+        assert_eq!(body["contents"][0]["role"], "user");
+        assert_eq!(body["contents"][0]["parts"][0]["text"], "Summarize this.");
+        assert_eq!(body["generationConfig"]["maxOutputTokens"], 512);
+        assert!(body["generationConfig"].get("topP").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_gemini_blocked_prompt_is_an_error() {
+        let (base_url, _server) =
+            mock_server(200, r#"{"promptFeedback":{"blockReason":"SAFETY"}}"#).await;
+        let err = call_gemini(&base_url).await.unwrap_err().to_string();
+        assert!(err.contains("blocked the prompt"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_gemini_blocked_response_is_an_error() {
+        let (base_url, _server) = mock_server(
+            200,
+            r#"{"candidates":[{"finishReason":"SAFETY","safetyRatings":[{"category":"HARM_CATEGORY_HARASSMENT","probability":"HIGH"}]}]}"#,
+        )
+        .await;
+        let err = call_gemini(&base_url).await.unwrap_err().to_string();
+        assert!(err.contains("blocked the response"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_gemini_http_error_is_reported() {
+        let (base_url, _server) =
+            mock_server(400, r#"{"error":{"code":400,"message":"API key not valid"}}"#).await;
+        let err = call_gemini(&base_url).await.unwrap_err().to_string();
+        assert!(err.contains("API key not valid"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_gemini_streaming() {
+        let (base_url, server) = mock_server(
+            200,
+            "data: {\"candidates\":[{\"content\":{\"parts\":[{\"text\":\"Hello\"}]}}]}\r\n\r\n\
+             data: {\"candidates\":[{\"content\":{\"parts\":[{\"text\":\" world\"}]},\"finishReason\":\"STOP\"}]}\r\n\r\n",
+        )
+        .await;
+
+        let (result, tokens) =
+            complete_streaming(&GeminiProvider, &config_for(&base_url, Some("test-key"))).await;
+        assert_eq!(result.unwrap(), "Hello world");
+        assert_eq!(tokens.concat(), "Hello world");
+        let request = server.await.unwrap();
+        assert!(request.starts_with("POST /v1beta/models/test-model:streamGenerateContent?alt=sse "));
+    }
+
+    #[tokio::test]
+    async fn test_list_models_strips_prefix() {
+        let (base_url, _server) = mock_server(
+            200,
+            r#"{"models":[{"name":"models/gemini-2.5-flash","displayName":"Gemini 2.5 Flash"}]}"#,
+        )
+        .await;
+        let models = GeminiProvider
+            .list_models(&Client::new(), &config_for(&base_url, Some("test-key")))
+            .await
+            .unwrap();
+        assert_eq!(models, vec!["gemini-2.5-flash"]);
+    }
+}
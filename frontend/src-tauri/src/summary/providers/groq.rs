@@ -0,0 +1,115 @@
+//! Groq's OpenAI-compatible chat completions API.
+
+use async_trait::async_trait;
+use reqwest::{Client, RequestBuilder};
+
+use super::{openai_compat, CompletionRequest, LlmProvider, ProviderConfig};
+
+const GROQ_API_BASE: &str = "https://api.groq.com/openai/v1";
+
+pub struct GroqProvider;
+
+#[async_trait]
+impl LlmProvider for GroqProvider {
+    fn id(&self) -> &'static str {
+        "groq"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Groq"
+    }
+
+    fn api_key_column(&self) -> Option<&'static str> {
+        Some("groqApiKey")
+    }
+
+    fn build_request(
+        &self,
+        client: &Client,
+        config: &ProviderConfig,
+        request: &CompletionRequest<'_>,
+        stream: bool,
+    ) -> Result<RequestBuilder, String> {
+        let base_url = config.base_url(GROQ_API_BASE);
+        Ok(openai_compat::chat_request(client, base_url, config, request, stream))
+    }
+
+    fn parse_response(&self, body: &str) -> Result<String, String> {
+        openai_compat::parse_chat_response(body)
+    }
+
+    fn parse_stream_event(&self, event: &serde_json::Value) -> Result<Option<String>, String> {
+        openai_compat::stream_delta(event)
+    }
+
+    async fn list_models(
+        &self,
+        client: &Client,
+        config: &ProviderConfig,
+    ) -> Result<Vec<String>, String> {
+        openai_compat::list_models(client, config.base_url(GROQ_API_BASE), config).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::summary::providers::test_support::{
+        complete_streaming, config_for, mock_server, request_json, REQUEST,
+    };
+
+    #[test]
+    fn test_default_endpoint() {
+        let request = GroqProvider
+            .build_request(
+                &Client::new(),
+                &ProviderConfig::default(),
+                &REQUEST,
+                false,
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(
+            request.url().as_str(),
+            "https://api.groq.com/openai/v1/chat/completions"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_streaming() {
+        let (base_url, server) = mock_server(
+            200,
+            "data: {\"choices\":[{\"delta\":{\"role\":\"assistant\"}}]}\n\n\
+             data: {\"choices\":[{\"delta\":{\"content\":\"# Sum\"}}]}\n\n\
+             data: {\"choices\":[{\"delta\":{\"content\":\"mary \"}}]}\n\n\
+             data: [DONE]\n\n",
+        )
+        .await;
+
+        let (result, tokens) =
+            complete_streaming(&GroqProvider, &config_for(&base_url, Some("gsk-test"))).await;
+        assert_eq!(result.unwrap(), "# Summary");
+        assert_eq!(tokens, vec!["# Sum", "mary "]);
+
+        let request = server.await.unwrap();
+        assert!(request.starts_with("POST /chat/completions "));
+        assert_eq!(request_json(&request)["stream"], true);
+    }
+
+    #[tokio::test]
+    async fn test_stream_error_event_fails_the_request() {
+        let (base_url, _server) = mock_server(
+            200,
+            "data: {\"error\":{\"message\":\"Rate limit reached\"}}\n\n",
+        )
+        .await;
+        let (result, tokens) =
+            complete_streaming(&GroqProvider, &config_for(&base_url, Some("gsk-test"))).await;
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Rate limit reached"));
+        assert!(tokens.is_empty());
+    }
+}
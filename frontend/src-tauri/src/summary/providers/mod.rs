@@ -0,0 +1,438 @@
+//! Pluggable LLM providers used for summary generation.
+//!
+//! Each provider implements [`LlmProvider`]: it builds the HTTP request for a completion,
+//! parses the response (whole or streamed), lists the models it offers and knows how to
+//! load its settings. Providers are looked up by id in the [`registry`], so adding one
+//! means writing an implementation and registering it in [`ProviderRegistry::default`].
+
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use reqwest::{Client, RequestBuilder};
+use sqlx::SqlitePool;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+
+use crate::database::repositories::setting::SettingsRepository;
+use crate::summary::llm_client::{
+    read_sse_stream, send_and_read, send_stream_request, LlmError, RetryPolicy, TokenCallback,
+    REQUEST_TIMEOUT_DURATION,
+};
+use crate::summary::tokenizer::{known_context_window, BpeCounter, TokenCounter};
+
+pub mod builtin;
+pub mod claude;
+pub mod custom_openai;
+pub mod gemini;
+pub mod groq;
+pub mod ollama;
+pub mod openai;
+mod openai_compat;
+pub mod openrouter;
+
+/// Timeout for model listing and connection tests
+const LIST_MODELS_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Settings a provider needs to make requests, loaded with [`LlmProvider::load_config`]
+#[derive(Debug, Clone, Default)]
+pub struct ProviderConfig {
+    pub api_key: Option<String>,
+    /// Base URL override (e.g. a custom Ollama host); providers fall back to their public API
+    pub endpoint: Option<String>,
+    pub max_tokens: Option<u32>,
+    pub temperature: Option<f32>,
+    pub top_p: Option<f32>,
+    /// App data directory, used by the built-in provider to locate model files
+    pub app_data_dir: Option<PathBuf>,
+    /// Retries for rate-limited and temporarily failing completion requests
+    pub retry: RetryPolicy,
+}
+
+impl ProviderConfig {
+    /// Configured endpoint without a trailing slash, or `default`
+    pub fn base_url<'a>(&'a self, default: &'a str) -> &'a str {
+        self.endpoint
+            .as_deref()
+            .filter(|e| !e.trim().is_empty())
+            .unwrap_or(default)
+            .trim_end_matches('/')
+    }
+
+    /// API key, if one is configured and non-empty
+    pub fn api_key(&self) -> Option<&str> {
+        self.api_key.as_deref().filter(|k| !k.is_empty())
+    }
+}
+
+/// A single completion: one system prompt and one user prompt
+#[derive(Debug, Clone, Copy)]
+pub struct CompletionRequest<'a> {
+    pub model: &'a str,
+    pub system_prompt: &'a str,
+    pub user_prompt: &'a str,
+    /// Constrains the response to JSON matching this schema, on providers that
+    /// [support it](LlmProvider::supports_json_schema)
+    pub json_schema: Option<&'a serde_json::Value>,
+}
+
+#[async_trait]
+pub trait LlmProvider: Send + Sync {
+    /// Provider id as stored in settings (e.g. "openai", "builtin-ai")
+    fn id(&self) -> &'static str;
+
+    /// Human-readable name for logs and errors
+    fn display_name(&self) -> &'static str;
+
+    /// Other ids accepted by [`get_provider`]
+    fn aliases(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Settings column the API key is stored under (see `SettingsRepository`)
+    fn api_key_column(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn requires_api_key(&self) -> bool {
+        self.api_key_column().is_some()
+    }
+
+    /// Runs on this machine, where every prompt token costs time rather than money
+    fn is_local(&self) -> bool {
+        false
+    }
+
+    /// Can constrain its output to [`CompletionRequest::json_schema`]. Other providers
+    /// ignore the schema, so callers must not rely on it without checking this.
+    fn supports_json_schema(&self) -> bool {
+        false
+    }
+
+    /// Builds the HTTP request for a completion, with streaming enabled if `stream` is set
+    fn build_request(
+        &self,
+        client: &Client,
+        config: &ProviderConfig,
+        request: &CompletionRequest<'_>,
+        stream: bool,
+    ) -> Result<RequestBuilder, String>;
+
+    /// Extracts the generated text from a complete response body
+    fn parse_response(&self, body: &str) -> Result<String, String>;
+
+    /// Extracts the text delta (if any) from one server-sent event of a streamed response
+    fn parse_stream_event(&self, event: &serde_json::Value) -> Result<Option<String>, String>;
+
+    /// Runs a completion and returns the trimmed text. With `on_token` the response is
+    /// streamed and every delta is passed on as it arrives.
+    async fn complete(
+        &self,
+        client: &Client,
+        config: &ProviderConfig,
+        request: &CompletionRequest<'_>,
+        cancellation_token: Option<&CancellationToken>,
+        on_token: Option<TokenCallback<'_>>,
+    ) -> Result<String, LlmError> {
+        if let Some(on_token) = on_token {
+            let http_request = self.build_request(client, config, request, true)?;
+            let response =
+                send_stream_request(http_request, &config.retry, cancellation_token).await?;
+            let extract_delta = |event: &serde_json::Value| self.parse_stream_event(event);
+            return read_sse_stream(response, cancellation_token, on_token, &extract_delta).await;
+        }
+
+        let http_request = self
+            .build_request(client, config, request, false)?
+            .timeout(REQUEST_TIMEOUT_DURATION);
+        let body = send_and_read(http_request, &config.retry, cancellation_token).await?;
+        self.parse_response(&body).map_err(LlmError::Failed)
+    }
+
+    /// Models available with this configuration
+    async fn list_models(
+        &self,
+        client: &Client,
+        config: &ProviderConfig,
+    ) -> Result<Vec<String>, String>;
+
+    /// Checks that the provider is reachable and the credentials are accepted
+    async fn test_connection(
+        &self,
+        client: &Client,
+        config: &ProviderConfig,
+    ) -> Result<(), String> {
+        self.list_models(client, config).await.map(|_| ())
+    }
+
+    /// Loads this provider's settings from the global settings database
+    async fn load_config(&self, settings_pool: &SqlitePool) -> Result<ProviderConfig, String> {
+        let mut config = ProviderConfig::default();
+        if self.api_key_column().is_some() {
+            config.api_key = SettingsRepository::get_api_key(settings_pool, self.id())
+                .await
+                .map_err(|e| format!("Failed to retrieve API key for {}: {}", self.id(), e))?
+                .filter(|key| !key.is_empty());
+        }
+        if self.requires_api_key() && config.api_key.is_none() {
+            return Err(format!("API key not found for {}", self.id()));
+        }
+        Ok(config)
+    }
+
+    /// Context window of `model` in tokens; transcripts that don't fit are chunked
+    async fn context_size(&self, _config: &ProviderConfig, model: &str) -> usize {
+        known_context_window(model)
+    }
+
+    /// Tokenizer used to size prompts and chunks for `model`
+    fn token_counter(&self, _config: &ProviderConfig, model: &str) -> Arc<dyn TokenCounter> {
+        Arc::new(BpeCounter::for_model(model))
+    }
+}
+
+/// All known LLM providers
+pub struct ProviderRegistry {
+    providers: Vec<Arc<dyn LlmProvider>>,
+}
+
+impl Default for ProviderRegistry {
+    fn default() -> Self {
+        Self {
+            providers: vec![
+                Arc::new(openai::OpenAiProvider),
+                Arc::new(claude::ClaudeProvider),
+                Arc::new(gemini::GeminiProvider),
+                Arc::new(groq::GroqProvider),
+                Arc::new(ollama::OllamaProvider),
+                Arc::new(openrouter::OpenRouterProvider),
+                Arc::new(builtin::BuiltInProvider),
+                Arc::new(custom_openai::CustomOpenAiProvider),
+            ],
+        }
+    }
+}
+
+impl ProviderRegistry {
+    /// Finds a provider by id or alias (case-insensitive)
+    pub fn get(&self, id: &str) -> Option<Arc<dyn LlmProvider>> {
+        let id = id.to_lowercase();
+        self.providers
+            .iter()
+            .find(|p| p.id() == id || p.aliases().contains(&id.as_str()))
+            .cloned()
+    }
+
+    pub fn providers(&self) -> impl Iterator<Item = &Arc<dyn LlmProvider>> {
+        self.providers.iter()
+    }
+}
+
+static REGISTRY: Lazy<ProviderRegistry> = Lazy::new(ProviderRegistry::default);
+
+pub fn registry() -> &'static ProviderRegistry {
+    &REGISTRY
+}
+
+/// Looks up a provider in the global registry
+pub fn get_provider(id: &str) -> Result<Arc<dyn LlmProvider>, String> {
+    registry()
+        .get(id)
+        .ok_or_else(|| format!("Unsupported LLM provider: {}", id))
+}
+
+/// GET request used for model listing, bounded by [`LIST_MODELS_TIMEOUT`] and not retried
+async fn fetch_json(request: RequestBuilder) -> Result<serde_json::Value, String> {
+    let request = request.timeout(LIST_MODELS_TIMEOUT);
+    let body = send_and_read(request, &RetryPolicy::none(), None).await?;
+    serde_json::from_str(&body).map_err(|e| format!("Failed to parse model list: {}", e))
+}
+
+/// String values at `field` of every object in the array at `pointer`
+fn collect_names(value: &serde_json::Value, pointer: &str, field: &str) -> Vec<String> {
+    value
+        .pointer(pointer)
+        .and_then(|items| items.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| item.get(field).and_then(|v| v.as_str()))
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+pub(crate) mod test_support {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// One canned HTTP response for [`mock_server_sequence`]
+    pub struct MockResponse {
+        pub status: u16,
+        pub headers: &'static [(&'static str, &'static str)],
+        pub body: &'static str,
+    }
+
+    /// Reads one request from `socket`, answers it and returns the raw request
+    async fn serve_one(mut socket: tokio::net::TcpStream, response: &MockResponse) -> String {
+        let mut request = Vec::new();
+        let mut buf = [0u8; 4096];
+        loop {
+            let n = socket.read(&mut buf).await.unwrap();
+            request.extend_from_slice(&buf[..n]);
+            let text = String::from_utf8_lossy(&request).to_string();
+            if let Some(header_end) = text.find("\r\n\r\n") {
+                let content_length = text[..header_end]
+                    .lines()
+                    .find_map(|line| {
+                        let (name, value) = line.split_once(':')?;
+                        name.eq_ignore_ascii_case("content-length")
+                            .then(|| value.trim().parse::<usize>().ok())
+                            .flatten()
+                    })
+                    .unwrap_or(0);
+                if request.len() >= header_end + 4 + content_length {
+                    break;
+                }
+            }
+            if n == 0 {
+                break;
+            }
+        }
+
+        let extra_headers: String = response
+            .headers
+            .iter()
+            .map(|(name, value)| format!("{}: {}\r\n", name, value))
+            .collect();
+        let raw = format!(
+            "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+            response.status,
+            extra_headers,
+            response.body.len(),
+            response.body
+        );
+        socket.write_all(raw.as_bytes()).await.unwrap();
+        String::from_utf8_lossy(&request).to_string()
+    }
+
+    /// Serves a single HTTP response and returns the raw request it received
+    pub async fn mock_server(
+        status: u16,
+        body: &'static str,
+    ) -> (String, tokio::task::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let response = MockResponse {
+                status,
+                headers: &[],
+                body,
+            };
+            serve_one(socket, &response).await
+        });
+
+        (base_url, handle)
+    }
+
+    /// Serves `responses` in order, one connection each, and returns the raw requests
+    pub async fn mock_server_sequence(
+        responses: Vec<MockResponse>,
+    ) -> (String, tokio::task::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            for response in &responses {
+                let (socket, _) = listener.accept().await.unwrap();
+                requests.push(serve_one(socket, response).await);
+            }
+            requests
+        });
+
+        (base_url, handle)
+    }
+
+    /// JSON body of a raw HTTP request captured by [`mock_server`]
+    pub fn request_json(request: &str) -> serde_json::Value {
+        serde_json::from_str(&request[request.find("\r\n\r\n").unwrap() + 4..]).unwrap()
+    }
+
+    pub fn config_for(base_url: &str, api_key: Option<&str>) -> ProviderConfig {
+        ProviderConfig {
+            api_key: api_key.map(str::to_string),
+            endpoint: Some(base_url.to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub const REQUEST: CompletionRequest<'static> = CompletionRequest {
+        model: "test-model",
+        system_prompt: "You are a summarizer.",
+        user_prompt: "Summarize this.",
+        json_schema: None,
+    };
+
+    /// Runs a streamed completion and returns the result and the deltas received
+    pub async fn complete_streaming(
+        provider: &dyn LlmProvider,
+        config: &ProviderConfig,
+    ) -> (Result<String, LlmError>, Vec<String>) {
+        let tokens = std::sync::Mutex::new(Vec::new());
+        let on_token = |delta: &str| tokens.lock().unwrap().push(delta.to_string());
+        let result = provider
+            .complete(&Client::new(), config, &REQUEST, None, Some(&on_token))
+            .await;
+        let tokens = tokens.into_inner().unwrap();
+        (result, tokens)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_lookup_by_id_and_alias() {
+        assert_eq!(get_provider("Gemini").unwrap().id(), "gemini");
+        assert_eq!(get_provider("local-llama").unwrap().id(), "builtin-ai");
+        assert!(get_provider("nope").unwrap_err().contains("Unsupported LLM provider"));
+    }
+
+    #[test]
+    fn test_registry_ids_are_unique() {
+        let mut ids: Vec<&str> = registry()
+            .providers()
+            .flat_map(|p| std::iter::once(p.id()).chain(p.aliases().iter().copied()))
+            .collect();
+        let count = ids.len();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), count);
+    }
+
+    #[test]
+    fn test_local_providers() {
+        let local: Vec<&str> = registry()
+            .providers()
+            .filter(|p| p.is_local())
+            .map(|p| p.id())
+            .collect();
+        assert_eq!(local, vec!["ollama", "builtin-ai"]);
+    }
+
+    #[tokio::test]
+    async fn test_cloud_context_size_from_model_name() {
+        let config = ProviderConfig::default();
+        let claude = get_provider("claude").unwrap();
+        assert_eq!(claude.context_size(&config, "claude-sonnet-4-5").await, 200_000);
+        let openrouter = get_provider("openrouter").unwrap();
+        assert_eq!(openrouter.context_size(&config, "openai/gpt-4o").await, 128_000);
+    }
+}
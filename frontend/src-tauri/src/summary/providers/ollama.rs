@@ -0,0 +1,175 @@
+//! Local Ollama server, through its OpenAI-compatible API.
+
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use reqwest::{Client, RequestBuilder};
+use sqlx::SqlitePool;
+use std::time::Duration;
+use tracing::{info, warn};
+
+use super::{
+    collect_names, fetch_json, openai_compat, CompletionRequest, LlmProvider, ProviderConfig,
+};
+use crate::database::repositories::setting::SettingsRepository;
+use crate::ollama::metadata::ModelMetadataCache;
+
+const OLLAMA_DEFAULT_HOST: &str = "http://localhost:11434";
+
+/// Context size assumed when the model metadata can't be fetched
+const FALLBACK_CONTEXT_SIZE: usize = 4096;
+
+// Global cache for model metadata (5 minute TTL)
+static METADATA_CACHE: Lazy<ModelMetadataCache> =
+    Lazy::new(|| ModelMetadataCache::new(Duration::from_secs(300)));
+
+pub struct OllamaProvider;
+
+#[async_trait]
+impl LlmProvider for OllamaProvider {
+    fn id(&self) -> &'static str {
+        "ollama"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Ollama"
+    }
+
+    /// Only needed when Ollama sits behind an authenticating proxy
+    fn api_key_column(&self) -> Option<&'static str> {
+        Some("ollamaApiKey")
+    }
+
+    fn requires_api_key(&self) -> bool {
+        false
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn build_request(
+        &self,
+        client: &Client,
+        config: &ProviderConfig,
+        request: &CompletionRequest<'_>,
+        stream: bool,
+    ) -> Result<RequestBuilder, String> {
+        let base_url = format!("{}/v1", config.base_url(OLLAMA_DEFAULT_HOST));
+        Ok(openai_compat::chat_request(client, &base_url, config, request, stream))
+    }
+
+    fn parse_response(&self, body: &str) -> Result<String, String> {
+        openai_compat::parse_chat_response(body)
+    }
+
+    fn parse_stream_event(&self, event: &serde_json::Value) -> Result<Option<String>, String> {
+        openai_compat::stream_delta(event)
+    }
+
+    /// Installed models, from Ollama's native `/api/tags`
+    async fn list_models(
+        &self,
+        client: &Client,
+        config: &ProviderConfig,
+    ) -> Result<Vec<String>, String> {
+        let url = format!("{}/api/tags", config.base_url(OLLAMA_DEFAULT_HOST));
+        let tags = fetch_json(client.get(url)).await?;
+        Ok(collect_names(&tags, "/models", "name"))
+    }
+
+    async fn load_config(&self, settings_pool: &SqlitePool) -> Result<ProviderConfig, String> {
+        let endpoint = match SettingsRepository::get_model_config(settings_pool).await {
+            Ok(Some(config)) => config.ollama_endpoint,
+            Ok(None) => None,
+            Err(e) => {
+                info!("Failed to retrieve Ollama endpoint: {}, using default", e);
+                None
+            }
+        };
+        let api_key = SettingsRepository::get_api_key(settings_pool, self.id())
+            .await
+            .unwrap_or_else(|e| {
+                warn!("Failed to retrieve Ollama API key: {}", e);
+                None
+            });
+
+        Ok(ProviderConfig {
+            api_key,
+            endpoint,
+            ..Default::default()
+        })
+    }
+
+    /// The context requests actually run with, which through the OpenAI-compatible API
+    /// is the model's `num_ctx` rather than its trained maximum
+    async fn context_size(&self, config: &ProviderConfig, model: &str) -> usize {
+        match METADATA_CACHE
+            .get_or_fetch(model, config.endpoint.as_deref())
+            .await
+        {
+            Ok(metadata) => metadata.request_context_size(),
+            Err(e) => {
+                warn!(
+                    "Failed to fetch context for {}: {}. Using default {}",
+                    model, e, FALLBACK_CONTEXT_SIZE
+                );
+                FALLBACK_CONTEXT_SIZE
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::summary::providers::test_support::{config_for, mock_server, request_json, REQUEST};
+
+    #[tokio::test]
+    async fn test_completion_uses_openai_compatible_path_without_key() {
+        let (base_url, server) = mock_server(
+            200,
+            r#"{"choices":[{"message":{"role":"assistant","content":"Local summary"}}]}"#,
+        )
+        .await;
+
+        let config = config_for(&format!("{}/", base_url), None);
+        let result = OllamaProvider
+            .complete(&Client::new(), &config, &REQUEST, None, None)
+            .await;
+        assert_eq!(result.unwrap(), "Local summary");
+
+        let request = server.await.unwrap();
+        assert!(request.starts_with("POST /v1/chat/completions "));
+        assert!(!request.to_lowercase().contains("authorization:"));
+        assert_eq!(request_json(&request)["model"], "test-model");
+    }
+
+    #[tokio::test]
+    async fn test_list_models_from_tags() {
+        let (base_url, server) = mock_server(
+            200,
+            r#"{"models":[{"name":"llama3.2:latest","size":2019393189},{"name":"gemma3:4b","size":3338801804}]}"#,
+        )
+        .await;
+        let models = OllamaProvider
+            .list_models(&Client::new(), &config_for(&base_url, None))
+            .await
+            .unwrap();
+        assert_eq!(models, vec!["llama3.2:latest", "gemma3:4b"]);
+        assert!(server.await.unwrap().starts_with("GET /api/tags "));
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_server_fails_connection_test() {
+        // Bind and drop a listener to get a port nothing is listening on
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let err = OllamaProvider
+            .test_connection(&Client::new(), &config_for(&base_url, None))
+            .await
+            .unwrap_err();
+        assert!(err.contains("Failed to send request"), "{}", err);
+    }
+}
@@ -0,0 +1,110 @@
+//! OpenAI chat completions API.
+
+use async_trait::async_trait;
+use reqwest::{Client, RequestBuilder};
+
+use super::{openai_compat, CompletionRequest, LlmProvider, ProviderConfig};
+
+const OPENAI_API_BASE: &str = "https://api.openai.com/v1";
+
+pub struct OpenAiProvider;
+
+#[async_trait]
+impl LlmProvider for OpenAiProvider {
+    fn id(&self) -> &'static str {
+        "openai"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "OpenAI"
+    }
+
+    fn api_key_column(&self) -> Option<&'static str> {
+        Some("openaiApiKey")
+    }
+
+    fn build_request(
+        &self,
+        client: &Client,
+        config: &ProviderConfig,
+        request: &CompletionRequest<'_>,
+        stream: bool,
+    ) -> Result<RequestBuilder, String> {
+        let base_url = config.base_url(OPENAI_API_BASE);
+        Ok(openai_compat::chat_request(client, base_url, config, request, stream))
+    }
+
+    fn parse_response(&self, body: &str) -> Result<String, String> {
+        openai_compat::parse_chat_response(body)
+    }
+
+    fn parse_stream_event(&self, event: &serde_json::Value) -> Result<Option<String>, String> {
+        openai_compat::stream_delta(event)
+    }
+
+    async fn list_models(
+        &self,
+        client: &Client,
+        config: &ProviderConfig,
+    ) -> Result<Vec<String>, String> {
+        openai_compat::list_models(client, config.base_url(OPENAI_API_BASE), config).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::summary::providers::test_support::{config_for, mock_server, request_json, REQUEST};
+
+    #[tokio::test]
+    async fn test_completion_request_and_response() {
+        let (base_url, server) = mock_server(
+            200,
+            r##"{"choices":[{"message":{"role":"assistant","content":"# Summary\n"}}]}"##,
+        )
+        .await;
+
+        let config = config_for(&base_url, Some("sk-test"));
+        let result = OpenAiProvider
+            .complete(&Client::new(), &config, &REQUEST, None, None)
+            .await;
+        assert_eq!(result.unwrap(), "# Summary");
+
+        let request = server.await.unwrap();
+        assert!(request.starts_with("POST /chat/completions "));
+        assert!(request.to_lowercase().contains("authorization: bearer sk-test"));
+        let body = request_json(&request);
+        assert_eq!(body["model"], "test-model");
+        assert_eq!(body["messages"][0]["role"], "system");
+        assert_eq!(body["messages"][1]["content"], "Summarize this.");
+        assert!(body.get("stream").is_none());
+        assert!(body.get("max_tokens").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_http_error_is_reported() {
+        let (base_url, _server) =
+            mock_server(401, r#"{"error":{"message":"Incorrect API key provided"}}"#).await;
+        let err = OpenAiProvider
+            .complete(&Client::new(), &config_for(&base_url, Some("bad")), &REQUEST, None, None)
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Incorrect API key provided"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_list_models() {
+        let (base_url, server) = mock_server(
+            200,
+            r#"{"object":"list","data":[{"id":"gpt-4o","object":"model"},{"id":"gpt-4o-mini","object":"model"}]}"#,
+        )
+        .await;
+        let models = OpenAiProvider
+            .list_models(&Client::new(), &config_for(&base_url, Some("sk-test")))
+            .await
+            .unwrap();
+        assert_eq!(models, vec!["gpt-4o", "gpt-4o-mini"]);
+        assert!(server.await.unwrap().starts_with("GET /models "));
+    }
+}
@@ -0,0 +1,134 @@
+//! Request and response handling shared by providers that speak the OpenAI
+//! `chat/completions` API (OpenAI, Groq, OpenRouter, Ollama and custom endpoints).
+
+use reqwest::{Client, RequestBuilder};
+use serde::{Deserialize, Serialize};
+
+use super::{collect_names, fetch_json, CompletionRequest, ProviderConfig};
+use crate::summary::llm_client::ChatMessage;
+
+// Generic structure for OpenAI-compatible API chat requests
+#[derive(Debug, Serialize)]
+pub struct ChatRequest {
+    pub model: String,
+    pub messages: Vec<ChatMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<bool>,
+}
+
+// Generic structure for OpenAI-compatible API chat responses
+#[derive(Deserialize, Debug)]
+pub struct ChatResponse {
+    pub choices: Vec<Choice>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Choice {
+    pub message: MessageContent,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct MessageContent {
+    pub content: String,
+}
+
+/// `POST {base_url}/chat/completions`, authenticated with a bearer token when a key is set
+pub(super) fn chat_request(
+    client: &Client,
+    base_url: &str,
+    config: &ProviderConfig,
+    request: &CompletionRequest<'_>,
+    stream: bool,
+) -> RequestBuilder {
+    let body = ChatRequest {
+        model: request.model.to_string(),
+        messages: vec![
+            ChatMessage {
+                role: "system".to_string(),
+                content: request.system_prompt.to_string(),
+            },
+            ChatMessage {
+                role: "user".to_string(),
+                content: request.user_prompt.to_string(),
+            },
+        ],
+        max_tokens: config.max_tokens,
+        temperature: config.temperature,
+        top_p: config.top_p,
+        stream: stream.then_some(true),
+    };
+
+    let builder = client
+        .post(format!("{}/chat/completions", base_url))
+        .json(&body);
+    match config.api_key() {
+        Some(key) => builder.bearer_auth(key),
+        None => builder,
+    }
+}
+
+pub(super) fn parse_chat_response(body: &str) -> Result<String, String> {
+    let chat_response: ChatResponse =
+        serde_json::from_str(body).map_err(|e| format!("Failed to parse LLM response: {}", e))?;
+    let content = chat_response
+        .choices
+        .first()
+        .ok_or("No content in LLM response")?
+        .message
+        .content
+        .trim();
+    Ok(content.to_string())
+}
+
+/// `chat.completion.chunk` events carry text in `choices[0].delta.content`
+pub(super) fn stream_delta(event: &serde_json::Value) -> Result<Option<String>, String> {
+    if let Some(error) = event.get("error") {
+        return Err(format!("LLM API request failed: {}", error));
+    }
+    Ok(event
+        .pointer("/choices/0/delta/content")
+        .and_then(|c| c.as_str())
+        .map(str::to_string))
+}
+
+/// `GET {base_url}/models`, returning the `data[].id` of each model
+pub(super) async fn list_models(
+    client: &Client,
+    base_url: &str,
+    config: &ProviderConfig,
+) -> Result<Vec<String>, String> {
+    let mut request = client.get(format!("{}/models", base_url));
+    if let Some(key) = config.api_key() {
+        request = request.bearer_auth(key);
+    }
+    let models = fetch_json(request).await?;
+    Ok(collect_names(&models, "/data", "id"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_chat_response_trims_content() {
+        let body = r#"{"choices":[{"message":{"role":"assistant","content":"  # Summary \n"}}]}"#;
+        assert_eq!(parse_chat_response(body).unwrap(), "# Summary");
+        assert!(parse_chat_response(r#"{"choices":[]}"#).is_err());
+    }
+
+    #[test]
+    fn test_stream_delta() {
+        let role = serde_json::json!({"choices":[{"delta":{"role":"assistant"}}]});
+        assert!(stream_delta(&role).unwrap().is_none());
+        let text = serde_json::json!({"choices":[{"delta":{"content":"Hi"}}]});
+        assert_eq!(stream_delta(&text).unwrap().as_deref(), Some("Hi"));
+        let error = serde_json::json!({"error":{"message":"rate limited"}});
+        assert!(stream_delta(&error).unwrap_err().contains("rate limited"));
+    }
+}
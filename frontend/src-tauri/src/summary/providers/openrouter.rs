@@ -0,0 +1,94 @@
+//! OpenRouter's OpenAI-compatible chat completions API.
+
+use async_trait::async_trait;
+use reqwest::{Client, RequestBuilder};
+
+use super::{openai_compat, CompletionRequest, LlmProvider, ProviderConfig};
+
+const OPENROUTER_API_BASE: &str = "https://openrouter.ai/api/v1";
+
+pub struct OpenRouterProvider;
+
+#[async_trait]
+impl LlmProvider for OpenRouterProvider {
+    fn id(&self) -> &'static str {
+        "openrouter"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "OpenRouter"
+    }
+
+    fn api_key_column(&self) -> Option<&'static str> {
+        Some("openRouterApiKey")
+    }
+
+    fn build_request(
+        &self,
+        client: &Client,
+        config: &ProviderConfig,
+        request: &CompletionRequest<'_>,
+        stream: bool,
+    ) -> Result<RequestBuilder, String> {
+        let base_url = config.base_url(OPENROUTER_API_BASE);
+        Ok(openai_compat::chat_request(client, base_url, config, request, stream))
+    }
+
+    fn parse_response(&self, body: &str) -> Result<String, String> {
+        openai_compat::parse_chat_response(body)
+    }
+
+    fn parse_stream_event(&self, event: &serde_json::Value) -> Result<Option<String>, String> {
+        openai_compat::stream_delta(event)
+    }
+
+    /// The model list is public, so this doesn't prove the API key is valid
+    async fn list_models(
+        &self,
+        client: &Client,
+        config: &ProviderConfig,
+    ) -> Result<Vec<String>, String> {
+        openai_compat::list_models(client, config.base_url(OPENROUTER_API_BASE), config).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::summary::providers::test_support::{
+        complete_streaming, config_for, mock_server, REQUEST,
+    };
+
+    #[tokio::test]
+    async fn test_completion() {
+        let (base_url, server) = mock_server(
+            200,
+            r#"{"id":"gen-1","model":"openai/gpt-4o","choices":[{"message":{"role":"assistant","content":"Done"}}]}"#,
+        )
+        .await;
+        let result = OpenRouterProvider
+            .complete(&Client::new(), &config_for(&base_url, Some("or-key")), &REQUEST, None, None)
+            .await;
+        assert_eq!(result.unwrap(), "Done");
+        assert!(server
+            .await
+            .unwrap()
+            .to_lowercase()
+            .contains("authorization: bearer or-key"));
+    }
+
+    #[tokio::test]
+    async fn test_streaming_ignores_keep_alive_comments() {
+        let (base_url, _server) = mock_server(
+            200,
+            ": OPENROUTER PROCESSING\n\n\
+             data: {\"choices\":[{\"delta\":{\"content\":\"Hello\"}}]}\n\n\
+             data: [DONE]\n\n",
+        )
+        .await;
+        let (result, tokens) =
+            complete_streaming(&OpenRouterProvider, &config_for(&base_url, Some("or-key"))).await;
+        assert_eq!(result.unwrap(), "Hello");
+        assert_eq!(tokens, vec!["Hello"]);
+    }
+}
@@ -1,27 +1,114 @@
 use crate::database::repositories::{
-    meeting::MeetingsRepository, setting::SettingsRepository, summary::SummaryProcessesRepository,
+    action_item::ActionItemsRepository, meeting::MeetingsRepository,
+    notes::MeetingNotesRepository, setting::SettingsRepository, speaker::SpeakersRepository,
+    summary::SummaryProcessesRepository,
+    summary_version::{SummaryVersionOrigin, SummaryVersionsRepository},
+    transcript::TranscriptsRepository,
 };
-use crate::summary::llm_client::LLMProvider;
-use crate::summary::processor::{extract_meeting_name_from_markdown, generate_meeting_summary};
-use crate::ollama::metadata::ModelMetadataCache;
+use crate::database::models::SummaryVersion;
+use crate::summary::extraction::{extract_structured_items, format_transcript_with_timestamps};
+use crate::summary::llm_client::{LlmError, RetryPolicy};
+use crate::summary::processor::{
+    extract_meeting_name_from_markdown, format_transcript_with_speakers, format_user_notes,
+    generate_meeting_summary, SummaryProgress,
+};
+use crate::summary::providers::{get_provider, LlmProvider, ProviderConfig};
+use crate::summary::templates;
+use crate::summary::tokenizer::TokenBudget;
+use crate::summary::versions::{diff_lines, summary_markdown, DiffLine};
+use crate::summary::{FallbackModel, SummaryFallbackConfig};
 use sqlx::SqlitePool;
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
-use std::time::{Duration, Instant};
-use tauri::{AppHandle, Manager};
+use std::time::Instant;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager};
 use tokio_util::sync::CancellationToken;
 use tracing::{error, info, warn};
 use once_cell::sync::Lazy;
 
-// Global cache for model metadata (5 minute TTL)
-static METADATA_CACHE: Lazy<ModelMetadataCache> = Lazy::new(|| {
-    ModelMetadataCache::new(Duration::from_secs(300))
-});
-
 // Global registry for cancellation tokens (thread-safe)
 static CANCELLATION_REGISTRY: Lazy<Arc<Mutex<HashMap<String, CancellationToken>>>> =
     Lazy::new(|| Arc::new(Mutex::new(HashMap::new())));
 
+/// Payload of the `summary-progress` event, emitted for every streamed piece of output
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SummaryProgressEvent<'a> {
+    meeting_id: &'a str,
+    /// Position in the fallback chain (1 = the selected model)
+    attempt: usize,
+    #[serde(flatten)]
+    progress: SummaryProgress,
+}
+
+/// Outcome of one model in the fallback chain, recorded in `summary_processes.metadata`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SummaryAttempt {
+    provider: String,
+    model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl SummaryAttempt {
+    fn new(candidate: &FallbackModel, error: Option<String>) -> Self {
+        Self {
+            provider: candidate.provider.clone(),
+            model: candidate.model.clone(),
+            error,
+        }
+    }
+}
+
+/// The selected model followed by the configured fallbacks, skipping blanks and duplicates
+fn fallback_chain(
+    provider: &str,
+    model: &str,
+    config: &SummaryFallbackConfig,
+) -> Vec<FallbackModel> {
+    let mut chain = vec![FallbackModel {
+        provider: provider.to_string(),
+        model: model.to_string(),
+    }];
+    for fallback in &config.fallbacks {
+        let usable = !fallback.provider.trim().is_empty() && !fallback.model.trim().is_empty();
+        if usable && !chain.contains(fallback) {
+            chain.push(fallback.clone());
+        }
+    }
+    chain
+}
+
+/// `summary_processes.metadata` for a completed summary; the last attempt is the one
+/// that succeeded
+fn summary_metadata(attempts: &[SummaryAttempt]) -> serde_json::Value {
+    let produced_by = attempts.last();
+    serde_json::json!({
+        "provider": produced_by.map(|a| a.provider.as_str()),
+        "model": produced_by.map(|a| a.model.as_str()),
+        "fallbackUsed": attempts.len() > 1,
+        "attempts": attempts,
+    })
+}
+
+fn describe_failures(attempts: &[SummaryAttempt]) -> String {
+    let failures: Vec<String> = attempts
+        .iter()
+        .map(|a| {
+            format!(
+                "{} / {}: {}",
+                a.provider,
+                a.model,
+                a.error.as_deref().unwrap_or("unknown error")
+            )
+        })
+        .collect();
+    format!("All summary models failed. {}", failures.join("; "))
+}
+
 /// Summary service - handles all summary generation logic
 pub struct SummaryService;
 
@@ -49,6 +136,14 @@ impl SummaryService {
         false
     }
 
+    /// Whether a summary is currently being generated for a meeting
+    fn is_generating(meeting_id: &str) -> bool {
+        CANCELLATION_REGISTRY
+            .lock()
+            .map(|registry| registry.contains_key(meeting_id))
+            .unwrap_or(false)
+    }
+
     /// Cleans up the cancellation token after processing completes
     fn cleanup_cancellation_token(meeting_id: &str) {
         if let Ok(mut registry) = CANCELLATION_REGISTRY.lock() {
@@ -61,11 +156,13 @@ impl SummaryService {
     /// Processes transcript in the background and generates summary
     ///
     /// This function is designed to be spawned as an async task and does not block
-    /// the main thread. It updates the database with progress and results.
+    /// the main thread. It updates the database with progress and results, and streams
+    /// the model output to the UI as `summary-progress` events while it is generated.
     ///
     /// # Arguments
-    /// * `_app` - Tauri app handle (for future use)
-    /// * `pool` - SQLx connection pool
+    /// * `app` - Tauri app handle (progress events, app data directory)
+    /// * `pool` - Workspace connection pool (meetings, transcripts, summaries)
+    /// * `settings_pool` - Global connection pool (model config and API keys)
     /// * `meeting_id` - Unique identifier for the meeting
     /// * `text` - Full transcript text
     /// * `model_provider` - LLM provider name (e.g., "ollama", "openai")
@@ -73,8 +170,9 @@ impl SummaryService {
     /// * `custom_prompt` - Optional user-provided context
     /// * `template_id` - Template identifier (e.g., "daily_standup", "standard_meeting")
     pub async fn process_transcript_background<R: tauri::Runtime>(
-        _app: AppHandle<R>,
+        app: AppHandle<R>,
         pool: SqlitePool,
+        settings_pool: SqlitePool,
         meeting_id: String,
         text: String,
         model_provider: String,
@@ -91,154 +189,162 @@ impl SummaryService {
         // Register cancellation token for this meeting
         let cancellation_token = Self::register_cancellation_token(&meeting_id);
 
-        // Parse provider
-        let provider = match LLMProvider::from_str(&model_provider) {
-            Ok(p) => p,
-            Err(e) => {
-                Self::update_process_failed(&pool, &meeting_id, &e).await;
-                return;
+        // Prefer stored segments when they carry speaker tags so the prompt gets "Me:" / "Others:"
+        // or diarized speaker labels (with user-assigned names)
+        let speaker_names = SpeakersRepository::get_speaker_names(&pool, &meeting_id)
+            .await
+            .unwrap_or_else(|e| {
+                warn!("Failed to load speaker names: {}", e);
+                HashMap::new()
+            });
+        let transcripts = TranscriptsRepository::get_transcripts_for_meeting(&pool, &meeting_id)
+            .await
+            .unwrap_or_else(|e| {
+                warn!("Failed to load transcripts for speaker labels: {}, using plain text", e);
+                Vec::new()
+            });
+        let text = match format_transcript_with_speakers(&transcripts, &speaker_names) {
+            Some(labelled) => {
+                info!("Using speaker-labelled transcript for meeting_id: {}", meeting_id);
+                labelled
             }
+            None => text,
         };
 
-        // Validate and setup api_key, Flexible for Ollama, BuiltInAI, and CustomOpenAI
-        let api_key = if provider == LLMProvider::Ollama || provider == LLMProvider::BuiltInAI || provider == LLMProvider::CustomOpenAI {
-            // These providers don't require API keys from the standard database column
-            String::new()
-        } else {
-            match SettingsRepository::get_api_key(&pool, &model_provider).await {
-                Ok(Some(key)) if !key.is_empty() => key,
-                Ok(None) | Ok(Some(_)) => {
-                    let err_msg = format!("API key not found for {}", &model_provider);
-                    Self::update_process_failed(&pool, &meeting_id, &err_msg).await;
-                    return;
-                }
-                Err(e) => {
-                    let err_msg = format!("Failed to retrieve API key for {}: {}", &model_provider, e);
-                    Self::update_process_failed(&pool, &meeting_id, &err_msg).await;
-                    return;
-                }
+        // Notes the user took during or after the meeting are passed as extra context
+        let user_notes = match MeetingNotesRepository::get_notes(&pool, &meeting_id).await {
+            Ok(Some(notes)) => format_user_notes(notes.notes_markdown.as_deref(), &notes.entries()),
+            Ok(None) => None,
+            Err(e) => {
+                warn!("Failed to load meeting notes: {}, summarizing without them", e);
+                None
             }
         };
 
-        // Get Ollama endpoint if provider is Ollama
-        let ollama_endpoint = if provider == LLMProvider::Ollama {
-            match SettingsRepository::get_model_config(&pool).await {
-                Ok(Some(config)) => config.ollama_endpoint,
-                Ok(None) => None,
+        let fallback_config =
+            match SettingsRepository::get_summary_fallback_config(&settings_pool).await {
+                Ok(config) => config.unwrap_or_default(),
                 Err(e) => {
-                    info!("Failed to retrieve Ollama endpoint: {}, using default", e);
-                    None
+                    warn!("Failed to load summary fallback config: {}, using defaults", e);
+                    SummaryFallbackConfig::default()
                 }
+            };
+        let chain = fallback_chain(&model_provider, &model_name, &fallback_config);
+        let app_data_dir = app.path().app_data_dir().ok();
+
+        // Generate summary, moving down the fallback chain until one model succeeds
+        let client = reqwest::Client::new();
+        let mut attempts: Vec<SummaryAttempt> = Vec::new();
+        let mut result = Err(LlmError::Failed("No summary model configured".to_string()));
+        let mut summarizer = None;
+        for (index, candidate) in chain.iter().enumerate() {
+            if index > 0 {
+                info!(
+                    "Falling back to {} / {} for meeting_id: {}",
+                    candidate.provider, candidate.model, meeting_id
+                );
             }
-        } else {
-            None
-        };
 
-        // Get CustomOpenAI config if provider is CustomOpenAI
-        let (custom_openai_endpoint, custom_openai_api_key, custom_openai_max_tokens, custom_openai_temperature, custom_openai_top_p) =
-            if provider == LLMProvider::CustomOpenAI {
-                match SettingsRepository::get_custom_openai_config(&pool).await {
-                    Ok(Some(config)) => {
-                        info!("✓ Using custom OpenAI endpoint: {}", config.endpoint);
-                        (
-                            Some(config.endpoint),
-                            config.api_key,
-                            config.max_tokens.map(|t| t as u32),
-                            config.temperature,
-                            config.top_p,
-                        )
-                    }
-                    Ok(None) => {
-                        let err_msg = "Custom OpenAI provider selected but no configuration found";
-                        Self::update_process_failed(&pool, &meeting_id, err_msg).await;
-                        return;
-                    }
-                    Err(e) => {
-                        let err_msg = format!("Failed to retrieve custom OpenAI config: {}", e);
-                        Self::update_process_failed(&pool, &meeting_id, &err_msg).await;
-                        return;
-                    }
+            let on_progress = |progress: SummaryProgress| {
+                let event = SummaryProgressEvent {
+                    meeting_id: &meeting_id,
+                    attempt: index + 1,
+                    progress,
+                };
+                if let Err(e) = app.emit("summary-progress", event) {
+                    warn!("Failed to emit summary-progress event: {}", e);
                 }
-            } else {
-                (None, None, None, None, None)
             };
 
-        // For CustomOpenAI, use its API key (if any) instead of the empty string
-        let final_api_key = if provider == LLMProvider::CustomOpenAI {
-            custom_openai_api_key.unwrap_or_default()
-        } else {
-            api_key
-        };
+            let (provider, config, budget) = match Self::prepare_provider(
+                &settings_pool,
+                candidate,
+                &fallback_config.retry,
+                app_data_dir.clone(),
+            )
+            .await
+            {
+                Ok(prepared) => prepared,
+                Err(e) => {
+                    warn!("Skipping {} / {}: {}", candidate.provider, candidate.model, e);
+                    attempts.push(SummaryAttempt::new(candidate, Some(e.clone())));
+                    result = Err(e.into());
+                    continue;
+                }
+            };
 
-        // Dynamically fetch context size based on provider and model
-        let token_threshold = if provider == LLMProvider::Ollama {
-            match METADATA_CACHE.get_or_fetch(&model_name, ollama_endpoint.as_deref()).await {
-                Ok(metadata) => {
-                    // Reserve 300 tokens for prompt overhead
-                    let optimal = metadata.context_size.saturating_sub(300);
-                    info!(
-                        "✓ Using dynamic context for {}: {} tokens (chunk size: {})",
-                        model_name, metadata.context_size, optimal
-                    );
-                    optimal
+            let outcome = generate_meeting_summary(
+                &client,
+                provider.as_ref(),
+                &config,
+                &candidate.model,
+                &text,
+                &custom_prompt,
+                user_notes.as_deref(),
+                &template_id,
+                &budget,
+                Some(&cancellation_token),
+                Some(&on_progress),
+            )
+            .await;
+
+            match outcome {
+                Ok(output) => {
+                    attempts.push(SummaryAttempt::new(candidate, None));
+                    result = Ok(output);
+                    summarizer = Some((provider, config, budget, candidate.model.clone()));
+                    break;
+                }
+                Err(LlmError::Cancelled) => {
+                    result = Err(LlmError::Cancelled);
+                    break;
                 }
                 Err(e) => {
                     warn!(
-                        "Failed to fetch context for {}: {}. Using default 4000",
-                        model_name, e
+                        "Summary generation with {} / {} failed: {}",
+                        candidate.provider, candidate.model, e
                     );
-                    4000  // Fallback to safe default
+                    attempts.push(SummaryAttempt::new(candidate, Some(e.to_string())));
+                    result = Err(e);
                 }
             }
-        } else if provider == LLMProvider::BuiltInAI {
-            // Get model's context size from registry
-            use crate::summary::summary_engine::models;
-            let model = models::get_model_by_name(&model_name)
-                .ok_or_else(|| format!("Unknown model: {}", model_name));
-
-            match model {
-                Ok(model_def) => {
-                    // Reserve 300 tokens for prompt overhead
-                    let optimal = model_def.context_size.saturating_sub(300) as usize;
-                    info!(
-                        "✓ Using BuiltInAI context size: {} tokens (chunk size: {})",
-                        model_def.context_size, optimal
-                    );
-                    optimal
-                }
-                Err(e) => {
-                    warn!("{}, using default 2048", e);
-                    1748  // 2048 - 300 for overhead
-                }
+        }
+        // With fallbacks configured, report every model's failure
+        let result = result.map_err(|e| match e {
+            LlmError::Failed(_) if attempts.len() > 1 => {
+                LlmError::Failed(describe_failures(&attempts))
             }
-        } else {
-            // Cloud providers (OpenAI, Claude, Groq, CustomOpenAI) handle large contexts automatically
-            100000  // Effectively unlimited for single-pass processing
-        };
+            e => e,
+        });
 
-        // Get app data directory for BuiltInAI provider
-        let app_data_dir = _app.path().app_data_dir().ok();
-
-        // Generate summary
-        let client = reqwest::Client::new();
-        let result = generate_meeting_summary(
-            &client,
-            &provider,
-            &model_name,
-            &final_api_key,
-            &text,
-            &custom_prompt,
-            &template_id,
-            token_threshold,
-            ollama_endpoint.as_deref(),
-            custom_openai_endpoint.as_deref(),
-            custom_openai_max_tokens,
-            custom_openai_temperature,
-            custom_openai_top_p,
-            app_data_dir.as_ref(),
-            Some(&cancellation_token),
-        )
-        .await;
+        // Action items and decisions are extracted with the model that wrote the summary
+        let mut extraction_counts = None;
+        let summarized = result.is_ok();
+        if let Some((provider, config, budget, model)) =
+            summarizer.as_ref().filter(|_| summarized)
+        {
+            let transcript = format_transcript_with_timestamps(&transcripts, &speaker_names)
+                .unwrap_or_else(|| text.clone());
+            match Self::extract_and_store_items(
+                &pool,
+                &client,
+                provider.as_ref(),
+                config,
+                model,
+                &meeting_id,
+                &template_id,
+                &transcript,
+                budget,
+                &cancellation_token,
+            )
+            .await
+            {
+                Ok(counts) => extraction_counts = counts,
+                Err(LlmError::Cancelled) => result = Err(LlmError::Cancelled),
+                // The markdown summary is still usable without the structured items
+                Err(e) => warn!("Structured extraction failed for meeting_id {}: {}", meeting_id, e),
+            }
+        }
 
         let duration = start_time.elapsed().as_secs_f64();
 
@@ -300,39 +406,185 @@ impl SummaryService {
                     "markdown": final_markdown,
                 });
 
+                // Record which model actually produced the summary
+                let mut metadata = summary_metadata(&attempts);
+                if let Some((action_items, decisions)) = extraction_counts {
+                    metadata["extracted"] = serde_json::json!({
+                        "actionItems": action_items,
+                        "decisions": decisions,
+                    });
+                }
+
+                // The successful attempt is the last one recorded
+                let origin = match attempts.last() {
+                    Some(attempt) => SummaryVersionOrigin::generated(
+                        &attempt.provider,
+                        &attempt.model,
+                        &template_id,
+                        &custom_prompt,
+                    ),
+                    None => SummaryVersionOrigin::generated(
+                        &model_provider,
+                        &model_name,
+                        &template_id,
+                        &custom_prompt,
+                    ),
+                };
+
                 // Update database with completed status
-                if let Err(e) = SummaryProcessesRepository::update_process_completed(
+                match SummaryProcessesRepository::update_process_completed(
                     &pool,
                     &meeting_id,
                     result_json,
                     num_chunks,
                     duration,
+                    metadata,
+                    &origin,
                 )
                 .await
                 {
-                    error!(
+                    Ok(version) => info!(
+                        "Summary saved successfully as version {} for meeting_id: {}",
+                        version.version, meeting_id
+                    ),
+                    Err(e) => error!(
                         "Failed to save completed process for {}: {}",
                         meeting_id, e
-                    );
-                } else {
-                    info!(
-                        "Summary saved successfully for meeting_id: {}",
-                        meeting_id
-                    );
+                    ),
                 }
             }
-            Err(e) => {
-                // Check if error is due to cancellation
-                if e.contains("cancelled") {
-                    info!("Summary generation was cancelled for meeting_id: {}", meeting_id);
-                    if let Err(db_err) = SummaryProcessesRepository::update_process_cancelled(&pool, &meeting_id).await {
-                        error!("Failed to update DB status to cancelled for {}: {}", meeting_id, db_err);
-                    }
-                } else {
-                    Self::update_process_failed(&pool, &meeting_id, &e).await;
+            Err(LlmError::Cancelled) => {
+                info!("Summary generation was cancelled for meeting_id: {}", meeting_id);
+                if let Err(db_err) = SummaryProcessesRepository::update_process_cancelled(&pool, &meeting_id).await {
+                    error!("Failed to update DB status to cancelled for {}: {}", meeting_id, db_err);
                 }
             }
+            Err(LlmError::Failed(e)) => {
+                Self::update_process_failed(&pool, &meeting_id, &e).await;
+            }
+        }
+    }
+
+    /// Extracts the action items and decisions the template asks for and stores them,
+    /// replacing the meeting's previous ones
+    ///
+    /// # Returns
+    /// The number of (action items, decisions) stored, or None if the template has no
+    /// sections marked for extraction
+    async fn extract_and_store_items(
+        pool: &SqlitePool,
+        client: &reqwest::Client,
+        provider: &dyn LlmProvider,
+        config: &ProviderConfig,
+        model_name: &str,
+        meeting_id: &str,
+        template_id: &str,
+        transcript: &str,
+        budget: &TokenBudget,
+        cancellation_token: &CancellationToken,
+    ) -> Result<Option<(usize, usize)>, LlmError> {
+        let template = templates::get_template(template_id)
+            .map_err(|e| format!("Failed to load template '{}': {}", template_id, e))?;
+        let Some(schema) = template.extraction_schema() else {
+            return Ok(None);
+        };
+
+        info!("Extracting action items and decisions for meeting_id: {}", meeting_id);
+        let extraction = extract_structured_items(
+            client,
+            provider,
+            config,
+            model_name,
+            &schema,
+            transcript,
+            budget,
+            Some(cancellation_token),
+        )
+        .await?;
+
+        ActionItemsRepository::replace_for_meeting(pool, meeting_id, &extraction)
+            .await
+            .map_err(|e| format!("Failed to save action items: {}", e))?;
+        Ok(Some((extraction.action_items.len(), extraction.decisions.len())))
+    }
+
+    /// Lists every version of a meeting's summary, newest first
+    pub async fn list_versions(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<SummaryVersion>, String> {
+        SummaryVersionsRepository::list_versions(pool, meeting_id)
+            .await
+            .map_err(|e| format!("Failed to load summary versions: {}", e))
+    }
+
+    /// Compares the markdown of two versions of a meeting's summary line by line
+    pub async fn diff_versions(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        from_version: i64,
+        to_version: i64,
+    ) -> Result<Vec<DiffLine>, String> {
+        let mut results = Vec::with_capacity(2);
+        for version in [from_version, to_version] {
+            let stored = SummaryVersionsRepository::get_version(pool, meeting_id, version)
+                .await
+                .map_err(|e| format!("Failed to load summary version: {}", e))?
+                .ok_or_else(|| format!("Summary version {} not found", version))?;
+            results.push(summary_markdown(&stored.result));
         }
+        Ok(diff_lines(&results[0], &results[1]))
+    }
+
+    /// Makes an earlier version the active summary again, recorded as a new version
+    pub async fn restore_version(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        version: i64,
+    ) -> Result<SummaryVersion, String> {
+        if Self::is_generating(meeting_id) {
+            return Err("Cannot restore a version while a summary is being generated".to_string());
+        }
+        SummaryVersionsRepository::restore_version(pool, meeting_id, version)
+            .await
+            .map_err(|e| format!("Failed to restore summary version: {}", e))?
+            .ok_or_else(|| format!("Summary version {} not found", version))
+    }
+
+    /// Pins a version so it stays the active summary when a new one is generated,
+    /// or unpins it
+    pub async fn pin_version(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        version: i64,
+        pinned: bool,
+    ) -> Result<SummaryVersion, String> {
+        SummaryVersionsRepository::set_pinned(pool, meeting_id, version, pinned)
+            .await
+            .map_err(|e| format!("Failed to update summary version: {}", e))?
+            .ok_or_else(|| format!("Summary version {} not found", version))
+    }
+
+    /// Looks up a fallback chain entry's provider and loads its settings
+    ///
+    /// # Returns
+    /// The provider, its config and the model's token budget
+    async fn prepare_provider(
+        settings_pool: &SqlitePool,
+        candidate: &FallbackModel,
+        retry: &RetryPolicy,
+        app_data_dir: Option<PathBuf>,
+    ) -> Result<(Arc<dyn LlmProvider>, ProviderConfig, TokenBudget), String> {
+        let provider = get_provider(&candidate.provider)?;
+        let mut config = provider.load_config(settings_pool).await?;
+        // Needed by BuiltInAI to locate model files
+        config.app_data_dir = app_data_dir;
+        config.retry = retry.clone();
+
+        // Transcripts that don't fit the model's context window are chunked
+        let budget = TokenBudget::for_model(provider.as_ref(), &config, &candidate.model).await;
+
+        Ok((provider, config, budget))
     }
 
     /// Updates the summary process status to failed with error message
@@ -356,3 +608,49 @@ impl SummaryService {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model(provider: &str, model: &str) -> FallbackModel {
+        FallbackModel {
+            provider: provider.to_string(),
+            model: model.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_fallback_chain_skips_duplicates_and_blanks() {
+        let config = SummaryFallbackConfig {
+            fallbacks: vec![
+                model("openai", "gpt-4o"),
+                model("claude", "claude-3-5-haiku-latest"),
+                model("", "orphan"),
+                model("claude", "claude-3-5-haiku-latest"),
+            ],
+            ..Default::default()
+        };
+        let chain = fallback_chain("openai", "gpt-4o", &config);
+        assert_eq!(
+            chain,
+            vec![model("openai", "gpt-4o"), model("claude", "claude-3-5-haiku-latest")]
+        );
+    }
+
+    #[test]
+    fn test_summary_metadata_records_fallback() {
+        let attempts = vec![
+            SummaryAttempt::new(&model("groq", "llama-3.3"), Some("rate limited".to_string())),
+            SummaryAttempt::new(&model("ollama", "llama3.2"), None),
+        ];
+        let metadata = summary_metadata(&attempts);
+        assert_eq!(metadata["provider"], "ollama");
+        assert_eq!(metadata["fallbackUsed"], true);
+        assert_eq!(metadata["attempts"][0]["error"], "rate limited");
+        assert!(metadata["attempts"][1].get("error").is_none());
+
+        let message = describe_failures(&attempts[..1]);
+        assert!(message.contains("groq / llama-3.3: rate limited"));
+    }
+}
@@ -33,6 +33,18 @@ enum Request {
         top_k: Option<i32>,
         top_p: Option<f32>,
         stop_tokens: Option<Vec<String>>,
+        // Emit `token` messages while generating (older helpers ignore this)
+        #[serde(skip_serializing_if = "std::ops::Not::not")]
+        stream: bool,
+        // Constrain the output to JSON matching this schema
+        #[serde(skip_serializing_if = "Option::is_none")]
+        json_schema: Option<serde_json::Value>,
+    },
+    /// Count tokens with the model's GGUF vocabulary
+    Tokenize {
+        texts: Vec<String>,
+        context_size: Option<u32>,
+        model_path: Option<String>,
     },
 }
 
@@ -40,6 +52,8 @@ enum Request {
 #[serde(tag = "type", rename_all = "snake_case")]
 enum Response {
     Response { text: String, error: Option<String> },
+    Token { text: String },
+    TokenCounts { counts: Vec<usize>, error: Option<String> },
     Error { message: String },
 }
 
@@ -122,7 +136,9 @@ fn get_cached_model_path(app_data_dir: &PathBuf, model_name: &str) -> Result<Pat
 /// * `model_name` - Model name (e.g., "gemma3:1b")
 /// * `system_prompt` - System instructions for the model
 /// * `user_prompt` - User message/task
+/// * `json_schema` - Optional JSON schema the output must match
 /// * `cancellation_token` - Optional token for cancellation
+/// * `on_token` - Optional callback receiving text as it is generated
 ///
 /// # Returns
 /// Generated text
@@ -131,7 +147,9 @@ pub async fn generate_with_builtin(
     model_name: &str,
     system_prompt: &str,
     user_prompt: &str,
+    json_schema: Option<&serde_json::Value>,
     cancellation_token: Option<&CancellationToken>,
+    on_token: Option<&(dyn Fn(&str) + Send + Sync)>,
 ) -> Result<String> {
     // Check cancellation at start
     if let Some(token) = cancellation_token {
@@ -184,6 +202,8 @@ pub async fn generate_with_builtin(
         top_k: Some(model_def.sampling.top_k),
         top_p: Some(model_def.sampling.top_p),
         stop_tokens: Some(model_def.sampling.stop_tokens.clone()),
+        stream: on_token.is_some(),
+        json_schema: json_schema.cloned(),
     };
 
     let request_json = serde_json::to_string(&request)?;
@@ -193,10 +213,26 @@ pub async fn generate_with_builtin(
 
     log::info!("Sending generation request to sidecar");
 
-    // Race between send_request and cancellation token
+    // Streaming requests time out only when the sidecar goes quiet, not on total duration
+    let send = async {
+        match on_token {
+            Some(on_token) => {
+                manager
+                    .send_request_streaming(
+                        request_json,
+                        Duration::from_secs(models::STREAM_IDLE_TIMEOUT_SECS),
+                        on_token,
+                    )
+                    .await
+            }
+            None => manager.send_request(request_json, timeout).await,
+        }
+    };
+
+    // Race between the request and cancellation token
     let response_json = if let Some(token) = cancellation_token {
         tokio::select! {
-            result = manager.send_request(request_json, timeout) => {
+            result = send => {
                 result?
             }
             _ = token.cancelled() => {
@@ -209,7 +245,7 @@ pub async fn generate_with_builtin(
             }
         }
     } else {
-        manager.send_request(request_json, timeout).await?
+        send.await?
     };
 
     // Check cancellation before parsing response
@@ -232,10 +268,73 @@ pub async fn generate_with_builtin(
                 Ok(text)
             }
         }
+        Response::Token { text } => Err(anyhow!("Unexpected token message after generation: {}", text)),
+        Response::TokenCounts { .. } => Err(anyhow!("Unexpected token counts after generation")),
         Response::Error { message } => Err(anyhow!("Sidecar error: {}", message)),
     }
 }
 
+/// Count tokens with a built-in model's own (GGUF) vocabulary
+///
+/// Loads the model in the sidecar if needed, with the same context size as generation,
+/// so the following generation request reuses it.
+///
+/// # Returns
+/// The token count of each text, in order
+pub async fn count_tokens_with_builtin(
+    app_data_dir: &PathBuf,
+    model_name: &str,
+    texts: &[&str],
+) -> Result<Vec<usize>> {
+    if texts.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let model_def = models::get_model_by_name(model_name)
+        .ok_or_else(|| anyhow!("Unknown model: {}", model_name))?;
+    let model_path = get_cached_model_path(app_data_dir, model_name)?;
+
+    let manager = {
+        let mut global_manager = SIDECAR_MANAGER.lock().await;
+        if global_manager.is_none() {
+            log::info!("Initializing sidecar manager");
+            let new_manager = SidecarManager::new(app_data_dir.clone())?;
+            *global_manager = Some(Arc::new(new_manager));
+        }
+        global_manager.clone().unwrap()
+    };
+    manager.ensure_running(model_path.clone()).await?;
+
+    let request = Request::Tokenize {
+        texts: texts.iter().map(|t| t.to_string()).collect(),
+        context_size: Some(model_def.context_size),
+        model_path: Some(model_path.to_string_lossy().to_string()),
+    };
+    let response_json = manager
+        .send_request(
+            serde_json::to_string(&request)?,
+            Duration::from_secs(models::TOKENIZE_TIMEOUT_SECS),
+        )
+        .await?;
+
+    let response: Response = serde_json::from_str(&response_json)
+        .with_context(|| format!("Failed to parse response: {}", response_json))?;
+    match response {
+        Response::TokenCounts { counts, error: None } if counts.len() == texts.len() => Ok(counts),
+        Response::TokenCounts { error: Some(err_msg), .. } => Err(anyhow!("Tokenization failed: {}", err_msg)),
+        Response::TokenCounts { counts, .. } => Err(anyhow!(
+            "Sidecar returned {} token counts for {} texts",
+            counts.len(),
+            texts.len()
+        )),
+        // Helpers that predate tokenization reject the request as invalid
+        Response::Error { message } => Err(anyhow!("Sidecar error: {}", message)),
+        Response::Response { .. } | Response::Token { .. } => {
+            Err(anyhow!("Unexpected sidecar message: {}", response_json))
+        }
+    }
+}
+
 /// Shutdown the global sidecar (graceful cleanup)
 /// Detaches the current manager and spawns a background task to drain active requests
 pub async fn shutdown_sidecar_gracefully() -> Result<()> {
@@ -304,15 +403,38 @@ mod tests {
             top_k: Some(64),
             top_p: Some(0.95),
             stop_tokens: Some(vec!["<end_of_turn>".to_string()]),
+            stream: false,
+            json_schema: None,
         };
 
         let json = serde_json::to_string(&request).unwrap();
+        assert!(!json.contains("\"stream\""));
+        assert!(!json.contains("\"json_schema\""));
         assert!(json.contains("\"type\":\"generate\""));
         assert!(json.contains("\"prompt\":\"test prompt\""));
         assert!(json.contains("\"max_tokens\":512"));
         assert!(json.contains("\"temperature\":1.0"));
     }
 
+    #[test]
+    fn test_json_schema_is_sent() {
+        let request = Request::Generate {
+            prompt: "test prompt".to_string(),
+            max_tokens: None,
+            context_size: None,
+            model_path: None,
+            temperature: None,
+            top_k: None,
+            top_p: None,
+            stop_tokens: None,
+            stream: false,
+            json_schema: Some(serde_json::json!({ "type": "object" })),
+        };
+
+        let json = serde_json::to_string(&request).unwrap();
+        assert!(json.contains("\"json_schema\":{\"type\":\"object\"}"));
+    }
+
     #[test]
     fn test_response_deserialization() {
         let json = r#"{"type":"response","text":"generated text","error":null}"#;
@@ -327,6 +449,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_token_message_deserialization() {
+        let json = r#"{"type":"token","text":" world"}"#;
+        let response: Response = serde_json::from_str(json).unwrap();
+        assert!(matches!(response, Response::Token { text } if text == " world"));
+    }
+
+    #[test]
+    fn test_tokenize_round_trip() {
+        let request = Request::Tokenize {
+            texts: vec!["Hello".to_string()],
+            context_size: Some(4096),
+            model_path: None,
+        };
+        let json = serde_json::to_string(&request).unwrap();
+        assert!(json.contains("\"type\":\"tokenize\""));
+        assert!(json.contains("\"texts\":[\"Hello\"]"));
+
+        let response: Response =
+            serde_json::from_str(r#"{"type":"token_counts","counts":[3,7],"error":null}"#).unwrap();
+        assert!(matches!(response, Response::TokenCounts { counts, error: None } if counts == vec![3, 7]));
+    }
+
     #[test]
     fn test_error_response_deserialization() {
         let json = r#"{"type":"error","message":"something went wrong"}"#;
@@ -8,13 +8,17 @@ use std::sync::Arc;
 use anyhow::{anyhow, Result};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 use tokio::fs::{self, OpenOptions};
 use tokio::io::{AsyncWriteExt, BufWriter};
 use tokio::sync::RwLock;
 use tokio::time::timeout;
 
-use super::models::{get_available_models, get_model_by_name};
+use super::models::{
+    custom_model_def, custom_model_name, get_available_models, get_model_by_name,
+    set_custom_models, GgufInfo, ModelDef,
+};
+use super::sidecar::SidecarManager;
 
 // ============================================================================
 // Model Status Types
@@ -101,6 +105,10 @@ pub struct ModelInfo {
 
     /// GGUF filename on disk
     pub gguf_file: String,
+
+    /// User-supplied GGUF file rather than a downloadable model
+    #[serde(default)]
+    pub is_custom: bool,
 }
 
 // ============================================================================
@@ -119,6 +127,18 @@ pub struct ModelManager {
 
     /// Cancellation flag for current download
     cancel_download_flag: Arc<RwLock<Option<String>>>,
+
+    /// Metadata of custom GGUF files by path, with the size and modification time it
+    /// was read at, so unchanged files are not inspected again on every scan
+    custom_model_cache: Arc<RwLock<HashMap<PathBuf, CachedInspection>>>,
+}
+
+/// Result of inspecting a custom GGUF file
+#[derive(Clone)]
+struct CachedInspection {
+    len: u64,
+    modified: Option<SystemTime>,
+    result: Result<GgufInfo, String>,
 }
 
 impl ModelManager {
@@ -161,6 +181,7 @@ impl ModelManager {
             available_models: Arc::new(RwLock::new(HashMap::new())),
             active_downloads: Arc::new(RwLock::new(HashSet::new())),
             cancel_download_flag: Arc::new(RwLock::new(None)),
+            custom_model_cache: Arc::new(RwLock::new(HashMap::new())),
         })
     }
 
@@ -278,11 +299,24 @@ impl ModelManager {
                 context_size: model_def.context_size,
                 description: model_def.description.clone(),
                 gguf_file: model_def.gguf_file.clone(),
+                is_custom: false,
             };
 
             models_map.insert(model_def.name.clone(), model_info);
         }
 
+        // Any other GGUF file in the directory is a user-supplied model
+        let (custom_infos, custom_defs) = self.scan_custom_models().await;
+        log::info!(
+            "Found {} custom GGUF models ({} usable)",
+            custom_infos.len(),
+            custom_defs.len()
+        );
+        set_custom_models(custom_defs);
+        for info in custom_infos {
+            models_map.entry(info.name.clone()).or_insert(info);
+        }
+
         let model_count = models_map.len();
 
         let mut models = self.available_models.write().await;
@@ -297,6 +331,125 @@ impl ModelManager {
         Ok(())
     }
 
+    /// Inspects GGUF files in the models directory that are not downloadable models
+    ///
+    /// # Returns
+    /// Every custom file for display, and the definitions of those with a supported
+    /// chat template
+    async fn scan_custom_models(&self) -> (Vec<ModelInfo>, Vec<ModelDef>) {
+        let known_files: HashSet<String> = get_available_models()
+            .into_iter()
+            .map(|model| model.gguf_file)
+            .collect();
+
+        let mut entries = match fs::read_dir(&self.models_dir).await {
+            Ok(entries) => entries,
+            Err(e) => {
+                log::warn!("Failed to read models directory: {}", e);
+                return (Vec::new(), Vec::new());
+            }
+        };
+
+        let mut files = Vec::new();
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            if !file_name.to_lowercase().ends_with(".gguf") || known_files.contains(&file_name) {
+                continue;
+            }
+            if let Ok(metadata) = entry.metadata().await {
+                if metadata.is_file() {
+                    files.push((file_name, entry.path(), metadata));
+                }
+            }
+        }
+        files.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut infos = Vec::new();
+        let mut defs = Vec::new();
+        let mut seen = HashSet::new();
+        for (file_name, path, metadata) in files {
+            let name = custom_model_name(&file_name);
+            if !seen.insert(name.clone()) {
+                log::warn!("Skipping {}: another custom model has the name {}", file_name, name);
+                continue;
+            }
+
+            let size_mb = metadata.len() / (1024 * 1024);
+            let definition = self
+                .inspect_custom_model(&path, &metadata)
+                .await
+                .map_err(|e| anyhow!(e))
+                .and_then(|info| custom_model_def(&file_name, size_mb, &info));
+
+            let (status, display_name, context_size, description) = match definition {
+                Ok(def) => {
+                    log::info!(
+                        "Custom model '{}': AVAILABLE ({} template, {} ctx)",
+                        def.name,
+                        def.template,
+                        def.context_size
+                    );
+                    let info = (
+                        ModelStatus::Available,
+                        def.display_name.clone(),
+                        def.context_size,
+                        def.description.clone(),
+                    );
+                    defs.push(def);
+                    info
+                }
+                Err(e) => {
+                    log::warn!("Custom model '{}' can't be used: {}", file_name, e);
+                    (ModelStatus::Error(e.to_string()), file_name.clone(), 0, String::new())
+                }
+            };
+
+            infos.push(ModelInfo {
+                name,
+                display_name,
+                status,
+                path,
+                size_mb,
+                context_size,
+                description,
+                gguf_file: file_name,
+                is_custom: true,
+            });
+        }
+        (infos, defs)
+    }
+
+    /// GGUF metadata of a custom model file, from the cache while the file is unchanged
+    async fn inspect_custom_model(
+        &self,
+        path: &PathBuf,
+        metadata: &std::fs::Metadata,
+    ) -> Result<GgufInfo, String> {
+        let modified = metadata.modified().ok();
+        if let Some(cached) = self.custom_model_cache.read().await.get(path) {
+            if cached.len == metadata.len() && cached.modified == modified {
+                return cached.result.clone();
+            }
+        }
+
+        // Reject other files before starting a process for them
+        let result = match self.validate_gguf_file(path).await {
+            Ok(()) => SidecarManager::inspect_model(path)
+                .await
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+        self.custom_model_cache.write().await.insert(
+            path.clone(),
+            CachedInspection {
+                len: metadata.len(),
+                modified,
+                result: result.clone(),
+            },
+        );
+        result
+    }
+
     /// Get list of all models with their status
     pub async fn list_models(&self) -> Vec<ModelInfo> {
         self.available_models
@@ -367,6 +520,13 @@ impl ModelManager {
         // Get model definition
         let model_def = get_model_by_name(model_name)
             .ok_or_else(|| anyhow!("Unknown model: {}", model_name))?;
+        if model_def.download_url.is_empty() {
+            return Err(anyhow!(
+                "{} is a custom model and can't be downloaded. Copy its GGUF file into {}",
+                model_name,
+                self.models_dir.display()
+            ));
+        }
 
         // Add to active downloads
         {
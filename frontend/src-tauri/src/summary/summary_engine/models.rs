@@ -2,8 +2,10 @@
 // Designed for easy extension - just add new entries to get_available_models()
 
 use anyhow::{anyhow, Result};
+use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::sync::RwLock;
 
 // ============================================================================
 // Model Definitions
@@ -103,9 +105,12 @@ pub fn get_available_models() -> Vec<ModelDef> {
     ]
 }
 
-/// Get a specific model by name
+/// Get a specific model by name, including registered custom models
 pub fn get_model_by_name(name: &str) -> Option<ModelDef> {
-    get_available_models().into_iter().find(|m| m.name == name)
+    get_available_models()
+        .into_iter()
+        .find(|m| m.name == name)
+        .or_else(|| get_custom_models().into_iter().find(|m| m.name == name))
 }
 
 /// Get the default model (first in list)
@@ -127,6 +132,124 @@ pub fn get_model_path(app_data_dir: &PathBuf, model_name: &str) -> Result<PathBu
     Ok(model_path)
 }
 
+// ============================================================================
+// Custom Models (user-supplied GGUF files)
+// ============================================================================
+
+/// Name prefix of custom models, e.g. "custom:qwen2.5-7b-instruct-q4_k_m"
+pub const CUSTOM_MODEL_PREFIX: &str = "custom:";
+
+/// Largest context size used for custom models, whatever they were trained with,
+/// to keep the KV cache within reach of local hardware
+pub const MAX_CUSTOM_CONTEXT_SIZE: u32 = 32768;
+
+/// Context size for custom models whose metadata has none
+const DEFAULT_CUSTOM_CONTEXT_SIZE: u32 = 4096;
+
+/// Custom models found by the last model scan
+static CUSTOM_MODELS: Lazy<RwLock<Vec<ModelDef>>> = Lazy::new(|| RwLock::new(Vec::new()));
+
+/// Replace the registered custom models (called by `ModelManager::scan_models`)
+pub fn set_custom_models(models: Vec<ModelDef>) {
+    *CUSTOM_MODELS.write().unwrap() = models;
+}
+
+/// Custom models found by the last model scan
+pub fn get_custom_models() -> Vec<ModelDef> {
+    CUSTOM_MODELS.read().unwrap().clone()
+}
+
+/// GGUF metadata reported by `llama-helper --inspect`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GgufInfo {
+    pub architecture: Option<String>,
+    pub name: Option<String>,
+    pub context_length: Option<u64>,
+    pub block_count: Option<u64>,
+    /// Jinja chat template embedded in the model
+    pub chat_template: Option<String>,
+}
+
+/// Picks the prompt template for a model from its embedded chat template, falling back
+/// to its architecture when the GGUF has none
+///
+/// # Returns
+/// One of [`SUPPORTED_TEMPLATES`], or None if the format is not supported
+pub fn detect_template(info: &GgufInfo) -> Option<&'static str> {
+    if let Some(chat_template) = &info.chat_template {
+        let markers = [
+            ("<|im_start|>", "chatml"),
+            ("<|start_header_id|>", "llama3"),
+            ("<start_of_turn>", "gemma3"),
+            ("<|assistant|>", "phi3"),
+            ("[INST]", "mistral"),
+        ];
+        if let Some((_, template)) = markers.iter().find(|(marker, _)| chat_template.contains(marker)) {
+            return Some(template);
+        }
+    }
+
+    match info.architecture.as_deref()? {
+        "qwen2" | "qwen2moe" | "qwen3" | "qwen3moe" => Some("chatml"),
+        "gemma3" => Some("gemma3"),
+        "phi3" => Some("phi3"),
+        _ => None,
+    }
+}
+
+/// Model name of a user-supplied GGUF file
+pub fn custom_model_name(gguf_file: &str) -> String {
+    let stem = gguf_file.strip_suffix(".gguf").unwrap_or(gguf_file);
+    format!("{}{}", CUSTOM_MODEL_PREFIX, stem.to_lowercase())
+}
+
+/// Builds the definition of a user-supplied GGUF file from its metadata
+///
+/// # Arguments
+/// * `gguf_file` - File name inside the models directory
+/// * `size_mb` - File size in MB
+/// * `info` - Metadata from `llama-helper --inspect`
+///
+/// # Returns
+/// The model definition, or why the file cannot be used
+pub fn custom_model_def(gguf_file: &str, size_mb: u64, info: &GgufInfo) -> Result<ModelDef> {
+    let template = detect_template(info).ok_or_else(|| {
+        anyhow!(
+            "Unsupported chat template (architecture: {}). Supported formats: {}",
+            info.architecture.as_deref().unwrap_or("unknown"),
+            SUPPORTED_TEMPLATES.join(", ")
+        )
+    })?;
+    let stem = gguf_file.strip_suffix(".gguf").unwrap_or(gguf_file);
+    let context_size = info
+        .context_length
+        .map(|n| n.min(MAX_CUSTOM_CONTEXT_SIZE as u64) as u32)
+        .filter(|&n| n > 0)
+        .unwrap_or(DEFAULT_CUSTOM_CONTEXT_SIZE);
+
+    Ok(ModelDef {
+        name: custom_model_name(gguf_file),
+        display_name: info.name.clone().unwrap_or_else(|| stem.to_string()),
+        gguf_file: gguf_file.to_string(),
+        template: template.to_string(),
+        download_url: String::new(),
+        size_mb,
+        context_size,
+        layer_count: info.block_count.unwrap_or(0) as u32,
+        sampling: SamplingParams {
+            temperature: 0.7,
+            top_k: 40,
+            top_p: 0.95,
+            stop_tokens: template_stop_tokens(template),
+        },
+        description: format!(
+            "Custom model ({}, {} template).",
+            info.architecture.as_deref().unwrap_or("unknown architecture"),
+            template
+        ),
+    })
+}
+
 /// Get the models directory path for built-in AI
 pub fn get_models_directory(app_data_dir: &PathBuf) -> PathBuf {
     app_data_dir.join("models").join("summary")
@@ -136,6 +259,9 @@ pub fn get_models_directory(app_data_dir: &PathBuf) -> PathBuf {
 // Prompt Templates (Model-Specific Formatting)
 // ============================================================================
 
+/// Prompt templates understood by [`format_prompt`]
+pub const SUPPORTED_TEMPLATES: &[&str] = &["gemma3", "chatml", "llama3", "phi3", "mistral"];
+
 /// Gemma 3 chat template format
 pub const GEMMA3_TEMPLATE: &str = "\
 <start_of_turn>user
@@ -145,10 +271,57 @@ pub const GEMMA3_TEMPLATE: &str = "\
 <start_of_turn>model
 ";
 
+/// ChatML format (Qwen, SmolLM, Hermes and most fine-tunes)
+pub const CHATML_TEMPLATE: &str = "\
+<|im_start|>system
+{system_prompt}<|im_end|>
+<|im_start|>user
+{user_prompt}<|im_end|>
+<|im_start|>assistant
+";
+
+/// Llama 3.x instruct format (the BOS token is added by the tokenizer)
+pub const LLAMA3_TEMPLATE: &str = "\
+<|start_header_id|>system<|end_header_id|>
+
+{system_prompt}<|eot_id|><|start_header_id|>user<|end_header_id|>
+
+{user_prompt}<|eot_id|><|start_header_id|>assistant<|end_header_id|>
+
+";
+
+/// Phi-3 / Phi-4 mini format
+pub const PHI3_TEMPLATE: &str = "\
+<|system|>
+{system_prompt}<|end|>
+<|user|>
+{user_prompt}<|end|>
+<|assistant|>
+";
+
+/// Mistral instruct format, which has no system role: the system prompt leads the
+/// first user turn
+pub const MISTRAL_TEMPLATE: &str = "[INST] {system_prompt}
+
+{user_prompt} [/INST]";
+
+/// Tokens that end the assistant's turn in a template
+pub fn template_stop_tokens(template_name: &str) -> Vec<String> {
+    let tokens: &[&str] = match template_name {
+        "gemma3" => &["<end_of_turn>"],
+        "chatml" => &["<|im_end|>", "<|im_start|>"],
+        "llama3" => &["<|eot_id|>", "<|start_header_id|>"],
+        "phi3" => &["<|end|>", "<|user|>"],
+        "mistral" => &["</s>", "[INST]"],
+        _ => &[],
+    };
+    tokens.iter().map(|t| t.to_string()).collect()
+}
+
 /// Format a prompt using the specified template
 ///
 /// # Arguments
-/// * `template_name` - Template identifier (e.g., "gemma3", "chatml", "llama3")
+/// * `template_name` - Template identifier (one of [`SUPPORTED_TEMPLATES`])
 /// * `system_prompt` - System message (instructions for the model)
 /// * `user_prompt` - User message (actual task/question)
 ///
@@ -161,6 +334,10 @@ pub fn format_prompt(
 ) -> Result<String> {
     let template = match template_name {
         "gemma3" => GEMMA3_TEMPLATE,
+        "chatml" => CHATML_TEMPLATE,
+        "llama3" => LLAMA3_TEMPLATE,
+        "phi3" => PHI3_TEMPLATE,
+        "mistral" => MISTRAL_TEMPLATE,
         _ => return Err(anyhow!("Unknown template: {}", template_name)),
     };
 
@@ -183,3 +360,68 @@ pub const DEFAULT_IDLE_TIMEOUT_SECS: u64 = 300; // 5 minutes
 
 /// Generation timeout (how long to wait for a response)
 pub const GENERATION_TIMEOUT_SECS: u64 = 900; // 15 minutes
+
+/// Streaming timeout (how long to wait between generated tokens); also covers model
+/// loading and prompt processing before the first token
+pub const STREAM_IDLE_TIMEOUT_SECS: u64 = 300; // 5 minutes
+
+/// Token counting timeout; includes loading the model when the sidecar starts cold
+pub const TOKENIZE_TIMEOUT_SECS: u64 = 120;
+
+/// Timeout for reading a custom model's GGUF metadata with `llama-helper --inspect`
+pub const INSPECT_TIMEOUT_SECS: u64 = 30;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(architecture: &str, chat_template: Option<&str>) -> GgufInfo {
+        GgufInfo {
+            architecture: Some(architecture.to_string()),
+            chat_template: chat_template.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_every_supported_template_formats() {
+        for template in SUPPORTED_TEMPLATES {
+            let prompt = format_prompt(template, "Be brief.", "Summarize.").unwrap();
+            assert!(prompt.contains("Be brief.") && prompt.contains("Summarize."), "{}", template);
+            assert!(!template_stop_tokens(template).is_empty(), "{}", template);
+        }
+        assert_eq!(
+            format_prompt("chatml", "S", "U").unwrap(),
+            "<|im_start|>system\nS<|im_end|>\n<|im_start|>user\nU<|im_end|>\n<|im_start|>assistant\n"
+        );
+        assert!(format_prompt("vicuna", "S", "U").is_err());
+    }
+
+    #[test]
+    fn test_detect_template() {
+        let qwen = "{% for message in messages %}{{'<|im_start|>' + message['role']}}";
+        assert_eq!(detect_template(&info("qwen2", Some(qwen))), Some("chatml"));
+        let llama = "{{ '<|start_header_id|>' + message['role'] + '<|end_header_id|>' }}";
+        assert_eq!(detect_template(&info("llama", Some(llama))), Some("llama3"));
+        assert_eq!(detect_template(&info("llama", Some("[INST] {{ content }} [/INST]"))), Some("mistral"));
+        // Without an embedded template only unambiguous architectures are recognized
+        assert_eq!(detect_template(&info("phi3", None)), Some("phi3"));
+        assert_eq!(detect_template(&info("llama", None)), None);
+    }
+
+    #[test]
+    fn test_custom_model_def() {
+        let mut metadata = info("qwen2", Some("<|im_start|>"));
+        metadata.name = Some("Qwen2.5 7B Instruct".to_string());
+        metadata.context_length = Some(131072);
+        let def = custom_model_def("Qwen2.5-7B-Instruct-Q4_K_M.gguf", 4460, &metadata).unwrap();
+        assert_eq!(def.name, "custom:qwen2.5-7b-instruct-q4_k_m");
+        assert_eq!(def.display_name, "Qwen2.5 7B Instruct");
+        assert_eq!(def.template, "chatml");
+        assert_eq!(def.context_size, MAX_CUSTOM_CONTEXT_SIZE);
+        assert!(def.sampling.stop_tokens.contains(&"<|im_end|>".to_string()));
+
+        let err = custom_model_def("mystery.gguf", 100, &info("rwkv6", None)).unwrap_err();
+        assert!(err.to_string().contains("Unsupported chat template"), "{}", err);
+    }
+}
@@ -1,7 +1,7 @@
 // Sidecar process lifecycle management for llama-helper
 // Handles spawning, health checking, keep-alive, and graceful shutdown
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Stdio;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
@@ -104,6 +104,40 @@ impl SidecarManager {
         })
     }
 
+    /// Read a model file's GGUF metadata with a one-off `llama-helper --inspect`, without
+    /// loading the model or touching the running sidecar
+    pub async fn inspect_model(model_path: &Path) -> Result<models::GgufInfo> {
+        let helper = Self::resolve_helper_binary()?;
+        let mut command = tokio::process::Command::new(&helper);
+        command
+            .arg("--inspect")
+            .arg(model_path)
+            .stdin(Stdio::null())
+            .kill_on_drop(true);
+
+        #[cfg(target_os = "windows")]
+        {
+            const CREATE_NO_WINDOW: u32 = 0x08000000;
+            command.creation_flags(CREATE_NO_WINDOW);
+        }
+
+        let output = tokio::time::timeout(
+            Duration::from_secs(models::INSPECT_TIMEOUT_SECS),
+            command.output(),
+        )
+        .await
+        .map_err(|_| anyhow!("Inspecting {} timed out", model_path.display()))?
+        .with_context(|| format!("Failed to run llama-helper at {:?}", helper))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow!("Failed to read GGUF metadata: {}", stderr.trim()));
+        }
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        serde_json::from_str(stdout.trim())
+            .with_context(|| format!("Failed to parse model metadata: {}", stdout.trim()))
+    }
+
     /// Resolve the path to llama-helper binary
     fn resolve_helper_binary() -> Result<PathBuf> {
         // 1. Check environment variable (dev mode or manual override)
@@ -353,23 +387,7 @@ impl SidecarManager {
         // Track active request
         let _guard = RequestGuard::new(self.active_request_count.clone());
 
-        // Write request to stdin
-        {
-            let mut stdin_lock = self.stdin_writer.lock().await;
-            let stdin = stdin_lock
-                .as_mut()
-                .ok_or_else(|| anyhow!("Sidecar not running"))?;
-
-            stdin
-                .write_all(request_json.as_bytes())
-                .await
-                .context("Failed to write request to stdin")?;
-            stdin
-                .write_all(b"\n")
-                .await
-                .context("Failed to write newline")?;
-            stdin.flush().await.context("Failed to flush stdin")?;
-        }
+        self.write_request(&request_json).await?;
 
         // Read response from stdout with timeout
         match tokio::time::timeout(timeout, self.read_response()).await {
@@ -389,6 +407,67 @@ impl SidecarManager {
         }
     }
 
+    /// Send a streaming request and forward each `token` message's text to `on_token`.
+    ///
+    /// `idle_timeout` applies between messages rather than to the whole generation, so
+    /// long outputs don't time out as long as tokens keep coming. Returns the final
+    /// (non-token) response line.
+    pub async fn send_request_streaming(
+        &self,
+        request_json: String,
+        idle_timeout: Duration,
+        on_token: &(dyn Fn(&str) + Send + Sync),
+    ) -> Result<String> {
+        let _guard = RequestGuard::new(self.active_request_count.clone());
+
+        self.write_request(&request_json).await?;
+
+        loop {
+            let line = match tokio::time::timeout(idle_timeout, self.read_response()).await {
+                Ok(line) => line?,
+                Err(_) => {
+                    log::error!(
+                        "No output from sidecar for {:?}, shutting down sidecar",
+                        idle_timeout
+                    );
+                    if let Err(shutdown_err) = self.shutdown().await {
+                        log::error!("Failed to shutdown sidecar after timeout: {}", shutdown_err);
+                    }
+                    return Err(anyhow!("Request timed out after {:?} without output", idle_timeout));
+                }
+            };
+            self.update_activity().await;
+
+            let message: serde_json::Value = serde_json::from_str(&line)
+                .with_context(|| format!("Failed to parse sidecar message: {}", line))?;
+            if message.get("type").and_then(|t| t.as_str()) != Some("token") {
+                return Ok(line);
+            }
+            if let Some(text) = message.get("text").and_then(|t| t.as_str()) {
+                on_token(text);
+            }
+        }
+    }
+
+    /// Write one JSON request line to the sidecar's stdin
+    async fn write_request(&self, request_json: &str) -> Result<()> {
+        let mut stdin_lock = self.stdin_writer.lock().await;
+        let stdin = stdin_lock
+            .as_mut()
+            .ok_or_else(|| anyhow!("Sidecar not running"))?;
+
+        stdin
+            .write_all(request_json.as_bytes())
+            .await
+            .context("Failed to write request to stdin")?;
+        stdin
+            .write_all(b"\n")
+            .await
+            .context("Failed to write newline")?;
+        stdin.flush().await.context("Failed to flush stdin")?;
+        Ok(())
+    }
+
     /// Read a single line response from stdout
     async fn read_response(&self) -> Result<String> {
         let mut stdout_lock = self.stdout_reader.lock().await;
@@ -1,5 +1,14 @@
 use serde::{Deserialize, Serialize};
 
+/// Values allowed in [`TemplateSection::extract`]
+pub const EXTRACT_TARGETS: &[&str] = &["action_items", "decisions"];
+
+/// Property of [`Template::summary_schema`] that holds the meeting title
+pub const SUMMARY_TITLE_KEY: &str = "meeting_title";
+
+/// Text of sections without content in a rendered summary
+const EMPTY_SECTION_TEXT: &str = "None noted in this section.";
+
 /// Represents a single section in a meeting template
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TemplateSection {
@@ -19,6 +28,29 @@ pub struct TemplateSection {
     /// Alternative formatting hint
     #[serde(skip_serializing_if = "Option::is_none")]
     pub example_item_format: Option<String>,
+
+    /// Structured extraction target for list sections: "action_items" or "decisions".
+    /// Items of marked sections are also extracted as JSON and stored in their own tables
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extract: Option<String>,
+}
+
+impl TemplateSection {
+    /// Column names of a list section whose items are table rows, from the header row of
+    /// its item format (e.g. "| **Owner** | Task |" gives ["Owner", "Task"])
+    pub fn table_columns(&self) -> Option<Vec<String>> {
+        let format = self.item_format.as_ref().or(self.example_item_format.as_ref())?;
+        let header = format.lines().next()?.trim();
+        if self.format != "list" || !header.starts_with('|') {
+            return None;
+        }
+        let columns: Vec<String> = header
+            .split('|')
+            .map(|cell| cell.trim().trim_matches('*').trim().to_string())
+            .filter(|cell| !cell.is_empty())
+            .collect();
+        (!columns.is_empty()).then_some(columns)
+    }
 }
 
 /// Represents a complete meeting template
@@ -65,6 +97,21 @@ impl Template {
                     section.title, other
                 )),
             }
+
+            if let Some(target) = &section.extract {
+                if !EXTRACT_TARGETS.contains(&target.as_str()) {
+                    return Err(format!(
+                        "Section '{}' has invalid extract target '{}'. Must be 'action_items' or 'decisions'",
+                        section.title, target
+                    ));
+                }
+                if section.format != "list" {
+                    return Err(format!(
+                        "Section '{}' can only be extracted if its format is 'list'",
+                        section.title
+                    ));
+                }
+            }
         }
 
         Ok(())
@@ -107,6 +154,197 @@ impl Template {
 
         instructions
     }
+
+    /// JSON Schema for structured extraction of the sections marked with `extract`
+    ///
+    /// The schema has one array property per extraction target ("action_items", "decisions"),
+    /// described by the instructions of the sections that feed it. Returns None when no
+    /// section is marked, i.e. the template only produces markdown.
+    pub fn extraction_schema(&self) -> Option<serde_json::Value> {
+        let mut properties = serde_json::Map::new();
+
+        for target in EXTRACT_TARGETS {
+            let instructions: Vec<&str> = self
+                .sections
+                .iter()
+                .filter(|s| s.extract.as_deref() == Some(*target))
+                .map(|s| s.instruction.as_str())
+                .collect();
+            if instructions.is_empty() {
+                continue;
+            }
+
+            let item = if *target == "action_items" {
+                serde_json::json!({
+                    "type": "object",
+                    "properties": {
+                        "text": { "type": "string", "description": "The task, phrased as an action" },
+                        "owner": { "type": ["string", "null"], "description": "Person responsible, if named" },
+                        "due_date": { "type": ["string", "null"], "description": "Due date as YYYY-MM-DD, if stated" },
+                        "source_timestamp": { "type": ["string", "null"], "description": "HH:MM:SS of the transcript line the item comes from" }
+                    },
+                    "required": ["text"],
+                    "additionalProperties": false
+                })
+            } else {
+                serde_json::json!({
+                    "type": "object",
+                    "properties": {
+                        "text": { "type": "string", "description": "The decision that was made" },
+                        "source_timestamp": { "type": ["string", "null"], "description": "HH:MM:SS of the transcript line the decision comes from" }
+                    },
+                    "required": ["text"],
+                    "additionalProperties": false
+                })
+            };
+
+            properties.insert(
+                target.to_string(),
+                serde_json::json!({
+                    "type": "array",
+                    "description": instructions.join(" "),
+                    "items": item,
+                }),
+            );
+        }
+
+        if properties.is_empty() {
+            return None;
+        }
+        let required: Vec<String> = properties.keys().cloned().collect();
+        Some(serde_json::json!({
+            "type": "object",
+            "properties": properties,
+            "required": required,
+            "additionalProperties": false,
+        }))
+    }
+
+    /// JSON Schema of the whole report, for providers that can constrain their output
+    ///
+    /// The object has the meeting title under [`SUMMARY_TITLE_KEY`] and one property per
+    /// section, named after its title: a string for paragraphs, an array of strings for
+    /// lists, and an array of row objects for lists formatted as tables. `required` lists
+    /// the properties in template order. Render the result with [`Self::render_summary`].
+    pub fn summary_schema(&self) -> serde_json::Value {
+        let mut properties = serde_json::Map::new();
+        let mut required = vec![SUMMARY_TITLE_KEY.to_string()];
+        properties.insert(
+            SUMMARY_TITLE_KEY.to_string(),
+            serde_json::json!({
+                "type": "string",
+                "description": "Concise, descriptive title for the meeting",
+            }),
+        );
+
+        for section in &self.sections {
+            let schema = match (section.format.as_str(), section.table_columns()) {
+                ("list", Some(columns)) => {
+                    let cells: serde_json::Map<String, serde_json::Value> = columns
+                        .iter()
+                        .map(|c| (c.clone(), serde_json::json!({ "type": "string" })))
+                        .collect();
+                    serde_json::json!({
+                        "type": "array",
+                        "description": section.instruction,
+                        "items": {
+                            "type": "object",
+                            "properties": cells,
+                            "required": columns,
+                            "additionalProperties": false,
+                        },
+                    })
+                }
+                ("list", None) => serde_json::json!({
+                    "type": "array",
+                    "description": section.instruction,
+                    "items": { "type": "string" },
+                }),
+                _ => serde_json::json!({
+                    "type": "string",
+                    "description": section.instruction,
+                }),
+            };
+            if properties.insert(section.title.clone(), schema).is_none() {
+                required.push(section.title.clone());
+            }
+        }
+
+        serde_json::json!({
+            "type": "object",
+            "properties": properties,
+            "required": required,
+            "additionalProperties": false,
+        })
+    }
+
+    /// Renders a report that follows [`Self::summary_schema`] as markdown, in the layout
+    /// of [`Self::to_markdown_structure`]
+    ///
+    /// Missing or empty sections are marked as having nothing noted.
+    pub fn render_summary(&self, summary: &serde_json::Value) -> String {
+        let title = summary
+            .get(SUMMARY_TITLE_KEY)
+            .and_then(|t| t.as_str())
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .unwrap_or("Meeting Summary");
+        let mut markdown = format!("# {}\n\n", title);
+
+        for section in &self.sections {
+            markdown.push_str(&format!("**{}**\n\n", section.title));
+            let value = summary.get(&section.title);
+            let content = match (value, section.table_columns()) {
+                (Some(serde_json::Value::Array(rows)), Some(columns)) if !rows.is_empty() => {
+                    let format = section.item_format.as_ref().or(section.example_item_format.as_ref());
+                    let mut table = format.map(|f| f.trim().to_string()).unwrap_or_default();
+                    for row in rows {
+                        let cells: Vec<String> = columns
+                            .iter()
+                            .map(|c| table_cell(row.get(c).unwrap_or(&serde_json::Value::Null)))
+                            .collect();
+                        table.push_str(&format!("\n| {} |", cells.join(" | ")));
+                    }
+                    table
+                }
+                (Some(serde_json::Value::Array(items)), _) => items
+                    .iter()
+                    .map(json_text)
+                    .filter(|item| !item.is_empty())
+                    .map(|item| format!("- {}", item))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+                (Some(value), _) => json_text(value),
+                (None, _) => String::new(),
+            };
+            if content.is_empty() {
+                markdown.push_str(EMPTY_SECTION_TEXT);
+            } else {
+                markdown.push_str(&content);
+            }
+            markdown.push_str("\n\n");
+        }
+
+        markdown.trim_end().to_string()
+    }
+}
+
+/// Trimmed text of a JSON value; strings without quotes, null as empty
+fn json_text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.trim().to_string(),
+        serde_json::Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+/// A value as the content of one markdown table cell
+fn table_cell(value: &serde_json::Value) -> String {
+    json_text(value)
+        .replace('|', "\\|")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
 #[cfg(test)]
@@ -125,6 +363,7 @@ mod tests {
                     format: "paragraph".to_string(),
                     item_format: None,
                     example_item_format: None,
+                    extract: None,
                 },
             ],
         };
@@ -155,10 +394,107 @@ mod tests {
                     format: "invalid".to_string(),
                     item_format: None,
                     example_item_format: None,
+                    extract: None,
                 },
             ],
         };
 
         assert!(template.validate().is_err());
     }
+
+    fn list_section(title: &str, extract: Option<&str>) -> TemplateSection {
+        TemplateSection {
+            title: title.to_string(),
+            instruction: format!("List the {}", title.to_lowercase()),
+            format: "list".to_string(),
+            item_format: None,
+            example_item_format: None,
+            extract: extract.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_validate_extract_target() {
+        let mut template = Template {
+            name: "Test".to_string(),
+            description: "Test".to_string(),
+            sections: vec![list_section("Action Items", Some("todos"))],
+        };
+        assert!(template.validate().is_err());
+
+        template.sections[0].extract = Some("action_items".to_string());
+        assert!(template.validate().is_ok());
+
+        template.sections[0].format = "paragraph".to_string();
+        assert!(template.validate().is_err());
+    }
+
+    #[test]
+    fn test_extraction_schema_follows_marked_sections() {
+        let mut template = Template {
+            name: "Test".to_string(),
+            description: "Test".to_string(),
+            sections: vec![
+                list_section("Key Decisions", None),
+                list_section("Action Items", Some("action_items")),
+            ],
+        };
+        let schema = template.extraction_schema().unwrap();
+        assert_eq!(schema["required"], serde_json::json!(["action_items"]));
+        assert_eq!(
+            schema["properties"]["action_items"]["description"],
+            "List the action items"
+        );
+        assert!(schema["properties"].get("decisions").is_none());
+
+        template.sections[1].extract = None;
+        assert!(template.extraction_schema().is_none());
+    }
+
+    #[test]
+    fn test_summary_schema_and_rendering() {
+        let mut tasks = list_section("Action Items", None);
+        tasks.item_format = Some("| **Owner** | Task |\n| --- | --- |".to_string());
+        let template = Template {
+            name: "Test".to_string(),
+            description: "Test".to_string(),
+            sections: vec![
+                TemplateSection {
+                    title: "Summary".to_string(),
+                    instruction: "Summarize the meeting".to_string(),
+                    format: "paragraph".to_string(),
+                    item_format: None,
+                    example_item_format: None,
+                    extract: None,
+                },
+                list_section("Key Decisions", None),
+                tasks,
+            ],
+        };
+
+        let schema = template.summary_schema();
+        assert_eq!(
+            schema["required"],
+            serde_json::json!([SUMMARY_TITLE_KEY, "Summary", "Key Decisions", "Action Items"])
+        );
+        assert_eq!(schema["properties"]["Key Decisions"]["items"]["type"], "string");
+        assert_eq!(
+            schema["properties"]["Action Items"]["items"]["required"],
+            serde_json::json!(["Owner", "Task"])
+        );
+
+        let summary = serde_json::json!({
+            "meeting_title": " Launch Planning ",
+            "Summary": "We planned the launch.",
+            "Key Decisions": [],
+            "Action Items": [{"Owner": "Alice", "Task": "Update the pricing | FAQ page"}],
+        });
+        assert_eq!(
+            template.render_summary(&summary),
+            "# Launch Planning\n\n**Summary**\n\nWe planned the launch.\n\n\
+             **Key Decisions**\n\nNone noted in this section.\n\n\
+             **Action Items**\n\n| **Owner** | Task |\n| --- | --- |\n\
+             | Alice | Update the pricing \\| FAQ page |"
+        );
+    }
 }
@@ -0,0 +1,315 @@
+//! Token counting and context budgets for summary generation.
+//!
+//! Transcript chunks are measured with the tokenizer of the model that will read them:
+//! built-in models count with their own GGUF vocabulary through llama-helper, everything
+//! else with tiktoken's BPE encodings (exact for OpenAI models, a close estimate with a
+//! safety margin for other vendors). The context window left for a prompt is described by
+//! a [`TokenBudget`].
+
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tiktoken_rs::CoreBPE;
+use tracing::{info, warn};
+
+use crate::summary::processor::rough_token_count;
+use crate::summary::providers::{LlmProvider, ProviderConfig};
+use crate::summary::summary_engine;
+
+/// Tokens kept free for system prompts and instructions around the transcript
+const PROMPT_OVERHEAD_TOKENS: usize = 500;
+
+/// Tokens reserved for the response when the provider sets no `max_tokens`
+const DEFAULT_RESPONSE_TOKENS: usize = 4096;
+
+/// Smallest prompt budget, so tiny context windows still make progress
+const MIN_INPUT_TOKENS: usize = 256;
+
+/// Multiplier for BPE counts of models that use a different tokenizer
+const ESTIMATE_MARGIN: f64 = 1.1;
+
+/// Context window assumed for cloud models that are not listed in [`CONTEXT_WINDOWS`]
+pub const DEFAULT_CONTEXT_WINDOW: usize = 32_768;
+
+/// Context windows by model name prefix, most specific first
+const CONTEXT_WINDOWS: &[(&str, usize)] = &[
+    ("gpt-4.1", 1_047_576),
+    ("gpt-4.5", 128_000),
+    ("gpt-5", 400_000),
+    ("gpt-oss", 131_072),
+    ("gpt-4o", 128_000),
+    ("gpt-4-turbo", 128_000),
+    ("gpt-4", 8_192),
+    ("gpt-3.5", 16_385),
+    ("chatgpt-4o", 128_000),
+    ("o1", 200_000),
+    ("o3", 200_000),
+    ("o4", 200_000),
+    ("claude", 200_000),
+    ("gemini-1.5-pro", 2_097_152),
+    ("gemini", 1_048_576),
+    ("llama3-", 8_192),
+    ("llama-3", 131_072),
+    ("llama-4", 131_072),
+    ("deepseek", 65_536),
+    ("qwen", 32_768),
+    ("mixtral", 32_768),
+    ("mistral", 32_768),
+    ("gemma2", 8_192),
+];
+
+/// Context window of a cloud model, from its name
+///
+/// Vendor prefixes such as OpenRouter's "anthropic/" are ignored. Unknown models get the
+/// conservative [`DEFAULT_CONTEXT_WINDOW`].
+pub fn known_context_window(model: &str) -> usize {
+    let model = model.trim().to_lowercase();
+    let name = model.rsplit('/').next().unwrap_or(&model);
+    CONTEXT_WINDOWS
+        .iter()
+        .find(|(prefix, _)| name.starts_with(prefix))
+        .map(|(_, size)| *size)
+        .unwrap_or(DEFAULT_CONTEXT_WINDOW)
+}
+
+/// Counts the tokens a model sees for a text
+///
+/// Counting never fails: implementations fall back to a less exact method instead.
+#[async_trait]
+pub trait TokenCounter: Send + Sync {
+    /// Token count of each text, in order
+    async fn count_batch(&self, texts: &[&str]) -> Vec<usize>;
+
+    async fn count(&self, text: &str) -> usize {
+        self.count_batch(&[text]).await.pop().unwrap_or(0)
+    }
+}
+
+/// tiktoken encodings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BpeEncoding {
+    /// GPT-3.5 and GPT-4
+    Cl100k,
+    /// GPT-4o and newer
+    O200k,
+}
+
+static CL100K: Lazy<Option<CoreBPE>> = Lazy::new(|| {
+    tiktoken_rs::cl100k_base()
+        .map_err(|e| warn!("Failed to load cl100k tokenizer: {}", e))
+        .ok()
+});
+
+static O200K: Lazy<Option<CoreBPE>> = Lazy::new(|| {
+    tiktoken_rs::o200k_base()
+        .map_err(|e| warn!("Failed to load o200k tokenizer: {}", e))
+        .ok()
+});
+
+impl BpeEncoding {
+    /// Encoding of an OpenAI model, or None for models of other vendors
+    pub fn for_openai_model(model: &str) -> Option<Self> {
+        let model = model.trim().to_lowercase();
+        let name = model.strip_prefix("openai/").unwrap_or(&model);
+        let o200k = [
+            "gpt-4o", "gpt-4.1", "gpt-4.5", "gpt-5", "gpt-oss", "chatgpt-4o", "o1", "o3", "o4",
+        ];
+        if o200k.iter().any(|prefix| name.starts_with(prefix)) {
+            Some(Self::O200k)
+        } else if name.starts_with("gpt-") {
+            Some(Self::Cl100k)
+        } else {
+            None
+        }
+    }
+
+    fn bpe(self) -> Option<&'static CoreBPE> {
+        match self {
+            Self::Cl100k => CL100K.as_ref(),
+            Self::O200k => O200K.as_ref(),
+        }
+    }
+}
+
+/// Counts with a tiktoken encoding
+///
+/// For OpenAI models the count is exact. Other models get the cl100k count plus a margin,
+/// since their vocabularies split text into somewhat different pieces.
+#[derive(Debug, Clone, Copy)]
+pub struct BpeCounter {
+    encoding: BpeEncoding,
+    exact: bool,
+}
+
+impl BpeCounter {
+    pub fn for_model(model: &str) -> Self {
+        match BpeEncoding::for_openai_model(model) {
+            Some(encoding) => Self {
+                encoding,
+                exact: true,
+            },
+            None => Self {
+                encoding: BpeEncoding::Cl100k,
+                exact: false,
+            },
+        }
+    }
+
+    pub fn count_text(&self, text: &str) -> usize {
+        let Some(bpe) = self.encoding.bpe() else {
+            return rough_token_count(text);
+        };
+        let count = bpe.encode_ordinary(text).len();
+        if self.exact {
+            count
+        } else {
+            (count as f64 * ESTIMATE_MARGIN).ceil() as usize
+        }
+    }
+}
+
+#[async_trait]
+impl TokenCounter for BpeCounter {
+    async fn count_batch(&self, texts: &[&str]) -> Vec<usize> {
+        texts.iter().map(|text| self.count_text(text)).collect()
+    }
+}
+
+/// Counts with a built-in model's GGUF vocabulary, through the llama-helper sidecar
+///
+/// Falls back to a BPE estimate when the sidecar cannot tokenize (model not downloaded,
+/// helper predating tokenization).
+pub struct BuiltinTokenCounter {
+    app_data_dir: Option<PathBuf>,
+    model: String,
+}
+
+impl BuiltinTokenCounter {
+    pub fn new(app_data_dir: Option<PathBuf>, model: &str) -> Self {
+        Self {
+            app_data_dir,
+            model: model.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl TokenCounter for BuiltinTokenCounter {
+    async fn count_batch(&self, texts: &[&str]) -> Vec<usize> {
+        let fallback = BpeCounter::for_model(&self.model);
+        let Some(app_data_dir) = &self.app_data_dir else {
+            return fallback.count_batch(texts).await;
+        };
+        match summary_engine::count_tokens_with_builtin(app_data_dir, &self.model, texts).await {
+            Ok(counts) => counts,
+            Err(e) => {
+                warn!(
+                    "Tokenizing with {} failed: {}. Estimating token counts instead",
+                    self.model, e
+                );
+                fallback.count_batch(texts).await
+            }
+        }
+    }
+}
+
+/// How much of a model's context window a prompt may use
+#[derive(Clone)]
+pub struct TokenBudget {
+    /// Context window of the model
+    pub context_tokens: usize,
+    /// Tokens available for the prompt, after reserving room for the response and
+    /// for instructions
+    pub max_input_tokens: usize,
+    pub counter: Arc<dyn TokenCounter>,
+}
+
+impl TokenBudget {
+    /// # Arguments
+    /// * `context_tokens` - Context window of the model
+    /// * `max_output_tokens` - Configured response limit, if any
+    pub fn new(
+        context_tokens: usize,
+        max_output_tokens: Option<u32>,
+        counter: Arc<dyn TokenCounter>,
+    ) -> Self {
+        let response_tokens = max_output_tokens
+            .map(|t| t as usize)
+            .unwrap_or_else(|| DEFAULT_RESPONSE_TOKENS.min(context_tokens / 4))
+            .min(context_tokens / 2);
+        let max_input_tokens = context_tokens
+            .saturating_sub(response_tokens)
+            .saturating_sub(PROMPT_OVERHEAD_TOKENS)
+            .max(MIN_INPUT_TOKENS);
+        Self {
+            context_tokens,
+            max_input_tokens,
+            counter,
+        }
+    }
+
+    /// Budget of `model` on `provider`, with the provider's context window and tokenizer
+    pub async fn for_model(
+        provider: &dyn LlmProvider,
+        config: &ProviderConfig,
+        model: &str,
+    ) -> Self {
+        let context_tokens = provider.context_size(config, model).await;
+        let budget = Self::new(
+            context_tokens,
+            config.max_tokens,
+            provider.token_counter(config, model),
+        );
+        info!(
+            "✓ Using context size for {}: {} tokens (prompt budget: {})",
+            model, budget.context_tokens, budget.max_input_tokens
+        );
+        budget
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_context_windows() {
+        assert_eq!(known_context_window("gpt-4o-mini"), 128_000);
+        assert_eq!(known_context_window("gpt-4-0613"), 8_192);
+        assert_eq!(known_context_window("anthropic/claude-3.5-sonnet"), 200_000);
+        assert_eq!(known_context_window("models/gemini-2.0-flash"), 1_048_576);
+        assert_eq!(known_context_window("llama-3.3-70b-versatile"), 131_072);
+        assert_eq!(known_context_window("my-finetune"), DEFAULT_CONTEXT_WINDOW);
+    }
+
+    #[test]
+    fn test_encoding_for_openai_models() {
+        assert_eq!(BpeEncoding::for_openai_model("gpt-4o-mini"), Some(BpeEncoding::O200k));
+        assert_eq!(BpeEncoding::for_openai_model("openai/o3-mini"), Some(BpeEncoding::O200k));
+        assert_eq!(BpeEncoding::for_openai_model("gpt-3.5-turbo"), Some(BpeEncoding::Cl100k));
+        assert_eq!(BpeEncoding::for_openai_model("claude-sonnet-4"), None);
+    }
+
+    #[test]
+    fn test_bpe_counts_non_openai_models_with_margin() {
+        let text = "Alice: We agreed to ship the billing migration next Monday.";
+        let exact = BpeCounter::for_model("gpt-4").count_text(text);
+        let estimate = BpeCounter::for_model("llama3.2:latest").count_text(text);
+        assert!(exact > 5 && exact < 30, "{}", exact);
+        assert_eq!(estimate, (exact as f64 * ESTIMATE_MARGIN).ceil() as usize);
+    }
+
+    #[test]
+    fn test_budget_reserves_response_and_overhead() {
+        let counter: Arc<dyn TokenCounter> = Arc::new(BpeCounter::for_model("gpt-4o"));
+        let budget = TokenBudget::new(8192, None, counter.clone());
+        assert_eq!(budget.max_input_tokens, 8192 - 2048 - PROMPT_OVERHEAD_TOKENS);
+
+        let budget = TokenBudget::new(128_000, Some(1000), counter.clone());
+        assert_eq!(budget.max_input_tokens, 128_000 - 1000 - PROMPT_OVERHEAD_TOKENS);
+
+        let budget = TokenBudget::new(512, Some(4000), counter);
+        assert_eq!(budget.max_input_tokens, MIN_INPUT_TOKENS);
+    }
+}
@@ -0,0 +1,80 @@
+use crate::database::models::SummaryVersion;
+use crate::summary::service::SummaryService;
+use crate::summary::versions::DiffLine;
+use crate::workspace::manager::WorkspaceManager;
+use tracing::{error, info};
+
+/// Lists every generated, edited and restored version of a meeting's summary, newest first
+#[tauri::command]
+pub async fn api_list_summary_versions(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    meeting_id: String,
+) -> Result<Vec<SummaryVersion>, String> {
+    info!("api_list_summary_versions called for meeting_id: {}", meeting_id);
+    let pool = workspace_mgr.active_pool().await?;
+    SummaryService::list_versions(&pool, &meeting_id).await
+}
+
+/// Line diff of the markdown of two summary versions
+///
+/// # Arguments
+/// * `from_version` / `to_version` - Version numbers within the meeting
+#[tauri::command]
+pub async fn api_diff_summary_versions(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    meeting_id: String,
+    from_version: i64,
+    to_version: i64,
+) -> Result<Vec<DiffLine>, String> {
+    info!(
+        "api_diff_summary_versions called for meeting_id: {} ({} -> {})",
+        meeting_id, from_version, to_version
+    );
+    let pool = workspace_mgr.active_pool().await?;
+    SummaryService::diff_versions(&pool, &meeting_id, from_version, to_version).await
+}
+
+/// Makes an earlier summary version active again
+///
+/// # Returns
+/// The new version holding the restored summary
+#[tauri::command]
+pub async fn api_restore_summary_version(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    meeting_id: String,
+    version: i64,
+) -> Result<SummaryVersion, String> {
+    info!(
+        "api_restore_summary_version called for meeting_id: {}, version: {}",
+        meeting_id, version
+    );
+    let pool = workspace_mgr.active_pool().await?;
+    SummaryService::restore_version(&pool, &meeting_id, version)
+        .await
+        .map_err(|e| {
+            error!("Failed to restore version {} of {}: {}", version, meeting_id, e);
+            e
+        })
+}
+
+/// Pins a summary version so regenerating keeps it as the active summary, or unpins it
+/// with `pinned: false`
+#[tauri::command]
+pub async fn api_pin_summary_version(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    meeting_id: String,
+    version: i64,
+    pinned: bool,
+) -> Result<SummaryVersion, String> {
+    info!(
+        "api_pin_summary_version called for meeting_id: {}, version: {}, pinned: {}",
+        meeting_id, version, pinned
+    );
+    let pool = workspace_mgr.active_pool().await?;
+    SummaryService::pin_version(&pool, &meeting_id, version, pinned)
+        .await
+        .map_err(|e| {
+            error!("Failed to pin version {} of {}: {}", version, meeting_id, e);
+            e
+        })
+}
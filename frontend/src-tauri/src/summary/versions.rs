@@ -0,0 +1,113 @@
+//! Comparing versions of a meeting summary.
+
+use serde::Serialize;
+
+/// What happened to a line between two versions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffKind {
+    Equal,
+    Added,
+    Removed,
+}
+
+/// One line of a version diff
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiffLine {
+    pub kind: DiffKind,
+    pub text: String,
+}
+
+/// The markdown of a stored summary result.
+///
+/// Summaries are stored as `{"markdown": ..., "summary_json": ...}`; older results
+/// without markdown are compared as pretty-printed JSON.
+pub fn summary_markdown(result: &str) -> String {
+    match serde_json::from_str::<serde_json::Value>(result) {
+        Ok(value) => match value.get("markdown").and_then(|m| m.as_str()) {
+            Some(markdown) => markdown.to_string(),
+            None => serde_json::to_string_pretty(&value).unwrap_or_else(|_| result.to_string()),
+        },
+        Err(_) => result.to_string(),
+    }
+}
+
+/// Line diff of two texts (longest common subsequence), in reading order with
+/// removals before additions
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // lcs[i][j] = length of the longest common subsequence of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let line = |kind: DiffKind, text: &str| DiffLine {
+        kind,
+        text: text.to_string(),
+    };
+    let mut diff = Vec::with_capacity(old.len().max(new.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            diff.push(line(DiffKind::Equal, old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            diff.push(line(DiffKind::Removed, old[i]));
+            i += 1;
+        } else {
+            diff.push(line(DiffKind::Added, new[j]));
+            j += 1;
+        }
+    }
+    diff.extend(old[i..].iter().map(|text| line(DiffKind::Removed, text)));
+    diff.extend(new[j..].iter().map(|text| line(DiffKind::Added, text)));
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(diff: &[DiffLine]) -> Vec<(DiffKind, &str)> {
+        diff.iter().map(|l| (l.kind, l.text.as_str())).collect()
+    }
+
+    #[test]
+    fn test_diff_lines_marks_changes() {
+        let old = "# Summary\n- Ship Monday\n- Alice owns QA";
+        let new = "# Summary\n- Ship Tuesday\n- Alice owns QA\n- Bob writes notes";
+        assert_eq!(
+            kinds(&diff_lines(old, new)),
+            vec![
+                (DiffKind::Equal, "# Summary"),
+                (DiffKind::Removed, "- Ship Monday"),
+                (DiffKind::Added, "- Ship Tuesday"),
+                (DiffKind::Equal, "- Alice owns QA"),
+                (DiffKind::Added, "- Bob writes notes"),
+            ]
+        );
+        assert!(diff_lines("same", "same")
+            .iter()
+            .all(|l| l.kind == DiffKind::Equal));
+    }
+
+    #[test]
+    fn test_summary_markdown_handles_both_formats() {
+        assert_eq!(
+            summary_markdown(r##"{"markdown":"# Notes","summary_json":[]}"##),
+            "# Notes"
+        );
+        let legacy = summary_markdown(r#"{"MeetingName":"Sync"}"#);
+        assert!(legacy.contains("\"MeetingName\": \"Sync\""));
+    }
+}
@@ -7,6 +7,7 @@ use serde::{Serialize, Deserialize};
 
 use super::whisper_engine::WhisperEngine;
 use super::system_monitor::SystemMonitor;
+use crate::audio::transcription::WordTiming;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AudioChunk {
@@ -25,6 +26,9 @@ pub struct TranscriptionResult {
     pub model_used: String,
     pub start_time_ms: f64,
     pub confidence_score: Option<f32>,
+    // Word timings in seconds from the start of the chunk
+    #[serde(default)]
+    pub words: Vec<WordTiming>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -141,6 +145,11 @@ impl ParallelProcessor {
         Ok(safe_count)
     }
 
+    /// Number of workers spawned by the last `start_processing` call
+    pub fn worker_count(&self) -> usize {
+        self.workers.len()
+    }
+
     /// Start parallel processing with resource-aware worker spawning
     pub async fn start_processing(
         &mut self,
@@ -222,9 +231,14 @@ impl ParallelProcessor {
             // Load model for this worker
             {
                 let mut engine_guard = engine_ref.write().await;
-                let engine = WhisperEngine::new().map_err(|e| anyhow!("Failed to create WhisperEngine: {}", e))?;
-                engine.load_model(&model_name).await.map_err(|e| anyhow!("Failed to load model {}: {}", model_name, e))?;
-                *engine_guard = Some(engine);
+                match Self::load_worker_engine(&model_name).await {
+                    Ok(engine) => *engine_guard = Some(engine),
+                    Err(e) => {
+                        error!("Worker {} could not load model {}: {}", worker_id, model_name, e);
+                        let _ = event_sender.send(ProcessingEvent::WorkerStopped(worker_id));
+                        return Err(e);
+                    }
+                }
                 info!("Worker {} loaded model {}", worker_id, model_name);
             }
 
@@ -325,6 +339,17 @@ impl ParallelProcessor {
         })
     }
 
+    /// Create a worker's own engine from the app's models directory and load `model_name`
+    async fn load_worker_engine(model_name: &str) -> Result<WhisperEngine> {
+        let engine = WhisperEngine::new_with_models_dir(super::commands::get_models_directory())
+            .map_err(|e| anyhow!("Failed to create WhisperEngine: {}", e))?;
+        engine.discover_models().await
+            .map_err(|e| anyhow!("Failed to discover models: {}", e))?;
+        engine.load_model(model_name).await
+            .map_err(|e| anyhow!("Failed to load model {}: {}", model_name, e))?;
+        Ok(engine)
+    }
+
     async fn process_chunk_safely(
         engine_ref: &Arc<RwLock<Option<WhisperEngine>>>,
         chunk: AudioChunk,
@@ -344,10 +369,10 @@ impl ParallelProcessor {
         let language = crate::get_language_preference_internal();
 
         // Transcribe with timeout to prevent hanging
-        let transcription_future = engine.transcribe_audio(chunk.data.clone(), language);
+        let transcription_future = engine.transcribe_audio_with_confidence(chunk.data.clone(), language);
         let timeout_duration = tokio::time::Duration::from_secs(120); // 2 minute timeout per chunk
 
-        let text = tokio::time::timeout(timeout_duration, transcription_future)
+        let transcription = tokio::time::timeout(timeout_duration, transcription_future)
             .await
             .map_err(|_| anyhow!("Transcription timeout for chunk {}", chunk.id))?
             .map_err(|e| anyhow!("Transcription failed for chunk {}: {}", chunk.id, e))?;
@@ -356,11 +381,12 @@ impl ParallelProcessor {
 
         let result = TranscriptionResult {
             chunk_id: chunk.id,
-            text,
+            text: transcription.text.trim().to_string(),
             processing_time_ms: processing_time,
             model_used: model_name.to_string(),
             start_time_ms: chunk.start_time_ms,
-            confidence_score: None, // TODO: Add confidence scoring if available
+            confidence_score: transcription.confidence,
+            words: transcription.words,
         };
 
         debug!("Worker {} completed chunk {} in {}ms",
@@ -4,13 +4,17 @@ use std::path::{PathBuf};
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use whisper_rs::{WhisperContext, WhisperContextParameters, FullParams, SamplingStrategy};
+use whisper_rs::{WhisperContext, WhisperContextParameters, WhisperState, FullParams, SamplingStrategy};
 use serde::{Serialize, Deserialize};
 use anyhow::{Result, anyhow};
 use reqwest::Client;
 use tokio::fs;
 use tokio::io::AsyncWriteExt;
 use crate::{perf_debug, perf_trace};
+use crate::audio::transcription::provider::{
+    confidence_from_words, words_from_tokens, TranscriptResult, WordTiming,
+};
+use crate::audio::transcription::vocabulary;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ModelStatus {
@@ -529,8 +533,30 @@ impl WhisperEngine {
         repeated_words as f32 / total_words
     }
     
+    /// Word timings of one segment from whisper.cpp's token timestamps (10ms units)
+    fn segment_words(ctx: &WhisperContext, state: &WhisperState, segment: i32) -> Vec<WordTiming> {
+        let token_count = state.full_n_tokens(segment).unwrap_or(0);
+        let tokens = (0..token_count).filter_map(|token| {
+            let data = state.full_get_token_data(segment, token).ok()?;
+            // Special tokens ([_BEG_], [_TT_n], end of text, ...) have ids from EOT upwards
+            if data.id >= ctx.token_eot() {
+                return None;
+            }
+            Some(WordTiming {
+                word: state.full_get_token_text_lossy(segment, token).ok()?,
+                start: data.t0 as f64 / 100.0,
+                end: data.t1 as f64 / 100.0,
+                probability: Some(data.p),
+            })
+        });
+        words_from_tokens(tokens.collect::<Vec<_>>())
+    }
+
     /// Transcribe audio with streaming support for partial results and adaptive quality
-    pub async fn transcribe_audio_with_confidence(&self, audio_data: Vec<f32>, language: Option<String>) -> Result<(String, f32, bool)> {
+    ///
+    /// The confidence is the mean probability of the recognised words. Word timings are
+    /// kept unless repetition cleanup changed the text (the words would no longer match it).
+    pub async fn transcribe_audio_with_confidence(&self, audio_data: Vec<f32>, language: Option<String>) -> Result<TranscriptResult> {
         let ctx_lock = self.current_context.read().await;
         let ctx = ctx_lock.as_ref()
             .ok_or_else(|| anyhow!("No model loaded. Please load a model first."))?;
@@ -584,6 +610,12 @@ impl WhisperEngine {
         params.set_max_len(200);
         params.set_single_segment(false);
 
+        // Bias decoding towards the spelling of the workspace vocabulary
+        let initial_prompt = vocabulary::initial_prompt(&vocabulary::active_vocabulary());
+        if let Some(prompt) = initial_prompt.as_deref() {
+            params.set_initial_prompt(prompt);
+        }
+
         // Set thread count based on hardware (if supported by whisper.cpp)
         if let Some(_max_threads) = adaptive_config.max_threads {
             // Note: whisper.cpp may or may not expose thread control through params
@@ -606,8 +638,7 @@ impl WhisperEngine {
             // Suppressor dropped here, stderr restored
         };
         let mut result = String::new();
-        let mut total_confidence = 0.0;
-        let mut segment_count = 0;
+        let mut words = Vec::new();
 
         let num_segments = num_segments?;
         for i in 0..num_segments {
@@ -615,16 +646,7 @@ impl WhisperEngine {
                 Ok(text) => text,
                 Err(_) => continue,
             };
-
-            // Calculate confidence based on segment length and duration (simplified approach)
-            let segment_length = segment_text.len() as f32;
-            let segment_confidence = if segment_length > 0.0 {
-                (segment_length / 100.0).min(0.9) + 0.1 // 0.1 to 1.0 confidence based on text length
-            } else {
-                0.1
-            };
-            total_confidence += segment_confidence;
-            segment_count += 1;
+            words.extend(Self::segment_words(ctx, &state, i));
 
             let cleaned_text = segment_text.trim();
             if !cleaned_text.is_empty() {
@@ -638,13 +660,19 @@ impl WhisperEngine {
         let final_result = result.trim().to_string();
         let cleaned_result = Self::clean_repetitive_text(&final_result);
 
-        let avg_confidence = if segment_count > 0 {
-            total_confidence / segment_count as f32
-        } else {
-            0.0
-        };
+        // Token probabilities of the decoded words, taken before the words may be dropped below
+        let confidence = confidence_from_words(&words);
+
+        if cleaned_result != final_result {
+            words.clear();
+        }
 
-        Ok((cleaned_result, avg_confidence, is_partial))
+        Ok(TranscriptResult {
+            text: cleaned_result,
+            confidence,
+            is_partial,
+            words,
+        })
     }
 
     pub async fn transcribe_audio(&self, audio_data: Vec<f32>, language: Option<String>) -> Result<String> {
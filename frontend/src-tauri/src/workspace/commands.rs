@@ -0,0 +1,231 @@
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Runtime};
+
+use super::manager::WorkspaceManager;
+use super::transfer::TransferredMeeting;
+use super::types::{WorkspaceEntry, WorkspaceManifest};
+
+/// Workspaces in sidebar order, plus the currently active one
+#[derive(Debug, Serialize)]
+pub struct WorkspaceListResponse {
+    pub workspaces: Vec<WorkspaceEntry>,
+    pub active_workspace_id: Option<String>,
+}
+
+/// Payload of the `workspace-switched` event
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceSwitchedEvent {
+    pub workspace_id: String,
+    pub name: String,
+}
+
+#[tauri::command]
+pub async fn workspace_list(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+) -> Result<WorkspaceListResponse, String> {
+    Ok(WorkspaceListResponse {
+        workspaces: workspace_mgr.list_workspaces().await,
+        active_workspace_id: workspace_mgr.active_workspace_id().await,
+    })
+}
+
+/// Create a workspace; it is not activated until `workspace_switch` is called
+#[tauri::command]
+pub async fn workspace_create(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    name: String,
+    icon: Option<String>,
+    accent_color: Option<String>,
+    description: Option<String>,
+) -> Result<WorkspaceEntry, String> {
+    log::info!("workspace_create called: {}", name);
+    let id = workspace_mgr.create_workspace(name).await?;
+
+    if icon.is_some() || accent_color.is_some() || description.is_some() {
+        if let Err(e) = workspace_mgr
+            .update_workspace_appearance(&id, icon, accent_color, description)
+            .await
+        {
+            // Don't leave a half-configured workspace behind
+            let _ = workspace_mgr.delete_workspace(&id).await;
+            return Err(e);
+        }
+    }
+
+    workspace_mgr
+        .list_workspaces()
+        .await
+        .into_iter()
+        .find(|w| w.id == id)
+        .ok_or_else(|| format!("Workspace not found after creation: {}", id))
+}
+
+#[tauri::command]
+pub async fn workspace_rename(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    workspace_id: String,
+    name: String,
+) -> Result<WorkspaceManifest, String> {
+    workspace_mgr.rename_workspace(&workspace_id, name).await
+}
+
+#[tauri::command]
+pub async fn workspace_get_manifest(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    workspace_id: String,
+) -> Result<WorkspaceManifest, String> {
+    workspace_mgr.get_manifest(&workspace_id).await
+}
+
+/// Update the icon, accent color and description shown for a workspace
+#[tauri::command]
+pub async fn workspace_update_manifest(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    workspace_id: String,
+    icon: Option<String>,
+    accent_color: Option<String>,
+    description: Option<String>,
+) -> Result<WorkspaceManifest, String> {
+    workspace_mgr
+        .update_workspace_appearance(&workspace_id, icon, accent_color, description)
+        .await
+}
+
+/// Persist a new sidebar order; `workspace_ids` must list every workspace once
+#[tauri::command]
+pub async fn workspace_reorder(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    workspace_ids: Vec<String>,
+) -> Result<Vec<WorkspaceEntry>, String> {
+    workspace_mgr.reorder_workspaces(&workspace_ids).await?;
+    Ok(workspace_mgr.list_workspaces().await)
+}
+
+/// Permanently delete a workspace with all of its meetings and recordings
+#[tauri::command]
+pub async fn workspace_delete(
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    workspace_id: String,
+) -> Result<(), String> {
+    log::info!("workspace_delete called: {}", workspace_id);
+    workspace_mgr.delete_workspace(&workspace_id).await
+}
+
+/// Switch the active workspace and notify the frontend with `workspace-switched`.
+///
+/// Refused while a recording is in progress, since the recording saves into the
+/// active workspace's database and folders.
+#[tauri::command]
+pub async fn workspace_switch<R: Runtime>(
+    app: AppHandle<R>,
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    workspace_id: String,
+) -> Result<WorkspaceSwitchedEvent, String> {
+    log::info!("workspace_switch called: {}", workspace_id);
+
+    if crate::audio::recording_commands::is_recording().await {
+        return Err("Cannot switch workspaces while a recording is in progress".to_string());
+    }
+
+    let entry = workspace_mgr
+        .list_workspaces()
+        .await
+        .into_iter()
+        .find(|w| w.id == workspace_id)
+        .ok_or_else(|| format!("Workspace not found: {}", workspace_id))?;
+
+    if workspace_mgr.active_workspace_id().await.as_deref() != Some(workspace_id.as_str()) {
+        workspace_mgr.switch_workspace(&workspace_id).await?;
+    }
+
+    let event = WorkspaceSwitchedEvent {
+        workspace_id: entry.id,
+        name: entry.name,
+    };
+    if let Err(e) = app.emit("workspace-switched", &event) {
+        log::warn!("Failed to emit workspace-switched event: {}", e);
+    }
+    Ok(event)
+}
+
+/// Move meetings with their transcripts, summaries, notes and recordings to another workspace
+#[tauri::command]
+pub async fn workspace_move_meetings<R: Runtime>(
+    app: AppHandle<R>,
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    source_workspace_id: String,
+    target_workspace_id: String,
+    meeting_ids: Vec<String>,
+) -> Result<Vec<TransferredMeeting>, String> {
+    log::info!(
+        "workspace_move_meetings called: {} meetings from {} to {}",
+        meeting_ids.len(),
+        source_workspace_id,
+        target_workspace_id
+    );
+    let moved = workspace_mgr
+        .move_meetings(&source_workspace_id, &target_workspace_id, &meeting_ids)
+        .await?;
+    emit_meetings_transferred(&app, &source_workspace_id, &target_workspace_id, &moved);
+    Ok(moved)
+}
+
+/// Copy meetings to another workspace; the copies get new ids
+#[tauri::command]
+pub async fn workspace_copy_meetings<R: Runtime>(
+    app: AppHandle<R>,
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    source_workspace_id: String,
+    target_workspace_id: String,
+    meeting_ids: Vec<String>,
+) -> Result<Vec<TransferredMeeting>, String> {
+    log::info!(
+        "workspace_copy_meetings called: {} meetings from {} to {}",
+        meeting_ids.len(),
+        source_workspace_id,
+        target_workspace_id
+    );
+    let copied = workspace_mgr
+        .copy_meetings(&source_workspace_id, &target_workspace_id, &meeting_ids)
+        .await?;
+    emit_meetings_transferred(&app, &source_workspace_id, &target_workspace_id, &copied);
+    Ok(copied)
+}
+
+/// Move every meeting of one workspace into another and delete the emptied workspace
+#[tauri::command]
+pub async fn workspace_merge<R: Runtime>(
+    app: AppHandle<R>,
+    workspace_mgr: tauri::State<'_, WorkspaceManager>,
+    source_workspace_id: String,
+    target_workspace_id: String,
+) -> Result<Vec<TransferredMeeting>, String> {
+    log::info!(
+        "workspace_merge called: {} into {}",
+        source_workspace_id,
+        target_workspace_id
+    );
+    let moved = workspace_mgr
+        .merge_workspace(&source_workspace_id, &target_workspace_id)
+        .await?;
+    emit_meetings_transferred(&app, &source_workspace_id, &target_workspace_id, &moved);
+    Ok(moved)
+}
+
+/// Let the meeting list refresh when meetings were added to or removed from a workspace
+fn emit_meetings_transferred<R: Runtime>(
+    app: &AppHandle<R>,
+    source_workspace_id: &str,
+    target_workspace_id: &str,
+    meetings: &[TransferredMeeting],
+) {
+    let payload = serde_json::json!({
+        "sourceWorkspaceId": source_workspace_id,
+        "targetWorkspaceId": target_workspace_id,
+        "meetings": meetings,
+    });
+    if let Err(e) = app.emit("workspace-meetings-transferred", payload) {
+        log::warn!("Failed to emit workspace-meetings-transferred event: {}", e);
+    }
+}
@@ -19,6 +19,23 @@ pub fn create_workspace_dir(workspaces_root: &Path, workspace_id: &str) -> Resul
     Ok(workspace_dir)
 }
 
+/// Recursively copy a directory tree. `to` must not exist yet.
+pub fn copy_dir_recursive(from: &Path, to: &Path) -> Result<(), String> {
+    fs::create_dir(to).map_err(|e| format!("Failed to create directory {:?}: {}", to, e))?;
+    let entries = fs::read_dir(from).map_err(|e| format!("Failed to read directory {:?}: {}", from, e))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+        let src = entry.path();
+        let dst = to.join(entry.file_name());
+        if src.is_dir() {
+            copy_dir_recursive(&src, &dst)?;
+        } else {
+            fs::copy(&src, &dst).map_err(|e| format!("Failed to copy {:?}: {}", src, e))?;
+        }
+    }
+    Ok(())
+}
+
 /// Write a manifest to `{workspace_dir}/manifest.json`.
 pub fn write_manifest(workspace_dir: &Path, manifest: &WorkspaceManifest) -> Result<(), String> {
     let manifest_path = workspace_dir.join("manifest.json");
@@ -7,6 +7,7 @@ use tokio::sync::RwLock;
 
 use crate::database::manager::DatabaseManager;
 use super::filesystem;
+use super::transfer::{self, TransferMode, TransferredMeeting};
 use super::types::{WorkspaceEntry, WorkspaceManifest, WorkspaceRegistry};
 
 /// Central abstraction that manages workspace lifecycle, database pool switching,
@@ -94,6 +95,16 @@ impl WorkspaceManager {
 
     /// Switch to a different workspace by closing the current pool and opening a new one.
     pub async fn switch_workspace(&self, workspace_id: &str) -> Result<(), String> {
+        // Resolve workspace path before closing the current pool, so a bad id keeps the
+        // current workspace usable
+        let workspace_dir = self.workspaces_root.join(workspace_id);
+        if !workspace_dir.exists() {
+            return Err(format!("Workspace directory does not exist: {:?}", workspace_dir));
+        }
+        if !workspace_dir.join("manifest.json").exists() {
+            return Err(format!("Workspace missing manifest.json: {:?}", workspace_dir));
+        }
+
         // Close current pool if any
         {
             let mut active = self.active_db.write().await;
@@ -104,31 +115,8 @@ impl WorkspaceManager {
             }
         }
 
-        // Resolve workspace path
-        let workspace_dir = self.workspaces_root.join(workspace_id);
-        if !workspace_dir.exists() {
-            return Err(format!("Workspace directory does not exist: {:?}", workspace_dir));
-        }
-        if !workspace_dir.join("manifest.json").exists() {
-            return Err(format!("Workspace missing manifest.json: {:?}", workspace_dir));
-        }
-
-        // Open workspace database
-        let db_path = workspace_dir.join("db.sqlite");
-        let options = SqliteConnectOptions::new()
-            .filename(&db_path)
-            .create_if_missing(true)
-            .journal_mode(SqliteJournalMode::Wal)
-            .foreign_keys(true);
-
-        let pool = SqlitePoolOptions::new()
-            .max_connections(5)
-            .connect_with(options)
-            .await
-            .map_err(|e| format!("Failed to connect to workspace database: {}", e))?;
-
-        // Run workspace migrations
-        Self::run_workspace_migrations(&pool).await?;
+        // Open workspace database and run workspace migrations
+        let pool = Self::open_workspace_pool(&workspace_dir, 5).await?;
 
         // Update active state
         let db_manager = DatabaseManager::from_pool(pool);
@@ -158,6 +146,7 @@ impl WorkspaceManager {
     /// Does NOT switch to the new workspace — call `switch_workspace` afterwards.
     /// Returns the new workspace's UUID.
     pub async fn create_workspace(&self, name: String) -> Result<String, String> {
+        let name = validate_workspace_name(&name)?;
         let id = uuid::Uuid::new_v4().to_string();
 
         // Create directory structure
@@ -195,6 +184,188 @@ impl WorkspaceManager {
         Ok(id)
     }
 
+    /// Read a workspace's manifest from disk.
+    pub async fn get_manifest(&self, workspace_id: &str) -> Result<WorkspaceManifest, String> {
+        let workspace_dir = self.workspace_dir(workspace_id).await?;
+        filesystem::read_manifest(&workspace_dir)
+    }
+
+    /// Rename a workspace, updating both its manifest and the registry cache.
+    pub async fn rename_workspace(&self, workspace_id: &str, name: String) -> Result<WorkspaceManifest, String> {
+        let name = validate_workspace_name(&name)?;
+        self.update_manifest(workspace_id, |manifest| manifest.name = name.clone())
+            .await
+    }
+
+    /// Update a workspace's icon, accent color and description.
+    ///
+    /// Each value replaces the current one; `None` clears it.
+    pub async fn update_workspace_appearance(
+        &self,
+        workspace_id: &str,
+        icon: Option<String>,
+        accent_color: Option<String>,
+        description: Option<String>,
+    ) -> Result<WorkspaceManifest, String> {
+        let icon = icon.map(|i| i.trim().to_string()).filter(|i| !i.is_empty());
+        let accent_color = accent_color
+            .map(|c| c.trim().to_string())
+            .filter(|c| !c.is_empty());
+        if let Some(color) = &accent_color {
+            if !is_valid_accent_color(color) {
+                return Err(format!("Invalid accent color '{}', expected #RRGGBB", color));
+            }
+        }
+        let description = description
+            .map(|d| d.trim().to_string())
+            .filter(|d| !d.is_empty());
+
+        self.update_manifest(workspace_id, |manifest| {
+            manifest.icon = icon.clone();
+            manifest.accent_color = accent_color.clone();
+            manifest.description = description.clone();
+        })
+        .await
+    }
+
+    /// Apply a change to a workspace manifest and refresh the cached registry entry.
+    async fn update_manifest<F>(&self, workspace_id: &str, apply: F) -> Result<WorkspaceManifest, String>
+    where
+        F: FnOnce(&mut WorkspaceManifest),
+    {
+        let workspace_dir = self.workspace_dir(workspace_id).await?;
+        let mut manifest = filesystem::read_manifest(&workspace_dir)?;
+        apply(&mut manifest);
+        manifest.last_modified = chrono::Utc::now().to_rfc3339();
+        filesystem::write_manifest(&workspace_dir, &manifest)?;
+
+        let mut reg = self.registry.write().await;
+        if let Some(entry) = reg.workspaces.iter_mut().find(|w| w.id == workspace_id) {
+            entry.name = manifest.name.clone();
+            entry.icon = manifest.icon.clone();
+        }
+        filesystem::save_registry(&self.workspaces_root, &reg)?;
+
+        Ok(manifest)
+    }
+
+    /// Reorder workspaces in the registry (sidebar display order).
+    ///
+    /// `ordered_ids` must contain every registered workspace exactly once.
+    pub async fn reorder_workspaces(&self, ordered_ids: &[String]) -> Result<(), String> {
+        let mut reg = self.registry.write().await;
+        if ordered_ids.len() != reg.workspaces.len() {
+            return Err("Workspace order must include every workspace exactly once".to_string());
+        }
+
+        let mut reordered = Vec::with_capacity(ordered_ids.len());
+        for id in ordered_ids {
+            let entry = reg
+                .workspaces
+                .iter()
+                .find(|w| &w.id == id)
+                .ok_or_else(|| format!("Unknown workspace: {}", id))?;
+            if reordered.iter().any(|w: &WorkspaceEntry| &w.id == id) {
+                return Err(format!("Workspace listed more than once: {}", id));
+            }
+            reordered.push(entry.clone());
+        }
+
+        reg.workspaces = reordered;
+        filesystem::save_registry(&self.workspaces_root, &reg)
+    }
+
+    /// Delete a workspace and all of its meetings, recordings and notes.
+    ///
+    /// The active workspace and the last remaining workspace cannot be deleted.
+    pub async fn delete_workspace(&self, workspace_id: &str) -> Result<(), String> {
+        if self.active_workspace_id().await.as_deref() == Some(workspace_id) {
+            return Err("Cannot delete the active workspace. Switch to another workspace first.".to_string());
+        }
+
+        let workspace_dir = self.workspace_dir(workspace_id).await?;
+        {
+            let mut reg = self.registry.write().await;
+            if reg.workspaces.len() <= 1 {
+                return Err("Cannot delete the only workspace".to_string());
+            }
+            reg.workspaces.retain(|w| w.id != workspace_id);
+            if reg.last_active.as_deref() == Some(workspace_id) {
+                reg.last_active = None;
+            }
+            filesystem::save_registry(&self.workspaces_root, &reg)?;
+        }
+
+        if workspace_dir.exists() {
+            std::fs::remove_dir_all(&workspace_dir)
+                .map_err(|e| format!("Failed to remove workspace directory: {}", e))?;
+        }
+
+        log::info!("Deleted workspace: {}", workspace_id);
+        Ok(())
+    }
+
+    /// Move meetings (rows and recording folders) from one workspace to another.
+    pub async fn move_meetings(
+        &self,
+        source_id: &str,
+        target_id: &str,
+        meeting_ids: &[String],
+    ) -> Result<Vec<TransferredMeeting>, String> {
+        let source_dir = self.workspace_dir(source_id).await?;
+        let target_dir = self.workspace_dir(target_id).await?;
+        transfer::transfer_meetings(&source_dir, &target_dir, meeting_ids, TransferMode::Move).await
+    }
+
+    /// Copy meetings into another workspace under new ids, leaving the originals in place.
+    pub async fn copy_meetings(
+        &self,
+        source_id: &str,
+        target_id: &str,
+        meeting_ids: &[String],
+    ) -> Result<Vec<TransferredMeeting>, String> {
+        let source_dir = self.workspace_dir(source_id).await?;
+        let target_dir = self.workspace_dir(target_id).await?;
+        transfer::transfer_meetings(&source_dir, &target_dir, meeting_ids, TransferMode::Copy).await
+    }
+
+    /// Fold every meeting of `source_id` into `target_id`, then delete the source workspace.
+    ///
+    /// The source workspace must not be active.
+    pub async fn merge_workspace(
+        &self,
+        source_id: &str,
+        target_id: &str,
+    ) -> Result<Vec<TransferredMeeting>, String> {
+        if self.active_workspace_id().await.as_deref() == Some(source_id) {
+            return Err("Cannot merge the active workspace. Switch to another workspace first.".to_string());
+        }
+        let source_dir = self.workspace_dir(source_id).await?;
+        let target_dir = self.workspace_dir(target_id).await?;
+
+        let meeting_ids = transfer::list_meeting_ids(&source_dir).await?;
+        let moved =
+            transfer::transfer_meetings(&source_dir, &target_dir, &meeting_ids, TransferMode::Move).await?;
+        self.delete_workspace(source_id).await?;
+
+        log::info!(
+            "Merged workspace {} into {} ({} meetings)",
+            source_id,
+            target_id,
+            moved.len()
+        );
+        Ok(moved)
+    }
+
+    /// Resolve the directory of a registered workspace.
+    async fn workspace_dir(&self, workspace_id: &str) -> Result<PathBuf, String> {
+        let reg = self.registry.read().await;
+        if !reg.workspaces.iter().any(|w| w.id == workspace_id) {
+            return Err(format!("Workspace not found: {}", workspace_id));
+        }
+        Ok(self.workspaces_root.join(workspace_id))
+    }
+
     /// List all workspaces from the in-memory registry.
     pub async fn list_workspaces(&self) -> Vec<WorkspaceEntry> {
         let reg = self.registry.read().await;
@@ -231,39 +402,332 @@ impl WorkspaceManager {
         Ok(())
     }
 
+    /// Open a workspace's `db.sqlite` and bring its schema up to date.
+    pub(crate) async fn open_workspace_pool(
+        workspace_dir: &Path,
+        max_connections: u32,
+    ) -> Result<SqlitePool, String> {
+        let db_path = workspace_dir.join("db.sqlite");
+        let options = SqliteConnectOptions::new()
+            .filename(&db_path)
+            .create_if_missing(true)
+            .journal_mode(SqliteJournalMode::Wal)
+            .foreign_keys(true);
+
+        let pool = SqlitePoolOptions::new()
+            .max_connections(max_connections)
+            .connect_with(options)
+            .await
+            .map_err(|e| format!("Failed to connect to workspace database: {}", e))?;
+
+        Self::run_workspace_migrations(&pool).await?;
+        Ok(pool)
+    }
+
     /// Run workspace schema migrations on a pool.
-    async fn run_workspace_migrations(pool: &SqlitePool) -> Result<(), String> {
-        let sql = include_str!("../../migrations/workspace/20260201000000_workspace_schema.sql");
-        Self::execute_multi_statement_sql(pool, sql, "workspace").await
+    ///
+    /// Migrations are re-applied on every switch, so each file must be idempotent.
+    pub(crate) async fn run_workspace_migrations(pool: &SqlitePool) -> Result<(), String> {
+        let migrations = [
+            include_str!("../../migrations/workspace/20260201000000_workspace_schema.sql"),
+            include_str!("../../migrations/workspace/20260301000000_search_index.sql"),
+            include_str!("../../migrations/workspace/20260310000000_speaker_diarization.sql"),
+            include_str!("../../migrations/workspace/20260320000000_action_items.sql"),
+            include_str!("../../migrations/workspace/20260325000000_meeting_chat.sql"),
+            include_str!("../../migrations/workspace/20260401000000_segment_embeddings.sql"),
+            include_str!("../../migrations/workspace/20260410000000_summary_versions.sql"),
+            include_str!("../../migrations/workspace/20260501000000_transcript_words.sql"),
+            include_str!("../../migrations/workspace/20260510000000_vocabulary.sql"),
+            include_str!("../../migrations/workspace/20260520000000_transcript_versions.sql"),
+            include_str!("../../migrations/workspace/20260530000000_transcript_confidence.sql"),
+        ];
+        for sql in migrations {
+            Self::execute_multi_statement_sql(pool, sql, "workspace").await?;
+        }
+        Ok(())
     }
 
     /// Run global schema migrations on a pool.
-    async fn run_global_migrations(pool: &SqlitePool) -> Result<(), String> {
-        let sql = include_str!("../../migrations/global/20260201000000_global_schema.sql");
-        Self::execute_multi_statement_sql(pool, sql, "global").await
+    ///
+    /// Like workspace migrations, these run on every start and must be idempotent.
+    pub(crate) async fn run_global_migrations(pool: &SqlitePool) -> Result<(), String> {
+        let migrations = [
+            include_str!("../../migrations/global/20260201000000_global_schema.sql"),
+            include_str!("../../migrations/global/20260320000000_summary_fallback.sql"),
+            include_str!("../../migrations/global/20260501000000_transcript_endpoint.sql"),
+        ];
+        for sql in migrations {
+            Self::execute_multi_statement_sql(pool, sql, "global").await?;
+        }
+        Ok(())
     }
 
-    /// Execute multi-statement SQL by splitting on semicolons and running each statement.
+    /// Execute multi-statement SQL by splitting it into statements and running each one.
+    ///
+    /// `ALTER TABLE ... ADD COLUMN` statements for columns that already exist are skipped,
+    /// which keeps re-applied migrations idempotent.
     async fn execute_multi_statement_sql(pool: &SqlitePool, sql: &str, label: &str) -> Result<(), String> {
-        for statement in sql.split(';') {
-            let trimmed = statement.trim();
-            if trimmed.is_empty() || trimmed.starts_with("--") {
-                continue;
-            }
-            // Skip lines that are only comments
-            let non_comment_content: String = trimmed
-                .lines()
-                .filter(|line| !line.trim().starts_with("--"))
-                .collect::<Vec<_>>()
-                .join("\n");
-            if non_comment_content.trim().is_empty() {
-                continue;
+        for statement in split_sql_statements(sql) {
+            if let Err(e) = sqlx::query(&statement).execute(pool).await {
+                let is_add_column = statement.to_uppercase().starts_with("ALTER TABLE");
+                if is_add_column && e.to_string().contains("duplicate column name") {
+                    continue;
+                }
+                return Err(format!("Failed to run {} migration statement: {}", label, e));
             }
-            sqlx::query(trimmed)
-                .execute(pool)
-                .await
-                .map_err(|e| format!("Failed to run {} migration statement: {}", label, e))?;
         }
         Ok(())
     }
 }
+
+/// Trim a workspace name and check it is usable as a display name.
+fn validate_workspace_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Workspace name cannot be empty".to_string());
+    }
+    if name.chars().count() > 100 {
+        return Err("Workspace name must be 100 characters or fewer".to_string());
+    }
+    Ok(name.to_string())
+}
+
+/// Accent colors are stored as `#RRGGBB` hex strings.
+fn is_valid_accent_color(color: &str) -> bool {
+    color
+        .strip_prefix('#')
+        .map_or(false, |hex| hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Split a migration script into individual statements.
+///
+/// Comments are dropped. Semicolons inside string literals, quoted identifiers and the
+/// `BEGIN ... END` body of a `CREATE TRIGGER` do not terminate the statement; nesting in
+/// a trigger is tracked by keyword (`CASE ... END` included), not by how a line ends.
+fn split_sql_statements(sql: &str) -> Vec<String> {
+    let mut statements = Vec::new();
+    let mut current = String::new();
+    let mut keywords = StatementKeywords::default();
+    let mut word = String::new();
+    let mut chars = sql.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            word.push(c);
+            current.push(c);
+            continue;
+        }
+        keywords.push(&word);
+        word.clear();
+
+        match c {
+            '\'' | '"' | '`' | '[' => {
+                let close = if c == '[' { ']' } else { c };
+                current.push(c);
+                for quoted in chars.by_ref() {
+                    current.push(quoted);
+                    if quoted == close {
+                        break;
+                    }
+                }
+            }
+            '-' if chars.peek() == Some(&'-') => {
+                for skipped in chars.by_ref() {
+                    if skipped == '\n' {
+                        current.push('\n');
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = ' ';
+                for skipped in chars.by_ref() {
+                    if previous == '*' && skipped == '/' {
+                        break;
+                    }
+                    previous = skipped;
+                }
+                current.push(' ');
+            }
+            ';' if keywords.depth == 0 => {
+                let statement = current.trim();
+                if !statement.is_empty() {
+                    statements.push(statement.to_string());
+                }
+                current.clear();
+                keywords = StatementKeywords::default();
+            }
+            _ => current.push(c),
+        }
+    }
+
+    let statement = current.trim();
+    if !statement.is_empty() {
+        statements.push(statement.to_string());
+    }
+    statements
+}
+
+/// Keywords seen so far in the statement being split
+#[derive(Default)]
+struct StatementKeywords {
+    /// The first three, enough to recognise `CREATE [TEMP] TRIGGER`
+    leading: Vec<String>,
+    /// Open `BEGIN`/`CASE` blocks inside a trigger
+    depth: usize,
+}
+
+impl StatementKeywords {
+    fn push(&mut self, word: &str) {
+        if word.is_empty() {
+            return;
+        }
+        let word = word.to_ascii_uppercase();
+        if self.leading.len() < 3 {
+            self.leading.push(word.clone());
+        }
+        let is_trigger = match self.leading.as_slice() {
+            [create, trigger, ..] if create == "CREATE" && trigger == "TRIGGER" => true,
+            [create, temp, trigger] => {
+                create == "CREATE"
+                    && (temp == "TEMP" || temp == "TEMPORARY")
+                    && trigger == "TRIGGER"
+            }
+            _ => false,
+        };
+        if !is_trigger {
+            return;
+        }
+        match word.as_str() {
+            "BEGIN" | "CASE" => self.depth += 1,
+            "END" => self.depth = self.depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_skips_comments_but_keeps_statements() {
+        let sql = "-- header\n-- Create table\nCREATE TABLE a (id TEXT);\n\n-- only a comment;\n";
+        let statements = split_sql_statements(sql);
+        assert_eq!(statements, vec!["CREATE TABLE a (id TEXT)".to_string()]);
+    }
+
+    #[test]
+    fn test_split_keeps_trigger_body_together() {
+        let sql = "CREATE TABLE a (id TEXT);\n\
+                   CREATE TRIGGER IF NOT EXISTS a_ai AFTER INSERT ON a BEGIN\n\
+                   INSERT INTO b VALUES (new.id);\n\
+                   INSERT INTO c VALUES (new.id);\n\
+                   END;\n\
+                   DROP TABLE b;";
+        let statements = split_sql_statements(sql);
+        assert_eq!(statements.len(), 3);
+        assert!(statements[1].starts_with("CREATE TRIGGER"));
+        assert!(statements[1].ends_with("END"));
+        assert!(statements[1].contains("INSERT INTO c VALUES (new.id);"));
+        assert_eq!(statements[2], "DROP TABLE b");
+    }
+
+    #[test]
+    fn test_split_tracks_trigger_nesting_strings_and_comments() {
+        let sql = "CREATE TRIGGER t_au AFTER UPDATE ON t BEGIN\n\
+                   UPDATE u SET state = CASE WHEN new.done THEN 'END' ELSE 'open;' END;\n\
+                   DELETE FROM v WHERE id = old.id; -- not the end;\n\
+                   END;\n\
+                   /* between; statements */ INSERT INTO w VALUES ('a;b', \"c;d\");\n\
+                   BEGIN; END";
+        let statements = split_sql_statements(sql);
+        assert_eq!(statements.len(), 4, "{:?}", statements);
+        assert!(statements[0].contains("ELSE 'open;' END;"));
+        assert!(statements[0].contains("DELETE FROM v WHERE id = old.id;"));
+        assert!(statements[0].ends_with("END"));
+        assert!(!statements[0].contains("not the end"));
+        assert_eq!(statements[1], "INSERT INTO w VALUES ('a;b', \"c;d\")");
+        assert_eq!(statements[2], "BEGIN");
+        assert_eq!(statements[3], "END");
+    }
+
+    #[test]
+    fn test_accent_color_validation() {
+        assert!(is_valid_accent_color("#3B82F6"));
+        assert!(!is_valid_accent_color("#fff"));
+        assert!(!is_valid_accent_color("3B82F6"));
+        assert!(!is_valid_accent_color("#3B82FG"));
+    }
+
+    #[tokio::test]
+    async fn test_workspace_lifecycle() {
+        let dir = tempfile::tempdir().unwrap();
+        let manager = WorkspaceManager::init(dir.path().to_path_buf()).await.unwrap();
+
+        let first = manager.create_workspace("Work".to_string()).await.unwrap();
+        let second = manager.create_workspace("Personal".to_string()).await.unwrap();
+        assert!(manager.create_workspace("   ".to_string()).await.is_err());
+
+        let manifest = manager.rename_workspace(&second, " Home ".to_string()).await.unwrap();
+        assert_eq!(manifest.name, "Home");
+        let manifest = manager
+            .update_workspace_appearance(&second, Some("🏠".to_string()), Some("#10B981".to_string()), None)
+            .await
+            .unwrap();
+        assert_eq!(manifest.accent_color.as_deref(), Some("#10B981"));
+        assert!(manager
+            .update_workspace_appearance(&second, None, Some("green".to_string()), None)
+            .await
+            .is_err());
+
+        manager.reorder_workspaces(&[second.clone(), first.clone()]).await.unwrap();
+        let listed = manager.list_workspaces().await;
+        assert_eq!(listed[0].id, second);
+        assert_eq!(listed[0].name, "Home");
+        assert_eq!(listed[0].icon.as_deref(), Some("🏠"));
+        assert!(manager.reorder_workspaces(&[first.clone()]).await.is_err());
+
+        // Registry survives a restart
+        let reloaded = filesystem::load_registry(manager.workspaces_root()).unwrap();
+        assert_eq!(reloaded.workspaces[0].id, second);
+
+        manager.switch_workspace(&first).await.unwrap();
+        assert!(manager.switch_workspace("missing").await.is_err());
+        assert_eq!(manager.active_workspace_id().await.as_deref(), Some(first.as_str()));
+        assert!(manager.active_pool().await.is_ok());
+
+        assert!(manager.delete_workspace(&first).await.is_err());
+        manager.delete_workspace(&second).await.unwrap();
+        assert!(!manager.workspaces_root().join(&second).exists());
+        assert_eq!(manager.list_workspaces().await.len(), 1);
+
+        manager.close_active_workspace().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_workspace_migrations_are_rerunnable() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+
+        WorkspaceManager::run_workspace_migrations(&pool).await.unwrap();
+        WorkspaceManager::run_workspace_migrations(&pool).await.unwrap();
+
+        let tables: Vec<(String,)> = sqlx::query_as(
+            "SELECT name FROM sqlite_master WHERE type IN ('table', 'trigger') ORDER BY name",
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        let names: Vec<&str> = tables.iter().map(|(n,)| n.as_str()).collect();
+        assert!(names.contains(&"meetings"));
+        assert!(names.contains(&"transcripts_fts"));
+        assert!(names.contains(&"transcripts_fts_au"));
+        assert!(names.contains(&"summaries_fts_ad"));
+        assert!(names.contains(&"meeting_speakers"));
+    }
+}
@@ -2,3 +2,5 @@ pub mod types;
 pub mod filesystem;
 pub mod manager;
 pub mod migration;
+pub mod commands;
+pub mod transfer;
@@ -0,0 +1,517 @@
+//! Moving and copying meetings between workspaces.
+//!
+//! Each workspace has its own SQLite database, so a transfer opens the target database,
+//! attaches the source database and copies every meeting-owned row inside one transaction.
+//! Recording folders are relocated into the target workspace's `audio/` directory before
+//! the transaction runs and are put back if it fails.
+
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use sqlx::{Connection, SqliteConnection};
+
+use super::filesystem;
+use super::manager::WorkspaceManager;
+
+/// Whether the source meeting is kept after the transfer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferMode {
+    Move,
+    Copy,
+}
+
+/// Tables keyed by `meeting_id` that travel with a meeting, with their remaining columns.
+/// `transcripts` is handled separately because its rows carry their own ids; action items,
+/// decisions, chat messages, summary and transcript versions use autoincrement ids, so copied rows
+/// simply get new ones.
+/// `segment_embeddings` is a derived index and is rebuilt in the target workspace.
+const MEETING_TABLES: &[(&str, &str)] = &[
+    (
+        "summary_processes",
+        "status, created_at, updated_at, error, result, start_time, end_time, chunk_count, \
+         processing_time, metadata",
+    ),
+    (
+        "transcript_chunks",
+        "meeting_name, transcript_text, model, model_name, chunk_size, overlap, created_at",
+    ),
+    ("meeting_notes", "notes_markdown, notes_json, created_at, updated_at"),
+    (
+        "meeting_speakers",
+        "label, display_name, segment_count, total_duration, created_at, updated_at",
+    ),
+    (
+        "action_items",
+        "text, owner, due_date, source_timestamp, status, created_at, updated_at, completed_at",
+    ),
+    ("decisions", "text, source_timestamp, created_at"),
+    ("meeting_chat_messages", "role, content, citations_json, created_at"),
+    (
+        "summary_versions",
+        "version, source, result, provider, model, template_id, prompt, restored_from, pinned, \
+         created_at",
+    ),
+    (
+        "transcript_versions",
+        "version, reason, replaced_by_provider, replaced_by_model, segment_count, segments, \
+         created_at",
+    ),
+];
+
+const TRANSCRIPT_COLUMNS: &str = "transcript, timestamp, summary, action_items, key_points, \
+     audio_start_time, audio_end_time, duration, speaker, speaker_label, confidence, low_confidence";
+
+/// Columns of `transcript_words` besides `transcript_id`; words follow their segment
+const TRANSCRIPT_WORD_COLUMNS: &str = "word_index, word, start_time, end_time, probability";
+
+/// Where a meeting ended up in the target workspace
+#[derive(Debug, Clone, Serialize)]
+pub struct TransferredMeeting {
+    pub source_meeting_id: String,
+    pub meeting_id: String,
+    pub folder_path: Option<String>,
+}
+
+/// A meeting to transfer, resolved against both workspaces
+struct PlannedMeeting {
+    source_id: String,
+    target_id: String,
+    /// Transcript ids must be regenerated when the meeting gets a new id
+    new_ids: bool,
+    folder_path: Option<String>,
+}
+
+/// A relocated recording folder, kept so it can be undone
+struct FolderTransfer {
+    from: PathBuf,
+    to: PathBuf,
+    renamed: bool,
+}
+
+/// Ids of every meeting in a workspace
+pub async fn list_meeting_ids(workspace_dir: &Path) -> Result<Vec<String>, String> {
+    let pool = WorkspaceManager::open_workspace_pool(workspace_dir, 1).await?;
+    let ids = sqlx::query_scalar::<_, String>("SELECT id FROM meetings ORDER BY created_at")
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| format!("Failed to list meetings: {}", e));
+    pool.close().await;
+    ids
+}
+
+/// Transfer meetings and all of their rows and recording folders to another workspace.
+///
+/// Copies always get fresh meeting and transcript ids. Moves keep their ids unless the
+/// target workspace already has a meeting with the same id.
+pub async fn transfer_meetings(
+    source_dir: &Path,
+    target_dir: &Path,
+    meeting_ids: &[String],
+    mode: TransferMode,
+) -> Result<Vec<TransferredMeeting>, String> {
+    if source_dir == target_dir {
+        return Err("Source and target workspace are the same".to_string());
+    }
+    if meeting_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // Opening the source pool also brings its schema in line with the target's
+    let source_pool = WorkspaceManager::open_workspace_pool(source_dir, 1).await?;
+    let mut sources = Vec::with_capacity(meeting_ids.len());
+    for meeting_id in meeting_ids {
+        let folder_path: Option<Option<String>> =
+            sqlx::query_scalar("SELECT folder_path FROM meetings WHERE id = ?")
+                .bind(meeting_id)
+                .fetch_optional(&source_pool)
+                .await
+                .map_err(|e| format!("Failed to read meeting {}: {}", meeting_id, e))?;
+        match folder_path {
+            Some(folder_path) => sources.push((meeting_id.clone(), folder_path)),
+            None => {
+                source_pool.close().await;
+                return Err(format!("Meeting not found: {}", meeting_id));
+            }
+        }
+    }
+    source_pool.close().await;
+
+    let target_pool = WorkspaceManager::open_workspace_pool(target_dir, 1).await?;
+    let mut conn = target_pool
+        .acquire()
+        .await
+        .map_err(|e| format!("Failed to connect to target workspace: {}", e))?;
+
+    let mut plan = Vec::with_capacity(sources.len());
+    for (source_id, folder_path) in sources {
+        let exists: bool = sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM meetings WHERE id = ?)")
+            .bind(&source_id)
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(|e| e.to_string())?;
+        let new_ids = mode == TransferMode::Copy || exists;
+        let target_id = if new_ids {
+            format!("meeting-{}", uuid::Uuid::new_v4())
+        } else {
+            source_id.clone()
+        };
+        plan.push(PlannedMeeting {
+            source_id,
+            target_id,
+            new_ids,
+            folder_path,
+        });
+    }
+
+    let folders = match relocate_folders(&mut plan, target_dir, mode) {
+        Ok(folders) => folders,
+        Err(e) => {
+            drop(conn);
+            target_pool.close().await;
+            return Err(e);
+        }
+    };
+
+    let source_db = source_dir.join("db.sqlite");
+    let result = copy_rows(&mut conn, &source_db, &plan, mode).await;
+    drop(conn);
+    target_pool.close().await;
+
+    if let Err(e) = result {
+        undo_folders(&folders);
+        return Err(format!("Failed to transfer meetings: {}", e));
+    }
+
+    // The rows are committed; a moved folder that had to be copied can now go
+    if mode == TransferMode::Move {
+        for folder in folders.iter().filter(|f| !f.renamed) {
+            if let Err(e) = std::fs::remove_dir_all(&folder.from) {
+                log::warn!("Failed to remove moved recording folder {:?}: {}", folder.from, e);
+            }
+        }
+    }
+
+    log::info!(
+        "{} {} meetings from {:?} to {:?}",
+        if mode == TransferMode::Move { "Moved" } else { "Copied" },
+        plan.len(),
+        source_dir,
+        target_dir
+    );
+
+    Ok(plan
+        .into_iter()
+        .map(|m| TransferredMeeting {
+            source_meeting_id: m.source_id,
+            meeting_id: m.target_id,
+            folder_path: m.folder_path,
+        })
+        .collect())
+}
+
+/// Insert the planned meetings into the target database (and delete them from the
+/// attached source database when moving) in one transaction.
+async fn copy_rows(
+    conn: &mut SqliteConnection,
+    source_db: &Path,
+    plan: &[PlannedMeeting],
+    mode: TransferMode,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("ATTACH DATABASE ? AS src")
+        .bind(source_db.to_string_lossy().to_string())
+        .execute(&mut *conn)
+        .await?;
+
+    let result = async {
+        let mut tx = conn.begin().await?;
+        for meeting in plan {
+            copy_meeting(&mut tx, meeting).await?;
+            if mode == TransferMode::Move {
+                delete_source_meeting(&mut tx, &meeting.source_id).await?;
+            }
+        }
+        tx.commit().await
+    }
+    .await;
+
+    if let Err(e) = sqlx::query("DETACH DATABASE src").execute(&mut *conn).await {
+        log::warn!("Failed to detach source workspace database: {}", e);
+    }
+    result
+}
+
+async fn copy_meeting(conn: &mut SqliteConnection, meeting: &PlannedMeeting) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO main.meetings (id, title, created_at, updated_at, folder_path)
+         SELECT ?, title, created_at, updated_at, ? FROM src.meetings WHERE id = ?",
+    )
+    .bind(&meeting.target_id)
+    .bind(&meeting.folder_path)
+    .bind(&meeting.source_id)
+    .execute(&mut *conn)
+    .await?;
+
+    if meeting.new_ids {
+        let transcript_ids: Vec<String> =
+            sqlx::query_scalar("SELECT id FROM src.transcripts WHERE meeting_id = ?")
+                .bind(&meeting.source_id)
+                .fetch_all(&mut *conn)
+                .await?;
+        let sql = format!(
+            "INSERT INTO main.transcripts (id, meeting_id, {cols}) SELECT ?, ?, {cols} FROM src.transcripts WHERE id = ?",
+            cols = TRANSCRIPT_COLUMNS
+        );
+        let words_sql = format!(
+            "INSERT INTO main.transcript_words (transcript_id, {cols}) SELECT ?, {cols} FROM src.transcript_words WHERE transcript_id = ?",
+            cols = TRANSCRIPT_WORD_COLUMNS
+        );
+        for transcript_id in transcript_ids {
+            let new_id = format!("transcript-{}", uuid::Uuid::new_v4());
+            sqlx::query(&sql)
+                .bind(&new_id)
+                .bind(&meeting.target_id)
+                .bind(&transcript_id)
+                .execute(&mut *conn)
+                .await?;
+            sqlx::query(&words_sql)
+                .bind(&new_id)
+                .bind(&transcript_id)
+                .execute(&mut *conn)
+                .await?;
+        }
+    } else {
+        let sql = format!(
+            "INSERT INTO main.transcripts (id, meeting_id, {cols}) SELECT id, ?, {cols} FROM src.transcripts WHERE meeting_id = ?",
+            cols = TRANSCRIPT_COLUMNS
+        );
+        sqlx::query(&sql)
+            .bind(&meeting.target_id)
+            .bind(&meeting.source_id)
+            .execute(&mut *conn)
+            .await?;
+        let words_sql = format!(
+            "INSERT INTO main.transcript_words (transcript_id, {cols}) SELECT w.transcript_id, {cols} FROM src.transcript_words w \
+             JOIN src.transcripts t ON t.id = w.transcript_id WHERE t.meeting_id = ?",
+            cols = TRANSCRIPT_WORD_COLUMNS
+        );
+        sqlx::query(&words_sql)
+            .bind(&meeting.source_id)
+            .execute(&mut *conn)
+            .await?;
+    }
+
+    for (table, columns) in MEETING_TABLES {
+        let sql = format!(
+            "INSERT INTO main.{table} (meeting_id, {columns}) SELECT ?, {columns} FROM src.{table} WHERE meeting_id = ?"
+        );
+        sqlx::query(&sql)
+            .bind(&meeting.target_id)
+            .bind(&meeting.source_id)
+            .execute(&mut *conn)
+            .await?;
+    }
+    Ok(())
+}
+
+async fn delete_source_meeting(conn: &mut SqliteConnection, meeting_id: &str) -> Result<(), sqlx::Error> {
+    for table in MEETING_TABLES.iter().map(|(table, _)| *table).chain(["transcripts"]) {
+        sqlx::query(&format!("DELETE FROM src.{table} WHERE meeting_id = ?"))
+            .bind(meeting_id)
+            .execute(&mut *conn)
+            .await?;
+    }
+    sqlx::query("DELETE FROM src.meetings WHERE id = ?")
+        .bind(meeting_id)
+        .execute(&mut *conn)
+        .await?;
+    Ok(())
+}
+
+/// Relocate each meeting's recording folder into `{target}/audio/` and point the plan at
+/// the new location. Meetings without a folder on disk keep their `folder_path`.
+fn relocate_folders(
+    plan: &mut [PlannedMeeting],
+    target_dir: &Path,
+    mode: TransferMode,
+) -> Result<Vec<FolderTransfer>, String> {
+    let audio_dir = target_dir.join("audio");
+    std::fs::create_dir_all(&audio_dir)
+        .map_err(|e| format!("Failed to create workspace audio dir: {}", e))?;
+
+    let mut done = Vec::new();
+    for meeting in plan.iter_mut() {
+        let Some(from) = meeting.folder_path.as_deref().map(PathBuf::from) else {
+            continue;
+        };
+        if !from.is_dir() {
+            log::warn!("Recording folder for meeting {} not found: {:?}", meeting.source_id, from);
+            continue;
+        }
+
+        let name = from
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| meeting.target_id.clone());
+        let to = unique_path(&audio_dir, &name);
+
+        let renamed = mode == TransferMode::Move && std::fs::rename(&from, &to).is_ok();
+        if !renamed {
+            if let Err(e) = filesystem::copy_dir_recursive(&from, &to) {
+                let _ = std::fs::remove_dir_all(&to);
+                undo_folders(&done);
+                return Err(e);
+            }
+        }
+
+        meeting.folder_path = Some(to.to_string_lossy().to_string());
+        done.push(FolderTransfer { from, to, renamed });
+    }
+    Ok(done)
+}
+
+/// Put relocated folders back after a failed transfer
+fn undo_folders(folders: &[FolderTransfer]) {
+    for folder in folders {
+        let result = if folder.renamed {
+            std::fs::rename(&folder.to, &folder.from)
+        } else {
+            std::fs::remove_dir_all(&folder.to)
+        };
+        if let Err(e) = result {
+            log::error!("Failed to roll back recording folder {:?}: {}", folder.to, e);
+        }
+    }
+}
+
+/// `dir/name`, or `dir/name-N` if that already exists
+fn unique_path(dir: &Path, name: &str) -> PathBuf {
+    let mut path = dir.join(name);
+    let mut n = 1;
+    while path.exists() {
+        path = dir.join(format!("{}-{}", name, n));
+        n += 1;
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::SqlitePool;
+
+    async fn seed_meeting(pool: &SqlitePool, id: &str, folder: &Path) {
+        std::fs::create_dir_all(folder).unwrap();
+        std::fs::write(folder.join("audio.mp4"), b"audio").unwrap();
+        sqlx::query("INSERT INTO meetings (id, title, created_at, updated_at, folder_path) VALUES (?, 'Client sync', datetime('now'), datetime('now'), ?)")
+            .bind(id)
+            .bind(folder.to_string_lossy().to_string())
+            .execute(pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO transcripts (id, meeting_id, transcript, timestamp, speaker_label) VALUES (?, ?, 'hello pricing', '00:00:01', 'Speaker 1')")
+            .bind(format!("transcript-{}", id))
+            .bind(id)
+            .execute(pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO summary_processes (meeting_id, status, created_at, updated_at, result) VALUES (?, 'completed', datetime('now'), datetime('now'), '{\"markdown\":\"# Summary\"}')")
+            .bind(id)
+            .execute(pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO meeting_notes (meeting_id, notes_markdown, created_at, updated_at) VALUES (?, 'notes', datetime('now'), datetime('now'))")
+            .bind(id)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    async fn count(pool: &SqlitePool, table: &str, meeting_id: &str) -> i64 {
+        sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {table} WHERE meeting_id = ?"))
+            .bind(meeting_id)
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_move_and_copy_meetings() {
+        let dir = tempfile::tempdir().unwrap();
+        let manager = WorkspaceManager::init(dir.path().to_path_buf()).await.unwrap();
+        let source = manager.create_workspace("Client A".to_string()).await.unwrap();
+        let target = manager.create_workspace("Client B".to_string()).await.unwrap();
+        manager.switch_workspace(&source).await.unwrap();
+
+        let recordings = dir.path().join("recordings");
+        let pool = manager.active_pool().await.unwrap();
+        seed_meeting(&pool, "m1", &recordings.join("Client sync")).await;
+        seed_meeting(&pool, "m2", &recordings.join("Other")).await;
+
+        let copied = manager
+            .copy_meetings(&source, &target, &["m2".to_string()])
+            .await
+            .unwrap();
+        assert_ne!(copied[0].meeting_id, "m2");
+        assert!(recordings.join("Other").join("audio.mp4").exists());
+
+        let moved = manager
+            .move_meetings(&source, &target, &["m1".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(moved[0].meeting_id, "m1");
+        let new_folder = PathBuf::from(moved[0].folder_path.clone().unwrap());
+        assert!(new_folder.starts_with(manager.workspaces_root().join(&target).join("audio")));
+        assert!(new_folder.join("audio.mp4").exists());
+        assert!(!recordings.join("Client sync").exists());
+
+        assert_eq!(count(&pool, "transcripts", "m1").await, 0);
+        assert_eq!(count(&pool, "transcripts", "m2").await, 1);
+
+        manager.switch_workspace(&target).await.unwrap();
+        let pool = manager.active_pool().await.unwrap();
+        for table in ["transcripts", "summary_processes", "meeting_notes"] {
+            assert_eq!(count(&pool, table, "m1").await, 1, "{table}");
+            assert_eq!(count(&pool, table, &copied[0].meeting_id).await, 1, "{table}");
+        }
+        let hits: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM transcripts_fts WHERE transcripts_fts MATCH 'pricing'")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(hits, 2);
+
+        assert!(manager
+            .move_meetings(&target, &source, &["missing".to_string()])
+            .await
+            .is_err());
+        manager.close_active_workspace().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_merge_workspace() {
+        let dir = tempfile::tempdir().unwrap();
+        let manager = WorkspaceManager::init(dir.path().to_path_buf()).await.unwrap();
+        let source = manager.create_workspace("Old".to_string()).await.unwrap();
+        let target = manager.create_workspace("New".to_string()).await.unwrap();
+
+        manager.switch_workspace(&source).await.unwrap();
+        let pool = manager.active_pool().await.unwrap();
+        seed_meeting(&pool, "m1", &dir.path().join("recordings").join("a")).await;
+        drop(pool);
+        assert!(manager.merge_workspace(&source, &target).await.is_err());
+
+        manager.switch_workspace(&target).await.unwrap();
+        let pool = manager.active_pool().await.unwrap();
+        seed_meeting(&pool, "m1", &dir.path().join("recordings").join("b")).await;
+
+        let moved = manager.merge_workspace(&source, &target).await.unwrap();
+        assert_eq!(moved.len(), 1);
+        // Conflicting id gets a fresh one instead of overwriting the target meeting
+        assert_ne!(moved[0].meeting_id, "m1");
+        assert_eq!(count(&pool, "transcripts", &moved[0].meeting_id).await, 1);
+        assert_eq!(count(&pool, "transcripts", "m1").await, 1);
+        assert!(!manager.workspaces_root().join(&source).exists());
+        assert_eq!(manager.list_workspaces().await.len(), 1);
+
+        manager.close_active_workspace().await.unwrap();
+    }
+}
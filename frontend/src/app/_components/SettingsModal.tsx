@@ -105,6 +105,7 @@ export function SettingsModals({
                     >
                       <option value="builtin-ai">Built-in AI</option>
                       <option value="claude">Claude</option>
+                      <option value="gemini">Google Gemini</option>
                       <option value="groq">Groq</option>
                       <option value="ollama">Ollama</option>
                       <option value="openrouter">OpenRouter</option>
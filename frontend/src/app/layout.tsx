@@ -21,6 +21,7 @@ import { OnboardingFlow } from '@/components/onboarding'
 import { DownloadProgressToastProvider } from '@/components/shared/DownloadProgressToast'
 import { UpdateCheckProvider } from '@/components/UpdateCheckProvider'
 import { RecordingPostProcessingProvider } from '@/contexts/RecordingPostProcessingProvider'
+import { SecretVaultPrompt } from '@/components/SecretVaultPrompt'
 
 const sourceSans3 = Source_Sans_3({
   subsets: ['latin'],
@@ -113,6 +114,9 @@ export default function RootLayout({
                             {/* Download progress toast provider - listens for background downloads */}
                             <DownloadProgressToastProvider />
 
+                            {/* Creates or unlocks the API key vault when there is no OS keyring */}
+                            <SecretVaultPrompt />
+
                             {/* Show onboarding or main app */}
                             {showOnboarding ? (
                               <OnboardingFlow onComplete={handleOnboardingComplete} />
@@ -43,6 +43,7 @@ interface SummaryPanelProps {
   summaryError: string | null;
   onRegenerateSummary: () => Promise<void>;
   getSummaryStatusMessage: (status: 'idle' | 'processing' | 'summarizing' | 'regenerating' | 'completed' | 'error') => string;
+  streamingSummary?: string;
   availableTemplates: Array<{ id: string, name: string, description: string }>;
   selectedTemplate: string;
   onTemplateSelect: (templateId: string, templateName: string) => void;
@@ -79,6 +80,7 @@ export function SummaryPanel({
   summaryError,
   onRegenerateSummary,
   getSummaryStatusMessage,
+  streamingSummary,
   availableTemplates,
   selectedTemplate,
   onTemplateSelect,
@@ -268,6 +270,11 @@ export function SummaryPanel({
                 'bg-blue-100 text-blue-700'
               }`}>
               <p className="text-sm font-medium">{getSummaryStatusMessage(summaryStatus)}</p>
+              {isSummaryLoading && streamingSummary && (
+                <pre className="mt-2 max-h-64 overflow-y-auto whitespace-pre-wrap font-sans text-xs text-gray-700">
+                  {streamingSummary}
+                </pre>
+              )}
             </div>
           )}
         </div>
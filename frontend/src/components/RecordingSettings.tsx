@@ -12,6 +12,8 @@ export interface RecordingPreferences {
   file_format: string;
   preferred_mic_device: string | null;
   preferred_system_device: string | null;
+  auto_retranscribe: boolean;
+  retranscribe_model: string | null;
 }
 
 interface RecordingSettingsProps {
@@ -24,7 +26,9 @@ export function RecordingSettings({ onSave }: RecordingSettingsProps) {
     auto_save: true,
     file_format: 'mp4',
     preferred_mic_device: null,
-    preferred_system_device: null
+    preferred_system_device: null,
+    auto_retranscribe: false,
+    retranscribe_model: null
   });
   const [loading, setLoading] = useState(true);
   const [saving, setSaving] = useState(false);
@@ -79,6 +83,12 @@ export function RecordingSettings({ onSave }: RecordingSettingsProps) {
     });
   };
 
+  const handleAutoRetranscribeToggle = async (enabled: boolean) => {
+    const newPreferences = { ...preferences, auto_retranscribe: enabled };
+    setPreferences(newPreferences);
+    await savePreferences(newPreferences);
+  };
+
   const handleDeviceChange = async (devices: SelectedDevices) => {
     const newPreferences = {
       ...preferences,
@@ -213,6 +223,23 @@ export function RecordingSettings({ onSave }: RecordingSettingsProps) {
         </div>
       )}
 
+      {/* Post-meeting re-transcription - needs the saved audio */}
+      {preferences.auto_save && (
+        <div className="flex items-center justify-between p-4 border rounded-lg">
+          <div className="flex-1">
+            <div className="font-medium">High-Accuracy Re-transcription</div>
+            <div className="text-sm text-gray-600">
+              After a meeting is saved, transcribe the recording again with Whisper {preferences.retranscribe_model || 'large-v3'}. The live transcript is kept as an earlier version.
+            </div>
+          </div>
+          <Switch
+            checked={preferences.auto_retranscribe}
+            onCheckedChange={handleAutoRetranscribeToggle}
+            disabled={saving}
+          />
+        </div>
+      )}
+
       {/* Recording Notification Toggle */}
       <div className="flex items-center justify-between p-4 border rounded-lg">
         <div className="flex-1">
@@ -0,0 +1,105 @@
+'use client';
+
+import { useEffect, useState } from 'react';
+import { Loader2, Lock, XCircle } from 'lucide-react';
+import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogDescription } from '@/components/ui/dialog';
+import { configService, SecretStoreStatus } from '@/services/configService';
+
+/**
+ * Asks for the vault passphrase when API keys can't go to the OS keyring.
+ *
+ * On first run this creates the vault, on later runs it unlocks it. Either way the
+ * backend then moves any API keys still stored in plaintext into the vault.
+ */
+export function SecretVaultPrompt() {
+  const [status, setStatus] = useState<SecretStoreStatus | null>(null);
+  const [passphrase, setPassphrase] = useState('');
+  const [confirmation, setConfirmation] = useState('');
+  const [isUnlocking, setIsUnlocking] = useState(false);
+  const [errorMessage, setErrorMessage] = useState('');
+
+  useEffect(() => {
+    configService
+      .getSecretStoreStatus()
+      .then(setStatus)
+      .catch((error) => console.error('[SecretVaultPrompt] Failed to get secret store status:', error));
+  }, []);
+
+  const isOpen = status?.backend === 'vault' && status.locked;
+  const isCreating = !status?.vault_exists;
+
+  const handleSubmit = async (e: React.FormEvent) => {
+    e.preventDefault();
+    if (isCreating && passphrase !== confirmation) {
+      setErrorMessage('Passphrases do not match');
+      return;
+    }
+
+    try {
+      setIsUnlocking(true);
+      setErrorMessage('');
+      setStatus(await configService.unlockSecretVault(passphrase));
+      setPassphrase('');
+      setConfirmation('');
+    } catch (error) {
+      console.error('[SecretVaultPrompt] Failed to unlock vault:', error);
+      setErrorMessage(String(error));
+    } finally {
+      setIsUnlocking(false);
+    }
+  };
+
+  return (
+    <Dialog open={isOpen} onOpenChange={() => {}}>
+      <DialogContent className="sm:max-w-[480px]" onPointerDownOutside={(e) => e.preventDefault()}>
+        <DialogHeader>
+          <DialogTitle className="flex items-center gap-2">
+            <Lock className="h-5 w-5" />
+            {isCreating ? 'Protect your API keys' : 'Unlock your API keys'}
+          </DialogTitle>
+          <DialogDescription className="pt-2">
+            {isCreating
+              ? 'No system keychain is available, so API keys are kept in an encrypted vault. Choose a passphrase (at least 8 characters) to create it.'
+              : 'Enter your vault passphrase to use your saved API keys.'}
+          </DialogDescription>
+        </DialogHeader>
+
+        <form onSubmit={handleSubmit} className="space-y-3">
+          <input
+            type="password"
+            autoFocus
+            value={passphrase}
+            onChange={(e) => setPassphrase(e.target.value)}
+            placeholder="Passphrase"
+            className="w-full px-3 py-2 border border-gray-300 rounded-lg focus:outline-none focus:ring-2 focus:ring-blue-500"
+          />
+          {isCreating && (
+            <input
+              type="password"
+              value={confirmation}
+              onChange={(e) => setConfirmation(e.target.value)}
+              placeholder="Confirm passphrase"
+              className="w-full px-3 py-2 border border-gray-300 rounded-lg focus:outline-none focus:ring-2 focus:ring-blue-500"
+            />
+          )}
+
+          {errorMessage && (
+            <div className="p-3 bg-red-50 border border-red-200 rounded-lg flex items-start gap-2">
+              <XCircle className="h-5 w-5 text-red-600 mt-0.5 flex-shrink-0" />
+              <p className="text-sm text-red-800">{errorMessage}</p>
+            </div>
+          )}
+
+          <button
+            type="submit"
+            disabled={!passphrase || isUnlocking}
+            className="w-full flex items-center justify-center gap-2 px-4 py-2 bg-blue-600 text-white rounded-lg hover:bg-blue-700 disabled:bg-gray-400 disabled:cursor-not-allowed transition-colors"
+          >
+            {isUnlocking && <Loader2 className="h-4 w-4 animate-spin" />}
+            <span>{isCreating ? 'Create vault' : 'Unlock'}</span>
+          </button>
+        </form>
+      </DialogContent>
+    </Dialog>
+  );
+}
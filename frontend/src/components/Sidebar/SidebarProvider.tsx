@@ -25,6 +25,9 @@ interface TranscriptSearchResult {
   title: string;
   matchContext: string;
   timestamp: string;
+  source: 'transcript' | 'summary';
+  transcriptId?: string;
+  audio_start_time?: number;
 };
 
 interface SidebarContextType {
@@ -37,6 +37,18 @@ interface SidebarItem {
   children?: SidebarItem[];
 }
 
+// Search snippets wrap matched terms in <mark>...</mark>; render them without injecting HTML
+const renderMatchContext = (context: string) =>
+  context.split(/(<mark>.*?<\/mark>)/g).map((part, index) =>
+    part.startsWith('<mark>') && part.endsWith('</mark>') ? (
+      <mark key={index} className="bg-yellow-200 text-gray-900 rounded-sm">
+        {part.slice('<mark>'.length, -'</mark>'.length)}
+      </mark>
+    ) : (
+      <React.Fragment key={index}>{part}</React.Fragment>
+    )
+  );
+
 const Sidebar: React.FC = () => {
   const router = useRouter();
   const pathname = usePathname();
@@ -211,7 +223,8 @@ const Sidebar: React.FC = () => {
       const payload = {
         provider: configToSave.provider,
         model: configToSave.model,
-        apiKey: configToSave.apiKey ?? null
+        apiKey: configToSave.apiKey ?? null,
+        endpoint: configToSave.endpoint ?? null
       };
       console.log('Saving transcript config with payload:', payload);
 
@@ -219,6 +232,7 @@ const Sidebar: React.FC = () => {
         provider: payload.provider,
         model: payload.model,
         apiKey: payload.apiKey,
+        endpoint: payload.endpoint,
       });
 
 
@@ -625,7 +639,7 @@ const Sidebar: React.FC = () => {
               {/* Show transcript match snippet if available */}
               {hasTranscriptMatch && (
                 <div className="mt-1 ml-8 text-xs text-gray-500 bg-yellow-50 p-1.5 rounded border border-yellow-100 line-clamp-2">
-                  <span className="font-medium text-yellow-600">Match:</span> {matchingResult.matchContext}
+                  <span className="font-medium text-yellow-600">Match:</span> {renderMatchContext(matchingResult.matchContext)}
                 </div>
               )}
             </div>
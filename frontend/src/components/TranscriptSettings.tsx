@@ -2,17 +2,21 @@ import { useState, useEffect } from 'react';
 import { invoke } from '@tauri-apps/api/core';
 import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from './ui/select';
 import { Input } from './ui/input';
+import { Textarea } from './ui/textarea';
 import { Button } from './ui/button';
 import { Label } from './ui/label';
 import { Eye, EyeOff, Lock, Unlock } from 'lucide-react';
 import { ModelManager } from './WhisperModelManager';
 import { ParakeetModelManager } from './ParakeetModelManager';
+import { toast } from 'sonner';
 
 
 export interface TranscriptModelProps {
-    provider: 'localWhisper' | 'parakeet' | 'deepgram' | 'elevenLabs' | 'groq' | 'openai';
+    provider: 'localWhisper' | 'parakeet' | 'deepgram' | 'elevenLabs' | 'groq' | 'openai' | 'whisperServer';
     model: string;
     apiKey?: string | null;
+    // Endpoint URL for remote providers; empty uses the provider's default
+    endpoint?: string | null;
 }
 
 export interface TranscriptSettingsProps {
@@ -28,6 +32,11 @@ export function TranscriptSettings({ transcriptModelConfig, setTranscriptModelCo
     const [isLockButtonVibrating, setIsLockButtonVibrating] = useState<boolean>(false);
     const [selectedWhisperModel, setSelectedWhisperModel] = useState<string>(transcriptModelConfig.provider === 'localWhisper' ? transcriptModelConfig.model : 'small');
     const [selectedParakeetModel, setSelectedParakeetModel] = useState<string>(transcriptModelConfig.provider === 'parakeet' ? transcriptModelConfig.model : 'parakeet-tdt-0.6b-v3-int8');
+    const [endpoint, setEndpoint] = useState<string>(transcriptModelConfig.endpoint || '');
+    const [isSaving, setIsSaving] = useState<boolean>(false);
+    // Custom vocabulary of the active workspace, one term per line
+    const [vocabulary, setVocabulary] = useState<string>('');
+    const [isSavingVocabulary, setIsSavingVocabulary] = useState<boolean>(false);
 
     useEffect(() => {
         if (transcriptModelConfig.provider === 'localWhisper' || transcriptModelConfig.provider === 'parakeet') {
@@ -35,6 +44,16 @@ export function TranscriptSettings({ transcriptModelConfig, setTranscriptModelCo
         }
     }, [transcriptModelConfig.provider]);
 
+    useEffect(() => {
+        setEndpoint(transcriptModelConfig.endpoint || '');
+    }, [transcriptModelConfig.endpoint]);
+
+    useEffect(() => {
+        invoke<string[]>('api_get_transcription_vocabulary')
+            .then((terms) => setVocabulary(terms.join('\n')))
+            .catch((err) => console.error('Failed to load vocabulary:', err));
+    }, []);
+
     const fetchApiKey = async (provider: string) => {
         try {
 
@@ -49,12 +68,62 @@ export function TranscriptSettings({ transcriptModelConfig, setTranscriptModelCo
     const modelOptions = {
         localWhisper: [selectedWhisperModel],
         parakeet: [selectedParakeetModel],
-        deepgram: ['nova-2-phonecall'],
+        deepgram: ['nova-3', 'nova-2'],
         elevenLabs: ['eleven_multilingual_v2'],
-        groq: ['llama-3.3-70b-versatile'],
-        openai: ['gpt-4o'],
+        groq: ['whisper-large-v3-turbo', 'whisper-large-v3'],
+        openai: ['gpt-4o-transcribe', 'gpt-4o-mini-transcribe', 'whisper-1'],
+        whisperServer: ['whisper-1'],
     };
     const requiresApiKey = transcriptModelConfig.provider === 'deepgram' || transcriptModelConfig.provider === 'elevenLabs' || transcriptModelConfig.provider === 'openai' || transcriptModelConfig.provider === 'groq';
+    const isRemoteProvider = requiresApiKey || transcriptModelConfig.provider === 'whisperServer';
+    const endpointPlaceholder = transcriptModelConfig.provider === 'whisperServer'
+        ? 'http://localhost:8178/inference'
+        : 'Leave empty to use the default endpoint';
+
+    const saveRemoteConfig = async () => {
+        if (transcriptModelConfig.provider === 'whisperServer' && !endpoint.trim()) {
+            toast.error('Enter the URL of your Whisper server');
+            return;
+        }
+        setIsSaving(true);
+        try {
+            const config = { ...transcriptModelConfig, apiKey, endpoint: endpoint.trim() || null };
+            await invoke('api_save_transcript_config', {
+                provider: config.provider,
+                model: config.model,
+                apiKey: config.apiKey ?? null,
+                endpoint: config.endpoint,
+            });
+            setTranscriptModelConfig(config);
+            setIsApiKeyLocked(true);
+            toast.success('Transcription settings saved');
+        } catch (error) {
+            console.error('Failed to save transcript config:', error);
+            toast.error('Failed to save transcription settings', {
+                description: String(error),
+            });
+        } finally {
+            setIsSaving(false);
+        }
+    };
+
+    const saveVocabulary = async () => {
+        setIsSavingVocabulary(true);
+        try {
+            const terms = await invoke<string[]>('api_save_transcription_vocabulary', {
+                terms: vocabulary.split('\n'),
+            });
+            setVocabulary(terms.join('\n'));
+            toast.success('Vocabulary saved');
+        } catch (error) {
+            console.error('Failed to save vocabulary:', error);
+            toast.error('Failed to save vocabulary', {
+                description: String(error),
+            });
+        } finally {
+            setIsSavingVocabulary(false);
+        }
+    };
 
     const handleInputClick = () => {
         if (isApiKeyLocked) {
@@ -108,7 +177,7 @@ export function TranscriptSettings({ transcriptModelConfig, setTranscriptModelCo
                                 onValueChange={(value) => {
                                     const provider = value as TranscriptModelProps['provider'];
                                     const newModel = provider === 'localWhisper' ? selectedWhisperModel : modelOptions[provider][0];
-                                    setTranscriptModelConfig({ ...transcriptModelConfig, provider, model: newModel });
+                                    setTranscriptModelConfig({ ...transcriptModelConfig, provider, model: newModel, endpoint: null });
                                     if (provider !== 'localWhisper') {
                                         fetchApiKey(provider);
                                     }
@@ -120,10 +189,11 @@ export function TranscriptSettings({ transcriptModelConfig, setTranscriptModelCo
                                 <SelectContent>
                                     <SelectItem value="parakeet">⚡ Parakeet (Recommended - Real-time / Accurate)</SelectItem>
                                     <SelectItem value="localWhisper">🏠 Local Whisper (High Accuracy)</SelectItem>
-                                    {/* <SelectItem value="deepgram">☁️ Deepgram (Backup)</SelectItem>
-                                    <SelectItem value="elevenLabs">☁️ ElevenLabs</SelectItem>
+                                    <SelectItem value="whisperServer">🖥️ Whisper Server (Self-hosted)</SelectItem>
+                                    <SelectItem value="deepgram">☁️ Deepgram</SelectItem>
+                                    {/* <SelectItem value="elevenLabs">☁️ ElevenLabs</SelectItem> */}
                                     <SelectItem value="groq">☁️ Groq</SelectItem>
-                                    <SelectItem value="openai">☁️ OpenAI</SelectItem> */}
+                                    <SelectItem value="openai">☁️ OpenAI</SelectItem>
                                 </SelectContent>
                             </Select>
 
@@ -216,6 +286,52 @@ export function TranscriptSettings({ transcriptModelConfig, setTranscriptModelCo
                             </div>
                         </div>
                     )}
+
+                    {isRemoteProvider && (
+                        <div>
+                            <Label className="block text-sm font-medium text-gray-700 mb-1">
+                                Endpoint URL
+                            </Label>
+                            <div className="mx-1">
+                                <Input
+                                    className="focus:ring-1 focus:ring-blue-500 focus:border-blue-500"
+                                    value={endpoint}
+                                    onChange={(e) => setEndpoint(e.target.value)}
+                                    placeholder={endpointPlaceholder}
+                                />
+                                <p className="text-xs text-gray-500 mt-1">
+                                    Speech segments are uploaded to this service for transcription.
+                                </p>
+                            </div>
+                            <div className="flex justify-end mt-4 mx-1">
+                                <Button onClick={saveRemoteConfig} disabled={isSaving}>
+                                    {isSaving ? 'Saving...' : 'Save'}
+                                </Button>
+                            </div>
+                        </div>
+                    )}
+
+                    <div>
+                        <Label className="block text-sm font-medium text-gray-700 mb-1">
+                            Custom Vocabulary
+                        </Label>
+                        <div className="mx-1">
+                            <Textarea
+                                className="min-h-[120px] focus:ring-1 focus:ring-blue-500 focus:border-blue-500"
+                                value={vocabulary}
+                                onChange={(e) => setVocabulary(e.target.value)}
+                                placeholder={'One term per line, e.g.\nKubernetes\nAcme Corp'}
+                            />
+                            <p className="text-xs text-gray-500 mt-1">
+                                Names, products and acronyms to spell as written. Applies to meetings recorded in this workspace.
+                            </p>
+                        </div>
+                        <div className="flex justify-end mt-4 mx-1">
+                            <Button onClick={saveVocabulary} disabled={isSavingVocabulary}>
+                                {isSavingVocabulary ? 'Saving...' : 'Save Vocabulary'}
+                            </Button>
+                        </div>
+                    </div>
                 </div>
             </div>
         </div>
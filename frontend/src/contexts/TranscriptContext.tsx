@@ -311,10 +311,13 @@ export function TranscriptProvider({ children }: { children: ReactNode }) {
             chunk_start_time: update.chunk_start_time,
             is_partial: update.is_partial,
             confidence: update.confidence,
+            low_confidence: update.low_confidence,
             // NEW: Recording-relative timestamps for playback sync
             audio_start_time: update.audio_start_time,
             audio_end_time: update.audio_end_time,
             duration: update.duration,
+            speaker: update.speaker,
+            words: update.words,
           };
 
           // Add to buffer
@@ -380,9 +383,11 @@ export function TranscriptProvider({ children }: { children: ReactNode }) {
             chunk_start_time: segment.audio_start_time,
             is_partial: false, // History segments are always final
             confidence: segment.confidence,
+            low_confidence: segment.low_confidence,
             audio_start_time: segment.audio_start_time,
             audio_end_time: segment.audio_end_time,
             duration: segment.duration,
+            speaker: segment.speaker,
           }));
 
           setTranscripts(formattedTranscripts);
@@ -421,9 +426,12 @@ export function TranscriptProvider({ children }: { children: ReactNode }) {
       chunk_start_time: update.chunk_start_time,
       is_partial: update.is_partial,
       confidence: update.confidence,
+      low_confidence: update.low_confidence,
       audio_start_time: update.audio_start_time,
       audio_end_time: update.audio_end_time,
       duration: update.duration,
+      speaker: update.speaker,
+      words: update.words,
     };
 
     setTranscripts(prev => {
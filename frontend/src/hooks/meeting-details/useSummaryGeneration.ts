@@ -1,8 +1,9 @@
-import { useState, useCallback } from 'react';
+import { useState, useCallback, useEffect, useRef } from 'react';
 import { Transcript, Summary } from '@/types';
 import { ModelConfig } from '@/components/ModelSettingsModal';
 import { CurrentMeeting, useSidebar } from '@/components/Sidebar/SidebarProvider';
 import { invoke as invokeTauri } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
 import { toast } from 'sonner';
 import Analytics from '@/lib/analytics';
 import { isOllamaNotInstalledError } from '@/lib/utils';
@@ -10,6 +11,17 @@ import { BuiltInModelInfo } from '@/lib/builtin-ai';
 
 type SummaryStatus = 'idle' | 'processing' | 'summarizing' | 'regenerating' | 'completed' | 'error';
 
+/** Payload of the backend `summary-progress` event (one streamed piece of LLM output) */
+export interface SummaryProgressEvent {
+  meetingId: string;
+  /** Position in the fallback chain (1 = the selected model) */
+  attempt: number;
+  stage: 'chunk' | 'combine' | 'final';
+  chunk: number | null;
+  totalChunks: number;
+  delta: string;
+}
+
 interface UseSummaryGenerationProps {
   meeting: any;
   transcripts: Transcript[];
@@ -39,6 +51,37 @@ export function useSummaryGeneration({
 
   const { startSummaryPolling, stopSummaryPolling } = useSidebar();
 
+  // Text of the LLM pass currently streaming, shown while the summary is generated
+  const [streamingSummary, setStreamingSummary] = useState('');
+  const streamingPassRef = useRef<string | null>(null);
+
+  useEffect(() => {
+    const unlistenPromise = listen<SummaryProgressEvent>('summary-progress', (event) => {
+      const progress = event.payload;
+      if (progress.meetingId !== meeting?.id) return;
+
+      // Each pass (chunk, combine, final) and each fallback model starts a fresh preview
+      const pass = `${progress.attempt}-${progress.stage}-${progress.chunk ?? ''}`;
+      if (streamingPassRef.current !== pass) {
+        streamingPassRef.current = pass;
+        setStreamingSummary(progress.delta);
+      } else {
+        setStreamingSummary((text) => text + progress.delta);
+      }
+    });
+
+    return () => {
+      unlistenPromise.then((unlisten) => unlisten());
+    };
+  }, [meeting?.id]);
+
+  useEffect(() => {
+    if (summaryStatus === 'completed' || summaryStatus === 'error' || summaryStatus === 'idle') {
+      streamingPassRef.current = null;
+      setStreamingSummary('');
+    }
+  }, [summaryStatus]);
+
   // Helper to get status message
   const getSummaryStatusMessage = useCallback((status: SummaryStatus) => {
     switch (status) {
@@ -613,5 +656,6 @@ export function useSummaryGeneration({
     handleRegenerateSummary,
     handleStopGeneration,
     getSummaryStatusMessage,
+    streamingSummary,
   };
 }
@@ -0,0 +1,103 @@
+import { useState, useEffect, useRef, useCallback } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { MeetingNoteEntry, MeetingNotes } from '@/types';
+
+const AUTOSAVE_DELAY_MS = 1500;
+
+interface UseMeetingNotesReturn {
+  notesMarkdown: string;
+  entries: MeetingNoteEntry[];
+  isSaving: boolean;
+  setNotesMarkdown: (value: string) => void;
+  addEntry: (text: string) => void;
+  removeEntry: (id: string) => void;
+}
+
+/**
+ * Custom hook for taking meeting notes with debounced autosave.
+ *
+ * While recording (no meetingId yet) notes are autosaved to the recording folder and
+ * new entries are stamped with the current recording position by the backend.
+ * For saved meetings notes are stored in the meeting_notes table.
+ */
+export function useMeetingNotes(meetingId: string | null, isRecording: boolean): UseMeetingNotesReturn {
+  const [notesMarkdown, setNotesMarkdownState] = useState('');
+  const [entries, setEntries] = useState<MeetingNoteEntry[]>([]);
+  const [isSaving, setIsSaving] = useState(false);
+  const dirty = useRef(false);
+
+  // Load existing notes
+  useEffect(() => {
+    const load = async () => {
+      try {
+        if (meetingId) {
+          const notes = await invoke<MeetingNotes>('api_get_meeting_notes', { meetingId });
+          setNotesMarkdownState(notes.notes_markdown ?? '');
+          setEntries(notes.entries);
+        } else if (isRecording) {
+          const notes = await invoke<{ notes_markdown?: string | null; entries: MeetingNoteEntry[] } | null>(
+            'get_recording_notes'
+          );
+          setNotesMarkdownState(notes?.notes_markdown ?? '');
+          setEntries(notes?.entries ?? []);
+        }
+      } catch (error) {
+        console.error('Failed to load meeting notes:', error);
+      }
+    };
+    load();
+  }, [meetingId, isRecording]);
+
+  // Debounced autosave
+  useEffect(() => {
+    if (!dirty.current || (!meetingId && !isRecording)) return;
+
+    const timer = setTimeout(async () => {
+      dirty.current = false;
+      setIsSaving(true);
+      try {
+        const notesPayload = { notesMarkdown: notesMarkdown || null, entries };
+        if (meetingId) {
+          await invoke<MeetingNotes>('api_save_meeting_notes', { meetingId, ...notesPayload });
+        } else {
+          const stamped = await invoke<MeetingNoteEntry[]>('save_recording_notes', notesPayload);
+          setEntries(current =>
+            current.map(entry => stamped.find(s => s.id === entry.id) ?? entry)
+          );
+        }
+      } catch (error) {
+        console.error('Failed to autosave meeting notes:', error);
+      } finally {
+        setIsSaving(false);
+      }
+    }, AUTOSAVE_DELAY_MS);
+
+    return () => clearTimeout(timer);
+  }, [notesMarkdown, entries, meetingId, isRecording]);
+
+  const setNotesMarkdown = useCallback((value: string) => {
+    dirty.current = true;
+    setNotesMarkdownState(value);
+  }, []);
+
+  const addEntry = useCallback((text: string) => {
+    if (!text.trim()) return;
+    dirty.current = true;
+    setEntries(current => [
+      ...current,
+      {
+        id: crypto.randomUUID(),
+        text: text.trim(),
+        audio_start_time: null,
+        created_at: new Date().toISOString(),
+      },
+    ]);
+  }, []);
+
+  const removeEntry = useCallback((id: string) => {
+    dirty.current = true;
+    setEntries(current => current.filter(entry => entry.id !== id));
+  }, []);
+
+  return { notesMarkdown, entries, isSaving, setNotesMarkdown, addEntry, removeEntry };
+}
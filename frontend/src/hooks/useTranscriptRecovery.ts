@@ -168,9 +168,12 @@ export function useTranscriptRecovery(): UseTranscriptRecoveryReturn {
         chunk_start_time: (t as any).chunk_start_time,
         is_partial: (t as any).is_partial || false,
         confidence: t.confidence,
+        low_confidence: (t as any).low_confidence,
         audio_start_time: (t as any).audio_start_time,
         audio_end_time: (t as any).audio_end_time,
         duration: (t as any).duration,
+        speaker: (t as any).speaker,
+        words: (t as any).words,
       }));
 
       // 6. Save to backend database using existing save utilities
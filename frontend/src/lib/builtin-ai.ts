@@ -8,6 +8,8 @@ export interface BuiltInModelInfo {
   context_size: number;
   description: string;
   gguf_file: string;
+  /** GGUF file placed in the models directory by the user; can't be downloaded */
+  is_custom?: boolean;
 }
 
 export type BuiltInModelStatus =
@@ -0,0 +1,86 @@
+/**
+ * Action Item Service
+ *
+ * Handles action items and decisions extracted from meeting summaries.
+ * Pure 1-to-1 wrapper - no error handling changes, exact same behavior as direct invoke calls.
+ */
+
+import { invoke } from '@tauri-apps/api/core';
+
+export type ActionItemStatus = 'open' | 'done';
+
+export interface ActionItem {
+  id: number;
+  meeting_id: string;
+  meeting_title: string;
+  text: string;
+  owner: string | null;
+  /** YYYY-MM-DD */
+  due_date: string | null;
+  /** Seconds from recording start of the segment the item came from */
+  source_timestamp: number | null;
+  status: ActionItemStatus;
+  created_at: string;
+  updated_at: string;
+  completed_at: string | null;
+}
+
+export interface Decision {
+  id: number;
+  meeting_id: string;
+  meeting_title: string;
+  text: string;
+  source_timestamp: number | null;
+  created_at: string;
+}
+
+export interface ActionItemFilter {
+  status?: ActionItemStatus;
+  /** Case-insensitive substring of the owner */
+  owner?: string;
+  meetingId?: string;
+  /** Only items due on or before this date (YYYY-MM-DD) */
+  dueBefore?: string;
+  /** Case-insensitive substring of the item text */
+  query?: string;
+}
+
+export interface DecisionFilter {
+  meetingId?: string;
+  query?: string;
+}
+
+/**
+ * Action Item Service
+ * Singleton service for listing and completing action items across the active workspace
+ */
+export class ActionItemService {
+  /**
+   * List action items across all meetings of the active workspace
+   * @param filter - Optional filters; open items come first, then by due date
+   */
+  async listActionItems(filter?: ActionItemFilter): Promise<ActionItem[]> {
+    return invoke<ActionItem[]>('api_list_action_items', { filter });
+  }
+
+  /**
+   * Mark an action item done, or reopen it
+   * @param id - Action item id
+   * @param completed - true to mark done, false to reopen
+   * @returns Promise with the updated action item
+   */
+  async completeActionItem(id: number, completed: boolean): Promise<ActionItem> {
+    return invoke<ActionItem>('api_complete_action_item', { id, completed });
+  }
+
+  /**
+   * List decisions across all meetings of the active workspace
+   * @param filter - Optional meeting / text filters
+   */
+  async listDecisions(filter?: DecisionFilter): Promise<Decision[]> {
+    return invoke<Decision[]>('api_list_decisions', { filter });
+  }
+}
+
+// Export singleton instance
+export const actionItemService = new ActionItemService();
@@ -9,7 +9,7 @@ import { invoke } from '@tauri-apps/api/core';
 import { TranscriptModelProps } from '@/components/TranscriptSettings';
 
 export interface ModelConfig {
-  provider: 'ollama' | 'groq' | 'claude' | 'openrouter' | 'openai' | 'builtin-ai' | 'custom-openai';
+  provider: 'ollama' | 'groq' | 'claude' | 'gemini' | 'openrouter' | 'openai' | 'builtin-ai' | 'custom-openai';
   model: string;
   whisperModel: string;
   apiKey?: string | null;
@@ -32,6 +32,18 @@ export interface CustomOpenAIConfig {
   topP: number | null;
 }
 
+export interface RetryPolicy {
+  maxRetries: number;
+  initialBackoffMs: number;
+  maxBackoffMs: number;
+}
+
+export interface SummaryFallbackConfig {
+  retry: RetryPolicy;
+  /** Tried in order when the selected summary model fails */
+  fallbacks: { provider: ModelConfig['provider']; model: string }[];
+}
+
 export interface RecordingPreferences {
   preferred_mic_device: string | null;
   preferred_system_device: string | null;
@@ -41,6 +53,12 @@ export interface LanguagePreference {
   language: string;
 }
 
+export interface SecretStoreStatus {
+  backend: 'keyring' | 'vault';
+  locked: boolean;
+  vault_exists: boolean;
+}
+
 /**
  * Configuration Service
  * Singleton service for managing app configuration
@@ -120,6 +138,56 @@ export class ConfigService {
       model,
     });
   }
+
+  /**
+   * List the models a summary provider offers, using its saved settings
+   * @param provider - Provider id (e.g. "openai", "ollama", "builtin-ai")
+   */
+  async listLLMModels(provider: string): Promise<string[]> {
+    return invoke<string[]>('api_list_llm_models', { provider });
+  }
+
+  /**
+   * Check that a summary provider is reachable with its saved settings
+   * @param provider - Provider id (e.g. "openai", "ollama", "builtin-ai")
+   */
+  async testLLMConnection(provider: string): Promise<{ status: string; message: string }> {
+    return invoke<{ status: string; message: string }>('api_test_llm_connection', { provider });
+  }
+
+  /**
+   * Get the retry policy and fallback models used when summary generation fails
+   */
+  async getSummaryFallbackConfig(): Promise<SummaryFallbackConfig> {
+    return invoke<SummaryFallbackConfig>('api_get_summary_fallback_config');
+  }
+
+  /**
+   * Save the retry policy and the ordered list of fallback models
+   * @param config - SummaryFallbackConfig to save
+   */
+  async saveSummaryFallbackConfig(config: SummaryFallbackConfig): Promise<{ status: string; message: string }> {
+    return invoke<{ status: string; message: string }>('api_save_summary_fallback_config', { config });
+  }
+
+  /**
+   * Get where API keys are stored (OS keyring or encrypted vault) and whether the vault is locked
+   */
+  async getSecretStoreStatus(): Promise<SecretStoreStatus> {
+    return invoke<SecretStoreStatus>('secrets_get_status');
+  }
+
+  /**
+   * Unlock the encrypted API key vault, creating it with this passphrase on first use
+   * @param passphrase - Vault passphrase (at least 8 characters when creating)
+   */
+  async unlockSecretVault(passphrase: string): Promise<SecretStoreStatus> {
+    return invoke<SecretStoreStatus>('secrets_unlock_vault', { passphrase });
+  }
+
+  async lockSecretVault(): Promise<void> {
+    return invoke<void>('secrets_lock_vault');
+  }
 }
 
 // Export singleton instance
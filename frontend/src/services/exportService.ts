@@ -0,0 +1,47 @@
+/**
+ * Export Service
+ *
+ * Handles meeting export (Markdown, SRT, WebVTT, DOCX, JSON archive) and archive import.
+ * Pure 1-to-1 wrapper - no error handling changes, exact same behavior as direct invoke calls.
+ */
+
+import { invoke } from '@tauri-apps/api/core';
+
+export type ExportFormat = 'markdown' | 'srt' | 'vtt' | 'docx' | 'json';
+
+export const EXPORT_FORMAT_EXTENSIONS: Record<ExportFormat, string> = {
+  markdown: 'md',
+  srt: 'srt',
+  vtt: 'vtt',
+  docx: 'docx',
+  json: 'json',
+};
+
+/**
+ * Export Service
+ * Singleton service for exporting meetings and importing meeting archives
+ */
+export class ExportService {
+  /**
+   * Export a meeting of the active workspace
+   * @param meetingId - Meeting to export
+   * @param format - Output format
+   * @param outputPath - Target file, or a directory to name the file after the meeting
+   * @returns Promise with the written file path
+   */
+  async exportMeeting(meetingId: string, format: ExportFormat, outputPath: string): Promise<string> {
+    return invoke<string>('api_export_meeting', { meetingId, format, outputPath });
+  }
+
+  /**
+   * Import a JSON meeting archive into the active workspace
+   * @param filePath - Path of the archive file
+   * @returns Promise with the id of the new meeting
+   */
+  async importMeetingArchive(filePath: string): Promise<string> {
+    return invoke<string>('api_import_meeting_archive', { filePath });
+  }
+}
+
+// Export singleton instance
+export const exportService = new ExportService();
@@ -0,0 +1,80 @@
+/**
+ * Meeting Chat Service
+ *
+ * Handles grounded Q&A about a single meeting's transcript.
+ * Pure 1-to-1 wrapper - no error handling changes, exact same behavior as direct invoke calls.
+ */
+
+import { invoke } from '@tauri-apps/api/core';
+import { listen, UnlistenFn } from '@tauri-apps/api/event';
+
+/** A transcript segment an answer refers to */
+export interface ChatCitation {
+  transcript_id: string;
+  /** Seconds from recording start */
+  audio_start_time: number;
+  audio_end_time: number | null;
+  text: string;
+}
+
+export interface MeetingChatEntry {
+  id: number;
+  role: 'user' | 'assistant';
+  content: string;
+  citations: ChatCitation[];
+  created_at: string;
+}
+
+/** Payload of the backend `meeting-chat-progress` event (one streamed piece of the answer) */
+export interface MeetingChatProgressEvent {
+  meetingId: string;
+  delta: string;
+}
+
+/**
+ * Meeting Chat Service
+ * Singleton service for asking questions about a meeting and reading the chat history
+ */
+export class MeetingChatService {
+  /**
+   * Ask a question about a meeting; the answer streams as `meeting-chat-progress` events
+   * @param meetingId - Meeting to ask about
+   * @param question - The question
+   * @param provider - Optional LLM provider (defaults to the saved summary model)
+   * @param model - Optional model name (defaults to the saved summary model)
+   * @returns Promise with the stored answer and its citations
+   */
+  async askMeeting(
+    meetingId: string,
+    question: string,
+    provider?: string,
+    model?: string
+  ): Promise<MeetingChatEntry> {
+    return invoke<MeetingChatEntry>('api_ask_meeting', { meetingId, question, provider, model });
+  }
+
+  /**
+   * Get the chat history of a meeting, oldest first
+   */
+  async getMeetingChat(meetingId: string): Promise<MeetingChatEntry[]> {
+    return invoke<MeetingChatEntry[]>('api_get_meeting_chat', { meetingId });
+  }
+
+  /**
+   * Delete the chat history of a meeting
+   */
+  async clearMeetingChat(meetingId: string): Promise<void> {
+    return invoke<void>('api_clear_meeting_chat', { meetingId });
+  }
+
+  /**
+   * Listen for streamed answer text
+   * @returns Promise with a function that stops listening
+   */
+  async onProgress(callback: (event: MeetingChatProgressEvent) => void): Promise<UnlistenFn> {
+    return listen<MeetingChatProgressEvent>('meeting-chat-progress', (event) => callback(event.payload));
+  }
+}
+
+// Export singleton instance
+export const meetingChatService = new MeetingChatService();
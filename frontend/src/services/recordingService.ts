@@ -22,6 +22,22 @@ export interface RecordingStoppedPayload {
   meeting_name?: string;
 }
 
+export interface ImportProgress {
+  stage: 'decoding' | 'detecting_speech' | 'transcribing' | 'saving' | 'completed' | 'failed' | 'cancelled';
+  progress: number;
+  message: string;
+  current_segment: number | null;
+  total_segments: number | null;
+}
+
+export interface ImportResult {
+  meeting_id: string;
+  title: string;
+  folder_path: string;
+  segment_count: number;
+  duration_seconds: number;
+}
+
 /**
  * Recording Service
  * Singleton service for managing recording lifecycle operations
@@ -164,6 +180,34 @@ export class RecordingService {
   async onSpeechDetected(callback: () => void): Promise<UnlistenFn> {
     return listen('speech-detected', callback);
   }
+
+  /**
+   * Transcribe an existing audio/video file into a new meeting
+   * @param filePath - Absolute path of the file to import
+   * @param title - Optional meeting title (defaults to the file name)
+   * @returns Promise with the created meeting
+   */
+  async importAudioFile(filePath: string, title?: string): Promise<ImportResult> {
+    return invoke<ImportResult>('import_audio_file', { filePath, title });
+  }
+
+  /**
+   * Cancel the running import after the current segment
+   */
+  async cancelAudioImport(): Promise<void> {
+    return invoke<void>('cancel_audio_import');
+  }
+
+  /**
+   * Listen for audio import progress
+   * @param callback - Function to call with each progress update
+   * @returns Promise that resolves to unlisten function
+   */
+  async onImportProgress(callback: (progress: ImportProgress) => void): Promise<UnlistenFn> {
+    return listen<ImportProgress>('import-progress', (event) => {
+      callback(event.payload);
+    });
+  }
 }
 
 // Export singleton instance
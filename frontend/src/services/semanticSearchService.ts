@@ -0,0 +1,100 @@
+/**
+ * Semantic Search Service
+ *
+ * Handles hybrid (meaning + keyword) search across all meetings of the active workspace.
+ * Pure 1-to-1 wrapper - no error handling changes, exact same behavior as direct invoke calls.
+ */
+
+import { invoke } from '@tauri-apps/api/core';
+import { listen, UnlistenFn } from '@tauri-apps/api/event';
+
+export interface SegmentSearchResult {
+  transcript_id: string;
+  text: string;
+  /** Seconds from recording start */
+  audio_start_time: number | null;
+  audio_end_time: number | null;
+  speaker_label: string | null;
+  score: number;
+}
+
+export interface MeetingSearchResult {
+  meeting_id: string;
+  title: string;
+  created_at: string;
+  score: number;
+  /** Best matching segments, best first */
+  segments: SegmentSearchResult[];
+}
+
+export interface SemanticSearchResponse {
+  meetings: MeetingSearchResult[];
+  /** False when only keyword ranking was used (embedding model not downloaded) */
+  semantic: boolean;
+  indexed_segments: number;
+  total_segments: number;
+}
+
+export interface EmbeddingModelStatus {
+  modelName: string;
+  path: string;
+  downloaded: boolean;
+}
+
+/** Payload of the backend `semantic-index-progress` event */
+export interface SemanticIndexProgressEvent {
+  indexed: number;
+  total: number;
+  status: 'indexing' | 'completed' | 'error';
+  error?: string | null;
+}
+
+/**
+ * Semantic Search Service
+ * Singleton service for the embedding model, the segment index and searching
+ */
+export class SemanticSearchService {
+  /**
+   * Search all meetings by meaning and keywords
+   * @param query - Natural-language query
+   * @param limit - Maximum number of meetings (default 20)
+   * @returns Promise with meetings ranked by their best matching segments
+   */
+  async search(query: string, limit?: number): Promise<SemanticSearchResponse> {
+    return invoke<SemanticSearchResponse>('api_semantic_search', { query, limit });
+  }
+
+  /**
+   * Start embedding segments that are not indexed yet; progress arrives as
+   * `semantic-index-progress` events
+   */
+  async buildIndex(): Promise<void> {
+    return invoke<void>('api_build_semantic_index');
+  }
+
+  /**
+   * Get whether the text embedding model is downloaded
+   */
+  async getModelStatus(): Promise<EmbeddingModelStatus> {
+    return invoke<EmbeddingModelStatus>('semantic_search_get_model_status');
+  }
+
+  /**
+   * Download the text embedding model; progress arrives as
+   * `embedding-model-download-progress` events
+   */
+  async downloadModel(): Promise<void> {
+    return invoke<void>('semantic_search_download_model');
+  }
+
+  /**
+   * Listen for indexing progress
+   * @returns Promise with a function that stops listening
+   */
+  async onIndexProgress(callback: (event: SemanticIndexProgressEvent) => void): Promise<UnlistenFn> {
+    return listen<SemanticIndexProgressEvent>('semantic-index-progress', (event) => callback(event.payload));
+  }
+}
+
+// Export singleton instance
+export const semanticSearchService = new SemanticSearchService();
@@ -0,0 +1,73 @@
+/**
+ * Summary Version Service
+ *
+ * Handles the version history of a meeting's summary (generated, edited and restored versions).
+ * Pure 1-to-1 wrapper - no error handling changes, exact same behavior as direct invoke calls.
+ */
+
+import { invoke } from '@tauri-apps/api/core';
+
+export interface SummaryVersion {
+  id: number;
+  meeting_id: string;
+  /** 1, 2, 3... within the meeting */
+  version: number;
+  source: 'generated' | 'edited' | 'restored';
+  /** Summary JSON, same format as the active summary */
+  result: string;
+  provider: string | null;
+  model: string | null;
+  template_id: string | null;
+  /** User-provided context the summary was generated with */
+  prompt: string | null;
+  /** Version a restored version was copied from */
+  restored_from: number | null;
+  /** A pinned version stays the active summary when a new one is generated */
+  pinned: boolean;
+  created_at: string;
+}
+
+export interface DiffLine {
+  kind: 'equal' | 'added' | 'removed';
+  text: string;
+}
+
+/**
+ * Summary Version Service
+ * Singleton service for listing, comparing, restoring and pinning summary versions
+ */
+export class SummaryVersionService {
+  /**
+   * List every version of a meeting's summary, newest first
+   */
+  async listVersions(meetingId: string): Promise<SummaryVersion[]> {
+    return invoke<SummaryVersion[]>('api_list_summary_versions', { meetingId });
+  }
+
+  /**
+   * Line diff of the markdown of two versions
+   * @param fromVersion - Older version number
+   * @param toVersion - Newer version number
+   */
+  async diffVersions(meetingId: string, fromVersion: number, toVersion: number): Promise<DiffLine[]> {
+    return invoke<DiffLine[]>('api_diff_summary_versions', { meetingId, fromVersion, toVersion });
+  }
+
+  /**
+   * Make an earlier version the active summary again
+   * @returns Promise with the new version holding the restored summary
+   */
+  async restoreVersion(meetingId: string, version: number): Promise<SummaryVersion> {
+    return invoke<SummaryVersion>('api_restore_summary_version', { meetingId, version });
+  }
+
+  /**
+   * Pin a version so regenerating keeps it active, or unpin it
+   */
+  async pinVersion(meetingId: string, version: number, pinned: boolean): Promise<SummaryVersion> {
+    return invoke<SummaryVersion>('api_pin_summary_version', { meetingId, version, pinned });
+  }
+}
+
+// Export singleton instance
+export const summaryVersionService = new SummaryVersionService();
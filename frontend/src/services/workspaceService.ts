@@ -0,0 +1,156 @@
+/**
+ * Workspace Service
+ *
+ * Handles all workspace-related Tauri backend calls and events.
+ * Pure 1-to-1 wrapper - no error handling changes, exact same behavior as direct invoke/listen calls.
+ */
+
+import { invoke } from '@tauri-apps/api/core';
+import { listen, UnlistenFn } from '@tauri-apps/api/event';
+
+export interface WorkspaceEntry {
+  id: string;
+  name: string;
+  icon: string | null;
+}
+
+export interface WorkspaceManifest {
+  version: number;
+  name: string;
+  icon: string | null;
+  accent_color: string | null;
+  description: string | null;
+  app_version: string | null;
+  created_at: string;
+  last_modified: string;
+}
+
+export interface WorkspaceList {
+  workspaces: WorkspaceEntry[];
+  active_workspace_id: string | null;
+}
+
+export interface WorkspaceSwitchedPayload {
+  workspaceId: string;
+  name: string;
+}
+
+export interface TransferredMeeting {
+  source_meeting_id: string;
+  meeting_id: string;
+  folder_path: string | null;
+}
+
+export interface MeetingsTransferredPayload {
+  sourceWorkspaceId: string;
+  targetWorkspaceId: string;
+  meetings: TransferredMeeting[];
+}
+
+export interface WorkspaceAppearance {
+  icon?: string | null;
+  accentColor?: string | null;
+  description?: string | null;
+}
+
+/**
+ * Workspace Service
+ * Singleton service for creating, editing and switching workspaces
+ */
+export class WorkspaceService {
+  /**
+   * List workspaces in sidebar order, with the active workspace id
+   */
+  async listWorkspaces(): Promise<WorkspaceList> {
+    return invoke<WorkspaceList>('workspace_list');
+  }
+
+  /**
+   * Create a workspace (does not switch to it)
+   */
+  async createWorkspace(name: string, appearance: WorkspaceAppearance = {}): Promise<WorkspaceEntry> {
+    return invoke<WorkspaceEntry>('workspace_create', { name, ...appearance });
+  }
+
+  async renameWorkspace(workspaceId: string, name: string): Promise<WorkspaceManifest> {
+    return invoke<WorkspaceManifest>('workspace_rename', { workspaceId, name });
+  }
+
+  async getManifest(workspaceId: string): Promise<WorkspaceManifest> {
+    return invoke<WorkspaceManifest>('workspace_get_manifest', { workspaceId });
+  }
+
+  /**
+   * Replace the icon, accent color (#RRGGBB) and description of a workspace
+   */
+  async updateManifest(workspaceId: string, appearance: WorkspaceAppearance): Promise<WorkspaceManifest> {
+    return invoke<WorkspaceManifest>('workspace_update_manifest', { workspaceId, ...appearance });
+  }
+
+  /**
+   * Persist sidebar order; must include every workspace id exactly once
+   */
+  async reorderWorkspaces(workspaceIds: string[]): Promise<WorkspaceEntry[]> {
+    return invoke<WorkspaceEntry[]>('workspace_reorder', { workspaceIds });
+  }
+
+  /**
+   * Permanently delete a workspace (not allowed for the active one)
+   */
+  async deleteWorkspace(workspaceId: string): Promise<void> {
+    return invoke<void>('workspace_delete', { workspaceId });
+  }
+
+  /**
+   * Switch the active workspace; rejected while recording
+   */
+  async switchWorkspace(workspaceId: string): Promise<WorkspaceSwitchedPayload> {
+    return invoke<WorkspaceSwitchedPayload>('workspace_switch', { workspaceId });
+  }
+
+  /**
+   * Move meetings (with transcripts, summaries, notes and recordings) to another workspace
+   */
+  async moveMeetings(sourceWorkspaceId: string, targetWorkspaceId: string, meetingIds: string[]): Promise<TransferredMeeting[]> {
+    return invoke<TransferredMeeting[]>('workspace_move_meetings', { sourceWorkspaceId, targetWorkspaceId, meetingIds });
+  }
+
+  /**
+   * Copy meetings to another workspace under new ids
+   */
+  async copyMeetings(sourceWorkspaceId: string, targetWorkspaceId: string, meetingIds: string[]): Promise<TransferredMeeting[]> {
+    return invoke<TransferredMeeting[]>('workspace_copy_meetings', { sourceWorkspaceId, targetWorkspaceId, meetingIds });
+  }
+
+  /**
+   * Fold a (non-active) workspace into another and delete it
+   */
+  async mergeWorkspace(sourceWorkspaceId: string, targetWorkspaceId: string): Promise<TransferredMeeting[]> {
+    return invoke<TransferredMeeting[]>('workspace_merge', { sourceWorkspaceId, targetWorkspaceId });
+  }
+
+  // Event Listeners
+
+  /**
+   * Listen for active workspace changes
+   * @param callback - Function to call after the backend switched workspaces
+   * @returns Promise that resolves to unlisten function
+   */
+  async onWorkspaceSwitched(callback: (payload: WorkspaceSwitchedPayload) => void): Promise<UnlistenFn> {
+    return listen<WorkspaceSwitchedPayload>('workspace-switched', (event) => {
+      callback(event.payload);
+    });
+  }
+
+  /**
+   * Listen for meetings moved or copied between workspaces
+   */
+  async onMeetingsTransferred(callback: (payload: MeetingsTransferredPayload) => void): Promise<UnlistenFn> {
+    return listen<MeetingsTransferredPayload>('workspace-meetings-transferred', (event) => {
+      callback(event.payload);
+    });
+  }
+}
+
+// Export singleton instance
+export const workspaceService = new WorkspaceService();
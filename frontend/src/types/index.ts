@@ -4,6 +4,14 @@ export interface Message {
   timestamp: string;
 }
 
+// Timing of one transcribed word, in seconds from recording start
+export interface WordTiming {
+  word: string;
+  start: number;
+  end: number;
+  probability?: number; // 0.0-1.0, absent if the engine doesn't report one
+}
+
 export interface Transcript {
   id: string;
   text: string;
@@ -12,10 +20,14 @@ export interface Transcript {
   chunk_start_time?: number; // Legacy field
   is_partial?: boolean;
   confidence?: number;
+  low_confidence?: boolean;   // Below the confidence threshold, candidate for re-transcription
   // NEW: Recording-relative timestamps for playback sync
   audio_start_time?: number; // Seconds from recording start (e.g., 125.3)
   audio_end_time?: number;   // Seconds from recording start (e.g., 128.6)
   duration?: number;          // Segment duration in seconds (e.g., 3.3)
+  speaker?: string;           // Source stream: 'mic' or 'system'
+  speaker_label?: string;     // Diarized speaker ("Speaker 1".."Speaker N")
+  words?: WordTiming[];       // Word-level timing, when the engine provides it
 }
 
 export interface TranscriptUpdate {
@@ -25,11 +37,38 @@ export interface TranscriptUpdate {
   sequence_id: number;
   chunk_start_time: number; // Legacy field
   is_partial: boolean;
-  confidence: number;
+  confidence?: number;      // 0.0-1.0, absent if the engine doesn't report one
+  low_confidence?: boolean; // Below the confidence threshold, candidate for re-transcription
   // NEW: Recording-relative timestamps for playback sync
   audio_start_time: number; // Seconds from recording start
   audio_end_time: number;   // Seconds from recording start
   duration: number;          // Segment duration in seconds
+  speaker?: string;          // Source stream: 'mic' or 'system'
+  words?: WordTiming[];      // Word-level timing, when the engine provides it
+}
+
+export interface MeetingSpeaker {
+  meeting_id: string;
+  label: string;               // "Speaker 1".."Speaker N"
+  display_name?: string | null; // User-assigned name
+  segment_count: number;
+  total_duration: number;      // Seconds
+  created_at: string;
+  updated_at: string;
+}
+
+export interface MeetingNoteEntry {
+  id: string;
+  text: string;
+  audio_start_time?: number | null; // Seconds from recording start; null for notes added afterwards
+  created_at: string;
+}
+
+export interface MeetingNotes {
+  meeting_id: string;
+  notes_markdown?: string | null;
+  entries: MeetingNoteEntry[];
+  updated_at?: string;
 }
 
 export interface Block {
@@ -0,0 +1,253 @@
+// GGUF header metadata reader
+// Reads only the key/value section at the start of the file, so inspecting a model
+// does not load its weights.
+
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+
+/// Metadata of a GGUF model relevant for running it
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct GgufMetadata {
+    /// `general.architecture`, e.g. "llama", "qwen2", "phi3", "gemma3"
+    pub architecture: Option<String>,
+    /// `general.name`
+    pub name: Option<String>,
+    /// `<arch>.context_length`: the context window the model was trained with
+    pub context_length: Option<u64>,
+    /// `<arch>.block_count`: number of transformer layers
+    pub block_count: Option<u64>,
+    /// `tokenizer.chat_template` (Jinja)
+    pub chat_template: Option<String>,
+}
+
+// GGUF value types
+const TYPE_UINT8: u32 = 0;
+const TYPE_INT8: u32 = 1;
+const TYPE_UINT16: u32 = 2;
+const TYPE_INT16: u32 = 3;
+const TYPE_UINT32: u32 = 4;
+const TYPE_INT32: u32 = 5;
+const TYPE_FLOAT32: u32 = 6;
+const TYPE_BOOL: u32 = 7;
+const TYPE_STRING: u32 = 8;
+const TYPE_ARRAY: u32 = 9;
+const TYPE_UINT64: u32 = 10;
+const TYPE_INT64: u32 = 11;
+const TYPE_FLOAT64: u32 = 12;
+
+/// Longest string value read into memory; chat templates are a few KB
+const MAX_STRING_LEN: u64 = 1024 * 1024;
+
+/// A metadata value, reduced to what the reader needs
+enum Value {
+    Int(u64),
+    Str(String),
+    Other,
+}
+
+/// Read the metadata of the GGUF file at `path`
+pub fn read_metadata(path: &Path) -> Result<GgufMetadata> {
+    let file = File::open(path).with_context(|| format!("unable to open {:?}", path))?;
+    read_from(&mut BufReader::new(file))
+}
+
+fn read_from<R: Read + Seek>(reader: &mut R) -> Result<GgufMetadata> {
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic).context("file too short")?;
+    if &magic != b"GGUF" {
+        bail!("not a GGUF file (magic {:?})", magic);
+    }
+    let version = read_u32(reader)?;
+    if version < 2 {
+        bail!("unsupported GGUF version {}", version);
+    }
+    let _tensor_count = read_u64(reader)?;
+    let kv_count = read_u64(reader)?;
+
+    let mut values = Vec::new();
+    for _ in 0..kv_count {
+        let key = read_string(reader)?;
+        let value_type = read_u32(reader)?;
+        let wanted = key.starts_with("general.")
+            || key == "tokenizer.chat_template"
+            || key.ends_with(".context_length")
+            || key.ends_with(".block_count");
+        let value = if wanted {
+            read_value(reader, value_type)?
+        } else {
+            skip_value(reader, value_type)?;
+            Value::Other
+        };
+        values.push((key, value));
+    }
+
+    let string = |wanted: &str| {
+        values.iter().find_map(|(key, value)| match value {
+            Value::Str(s) if key == wanted => Some(s.clone()),
+            _ => None,
+        })
+    };
+    let architecture = string("general.architecture");
+    let int = |suffix: &str| {
+        let key = format!("{}.{}", architecture.as_deref()?, suffix);
+        values.iter().find_map(|(k, value)| match value {
+            Value::Int(n) if *k == key => Some(*n),
+            _ => None,
+        })
+    };
+
+    Ok(GgufMetadata {
+        name: string("general.name"),
+        context_length: int("context_length"),
+        block_count: int("block_count"),
+        chat_template: string("tokenizer.chat_template"),
+        architecture,
+    })
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> Result<u32> {
+    let mut buf = [0u8; 4];
+    reader.read_exact(&mut buf)?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> Result<u64> {
+    let mut buf = [0u8; 8];
+    reader.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+fn read_string<R: Read + Seek>(reader: &mut R) -> Result<String> {
+    let len = read_u64(reader)?;
+    if len > MAX_STRING_LEN {
+        bail!("string of {} bytes in metadata", len);
+    }
+    let mut buf = vec![0u8; len as usize];
+    reader.read_exact(&mut buf)?;
+    Ok(String::from_utf8_lossy(&buf).into_owned())
+}
+
+/// Size in bytes of a fixed-size value type
+fn scalar_size(value_type: u32) -> Result<i64> {
+    Ok(match value_type {
+        TYPE_UINT8 | TYPE_INT8 | TYPE_BOOL => 1,
+        TYPE_UINT16 | TYPE_INT16 => 2,
+        TYPE_UINT32 | TYPE_INT32 | TYPE_FLOAT32 => 4,
+        TYPE_UINT64 | TYPE_INT64 | TYPE_FLOAT64 => 8,
+        other => bail!("unknown GGUF value type {}", other),
+    })
+}
+
+fn read_value<R: Read + Seek>(reader: &mut R, value_type: u32) -> Result<Value> {
+    Ok(match value_type {
+        TYPE_UINT8 => {
+            let mut buf = [0u8; 1];
+            reader.read_exact(&mut buf)?;
+            Value::Int(buf[0] as u64)
+        }
+        TYPE_UINT16 => {
+            let mut buf = [0u8; 2];
+            reader.read_exact(&mut buf)?;
+            Value::Int(u16::from_le_bytes(buf) as u64)
+        }
+        TYPE_UINT32 | TYPE_INT32 => Value::Int(read_u32(reader)? as u64),
+        TYPE_UINT64 | TYPE_INT64 => Value::Int(read_u64(reader)?),
+        TYPE_STRING => Value::Str(read_string(reader)?),
+        other => {
+            skip_value(reader, other)?;
+            Value::Other
+        }
+    })
+}
+
+fn skip_value<R: Read + Seek>(reader: &mut R, value_type: u32) -> Result<()> {
+    match value_type {
+        TYPE_STRING => {
+            let len = read_u64(reader)?;
+            reader.seek(SeekFrom::Current(len as i64))?;
+        }
+        TYPE_ARRAY => {
+            let element_type = read_u32(reader)?;
+            let count = read_u64(reader)?;
+            if element_type == TYPE_STRING || element_type == TYPE_ARRAY {
+                // Vocabularies are arrays of strings; each element has its own length
+                for _ in 0..count {
+                    skip_value(reader, element_type)?;
+                }
+            } else {
+                reader.seek(SeekFrom::Current(scalar_size(element_type)? * count as i64))?;
+            }
+        }
+        other => {
+            reader.seek(SeekFrom::Current(scalar_size(other)?))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn string(s: &str) -> Vec<u8> {
+        let mut bytes = (s.len() as u64).to_le_bytes().to_vec();
+        bytes.extend_from_slice(s.as_bytes());
+        bytes
+    }
+
+    fn kv(key: &str, value_type: u32, value: Vec<u8>) -> Vec<u8> {
+        let mut bytes = string(key);
+        bytes.extend_from_slice(&value_type.to_le_bytes());
+        bytes.extend(value);
+        bytes
+    }
+
+    #[test]
+    fn test_reads_metadata_and_skips_other_values() {
+        let mut tokens = TYPE_STRING.to_le_bytes().to_vec();
+        tokens.extend_from_slice(&2u64.to_le_bytes());
+        tokens.extend(string("<|im_start|>"));
+        tokens.extend(string("hello"));
+
+        let pairs = [
+            kv("general.architecture", TYPE_STRING, string("qwen2")),
+            kv("general.name", TYPE_STRING, string("Qwen2.5 7B Instruct")),
+            kv("general.file_type", TYPE_UINT32, 15u32.to_le_bytes().to_vec()),
+            kv("qwen2.rope.freq_base", TYPE_FLOAT32, 1e6f32.to_le_bytes().to_vec()),
+            kv("tokenizer.ggml.tokens", TYPE_ARRAY, tokens),
+            kv("qwen2.context_length", TYPE_UINT32, 32768u32.to_le_bytes().to_vec()),
+            kv("qwen2.block_count", TYPE_UINT64, 28u64.to_le_bytes().to_vec()),
+            kv("tokenizer.chat_template", TYPE_STRING, string("{{ '<|im_start|>' }}")),
+        ];
+        let mut file = b"GGUF".to_vec();
+        file.extend_from_slice(&3u32.to_le_bytes());
+        file.extend_from_slice(&0u64.to_le_bytes());
+        file.extend_from_slice(&(pairs.len() as u64).to_le_bytes());
+        for pair in pairs {
+            file.extend(pair);
+        }
+
+        let metadata = read_from(&mut Cursor::new(file)).unwrap();
+        assert_eq!(
+            metadata,
+            GgufMetadata {
+                architecture: Some("qwen2".to_string()),
+                name: Some("Qwen2.5 7B Instruct".to_string()),
+                context_length: Some(32768),
+                block_count: Some(28),
+                chat_template: Some("{{ '<|im_start|>' }}".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_rejects_non_gguf_files() {
+        let err = read_from(&mut Cursor::new(b"ggml\x01\x00\x00\x00".to_vec())).unwrap_err();
+        assert!(err.to_string().contains("not a GGUF file"));
+    }
+}
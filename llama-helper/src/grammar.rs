@@ -0,0 +1,242 @@
+// JSON Schema to GBNF conversion
+// Turns the subset of JSON Schema used by the app (objects with properties, arrays,
+// primitive types, type lists and string enums) into a llama.cpp grammar, so sampling
+// can only produce JSON that matches the schema.
+
+use anyhow::{bail, Result};
+use serde_json::Value;
+
+/// Rules every grammar starts with: whitespace, primitives and untyped JSON values
+const PRIMITIVE_RULES: &str = r#"space ::= | " " | "\n" [ \t]{0,20}
+char ::= [^"\\\x7F\x00-\x1F] | [\\] (["\\bfnrt] | "u" [0-9a-fA-F]{4})
+string ::= "\"" char* "\"" space
+number ::= "-"? ([0-9] | [1-9] [0-9]{0,15}) ("." [0-9]+)? ([eE] [-+]? [0-9]+)? space
+integer ::= "-"? ([0-9] | [1-9] [0-9]{0,15}) space
+boolean ::= ("true" | "false") space
+null ::= "null" space
+value ::= object | array | string | number | boolean | null
+object ::= "{" space (string ":" space value ("," space string ":" space value)*)? "}" space
+array ::= "[" space (value ("," space value)*)? "]" space
+"#;
+
+/// Converts a JSON schema into a GBNF grammar whose start rule is `root`
+///
+/// Properties are written in the order of `required`, followed by the optional ones.
+/// The grammar requires all of them, so optional properties are always present (as null
+/// where their type allows it). Properties outside `properties` are never produced.
+pub fn json_schema_to_grammar(schema: &Value) -> Result<String> {
+    let mut converter = Converter { rules: Vec::new() };
+    let root = converter.visit(schema, "root")?;
+    if root != "root" {
+        converter.rules.insert(0, ("root".to_string(), root));
+    }
+
+    let mut grammar = String::new();
+    for (name, body) in &converter.rules {
+        grammar.push_str(&format!("{} ::= {}\n", name, body));
+    }
+    grammar.push_str(PRIMITIVE_RULES);
+    Ok(grammar)
+}
+
+struct Converter {
+    rules: Vec<(String, String)>,
+}
+
+impl Converter {
+    /// Adds a rule named after `name` and returns the name it got
+    fn add_rule(&mut self, name: &str, body: String) -> String {
+        let mut unique = name.to_string();
+        let mut n = 1;
+        while self.rules.iter().any(|(existing, _)| *existing == unique) {
+            n += 1;
+            unique = format!("{}{}", name, n);
+        }
+        self.rules.push((unique.clone(), body));
+        unique
+    }
+
+    /// Returns a grammar expression matching `schema`, adding the rules it needs
+    fn visit(&mut self, schema: &Value, name: &str) -> Result<String> {
+        if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+            let alternatives = values
+                .iter()
+                .map(|v| Ok(format!("{} space", literal(&serde_json::to_string(v)?))))
+                .collect::<Result<Vec<_>>>()?;
+            if alternatives.is_empty() {
+                bail!("empty enum at {}", name);
+            }
+            return Ok(self.add_rule(name, alternatives.join(" | ")));
+        }
+
+        match schema.get("type") {
+            Some(Value::String(t)) => self.visit_type(schema, t, name),
+            Some(Value::Array(types)) => {
+                let mut alternatives = Vec::new();
+                for t in types {
+                    let Some(t) = t.as_str() else {
+                        bail!("invalid type list at {}", name);
+                    };
+                    alternatives.push(self.visit_type(schema, t, &format!("{}-{}", name, t))?);
+                }
+                if alternatives.is_empty() {
+                    bail!("empty type list at {}", name);
+                }
+                Ok(self.add_rule(name, alternatives.join(" | ")))
+            }
+            Some(other) => bail!("invalid type {} at {}", other, name),
+            // Untyped schemas allow any value, unless they describe an object
+            None if schema.get("properties").is_some() => self.visit_type(schema, "object", name),
+            None => Ok("value".to_string()),
+        }
+    }
+
+    fn visit_type(&mut self, schema: &Value, value_type: &str, name: &str) -> Result<String> {
+        match value_type {
+            "string" | "number" | "integer" | "boolean" | "null" => Ok(value_type.to_string()),
+            "array" => {
+                let item = match schema.get("items") {
+                    Some(items) => self.visit(items, &format!("{}-item", name))?,
+                    None => "value".to_string(),
+                };
+                let body = format!(
+                    r#""[" space ({item} ("," space {item})*)? "]" space"#,
+                    item = item
+                );
+                Ok(self.add_rule(name, body))
+            }
+            "object" => {
+                let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
+                    return Ok("object".to_string());
+                };
+                // Object keys may arrive sorted, so the order comes from `required`
+                let required: Vec<&str> = schema
+                    .get("required")
+                    .and_then(Value::as_array)
+                    .map(|keys| keys.iter().filter_map(Value::as_str).collect())
+                    .unwrap_or_default();
+                let mut keys: Vec<&String> = properties.keys().collect();
+                keys.sort_by_key(|key| {
+                    required
+                        .iter()
+                        .position(|r| *r == key.as_str())
+                        .unwrap_or(required.len())
+                });
+
+                let mut members = Vec::new();
+                for key in keys {
+                    let property = &properties[key];
+                    let value = self.visit(property, &format!("{}-{}", name, rule_name(key)))?;
+                    members.push(format!(
+                        r#"{} space ":" space {}"#,
+                        literal(&serde_json::to_string(key)?),
+                        value
+                    ));
+                }
+                let body = if members.is_empty() {
+                    r#""{" space "}" space"#.to_string()
+                } else {
+                    format!(
+                        r#""{{" space {} "}}" space"#,
+                        members.join(r#" "," space "#)
+                    )
+                };
+                Ok(self.add_rule(name, body))
+            }
+            other => bail!("unsupported type '{}' at {}", other, name),
+        }
+    }
+}
+
+/// Rule name fragment for a property key; GBNF names allow letters, digits and dashes
+fn rule_name(key: &str) -> String {
+    let name: String = key
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    if name.is_empty() {
+        "property".to_string()
+    } else {
+        name
+    }
+}
+
+/// GBNF string literal matching `text` exactly
+fn literal(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+    escaped.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_object_schema_to_grammar() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "title": { "type": "string" },
+                "action_items": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "text": { "type": "string" },
+                            "owner": { "type": ["string", "null"] }
+                        },
+                        "required": ["text"]
+                    }
+                }
+            },
+            "required": ["title", "action_items"]
+        });
+
+        let grammar = json_schema_to_grammar(&schema).unwrap();
+        let rules: Vec<&str> = grammar.lines().take(4).collect();
+        assert_eq!(
+            rules,
+            vec![
+                r#"root-action-items-item-owner ::= string | null"#,
+                r#"root-action-items-item ::= "{" space "\"text\"" space ":" space string "," space "\"owner\"" space ":" space root-action-items-item-owner "}" space"#,
+                r#"root-action-items ::= "[" space (root-action-items-item ("," space root-action-items-item)*)? "]" space"#,
+                r#"root ::= "{" space "\"title\"" space ":" space string "," space "\"action_items\"" space ":" space root-action-items "}" space"#,
+            ]
+        );
+        assert!(grammar.contains("\nstring ::= "));
+    }
+
+    #[test]
+    fn test_primitive_root_and_enums() {
+        let grammar = json_schema_to_grammar(&json!({ "type": "integer" })).unwrap();
+        assert!(grammar.starts_with("root ::= integer\n"));
+
+        let grammar = json_schema_to_grammar(&json!({ "enum": ["a \"b\"", 1] })).unwrap();
+        assert!(grammar.starts_with(r#"root ::= "\"a \\\"b\\\"\"" space | "1" space"#));
+    }
+
+    #[test]
+    fn test_unsupported_schemas_are_rejected() {
+        assert!(json_schema_to_grammar(&json!({ "type": "date" })).is_err());
+        assert!(json_schema_to_grammar(&json!({ "type": [] })).is_err());
+    }
+}
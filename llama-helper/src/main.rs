@@ -15,6 +15,9 @@ use llama_cpp_2::model::params::LlamaModelParams;
 use llama_cpp_2::model::{AddBos, LlamaModel, Special};
 use serde::{Deserialize, Serialize};
 
+mod gguf;
+mod grammar;
+
 // ============================================================================
 // Protocol Messages (JSON over stdin/stdout)
 // ============================================================================
@@ -32,6 +35,20 @@ enum Request {
         top_k: Option<i32>,
         top_p: Option<f32>,
         stop_tokens: Option<Vec<String>>,
+        // Emit a `token` message for each piece of generated text before the final response
+        #[serde(default)]
+        stream: bool,
+        /// GBNF grammar the output must match (start rule `root`)
+        grammar: Option<String>,
+        /// JSON schema the output must match; converted to a grammar, ignored if
+        /// `grammar` is set
+        json_schema: Option<serde_json::Value>,
+    },
+    /// Count the tokens of each text with the model's own vocabulary
+    Tokenize {
+        texts: Vec<String>,
+        context_size: Option<u32>,
+        model_path: Option<String>,
     },
     Ping,
     Shutdown,
@@ -41,6 +58,8 @@ enum Request {
 #[serde(tag = "type", rename_all = "snake_case")]
 enum Response {
     Response { text: String, error: Option<String> },
+    Token { text: String },
+    TokenCounts { counts: Vec<usize>, error: Option<String> },
     Pong,
     Goodbye,
     Error { message: String },
@@ -184,17 +203,38 @@ fn calculate_gpu_layers(
 /// Get default GPU layer count with smart detection
 fn get_default_gpu_layers(model_path: &PathBuf, context_size: u32) -> u32 {
     let vram = detect_vram_gb();
-    // TODO: Use actual model metadata instead of heuristics
-    // Heuristic: Estimate total layers based on file size
+
+    // Layer count from the GGUF metadata, else estimated from the file size:
     // 7B models (Q4) are ~4.1GB and have ~32-35 layers
     // 1B models (Q4) are ~1.1GB and have ~20-28 layers
-    let file_size_gb = std::fs::metadata(model_path)
-        .map(|m| m.len() as f32 / 1024.0 / 1024.0 / 1024.0)
-        .unwrap_or(0.0);
+    let metadata_layers = gguf::read_metadata(model_path)
+        .ok()
+        .and_then(|metadata| metadata.block_count)
+        .filter(|&layers| layers > 0);
+    let model_layers = match metadata_layers {
+        Some(layers) => layers as u32,
+        None => {
+            let file_size_gb = std::fs::metadata(model_path)
+                .map(|m| m.len() as f32 / 1024.0 / 1024.0 / 1024.0)
+                .unwrap_or(0.0);
+            if file_size_gb > 2.5 {
+                33
+            } else {
+                28
+            }
+        }
+    };
 
-    let estimated_layers = if file_size_gb > 2.5 { 33 } else { 28 };
+    calculate_gpu_layers(model_path, model_layers, vram, context_size)
+}
 
-    calculate_gpu_layers(model_path, estimated_layers, vram, context_size)
+/// `llama-helper --inspect <model.gguf>`: print the model's GGUF metadata as one JSON
+/// line and exit, without loading the model
+fn inspect(model_path: &str) -> Result<()> {
+    let metadata = gguf::read_metadata(&PathBuf::from(model_path))?;
+    println!("{}", serde_json::to_string(&metadata)?);
+    io::stdout().flush()?;
+    Ok(())
 }
 
 // ============================================================================
@@ -268,6 +308,22 @@ impl ModelState {
         Ok(())
     }
 
+    /// Number of tokens in each text, without the BOS token added to prompts
+    fn count_tokens(&self, texts: &[String]) -> Result<Vec<usize>> {
+        let model = self.model.as_ref().context("Model not loaded")?;
+        let counts = texts
+            .iter()
+            .map(|text| {
+                model
+                    .str_to_token(text, AddBos::Never)
+                    .map(|tokens| tokens.len())
+                    .with_context(|| "failed to tokenize text")
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.update_activity();
+        Ok(counts)
+    }
+
     fn generate(
         &mut self,
         prompt: String,
@@ -276,6 +332,8 @@ impl ModelState {
         top_k: i32,
         top_p: f32,
         stop_tokens: Vec<String>,
+        grammar: Option<&str>,
+        on_token: &mut dyn FnMut(&str) -> Result<()>,
     ) -> Result<String> {
         let start_time = Instant::now();
         let model = self.model.as_ref().context("Model not loaded")?;
@@ -326,8 +384,46 @@ impl ModelState {
         let mut n_cur = n_prompt_tokens;
         let mut decoder = encoding_rs::UTF_8.new_decoder();
         let mut output = String::new();
+        // Text already passed to `on_token`. The last `holdback` bytes are withheld until
+        // they can no longer turn out to be the start of a stop token.
+        let mut emitted = 0;
+        let holdback = stop_tokens.iter().map(|t| t.len()).max().unwrap_or(0);
+
+        use llama_cpp_2::sampling::LlamaSampler;
+
+        // The sampler lives for the whole generation: a grammar tracks what has been
+        // generated so far. It goes first so the other samplers only see allowed tokens.
+        let mut samplers = Vec::new();
+        if let Some(grammar) = grammar {
+            samplers.push(
+                LlamaSampler::grammar(model, grammar, "root").context("invalid grammar")?,
+            );
+        }
+        if temperature <= 0.0 {
+            // Greedy sampling for temp <= 0
+            samplers.push(LlamaSampler::greedy());
+        } else {
+            // Random sampling with temperature/top_k/top_p
+            let seed = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u32;
+
+            samplers.extend([
+                LlamaSampler::top_k(top_k),
+                LlamaSampler::top_p(top_p, 1),
+                LlamaSampler::temp(temperature),
+                LlamaSampler::dist(seed),
+            ]);
+        }
+        let sampler = LlamaSampler::chain_simple(samplers);
+        let mut sampler = pin!(sampler);
 
-        eprintln!("🔄 Starting generation (max_tokens: {})", max_tokens);
+        eprintln!(
+            "🔄 Starting generation (max_tokens: {}, grammar: {})",
+            max_tokens,
+            grammar.is_some()
+        );
 
         loop {
             // Check if we've generated enough tokens
@@ -336,29 +432,8 @@ impl ModelState {
                 break;
             }
 
-            use llama_cpp_2::sampling::LlamaSampler;
-
-            let sampler = if temperature <= 0.0 {
-                // Greedy sampling for temp <= 0
-                LlamaSampler::chain_simple([LlamaSampler::greedy()])
-            } else {
-                // Random sampling with temperature/top_k/top_p
-                let seed = SystemTime::now()
-                    .duration_since(UNIX_EPOCH)
-                    .unwrap_or_default()
-                    .as_millis() as u32;
-
-                LlamaSampler::chain_simple([
-                    LlamaSampler::top_k(top_k),
-                    LlamaSampler::top_p(top_p, 1),
-                    LlamaSampler::temp(temperature),
-                    LlamaSampler::dist(seed),
-                ])
-            };
-
-            let mut sampler = pin!(sampler);
+            // Samples and accepts the token, which advances the grammar
             let token = sampler.as_mut().sample(&ctx, batch.n_tokens() - 1);
-            sampler.as_mut().accept(token);
 
             if model.is_eog_token(token) {
                 eprintln!(
@@ -395,6 +470,15 @@ impl ModelState {
                 break;
             }
 
+            let mut safe_end = output.len().saturating_sub(holdback);
+            while !output.is_char_boundary(safe_end) {
+                safe_end -= 1;
+            }
+            if safe_end > emitted {
+                on_token(&output[emitted..safe_end])?;
+                emitted = safe_end;
+            }
+
             batch.clear();
             batch
                 .add(token, n_cur, &[0], true)
@@ -403,6 +487,10 @@ impl ModelState {
             ctx.decode(&mut batch).context("failed to eval")?;
         }
 
+        if let Some(rest) = output.get(emitted..).filter(|rest| !rest.is_empty()) {
+            on_token(rest)?;
+        }
+
         // Generation statistics
         let total_time = start_time.elapsed();
         let gen_time = total_time.saturating_sub(prompt_time);
@@ -440,6 +528,13 @@ fn send_response(response: &Response) -> Result<()> {
 }
 
 fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().collect();
+    if let [_, flag, model_path] = args.as_slice() {
+        if flag == "--inspect" {
+            return inspect(model_path);
+        }
+    }
+
     // Get idle timeout from environment variable (default 5 minutes)
     let idle_timeout_secs = std::env::var("LLAMA_IDLE_TIMEOUT")
         .ok()
@@ -490,6 +585,9 @@ fn main() -> Result<()> {
                         top_k,
                         top_p,
                         stop_tokens,
+                        stream,
+                        grammar,
+                        json_schema,
                     }) => {
                         let max_tokens = max_tokens.unwrap_or(512);
                         let context_size = context_size.unwrap_or(2048);
@@ -500,6 +598,22 @@ fn main() -> Result<()> {
                         let top_p = top_p.unwrap_or(0.95);
                         let stop_tokens = stop_tokens.unwrap_or_else(Vec::new);
 
+                        let grammar = match (grammar, json_schema) {
+                            (Some(grammar), _) => Some(grammar),
+                            (None, Some(schema)) => match grammar::json_schema_to_grammar(&schema)
+                            {
+                                Ok(grammar) => Some(grammar),
+                                Err(e) => {
+                                    send_response(&Response::Response {
+                                        text: String::new(),
+                                        error: Some(format!("Unsupported JSON schema: {}", e)),
+                                    })?;
+                                    continue;
+                                }
+                            },
+                            (None, None) => None,
+                        };
+
                         // Load model if path provided
                         if let Some(path_str) = model_path {
                             let path = PathBuf::from(path_str);
@@ -512,6 +626,15 @@ fn main() -> Result<()> {
                             }
                         }
 
+                        let mut on_token = |text: &str| -> Result<()> {
+                            if stream {
+                                send_response(&Response::Token {
+                                    text: text.to_string(),
+                                })?;
+                            }
+                            Ok(())
+                        };
+
                         // Generate response with sampling parameters
                         match state.generate(
                             prompt,
@@ -520,6 +643,8 @@ fn main() -> Result<()> {
                             top_k,
                             top_p,
                             stop_tokens,
+                            grammar.as_deref(),
+                            &mut on_token,
                         ) {
                             Ok(text) => {
                                 send_response(&Response::Response { text, error: None })?;
@@ -532,6 +657,36 @@ fn main() -> Result<()> {
                             }
                         }
                     }
+                    Ok(Request::Tokenize {
+                        texts,
+                        context_size,
+                        model_path,
+                    }) => {
+                        // Same defaults as generation, so the loaded model is reused
+                        let context_size = context_size.unwrap_or(state.context_size);
+                        if let Some(path_str) = model_path {
+                            let path = PathBuf::from(path_str);
+                            if let Err(e) = state.load_model_if_needed(path, context_size) {
+                                send_response(&Response::TokenCounts {
+                                    counts: Vec::new(),
+                                    error: Some(format!("Failed to load model: {}", e)),
+                                })?;
+                                continue;
+                            }
+                        }
+
+                        match state.count_tokens(&texts) {
+                            Ok(counts) => {
+                                send_response(&Response::TokenCounts { counts, error: None })?;
+                            }
+                            Err(e) => {
+                                send_response(&Response::TokenCounts {
+                                    counts: Vec::new(),
+                                    error: Some(format!("Tokenization failed: {}", e)),
+                                })?;
+                            }
+                        }
+                    }
                     Ok(Request::Ping) => {
                         state.update_activity();
                         send_response(&Response::Pong)?;